@@ -38,6 +38,13 @@ CREATE TABLE payment_attempt_queue (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `card_network` Nullable(String),
+    `card_issuing_country` Nullable(String),
+    `fx_quote_id` Nullable(String),
+    `presentment_amount` Nullable(UInt64),
+    `presentment_currency` Nullable(String),
+    `fx_rate` Nullable(String),
+    `fx_markup_bps` Nullable(UInt32),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -85,13 +92,21 @@ CREATE TABLE payment_attempts (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `card_network` LowCardinality(Nullable(String)),
+    `card_issuing_country` LowCardinality(Nullable(String)),
+    `fx_quote_id` Nullable(String),
+    `presentment_amount` Nullable(UInt64),
+    `presentment_currency` LowCardinality(Nullable(String)),
+    `fx_rate` Nullable(String),
+    `fx_markup_bps` Nullable(UInt32),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
     INDEX authenticationTypeIndex authentication_type TYPE bloom_filter GRANULARITY 1,
     INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1,
-    INDEX statusIndex status TYPE bloom_filter GRANULARITY 1
+    INDEX statusIndex status TYPE bloom_filter GRANULARITY 1,
+    INDEX errorCodeIndex error_code TYPE bloom_filter GRANULARITY 1
 ) ENGINE = CollapsingMergeTree(sign_flag) PARTITION BY toStartOfDay(created_at)
 ORDER BY
     (created_at, merchant_id, attempt_id) TTL created_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
@@ -136,6 +151,13 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `unified_code` Nullable(String),
     `unified_message` Nullable(String),
     `mandate_data` Nullable(String),
+    `card_network` Nullable(String),
+    `card_issuing_country` Nullable(String),
+    `fx_quote_id` Nullable(String),
+    `presentment_amount` Nullable(UInt64),
+    `presentment_currency` Nullable(String),
+    `fx_rate` Nullable(String),
+    `fx_markup_bps` Nullable(UInt32),
     `inserted_at` DateTime64(3),
     `sign_flag` Int8
 ) AS
@@ -179,6 +201,13 @@ SELECT
     unified_code,
     unified_message,
     mandate_data,
+    card_network,
+    card_issuing_country,
+    fx_quote_id,
+    presentment_amount,
+    presentment_currency,
+    fx_rate,
+    fx_markup_bps,
     now() AS inserted_at,
     sign_flag
 FROM
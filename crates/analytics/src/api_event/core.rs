@@ -152,6 +152,9 @@ pub async fn get_api_event_metrics(
                     data.api_count = data.api_count.or(value.api_count);
                     data.status_code_count = data.status_code_count.or(value.status_code_count);
                     data.latency = data.latency.or(value.latency);
+                    data.latency_p50 = data.latency_p50.or(value.latency_p50);
+                    data.latency_p90 = data.latency_p90.or(value.latency_p90);
+                    data.latency_p99 = data.latency_p99.or(value.latency_p99);
                 })
                 .or_insert(value);
         }
@@ -164,6 +167,9 @@ pub async fn get_api_event_metrics(
                 latency: val.latency,
                 api_count: val.api_count,
                 status_code_count: val.status_code_count,
+                latency_p50: val.latency_p50,
+                latency_p90: val.latency_p90,
+                latency_p99: val.latency_p99,
             },
             dimensions: id,
         })
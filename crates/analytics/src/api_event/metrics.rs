@@ -13,18 +13,26 @@ use crate::{
 
 mod api_count;
 pub mod latency;
+mod latency_percentiles;
 mod status_code_count;
 use api_count::ApiCount;
 use latency::MaxLatency;
+use latency_percentiles::LatencyPercentiles;
 use status_code_count::StatusCodeCount;
 
 use self::latency::LatencyAvg;
 
-#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize)]
 pub struct ApiEventMetricRow {
+    pub status_code: Option<u16>,
+    pub flow_type: Option<String>,
+    pub api_flow: Option<String>,
     pub latency: Option<u64>,
     pub api_count: Option<u64>,
     pub status_code_count: Option<u64>,
+    pub latency_p50: Option<f64>,
+    pub latency_p90: Option<f64>,
+    pub latency_p99: Option<f64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -105,6 +113,18 @@ where
                     )
                     .await
             }
+            Self::LatencyPercentiles => {
+                LatencyPercentiles
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
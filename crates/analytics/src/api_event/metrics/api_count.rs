@@ -27,7 +27,7 @@ where
 {
     async fn load_metrics(
         &self,
-        _dimensions: &[ApiEventDimensions],
+        dimensions: &[ApiEventDimensions],
         merchant_id: &str,
         filters: &ApiEventFilters,
         granularity: &Option<Granularity>,
@@ -36,6 +36,10 @@ where
     ) -> MetricsResult<Vec<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
         let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::ApiEvents);
 
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
         query_builder
             .add_select_column(Aggregate::Count {
                 field: None,
@@ -60,6 +64,13 @@ where
                 alias: Some("end_bucket"),
             })
             .switch()?;
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
         if let Some(granularity) = granularity.as_ref() {
             granularity
                 .set_group_by_clause(&mut query_builder)
@@ -84,16 +95,21 @@ where
             .into_iter()
             .map(|i| {
                 Ok((
-                    ApiEventMetricsBucketIdentifier::new(TimeRange {
-                        start_time: match (granularity, i.start_bucket) {
-                            (Some(g), Some(st)) => g.clip_to_start(st)?,
-                            _ => time_range.start_time,
+                    ApiEventMetricsBucketIdentifier::new(
+                        i.status_code,
+                        i.flow_type.clone(),
+                        i.api_flow.clone(),
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
                         },
-                        end_time: granularity.as_ref().map_or_else(
-                            || Ok(time_range.end_time),
-                            |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
-                        )?,
-                    }),
+                    ),
                     i,
                 ))
             })
@@ -30,7 +30,7 @@ where
 {
     async fn load_metrics(
         &self,
-        _dimensions: &[ApiEventDimensions],
+        dimensions: &[ApiEventDimensions],
         merchant_id: &str,
         filters: &ApiEventFilters,
         granularity: &Option<Granularity>,
@@ -39,6 +39,10 @@ where
     ) -> MetricsResult<Vec<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
         let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::ApiEvents);
 
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
         query_builder
             .add_select_column(Aggregate::Sum {
                 field: "latency",
@@ -65,6 +69,13 @@ where
                 alias: Some("end_bucket"),
             })
             .switch()?;
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
         if let Some(granularity) = granularity.as_ref() {
             granularity
                 .set_group_by_clause(&mut query_builder)
@@ -96,17 +107,25 @@ where
             .into_iter()
             .map(|i| {
                 Ok((
-                    ApiEventMetricsBucketIdentifier::new(TimeRange {
-                        start_time: match (granularity, i.start_bucket) {
-                            (Some(g), Some(st)) => g.clip_to_start(st)?,
-                            _ => time_range.start_time,
+                    ApiEventMetricsBucketIdentifier::new(
+                        i.status_code,
+                        i.flow_type.clone(),
+                        i.api_flow.clone(),
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
                         },
-                        end_time: granularity.as_ref().map_or_else(
-                            || Ok(time_range.end_time),
-                            |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
-                        )?,
-                    }),
+                    ),
                     ApiEventMetricRow {
+                        status_code: i.status_code,
+                        flow_type: i.flow_type,
+                        api_flow: i.api_flow,
                         latency: if i.latency_count != 0 {
                             Some(i.latency_sum.unwrap_or(0) / i.latency_count)
                         } else {
@@ -114,6 +133,9 @@ where
                         },
                         api_count: None,
                         status_code_count: None,
+                        latency_p50: None,
+                        latency_p90: None,
+                        latency_p99: None,
                         start_bucket: i.start_bucket,
                         end_bucket: i.end_bucket,
                     },
@@ -129,6 +151,9 @@ where
 
 #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
 pub struct LatencyAvg {
+    status_code: Option<u16>,
+    flow_type: Option<String>,
+    api_flow: Option<String>,
     latency_sum: Option<u64>,
     latency_count: u64,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -23,7 +23,7 @@ use crate::{
         filters::ApiEventFilter,
         metrics::{latency::LatencyAvg, ApiEventMetricRow},
     },
-    connector_events::events::ConnectorEventsResult,
+    connector_events::{events::ConnectorEventsResult, metrics::ConnectorEventMetricRow},
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
     sdk_events::events::SdkEventsResult,
@@ -128,6 +128,7 @@ impl AnalyticsDataSource for ClickhouseClient {
     fn get_table_engine(table: AnalyticsCollection) -> TableEngine {
         match table {
             AnalyticsCollection::Payment
+            | AnalyticsCollection::EnrichedPayment
             | AnalyticsCollection::Refund
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::Dispute => {
@@ -165,6 +166,7 @@ impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
 impl super::connector_events::events::ConnectorEventLogAnalytics for ClickhouseClient {}
+impl super::connector_events::metrics::ConnectorEventMetricAnalytics for ClickhouseClient {}
 impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
     for ClickhouseClient
 {
@@ -214,6 +216,16 @@ impl TryInto<ConnectorEventsResult> for serde_json::Value {
     }
 }
 
+impl TryInto<ConnectorEventMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorEventMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorEventMetricRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<PaymentMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -370,7 +382,9 @@ impl ToSql<ClickhouseClient> for PrimitiveDateTime {
 impl ToSql<ClickhouseClient> for AnalyticsCollection {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         match self {
-            Self::Payment => Ok("payment_attempts".to_string()),
+            // Card details and unified error details are already part of the payment attempt
+            // events, the unified details being resolved from the GSM rules by the router
+            Self::Payment | Self::EnrichedPayment => Ok("payment_attempts".to_string()),
             Self::Refund => Ok("refunds".to_string()),
             Self::SdkEvents => Ok("sdk_events_audit".to_string()),
             Self::ApiEvents => Ok("api_events_audit".to_string()),
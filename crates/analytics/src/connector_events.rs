@@ -1,5 +1,7 @@
 mod core;
 pub mod events;
+pub mod metrics;
+pub mod types;
 pub trait ConnectorEventAnalytics: events::ConnectorEventLogAnalytics {}
 
-pub use self::core::connector_events_core;
+pub use self::core::{connector_events_core, get_connector_event_metrics};
@@ -1,9 +1,29 @@
-use api_models::analytics::connector_events::ConnectorEventsRequest;
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventMetricsBucketIdentifier, ConnectorEventMetricsBucketResponse,
+        ConnectorEventMetricsBucketValue, ConnectorEventsRequest,
+    },
+    AnalyticsMetadata, GetConnectorEventMetricRequest, MetricsResponse,
+};
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
 
-use super::events::{get_connector_events, ConnectorEventsResult};
-use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+use super::{
+    events::{get_connector_events, ConnectorEventsResult},
+    metrics::ConnectorEventMetricRow,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    types::FiltersError,
+    AnalyticsProvider,
+};
 
 pub async fn connector_events_core(
     pool: &AnalyticsProvider,
@@ -24,3 +44,230 @@ pub async fn connector_events_core(
     .switch()?;
     Ok(data)
 }
+
+/// Connector events are only stored in ClickHouse, so these metrics are not available when
+/// analytics is served from Postgres alone.
+#[instrument(skip_all)]
+pub async fn get_connector_event_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &str,
+    req: GetConnectorEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<ConnectorEventMetricsBucketResponse>> {
+    if let AnalyticsProvider::Sqlx(_) = pool {
+        return Err(AnalyticsError::NotImplemented(
+            "Connector Event metrics not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Connector Event metrics");
+    }
+
+    let mut metrics_accumulator: HashMap<
+        ConnectorEventMetricsBucketIdentifier,
+        ConnectorEventMetricRow,
+    > = HashMap::new();
+
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_connector_event_metrics_query",
+            connector_event_metric = metric_type.as_ref()
+        );
+
+        // TODO: lifetime issues with joinset,
+        // can be optimized away if joinset lifetime requirements are relaxed
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_connector_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = &[
+            metrics::request::add_attributes("metric_type", metric.to_string()),
+            metrics::request::add_attributes("source", pool.to_string()),
+        ];
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(&metrics::CONTEXT, val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+        for (id, value) in data {
+            accumulate_metric_row(&mut metrics_accumulator, id, value);
+        }
+    }
+
+    let query_data: Vec<ConnectorEventMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| ConnectorEventMetricsBucketResponse {
+            values: ConnectorEventMetricsBucketValue {
+                request_count: val.request_count.and_then(|i| u64::try_from(i).ok()),
+                latency_p50: val.latency_p50,
+                latency_p90: val.latency_p90,
+                latency_p99: val.latency_p99,
+            },
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
+
+/// Merges the row of a metric into the bucket it belongs to, since each metric query only
+/// populates the columns of that metric.
+fn accumulate_metric_row(
+    metrics_accumulator: &mut HashMap<
+        ConnectorEventMetricsBucketIdentifier,
+        ConnectorEventMetricRow,
+    >,
+    id: ConnectorEventMetricsBucketIdentifier,
+    value: ConnectorEventMetricRow,
+) {
+    metrics_accumulator
+        .entry(id)
+        .and_modify(|data| {
+            data.request_count = data.request_count.or(value.request_count);
+            data.latency_p50 = data.latency_p50.or(value.latency_p50);
+            data.latency_p90 = data.latency_p90.or(value.latency_p90);
+            data.latency_p99 = data.latency_p99.or(value.latency_p99);
+        })
+        .or_insert(value);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use api_models::analytics::{connector_events::ConnectorEventMetrics, TimeRange};
+
+    use super::*;
+    use crate::{sqlx::SqlxClient, types::MetricsError};
+
+    fn metric_row(
+        request_count: Option<i64>,
+        latency_p50: Option<f64>,
+        latency_p99: Option<f64>,
+    ) -> ConnectorEventMetricRow {
+        ConnectorEventMetricRow {
+            connector_name: Some("stripe".to_string()),
+            flow: Some("Authorize".to_string()),
+            status_code: Some(200),
+            request_count,
+            latency_p50,
+            latency_p90: None,
+            latency_p99,
+            start_bucket: None,
+            end_bucket: None,
+        }
+    }
+
+    fn bucket_identifier(time_range: TimeRange) -> ConnectorEventMetricsBucketIdentifier {
+        ConnectorEventMetricsBucketIdentifier::new(
+            Some("stripe".to_string()),
+            Some("Authorize".to_string()),
+            Some(200),
+            time_range,
+        )
+    }
+
+    #[test]
+    fn test_accumulate_metric_row_merges_metrics_of_same_bucket() {
+        let mut metrics_accumulator = HashMap::new();
+        let time_range = TimeRange {
+            start_time: common_utils::date_time::now(),
+            end_time: None,
+        };
+
+        accumulate_metric_row(
+            &mut metrics_accumulator,
+            bucket_identifier(time_range),
+            metric_row(Some(42), None, None),
+        );
+        accumulate_metric_row(
+            &mut metrics_accumulator,
+            bucket_identifier(time_range),
+            metric_row(None, Some(120.0), Some(950.0)),
+        );
+
+        assert_eq!(metrics_accumulator.len(), 1);
+        let row = metrics_accumulator
+            .get(&bucket_identifier(time_range))
+            .unwrap();
+        assert_eq!(row.request_count, Some(42));
+        assert_eq!(row.latency_p50, Some(120.0));
+        assert_eq!(row.latency_p99, Some(950.0));
+    }
+
+    #[tokio::test]
+    async fn test_connector_event_metrics_are_not_implemented_for_sqlx() {
+        let pool = AnalyticsProvider::Sqlx(SqlxClient::default());
+
+        let result = pool
+            .get_connector_event_metrics(
+                &ConnectorEventMetrics::LatencyPercentiles,
+                &[],
+                "merchant_1",
+                &Default::default(),
+                &None,
+                &TimeRange {
+                    start_time: common_utils::date_time::now(),
+                    end_time: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result.as_ref().map_err(|error| error.current_context()),
+            Err(MetricsError::NotImplemented)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_connector_event_metrics_request_is_rejected_for_sqlx() {
+        let pool = AnalyticsProvider::Sqlx(SqlxClient::default());
+        let req = GetConnectorEventMetricRequest {
+            time_series: None,
+            time_range: TimeRange {
+                start_time: common_utils::date_time::now(),
+                end_time: None,
+            },
+            group_by_names: vec![],
+            filters: Default::default(),
+            metrics: [ConnectorEventMetrics::LatencyPercentiles].into(),
+            delta: false,
+        };
+
+        let result = get_connector_event_metrics(&pool, "merchant_1", req).await;
+
+        assert!(matches!(
+            result.as_ref().map_err(|error| error.current_context()),
+            Err(AnalyticsError::NotImplemented(_))
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+use api_models::analytics::{
+    connector_events::{
+        ConnectorEventDimensions, ConnectorEventFilters, ConnectorEventMetrics,
+        ConnectorEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+mod latency_percentiles;
+mod request_count;
+
+use latency_percentiles::LatencyPercentiles;
+use request_count::RequestCount;
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+pub struct ConnectorEventMetricRow {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub status_code: Option<u16>,
+    pub request_count: Option<i64>,
+    pub latency_p50: Option<f64>,
+    pub latency_p90: Option<f64>,
+    pub latency_p99: Option<f64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait ConnectorEventMetricAnalytics: LoadRow<ConnectorEventMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait ConnectorEventMetric<T>
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &str,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        Vec<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> ConnectorEventMetric<T> for ConnectorEventMetrics
+where
+    T: AnalyticsDataSource + ConnectorEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &str,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        Vec<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::RequestCount => {
+                RequestCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::LatencyPercentiles => {
+                LatencyPercentiles
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -31,6 +31,11 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_api_event_dimensions(),
         },
+        AnalyticsDomain::ConnectorEvents => GetInfoResponse {
+            metrics: utils::get_connector_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_connector_event_dimensions(),
+        },
         AnalyticsDomain::Dispute => GetInfoResponse {
             metrics: utils::get_dispute_metrics_info(),
             download_dimensions: None,
@@ -36,6 +36,10 @@ use api_models::analytics::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
     auth_events::{AuthEventMetrics, AuthEventMetricsBucketIdentifier},
+    connector_events::{
+        ConnectorEventDimensions, ConnectorEventFilters, ConnectorEventMetrics,
+        ConnectorEventMetricsBucketIdentifier,
+    },
     disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
@@ -57,6 +61,7 @@ use strum::Display;
 
 use self::{
     auth_events::metrics::{AuthEventMetric, AuthEventMetricRow},
+    connector_events::metrics::{ConnectorEventMetric, ConnectorEventMetricRow},
     payments::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
@@ -601,6 +606,41 @@ impl AnalyticsProvider {
         }
     }
 
+    /// Connector events are only stored in ClickHouse, there are no sqlx queries for them.
+    pub async fn get_connector_event_metrics(
+        &self,
+        metric: &ConnectorEventMetrics,
+        dimensions: &[ConnectorEventDimensions],
+        merchant_id: &str,
+        filters: &ConnectorEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        Vec<(
+            ConnectorEventMetricsBucketIdentifier,
+            ConnectorEventMetricRow,
+        )>,
+    > {
+        match self {
+            Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+            Self::Clickhouse(ckh_pool)
+            | Self::CombinedCkh(_, ckh_pool)
+            | Self::CombinedSqlx(_, ckh_pool) => {
+                // Since connector events are ckh only use ckh here
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        ckh_pool,
+                    )
+                    .await
+            }
+        }
+    }
+
     pub async fn from_conf(config: &AnalyticsConfig) -> Self {
         match config {
             AnalyticsConfig::Sqlx { sqlx } => Self::Sqlx(SqlxClient::from_conf(sqlx).await),
@@ -726,11 +766,18 @@ pub enum AnalyticsFlow {
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
+    GetConnectorEventMetrics,
     GetOutgoingWebhookEvents,
     GetGlobalSearchResults,
     GetSearchResults,
     GetDisputeFilters,
     GetDisputeMetrics,
+    CreateAlertRule,
+    ListAlertRules,
+    RetrieveAlertRule,
+    UpdateAlertRule,
+    DeleteAlertRule,
+    ListAlerts,
 }
 
 impl FlowMetric for AnalyticsFlow {}
@@ -1,4 +1,6 @@
-use api_models::analytics::payments::{ErrorResult, PaymentMetricsBucketValue};
+use api_models::analytics::payments::{
+    ErrorResult, PaymentDistributions, PaymentMetricsBucketValue,
+};
 use bigdecimal::ToPrimitive;
 use diesel_models::enums as storage_enums;
 use router_env::logger;
@@ -16,6 +18,9 @@ pub struct PaymentMetricsAccumulator {
     pub retries_count: CountAccumulator,
     pub retries_amount_processed: SumAccumulator,
     pub connector_success_rate: SuccessRateAccumulator,
+    pub payment_failure_count: CountAccumulator,
+    pub payment_error_code: ErrorDistributionAccumulator,
+    pub payment_unified_message: ErrorDistributionAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -65,7 +70,11 @@ pub trait PaymentMetricAccumulator {
 pub trait PaymentDistributionAccumulator {
     type DistributionOutput;
 
-    fn add_distribution_bucket(&mut self, distribution: &PaymentDistributionRow);
+    fn add_distribution_bucket(
+        &mut self,
+        distribution_for: &PaymentDistributions,
+        distribution: &PaymentDistributionRow,
+    );
 
     fn collect(self) -> Self::DistributionOutput;
 }
@@ -73,7 +82,17 @@ pub trait PaymentDistributionAccumulator {
 impl PaymentDistributionAccumulator for ErrorDistributionAccumulator {
     type DistributionOutput = Option<Vec<ErrorResult>>;
 
-    fn add_distribution_bucket(&mut self, distribution: &PaymentDistributionRow) {
+    fn add_distribution_bucket(
+        &mut self,
+        distribution_for: &PaymentDistributions,
+        distribution: &PaymentDistributionRow,
+    ) {
+        let reason = match distribution_for {
+            PaymentDistributions::PaymentErrorMessage => &distribution.error_message,
+            PaymentDistributions::PaymentErrorCode => &distribution.error_code,
+            PaymentDistributions::PaymentUnifiedMessage => &distribution.unified_message,
+        };
+
         self.error_vec.push(ErrorDistributionRow {
             count: distribution.count.unwrap_or_default(),
             total: distribution
@@ -81,7 +100,7 @@ impl PaymentDistributionAccumulator for ErrorDistributionAccumulator {
                 .clone()
                 .map(|i| i.to_i64().unwrap_or_default())
                 .unwrap_or_default(),
-            error_message: distribution.error_message.clone().unwrap_or("".to_string()),
+            error_message: reason.clone().unwrap_or("".to_string()),
         })
     }
 
@@ -205,6 +224,78 @@ impl PaymentMetricsAccumulator {
             retries_count: self.retries_count.collect(),
             retries_amount_processed: self.retries_amount_processed.collect(),
             connector_success_rate: self.connector_success_rate.collect(),
+            payment_failure_count: self.payment_failure_count.collect(),
+            payment_error_code: self.payment_error_code.collect(),
+            payment_unified_message: self.payment_unified_message.collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::indexing_slicing, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn distribution_row(
+        error_code: &str,
+        error_message: &str,
+        count: i64,
+        total: i64,
+    ) -> PaymentDistributionRow {
+        PaymentDistributionRow {
+            currency: None,
+            status: None,
+            connector: None,
+            authentication_type: None,
+            payment_method: None,
+            payment_method_type: None,
+            total: Some(bigdecimal::BigDecimal::from(total)),
+            count: Some(count),
+            error_code: Some(error_code.to_string()),
+            error_message: Some(error_message.to_string()),
+            unified_code: None,
+            unified_message: None,
+            card_network: None,
+            card_issuing_country: None,
+            start_bucket: None,
+            end_bucket: None,
         }
     }
+
+    #[test]
+    fn test_error_distribution_is_keyed_on_distribution_for() {
+        let mut accumulator = ErrorDistributionAccumulator::default();
+        accumulator.add_distribution_bucket(
+            &PaymentDistributions::PaymentErrorCode,
+            &distribution_row("05", "Do not honor", 1, 4),
+        );
+        accumulator.add_distribution_bucket(
+            &PaymentDistributions::PaymentErrorCode,
+            &distribution_row("51", "Insufficient funds", 3, 4),
+        );
+
+        let result = accumulator.collect().unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].reason, "51");
+        assert_eq!(result[0].count, 3);
+        assert_eq!(result[0].percentage, 75.0);
+        assert_eq!(result[1].reason, "05");
+        assert_eq!(result[1].percentage, 25.0);
+    }
+
+    #[test]
+    fn test_error_distribution_without_value_for_distribution_for() {
+        let mut accumulator = ErrorDistributionAccumulator::default();
+        accumulator.add_distribution_bucket(
+            &PaymentDistributions::PaymentUnifiedMessage,
+            &distribution_row("05", "Do not honor", 2, 2),
+        );
+
+        let result = accumulator.collect().unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].reason, "");
+    }
 }
@@ -161,6 +161,9 @@ pub async fn get_metrics(
                                 .connector_success_rate
                                 .add_metrics_bucket(&value);
                         }
+                        PaymentMetrics::PaymentFailureCount => metrics_builder
+                            .payment_failure_count
+                            .add_metrics_bucket(&value),
                     }
                 }
 
@@ -189,7 +192,13 @@ pub async fn get_metrics(
                     match distribution {
                         PaymentDistributions::PaymentErrorMessage => metrics_accumulator
                             .payment_error_message
-                            .add_distribution_bucket(&value),
+                            .add_distribution_bucket(&distribution, &value),
+                        PaymentDistributions::PaymentErrorCode => metrics_accumulator
+                            .payment_error_code
+                            .add_distribution_bucket(&distribution, &value),
+                        PaymentDistributions::PaymentUnifiedMessage => metrics_accumulator
+                            .payment_unified_message
+                            .add_distribution_bucket(&distribution, &value),
                     }
                 }
 
@@ -291,6 +300,12 @@ pub async fn get_filters(
             PaymentDimensions::AuthType => fil.authentication_type.map(|i| i.as_ref().to_string()),
             PaymentDimensions::PaymentMethod => fil.payment_method,
             PaymentDimensions::PaymentMethodType => fil.payment_method_type,
+            PaymentDimensions::ErrorCode => fil.error_code,
+            PaymentDimensions::ErrorMessage => fil.error_message,
+            PaymentDimensions::UnifiedCode => fil.unified_code,
+            PaymentDimensions::UnifiedMessage => fil.unified_message,
+            PaymentDimensions::CardNetwork => fil.card_network,
+            PaymentDimensions::CardIssuingCountry => fil.card_issuing_country,
         })
         .collect::<Vec<String>>();
         res.query_data.push(FilterValue {
@@ -26,7 +26,12 @@ pub struct PaymentDistributionRow {
     pub payment_method_type: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
+    pub error_code: Option<String>,
     pub error_message: Option<String>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuing_country: Option<String>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -74,7 +79,9 @@ where
         pool: &T,
     ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>> {
         match self {
-            Self::PaymentErrorMessage => {
+            // The query is parameterised over `distribution_for`, so every error column
+            // distribution shares the same implementation
+            Self::PaymentErrorMessage | Self::PaymentErrorCode | Self::PaymentUnifiedMessage => {
                 PaymentErrorMessage
                     .load_distribution(
                         distribution,
@@ -1,14 +1,16 @@
 use api_models::analytics::{
-    payments::{PaymentDimensions, PaymentFilters, PaymentMetricsBucketIdentifier},
+    payments::{
+        PaymentDimensions, PaymentDistributions, PaymentFilters, PaymentMetricsBucketIdentifier,
+    },
     Distribution, Granularity, TimeRange,
 };
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums as storage_enums;
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
 use super::{PaymentDistribution, PaymentDistributionRow};
 use crate::{
+    payments::types::{get_payment_collection, FAILED_ATTEMPT_STATUSES},
     query::{
         Aggregate, GroupByClause, Order, QueryBuilder, QueryFilter, SeriesBucket, ToSql, Window,
     },
@@ -38,7 +40,13 @@ where
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>> {
-        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+        let collection = match distribution.distribution_for {
+            PaymentDistributions::PaymentUnifiedMessage => AnalyticsCollection::EnrichedPayment,
+            PaymentDistributions::PaymentErrorMessage | PaymentDistributions::PaymentErrorCode => {
+                get_payment_collection(dimensions, filters)
+            }
+        };
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
 
         for dim in dimensions.iter() {
             query_builder.add_select_column(dim).switch()?;
@@ -98,10 +106,7 @@ where
         }
 
         query_builder
-            .add_filter_clause(
-                PaymentDimensions::PaymentStatus,
-                storage_enums::AttemptStatus::Failure,
-            )
+            .add_filter_in_range_clause(PaymentDimensions::PaymentStatus, &FAILED_ATTEMPT_STATUSES)
             .switch()?;
 
         for dim in dimensions.iter() {
@@ -153,6 +158,20 @@ where
                         i.authentication_type.as_ref().map(|i| i.0),
                         i.payment_method.clone(),
                         i.payment_method_type.clone(),
+                        i.error_code
+                            .clone()
+                            .filter(|_| dimensions.contains(&PaymentDimensions::ErrorCode)),
+                        i.error_message
+                            .clone()
+                            .filter(|_| dimensions.contains(&PaymentDimensions::ErrorMessage)),
+                        i.unified_code
+                            .clone()
+                            .filter(|_| dimensions.contains(&PaymentDimensions::UnifiedCode)),
+                        i.unified_message
+                            .clone()
+                            .filter(|_| dimensions.contains(&PaymentDimensions::UnifiedMessage)),
+                        i.card_network.clone(),
+                        i.card_issuing_country.clone(),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -1,10 +1,14 @@
-use api_models::analytics::{payments::PaymentDimensions, Granularity, TimeRange};
+use api_models::analytics::{
+    payments::{PaymentDimensions, PaymentFilters},
+    Granularity, TimeRange,
+};
 use common_utils::errors::ReportSwitchExt;
 use diesel_models::enums::{AttemptStatus, AuthenticationType, Currency};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
 use crate::{
+    payments::types::get_payment_collection,
     query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
     types::{
         AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
@@ -28,7 +32,10 @@ where
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
-    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(get_payment_collection(
+        [&dimension],
+        &PaymentFilters::default(),
+    ));
 
     query_builder.add_select_column(dimension).switch()?;
     time_range
@@ -57,4 +64,10 @@ pub struct FilterRow {
     pub authentication_type: Option<DBEnumWrapper<AuthenticationType>>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuing_country: Option<String>,
 }
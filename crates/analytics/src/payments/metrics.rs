@@ -13,6 +13,7 @@ use crate::{
 mod avg_ticket_size;
 mod connector_success_rate;
 mod payment_count;
+mod payment_failure_count;
 mod payment_processed_amount;
 mod payment_success_count;
 mod retries_count;
@@ -21,6 +22,7 @@ mod success_rate;
 use avg_ticket_size::AvgTicketSize;
 use connector_success_rate::ConnectorSuccessRate;
 use payment_count::PaymentCount;
+use payment_failure_count::PaymentFailureCount;
 use payment_processed_amount::PaymentProcessedAmount;
 use payment_success_count::PaymentSuccessCount;
 use success_rate::PaymentSuccessRate;
@@ -35,6 +37,12 @@ pub struct PaymentMetricRow {
     pub authentication_type: Option<DBEnumWrapper<storage_enums::AuthenticationType>>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuing_country: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -165,6 +173,18 @@ where
                     )
                     .await
             }
+            Self::PaymentFailureCount => {
+                PaymentFailureCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
@@ -9,6 +9,7 @@ use time::PrimitiveDateTime;
 
 use super::PaymentMetricRow;
 use crate::{
+    payments::types::get_payment_collection,
     query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, SeriesBucket, ToSql, Window},
     types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
 };
@@ -35,7 +36,8 @@ where
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
-        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+        let mut query_builder: QueryBuilder<T> =
+            QueryBuilder::new(get_payment_collection(dimensions, filters));
 
         for dim in dimensions.iter() {
             query_builder.add_select_column(dim).switch()?;
@@ -107,6 +109,12 @@ where
                         i.authentication_type.as_ref().map(|i| i.0),
                         i.payment_method.clone(),
                         i.payment_method_type.clone(),
+                        i.error_code.clone(),
+                        i.error_message.clone(),
+                        i.unified_code.clone(),
+                        i.unified_message.clone(),
+                        i.card_network.clone(),
+                        i.card_issuing_country.clone(),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
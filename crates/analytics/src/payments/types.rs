@@ -1,4 +1,5 @@
 use api_models::analytics::payments::{PaymentDimensions, PaymentFilters};
+use diesel_models::enums::AttemptStatus;
 use error_stack::ResultExt;
 
 use crate::{
@@ -6,6 +7,39 @@ use crate::{
     types::{AnalyticsCollection, AnalyticsDataSource},
 };
 
+/// Attempt statuses that are counted as failed payments in the failure metrics and distributions.
+pub(crate) const FAILED_ATTEMPT_STATUSES: [AttemptStatus; 2] =
+    [AttemptStatus::Failure, AttemptStatus::AuthorizationFailed];
+
+/// Dimensions that are not stored as columns of the payment attempts in every data source, and
+/// can only be queried from the [`AnalyticsCollection::EnrichedPayment`] collection.
+const ENRICHED_PAYMENT_DIMENSIONS: [PaymentDimensions; 4] = [
+    PaymentDimensions::UnifiedCode,
+    PaymentDimensions::UnifiedMessage,
+    PaymentDimensions::CardNetwork,
+    PaymentDimensions::CardIssuingCountry,
+];
+
+/// Returns the collection to query payment attempts from, only using the enriched collection when
+/// any of the requested dimensions or filters need it.
+pub(crate) fn get_payment_collection<'a>(
+    dimensions: impl IntoIterator<Item = &'a PaymentDimensions>,
+    filters: &PaymentFilters,
+) -> AnalyticsCollection {
+    let is_enriched = dimensions
+        .into_iter()
+        .any(|dimension| ENRICHED_PAYMENT_DIMENSIONS.contains(dimension))
+        || !filters.unified_code.is_empty()
+        || !filters.card_network.is_empty()
+        || !filters.card_issuing_country.is_empty();
+
+    if is_enriched {
+        AnalyticsCollection::EnrichedPayment
+    } else {
+        AnalyticsCollection::Payment
+    }
+}
+
 impl<T> QueryFilter<T> for PaymentFilters
 where
     T: AnalyticsDataSource,
@@ -50,6 +84,68 @@ where
                 )
                 .attach_printable("Error adding payment method filter")?;
         }
+
+        if !self.error_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::ErrorCode, &self.error_code)
+                .attach_printable("Error adding error code filter")?;
+        }
+
+        if !self.unified_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::UnifiedCode, &self.unified_code)
+                .attach_printable("Error adding unified code filter")?;
+        }
+
+        if !self.card_network.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::CardNetwork, &self.card_network)
+                .attach_printable("Error adding card network filter")?;
+        }
+
+        if !self.card_issuing_country.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::CardIssuingCountry,
+                    &self.card_issuing_country,
+                )
+                .attach_printable("Error adding card issuing country filter")?;
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_payment_collection() {
+        let filters = PaymentFilters::default();
+
+        assert_eq!(
+            get_payment_collection(&[PaymentDimensions::Connector], &filters),
+            AnalyticsCollection::Payment
+        );
+        assert_eq!(
+            get_payment_collection(
+                &[PaymentDimensions::Connector, PaymentDimensions::CardNetwork],
+                &filters
+            ),
+            AnalyticsCollection::EnrichedPayment
+        );
+        assert_eq!(
+            get_payment_collection(&[PaymentDimensions::UnifiedMessage], &filters),
+            AnalyticsCollection::EnrichedPayment
+        );
+
+        let filters = PaymentFilters {
+            card_issuing_country: vec!["US".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            get_payment_collection(&[], &filters),
+            AnalyticsCollection::EnrichedPayment
+        );
+    }
+}
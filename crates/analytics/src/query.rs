@@ -5,6 +5,7 @@ use api_models::{
         self as analytics_api,
         api_event::ApiEventDimensions,
         auth_events::AuthEventFlows,
+        connector_events::ConnectorEventDimensions,
         disputes::DisputeDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
         refunds::{RefundDimensions, RefundType},
@@ -12,8 +13,8 @@ use api_models::{
         Granularity,
     },
     enums::{
-        AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, PaymentMethod,
-        PaymentMethodType,
+        AttemptStatus, AuthenticationType, CardNetwork, Connector, Currency, DisputeStage,
+        PaymentMethod, PaymentMethodType,
     },
     refunds::RefundStatus,
 };
@@ -371,6 +372,7 @@ impl_to_sql_for_to_string!(
     PaymentMethod,
     PaymentMethodType,
     AuthenticationType,
+    CardNetwork,
     Connector,
     AttemptStatus,
     RefundStatus,
@@ -392,6 +394,8 @@ impl_to_sql_for_to_string!(
     AuthEventFlows,
     &ApiEventDimensions,
     ApiEventDimensions,
+    &ConnectorEventDimensions,
+    ConnectorEventDimensions,
     &DisputeDimensions,
     DisputeDimensions,
     DisputeStage
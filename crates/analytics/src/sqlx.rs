@@ -260,6 +260,32 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_message: Option<String> =
+            row.try_get("unified_message").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -282,6 +308,12 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             authentication_type,
             payment_method,
             payment_method_type,
+            error_code,
+            error_message,
+            unified_code,
+            unified_message,
+            card_network,
+            card_issuing_country,
             total,
             count,
             start_bucket,
@@ -329,10 +361,32 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_message: Option<String> =
+            row.try_get("unified_message").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -349,7 +403,12 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             payment_method_type,
             total,
             count,
+            error_code,
             error_message,
+            unified_code,
+            unified_message,
+            card_network,
+            card_issuing_country,
             start_bucket,
             end_bucket,
         })
@@ -387,6 +446,32 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::FilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let error_code: Option<String> = row.try_get("error_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_message: Option<String> =
+            row.try_get("unified_message").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             currency,
             status,
@@ -394,6 +479,12 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::FilterRow {
             authentication_type,
             payment_method,
             payment_method_type,
+            error_code,
+            error_message,
+            unified_code,
+            unified_message,
+            card_network,
+            card_issuing_country,
         })
     }
 }
@@ -509,6 +600,23 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         match self {
             Self::Payment => Ok("payment_attempt".to_string()),
+            // Card details are only available inside the `payment_method_data` JSON in postgres,
+            // and the unified error details are only stored on attempts created after the GSM
+            // rule was configured, so these are projected as columns of a subquery
+            Self::EnrichedPayment => Ok("(SELECT \
+                pa.merchant_id, pa.created_at, pa.currency, pa.status, pa.connector, \
+                pa.authentication_type, pa.payment_method, pa.payment_method_type, pa.amount, \
+                pa.error_code, pa.error_message, \
+                COALESCE(pa.unified_code, gsm.unified_code) AS unified_code, \
+                COALESCE(pa.unified_message, gsm.unified_message) AS unified_message, \
+                pa.payment_method_data -> 'card' ->> 'card_network' AS card_network, \
+                pa.payment_method_data -> 'card' ->> 'card_issuing_country' AS card_issuing_country \
+                FROM payment_attempt pa \
+                LEFT JOIN LATERAL (SELECT unified_code, unified_message FROM gateway_status_map \
+                WHERE connector = pa.connector AND code = pa.error_code \
+                AND message = pa.error_message AND unified_code IS NOT NULL \
+                ORDER BY last_modified DESC LIMIT 1) AS gsm ON TRUE) AS payment_attempt"
+                .to_string()),
             Self::Refund => Ok("refund".to_string()),
             Self::SdkEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("SdkEvents table is not implemented for Sqlx"))?,
@@ -18,12 +18,14 @@ pub enum AnalyticsDomain {
     AuthEvents,
     SdkEvents,
     ApiEvents,
+    ConnectorEvents,
     Dispute,
 }
 
-#[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
+#[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy, PartialEq, Eq)]
 pub enum AnalyticsCollection {
     Payment,
+    EnrichedPayment,
     Refund,
     SdkEvents,
     ApiEvents,
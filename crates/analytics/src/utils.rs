@@ -1,6 +1,7 @@
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::AuthEventMetrics,
+    connector_events::{ConnectorEventDimensions, ConnectorEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
@@ -52,3 +53,11 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_connector_event_metrics_info() -> Vec<NameDescription> {
+    ConnectorEventMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_event_dimensions() -> Vec<NameDescription> {
+    ConnectorEventDimensions::iter().map(Into::into).collect()
+}
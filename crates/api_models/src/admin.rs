@@ -845,6 +845,46 @@ pub struct ToggleAllKVResponse {
     #[schema(example = true)]
     pub kv_enabled: bool,
 }
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MerchantKeyRotationResponse {
+    /// The identifier for the key rotation
+    #[schema(max_length = 64, example = "key_rotation_0x1a2b3c4d5e6f")]
+    pub rotation_id: String,
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The version of the key being replaced
+    #[schema(example = 1)]
+    pub from_key_version: i32,
+    /// The version of the newly generated key
+    #[schema(example = 2)]
+    pub to_key_version: i32,
+    /// The status of the key rotation
+    #[schema(value_type = KeyRotationStatus, example = "in_progress")]
+    pub status: enums::KeyRotationStatus,
+    /// The entity whose records are currently being re-encrypted
+    #[schema(value_type = Option<KeyRotationEntity>, example = "customer")]
+    pub current_entity: Option<enums::KeyRotationEntity>,
+    /// The number of records re-encrypted with the new key so far
+    #[schema(example = 1250)]
+    pub records_re_encrypted: i64,
+    /// The reason the key rotation failed, if it did
+    pub error_message: Option<String>,
+    /// The time at which the key rotation was started
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the progress of the key rotation was last updated
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+    /// The time at which all data was re-encrypted and the previous key was retired
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
     /// Creds Identifier is to uniquely identify the credentials. Do not send any sensitive info in this field. And do not send the string "null".
@@ -938,6 +978,13 @@ pub struct BusinessProfileCreate {
 
     /// A boolean value to indicate if cusomter shipping details needs to be sent for wallets payments
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+
+    /// The action taken on manual capture payments shortly before their authorization expires
+    pub authorization_expiry_policy: Option<AuthorizationExpiryPolicy>,
+
+    /// The policy used for automatically retrying outgoing webhooks which could not be delivered.
+    /// The global retry schedule is used if not provided.
+    pub webhook_retry_policy: Option<WebhookRetryPolicy>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1010,6 +1057,19 @@ pub struct BusinessProfileResponse {
 
     // Whether to use the billing details passed when creating the intent as payment method billing
     pub use_billing_as_payment_method_billing: Option<bool>,
+
+    /// The action taken on manual capture payments shortly before their authorization expires
+    pub authorization_expiry_policy: Option<AuthorizationExpiryPolicy>,
+
+    /// The policy used for automatically retrying outgoing webhooks which could not be delivered.
+    /// The global retry schedule is used if not provided.
+    pub webhook_retry_policy: Option<WebhookRetryPolicy>,
+
+    /// The time at which the webhook endpoint was disabled due to sustained delivery failures.
+    /// Updating the webhook details of the business profile re-enables the endpoint.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub webhook_endpoint_disabled_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1080,10 +1140,95 @@ pub struct BusinessProfileUpdate {
 
     /// A boolean value to indicate if cusomter shipping details needs to be sent for wallets payments
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+
+    /// The action taken on manual capture payments shortly before their authorization expires
+    pub authorization_expiry_policy: Option<AuthorizationExpiryPolicy>,
+
+    /// The policy used for automatically retrying outgoing webhooks which could not be delivered.
+    /// The global retry schedule is used if not provided.
+    pub webhook_retry_policy: Option<WebhookRetryPolicy>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, ToSchema)]
+pub struct AuthorizationExpiryPolicy {
+    /// The action taken on the payment before its authorization expires
+    pub action: AuthorizationExpiryAction,
+    /// Number of seconds before the expected expiry of the authorization at which the action is
+    /// taken, defaults to a day
+    #[schema(default = 86400, example = 86400)]
+    #[serde(default = "default_authorization_expiry_lead_time")]
+    pub lead_time_in_secs: u32,
+}
+
+fn default_authorization_expiry_lead_time() -> u32 {
+    24 * 60 * 60
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, ToSchema)]
+pub struct WebhookRetryPolicy {
+    /// Maximum number of automatic retries after the initial delivery attempt
+    #[schema(default = 10, example = 10)]
+    #[serde(default = "default_webhook_max_retries")]
+    pub max_retries: u16,
+    /// Number of seconds to wait before the first automatic retry
+    #[schema(default = 60, example = 60)]
+    #[serde(default = "default_webhook_initial_backoff")]
+    pub initial_backoff_in_secs: u32,
+    /// Factor by which the wait between consecutive retries grows, `1` retries at fixed intervals
+    #[schema(default = 2, example = 2)]
+    #[serde(default = "default_webhook_backoff_multiplier")]
+    pub backoff_multiplier: u16,
+    /// Upper bound for the wait between consecutive retries, defaults to 6 hours
+    #[schema(default = 21600, example = 21600)]
+    #[serde(default = "default_webhook_max_backoff")]
+    pub max_backoff_in_secs: u32,
+    /// Events older than this are not retried any further, defaults to 3 days
+    #[schema(default = 259200, example = 259200)]
+    #[serde(default = "default_webhook_max_event_age")]
+    pub max_event_age_in_secs: u32,
+    /// The webhook endpoint is disabled once no webhook could be delivered to it for this many
+    /// seconds, and the merchant is notified by email. The endpoint is never disabled if not
+    /// provided.
+    #[schema(example = 86400)]
+    pub disable_endpoint_after_failing_for_secs: Option<u32>,
+}
+
+fn default_webhook_max_retries() -> u16 {
+    10
+}
+
+fn default_webhook_initial_backoff() -> u32 {
+    60
+}
+
+fn default_webhook_backoff_multiplier() -> u16 {
+    2
+}
+
+fn default_webhook_max_backoff() -> u32 {
+    6 * 60 * 60
+}
+
+fn default_webhook_max_event_age() -> u32 {
+    3 * 24 * 60 * 60
+}
+
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// Void the authorization, releasing the funds held on the customer's card
+    Void,
+    /// Capture the authorized amount
+    Capture,
+    /// Only send a `payment_authorization_expiring` webhook
+    Notify,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
 pub struct BusinessPaymentLinkConfig {
+    /// Custom domain on which payment links of the business profile are generated and served,
+    /// such as `pay.example.com`. The domain should point to the router.
+    #[schema(value_type = Option<String>, max_length = 255, example = "pay.example.com")]
     pub domain_name: Option<String>,
     #[serde(flatten)]
     pub config: PaymentLinkConfigRequest,
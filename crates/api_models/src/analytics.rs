@@ -6,6 +6,7 @@ use masking::Secret;
 use self::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::AuthEventMetrics,
+    connector_events::{ConnectorEventDimensions, ConnectorEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
@@ -13,6 +14,7 @@ use self::{
 };
 pub use crate::payments::TimeRange;
 
+pub mod alerts;
 pub mod api_event;
 pub mod auth_events;
 pub mod connector_events;
@@ -263,6 +265,22 @@ pub struct GetApiEventMetricRequest {
     pub delta: bool,
 }
 
+/// Connector event metrics are computed from ClickHouse only, requests made while analytics is
+/// backed by Postgres alone are rejected as not implemented.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorEventDimensions>,
+    #[serde(default)]
+    pub filters: connector_events::ConnectorEventFilters,
+    pub metrics: HashSet<ConnectorEventMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDisputeFilterRequest {
@@ -0,0 +1,197 @@
+use common_enums::AnalyticsAlertMetric;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::Connector;
+
+fn default_evaluation_window_in_mins() -> u32 {
+    30
+}
+
+fn default_baseline_window_in_days() -> u32 {
+    7
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyticsAlertRuleCreateRequest {
+    /// A unique name for the alert rule
+    #[schema(max_length = 64, example = "Stripe auth rate drop")]
+    pub name: String,
+
+    /// The business profile whose webhook endpoint receives the alert. Defaults to the merchant's
+    /// default profile.
+    #[schema(max_length = 64)]
+    pub profile_id: Option<String>,
+
+    /// The metric the rule is evaluated against
+    #[schema(example = "connector_success_rate")]
+    pub metric: AnalyticsAlertMetric,
+
+    /// Restrict the metric to payments routed through this connector
+    #[schema(example = "stripe")]
+    pub connector: Option<Connector>,
+
+    /// Number of percentage points the metric must fall below its baseline for the rule to fire
+    #[schema(example = 15.0)]
+    pub drop_threshold: f64,
+
+    /// The trailing window (in minutes) over which the current value of the metric is computed
+    #[serde(default = "default_evaluation_window_in_mins")]
+    #[schema(example = 30)]
+    pub evaluation_window_in_mins: u32,
+
+    /// The trailing window (in days) over which the baseline value of the metric is computed
+    #[serde(default = "default_baseline_window_in_days")]
+    #[schema(example = 7)]
+    pub baseline_window_in_days: u32,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyticsAlertRuleUpdateRequest {
+    /// A unique name for the alert rule
+    #[schema(max_length = 64)]
+    pub name: Option<String>,
+
+    /// Number of percentage points the metric must fall below its baseline for the rule to fire
+    pub drop_threshold: Option<f64>,
+
+    /// The trailing window (in minutes) over which the current value of the metric is computed
+    pub evaluation_window_in_mins: Option<u32>,
+
+    /// The trailing window (in days) over which the baseline value of the metric is computed
+    pub baseline_window_in_days: Option<u32>,
+
+    /// Enable or disable evaluation of the rule
+    pub is_active: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AnalyticsAlertRuleResponse {
+    /// The identifier for the alert rule
+    #[schema(max_length = 64, example = "alert_rule_0x1a2b3c4d5e6f")]
+    pub rule_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(max_length = 64, example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The business profile whose webhook endpoint receives the alert
+    pub profile_id: Option<String>,
+
+    /// The name of the alert rule
+    pub name: String,
+
+    /// The metric the rule is evaluated against
+    pub metric: AnalyticsAlertMetric,
+
+    /// The connector the metric is restricted to
+    pub connector: Option<String>,
+
+    /// Number of percentage points the metric must fall below its baseline for the rule to fire
+    pub drop_threshold: f64,
+
+    /// The trailing window (in minutes) over which the current value of the metric is computed
+    pub evaluation_window_in_mins: u32,
+
+    /// The trailing window (in days) over which the baseline value of the metric is computed
+    pub baseline_window_in_days: u32,
+
+    /// Whether the rule is currently being evaluated
+    pub is_active: bool,
+
+    /// The time at which the alert rule was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the alert rule was last modified
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AnalyticsAlertRuleDeleteResponse {
+    /// The identifier for the alert rule
+    pub rule_id: String,
+
+    /// Indicates whether the alert rule was deleted
+    pub deleted: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyticsAlertListConstraints {
+    /// Only return alerts raised by this rule
+    pub rule_id: Option<String>,
+
+    /// Only return alerts raised after this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only return alerts raised before this time
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of alerts to return
+    pub limit: Option<i64>,
+
+    /// The number of alerts to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct AnalyticsAlertResponse {
+    /// The identifier for the alert
+    #[schema(max_length = 64, example = "alert_0x1a2b3c4d5e6f")]
+    pub alert_id: String,
+
+    /// The identifier for the rule that raised the alert
+    #[schema(max_length = 64, example = "alert_rule_0x1a2b3c4d5e6f")]
+    pub rule_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(max_length = 64, example = "merchant_1668273825")]
+    pub merchant_id: String,
+
+    /// The metric that breached the rule
+    pub metric: AnalyticsAlertMetric,
+
+    /// The connector the metric was restricted to
+    pub connector: Option<String>,
+
+    /// Value of the metric (in percent) over the evaluation window
+    #[schema(example = 61.5)]
+    pub observed_value: f64,
+
+    /// Value of the metric (in percent) over the baseline window
+    #[schema(example = 82.3)]
+    pub baseline_value: f64,
+
+    /// The drop threshold configured on the rule when the alert was raised
+    #[schema(example = 15.0)]
+    pub drop_threshold: f64,
+
+    /// Start of the evaluation window
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_start: PrimitiveDateTime,
+
+    /// End of the evaluation window
+    #[schema(example = "2022-09-10T10:41:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_end: PrimitiveDateTime,
+
+    /// Whether an email notification was sent for the alert
+    pub email_sent: bool,
+
+    /// Whether an outgoing webhook was triggered for the alert
+    pub webhook_sent: bool,
+
+    /// The time at which the alert was raised
+    #[schema(example = "2022-09-10T10:41:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
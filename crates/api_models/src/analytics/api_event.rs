@@ -89,6 +89,7 @@ pub enum ApiEventMetrics {
     Latency,
     ApiCount,
     StatusCodeCount,
+    LatencyPercentiles,
 }
 
 impl From<ApiEventMetrics> for NameDescription {
@@ -102,6 +103,9 @@ impl From<ApiEventMetrics> for NameDescription {
 
 #[derive(Debug, serde::Serialize, Eq)]
 pub struct ApiEventMetricsBucketIdentifier {
+    pub status_code: Option<u16>,
+    pub flow_type: Option<String>,
+    pub api_flow: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -111,8 +115,16 @@ pub struct ApiEventMetricsBucketIdentifier {
 }
 
 impl ApiEventMetricsBucketIdentifier {
-    pub fn new(normalized_time_range: TimeRange) -> Self {
+    pub fn new(
+        status_code: Option<u16>,
+        flow_type: Option<String>,
+        api_flow: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
         Self {
+            status_code,
+            flow_type,
+            api_flow,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -121,6 +133,9 @@ impl ApiEventMetricsBucketIdentifier {
 
 impl Hash for ApiEventMetricsBucketIdentifier {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.status_code.hash(state);
+        self.flow_type.hash(state);
+        self.api_flow.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -140,6 +155,9 @@ pub struct ApiEventMetricsBucketValue {
     pub latency: Option<u64>,
     pub api_count: Option<u64>,
     pub status_code_count: Option<u64>,
+    pub latency_p50: Option<f64>,
+    pub latency_p90: Option<f64>,
+    pub latency_p99: Option<f64>,
 }
 
 #[derive(Debug, serde::Serialize)]
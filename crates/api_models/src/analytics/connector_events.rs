@@ -1,6 +1,149 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct ConnectorEventsRequest {
     pub payment_id: String,
     pub refund_id: Option<String>,
     pub dispute_id: Option<String>,
 }
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorEventDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    ConnectorName,
+    Flow,
+    StatusCode,
+}
+
+impl From<ConnectorEventDimensions> for NameDescription {
+    fn from(value: ConnectorEventDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorEventFilters {
+    #[serde(default)]
+    pub connector_name: Vec<String>,
+    #[serde(default)]
+    pub flow: Vec<String>,
+    #[serde(default)]
+    pub status_code: Vec<u64>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorEventMetrics {
+    RequestCount,
+    LatencyPercentiles,
+}
+
+impl From<ConnectorEventMetrics> for NameDescription {
+    fn from(value: ConnectorEventMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct ConnectorEventMetricsBucketIdentifier {
+    pub connector_name: Option<String>,
+    pub flow: Option<String>,
+    pub status_code: Option<u16>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    // Coz FE sucks
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl ConnectorEventMetricsBucketIdentifier {
+    pub fn new(
+        connector_name: Option<String>,
+        flow: Option<String>,
+        status_code: Option<u16>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector_name,
+            flow,
+            status_code,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+impl Hash for ConnectorEventMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector_name.hash(state);
+        self.flow.hash(state);
+        self.status_code.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for ConnectorEventMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventMetricsBucketValue {
+    pub request_count: Option<u64>,
+    pub latency_p50: Option<f64>,
+    pub latency_p90: Option<f64>,
+    pub latency_p99: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorEventMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: ConnectorEventMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: ConnectorEventMetricsBucketIdentifier,
+}
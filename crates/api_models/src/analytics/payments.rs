@@ -5,7 +5,8 @@ use std::{
 
 use super::{NameDescription, TimeRange};
 use crate::enums::{
-    AttemptStatus, AuthenticationType, Connector, Currency, PaymentMethod, PaymentMethodType,
+    AttemptStatus, AuthenticationType, CardNetwork, Connector, Currency, PaymentMethod,
+    PaymentMethodType,
 };
 
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
@@ -22,6 +23,14 @@ pub struct PaymentFilters {
     pub payment_method: Vec<PaymentMethod>,
     #[serde(default)]
     pub payment_method_type: Vec<PaymentMethodType>,
+    #[serde(default)]
+    pub error_code: Vec<String>,
+    #[serde(default)]
+    pub unified_code: Vec<String>,
+    #[serde(default)]
+    pub card_network: Vec<CardNetwork>,
+    #[serde(default)]
+    pub card_issuing_country: Vec<String>,
 }
 
 #[derive(
@@ -53,6 +62,12 @@ pub enum PaymentDimensions {
     #[strum(serialize = "status")]
     #[serde(rename = "status")]
     PaymentStatus,
+    ErrorCode,
+    ErrorMessage,
+    UnifiedCode,
+    UnifiedMessage,
+    CardNetwork,
+    CardIssuingCountry,
 }
 
 #[derive(
@@ -77,6 +92,7 @@ pub enum PaymentMetrics {
     AvgTicketSize,
     RetriesCount,
     ConnectorSuccessRate,
+    PaymentFailureCount,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -104,6 +120,10 @@ pub struct ErrorResult {
 pub enum PaymentDistributions {
     #[strum(serialize = "error_message")]
     PaymentErrorMessage,
+    #[strum(serialize = "error_code")]
+    PaymentErrorCode,
+    #[strum(serialize = "unified_message")]
+    PaymentUnifiedMessage,
 }
 
 pub mod metric_behaviour {
@@ -141,6 +161,12 @@ pub struct PaymentMetricsBucketIdentifier {
     pub auth_type: Option<AuthenticationType>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuing_country: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -150,6 +176,7 @@ pub struct PaymentMetricsBucketIdentifier {
 }
 
 impl PaymentMetricsBucketIdentifier {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         currency: Option<Currency>,
         status: Option<AttemptStatus>,
@@ -157,6 +184,12 @@ impl PaymentMetricsBucketIdentifier {
         auth_type: Option<AuthenticationType>,
         payment_method: Option<String>,
         payment_method_type: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+        unified_code: Option<String>,
+        unified_message: Option<String>,
+        card_network: Option<String>,
+        card_issuing_country: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -166,6 +199,12 @@ impl PaymentMetricsBucketIdentifier {
             auth_type,
             payment_method,
             payment_method_type,
+            error_code,
+            error_message,
+            unified_code,
+            unified_message,
+            card_network,
+            card_issuing_country,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -180,6 +219,12 @@ impl Hash for PaymentMetricsBucketIdentifier {
         self.auth_type.map(|i| i.to_string()).hash(state);
         self.payment_method.hash(state);
         self.payment_method_type.hash(state);
+        self.error_code.hash(state);
+        self.error_message.hash(state);
+        self.unified_code.hash(state);
+        self.unified_message.hash(state);
+        self.card_network.hash(state);
+        self.card_issuing_country.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -205,6 +250,9 @@ pub struct PaymentMetricsBucketValue {
     pub retries_count: Option<u64>,
     pub retries_amount_processed: Option<u64>,
     pub connector_success_rate: Option<f64>,
+    pub payment_failure_count: Option<u64>,
+    pub payment_error_code: Option<Vec<ErrorResult>>,
+    pub payment_unified_message: Option<Vec<ErrorResult>>,
 }
 
 #[derive(Debug, serde::Serialize)]
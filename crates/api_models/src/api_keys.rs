@@ -1,3 +1,4 @@
+use common_enums::PermissionGroup;
 use common_utils::custom_serde;
 use masking::StrongSecret;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to. If not provided, the API Key has full
+    /// access to the merchant account.
+    #[schema(example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<PermissionGroup>>,
+
+    /// The IP addresses or CIDR blocks the API Key can be used from. If not provided, the API Key
+    /// can be used from any IP address.
+    #[schema(example = json!(["192.168.1.0/24", "10.0.0.1"]))]
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +71,16 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to. If not present, the API Key has full
+    /// access to the merchant account.
+    #[schema(example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<PermissionGroup>>,
+
+    /// The IP addresses or CIDR blocks the API Key can be used from. If not present, the API Key
+    /// can be used from any IP address.
+    #[schema(example = json!(["192.168.1.0/24", "10.0.0.1"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +123,16 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to. If not present, the API Key has full
+    /// access to the merchant account.
+    #[schema(example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<PermissionGroup>>,
+
+    /// The IP addresses or CIDR blocks the API Key can be used from. If not present, the API Key
+    /// can be used from any IP address.
+    #[schema(example = json!(["192.168.1.0/24", "10.0.0.1"]))]
+    pub allowed_ips: Option<Vec<String>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +161,16 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permission groups the API Key is restricted to. Replaces the existing permission
+    /// groups of the API Key.
+    #[schema(example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<PermissionGroup>>,
+
+    /// The IP addresses or CIDR blocks the API Key can be used from. Replaces the existing
+    /// allowlist of the API Key, an empty list removes the restriction.
+    #[schema(example = json!(["192.168.1.0/24", "10.0.0.1"]))]
+    pub allowed_ips: Option<Vec<String>>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
@@ -1,5 +1,5 @@
 use common_enums::enums;
-use common_utils::events::ApiEventMetric;
+use common_utils::{events::ApiEventMetric, pii, types::MinorUnit};
 use masking::StrongSecret;
 use utoipa::ToSchema;
 
@@ -9,6 +9,16 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    #[schema(value_type = String)]
+    CustomerEmail(pii::Email),
+    #[schema(value_type = String)]
+    IpAddress(std::net::IpAddr),
+    CustomerId(String),
+    #[schema(value_type = CountryAlpha2)]
+    BillingCountry(enums::CountryAlpha2),
+    /// Hex encoded SHA-256 digest of the browser information of the payment, as logged when a
+    /// payment is blocked or flagged
+    DeviceFingerprint(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -65,6 +75,63 @@ pub struct ToggleBlocklistQuery {
     pub status: bool,
 }
 
+/// Velocity rules evaluated against payments when the blocklist guard is enabled
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityRulesConfig {
+    pub rules: Vec<VelocityRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VelocityRule {
+    /// Name of the rule, unique among the rules of the merchant
+    #[schema(example = "attempts_per_card_hourly")]
+    pub name: String,
+    pub limit: VelocityLimit,
+    /// Duration of the window over which the limit is evaluated
+    #[schema(example = 3600)]
+    pub window_in_secs: u32,
+    pub action: VelocityRuleAction,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum VelocityLimit {
+    /// Maximum number of payment attempts made with the same card
+    AttemptsPerCard { max_attempts: u32 },
+    /// Maximum number of distinct cards used from the same IP address
+    DistinctCardsPerIp { max_cards: u32 },
+    /// Maximum total amount attempted by the same customer
+    AmountPerCustomer {
+        #[schema(value_type = i64, example = 100000)]
+        max_amount: MinorUnit,
+    },
+}
+
+/// The action taken on a payment which exceeds the limit of a velocity rule
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleAction {
+    /// Only record that the rule was triggered
+    Flag,
+    /// Force 3DS authentication for card payments
+    Force3ds,
+    /// Reject the payment
+    Block,
+}
+
 impl ApiEventMetric for BlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ToggleBlocklistResponse {}
@@ -73,3 +140,4 @@ impl ApiEventMetric for GenerateFingerprintRequest {}
 impl ApiEventMetric for ToggleBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintResponsePayload {}
 impl ApiEventMetric for Card {}
+impl ApiEventMetric for VelocityRulesConfig {}
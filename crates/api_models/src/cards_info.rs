@@ -29,3 +29,52 @@ pub struct CardInfoResponse {
     #[schema(example = "INDIA")]
     pub card_issuing_country: Option<String>,
 }
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardInfoImportResponse {
+    /// Provider the BIN records were sourced from, recorded as the `last_updated_provider` of
+    /// every inserted or updated record
+    #[schema(example = "binlist_2024_06")]
+    pub provider: String,
+    /// Whether the import was only diffed against the stored records, without writing them
+    pub dry_run: bool,
+    /// Number of records read from the file
+    pub total_records: usize,
+    /// Number of records that were not stored for the BIN yet
+    pub inserted: usize,
+    /// Number of records that differ from the stored record for the BIN
+    pub updated: usize,
+    /// Number of records identical to the stored record for the BIN
+    pub unchanged: usize,
+    /// Records that could not be imported
+    pub errors: Vec<CardInfoImportError>,
+    /// Records that were (or, for a dry run, would be) inserted or updated
+    pub changes: Vec<CardInfoChange>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardInfoImportError {
+    /// Line of the file the record was read from
+    pub line: u64,
+    pub card_iin: Option<String>,
+    pub error: String,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct CardInfoChange {
+    #[schema(example = "374431")]
+    pub card_iin: String,
+    pub change_type: CardInfoChangeType,
+    /// Fields of the stored record that differ from the imported record
+    #[schema(example = json!(["card_issuer", "card_issuing_country"]))]
+    pub changed_fields: Vec<String>,
+    /// Provider the stored record was last updated from
+    pub previous_provider: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CardInfoChangeType {
+    Inserted,
+    Updated,
+}
@@ -0,0 +1,85 @@
+use common_enums::{AuditAction, AuditActorType, AuditResourceType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when filtering configuration audit records.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigAuditLogListConstraints {
+    /// Filter records of changes made at or after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// Filter records of changes made at or before the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+
+    /// Filter records of changes made to the specified kind of configuration.
+    #[schema(value_type = Option<AuditResourceType>)]
+    pub resource_type: Option<AuditResourceType>,
+
+    /// Include at most the specified number of records.
+    pub limit: Option<u16>,
+
+    /// Include records after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// A recorded change to a configuration.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConfigAuditLogResponse {
+    /// The identifier for the audit record.
+    #[schema(max_length = 64, example = "audit_hlRG9k5bS3qBD4mGIXgBaW")]
+    pub audit_id: String,
+
+    /// The identifier for the merchant account whose configuration was changed. This is absent
+    /// for changes to global configurations.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
+
+    /// The kind of configuration that was changed.
+    #[schema(value_type = AuditResourceType)]
+    pub resource_type: AuditResourceType,
+
+    /// The identifier for the configuration that was changed.
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub resource_id: String,
+
+    /// The kind of change made to the configuration.
+    #[schema(value_type = AuditAction)]
+    pub action: AuditAction,
+
+    /// The kind of credential that was used to make the change.
+    #[schema(value_type = AuditActorType)]
+    pub actor_type: AuditActorType,
+
+    /// The identifier for the user or the API key that made the change.
+    #[schema(example = "d7b5a1c2-5f3e-4a3b-9c1d-2e6f7a8b9c0d")]
+    pub actor_id: Option<String>,
+
+    /// The identifier for the request that made the change.
+    pub request_id: Option<String>,
+
+    /// The fields that were changed, keyed by their path. Each change holds the `before` and
+    /// `after` values of the field, with sensitive values redacted.
+    #[schema(value_type = Object, example = r#"{ "connector_account_details.api_key": { "before": "*** alloc::string::String ***", "after": "*** alloc::string::String ***" } }"#)]
+    pub changes: serde_json::Value,
+
+    /// Time at which the change was made.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The list of recorded changes to configurations.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConfigAuditLogListResponse {
+    /// The number of records included in the list.
+    pub count: usize,
+
+    /// The records, most recent first.
+    pub data: Vec<ConfigAuditLogResponse>,
+}
@@ -1,4 +1,8 @@
 use common_utils::{events::ApiEventMetric, types::MinorUnit};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::Currency;
 
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
@@ -15,7 +19,81 @@ pub struct CurrencyConversionParams {
 pub struct CurrencyConversionResponse {
     pub converted_amount: String,
     pub currency: String,
+    /// Provider of the exchange rates applied to the conversion
+    pub rate_source: Option<String>,
+    /// Unix timestamp of the exchange rates applied to the conversion
+    pub rate_timestamp: i64,
+    /// Markup applied to the converted amount, in basis points
+    pub markup_bps: u16,
 }
 
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+
+/// Request to quote the amount of a payment in the currency of the cardholder
+#[derive(Debug, Clone, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FxQuoteRequest {
+    /// The amount of the payment, in the minor unit of its currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency the payment is settled in
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The currency to present the amount in. Suggested from the card BIN when not provided.
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<Currency>,
+
+    /// The first 6 or 8 digits of the card, used to suggest the currency of the cardholder
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+}
+
+/// A quote locking the exchange rate used to present a payment in the currency of the cardholder
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FxQuoteResponse {
+    /// Identifier of the quote, to be passed as `quote_id` when confirming the payment
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: String,
+
+    /// The amount of the payment, in the minor unit of the settlement currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency the payment is settled in
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The amount presented to the cardholder, in the minor unit of the presentment currency
+    #[schema(value_type = i64, example = 6112)]
+    pub presentment_amount: MinorUnit,
+
+    /// The currency presented to the cardholder
+    #[schema(value_type = Currency, example = "EUR")]
+    pub presentment_currency: Currency,
+
+    /// The exchange rate from the settlement currency to the presentment currency, including the markup
+    #[schema(example = "0.9346")]
+    pub rate: String,
+
+    /// Markup included in the rate, in basis points
+    #[schema(example = 150)]
+    pub markup_bps: u16,
+
+    /// Provider of the exchange rates the quote is based on
+    #[schema(example = "open_exchange_rates")]
+    pub rate_source: Option<String>,
+
+    /// Unix timestamp of the exchange rates the quote is based on
+    pub rate_timestamp: i64,
+
+    /// Time after which the quote can no longer be used to confirm a payment
+    #[schema(example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for FxQuoteRequest {}
+impl ApiEventMetric for FxQuoteResponse {}
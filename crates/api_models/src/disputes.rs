@@ -139,6 +139,21 @@ pub struct DisputeListConstraints {
     pub received_time_gte: Option<PrimitiveDateTime>,
 }
 
+fn default_expiring_soon_within_days() -> u32 {
+    7
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeExpiringSoonConstraints {
+    /// Number of days ahead of now within which the evidence submission deadline falls
+    #[serde(default = "default_expiring_soon_within_days")]
+    #[schema(example = 7)]
+    pub within_days: u32,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+}
+
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct SubmitEvidenceRequest {
     ///Dispute Id
@@ -22,11 +22,12 @@ use common_utils::{
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
+        alerts::*, api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
         outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
     cards_info::*,
+    config_audit::*,
     disputes::*,
     files::*,
     mandates::*,
@@ -51,8 +52,17 @@ impl_misc_api_event_type!(
     MandateRevokedResponse,
     RetrievePaymentLinkRequest,
     PaymentLinkListConstraints,
+    ReusablePaymentLinkCreateRequest,
+    ReusablePaymentLinkUpdateRequest,
+    ReusablePaymentLinkResponse,
+    ReusablePaymentLinkListConstraints,
+    ReusablePaymentLinkListResponse,
+    ReusablePaymentLinkPaymentsResponse,
+    ReusablePaymentLinkInitiateRequest,
+    PaymentLinkQrCodeRequest,
     MandateId,
     DisputeListConstraints,
+    DisputeExpiringSoonConstraints,
     RetrieveApiKeyResponse,
     BusinessProfileResponse,
     BusinessProfileUpdate,
@@ -64,7 +74,9 @@ impl_misc_api_event_type!(
     ToggleAllKVResponse,
     MerchantAccountDeleteResponse,
     MerchantAccountUpdate,
+    MerchantKeyRotationResponse,
     CardInfoResponse,
+    CardInfoImportResponse,
     CreateApiKeyResponse,
     CreateApiKeyRequest,
     MerchantConnectorDeleteResponse,
@@ -95,6 +107,7 @@ impl_misc_api_event_type!(
     SdkEventFiltersResponse,
     ApiLogsRequest,
     GetApiEventMetricRequest,
+    GetConnectorEventMetricRequest,
     SdkEventsRequest,
     ReportRequest,
     ConnectorEventsRequest,
@@ -105,7 +118,15 @@ impl_misc_api_event_type!(
     GetSearchRequestWithIndex,
     GetDisputeFilterRequest,
     DisputeFiltersResponse,
-    GetDisputeMetricRequest
+    GetDisputeMetricRequest,
+    AnalyticsAlertRuleCreateRequest,
+    AnalyticsAlertRuleUpdateRequest,
+    AnalyticsAlertRuleResponse,
+    AnalyticsAlertRuleDeleteResponse,
+    AnalyticsAlertListConstraints,
+    AnalyticsAlertResponse,
+    ConfigAuditLogListConstraints,
+    ConfigAuditLogListResponse
 );
 
 #[cfg(feature = "stripe")]
@@ -8,10 +8,10 @@ use crate::{
         PaymentMethodResponse, PaymentMethodUpdate,
     },
     payments::{
-        ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
-        PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
-        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
-        PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
+        ExtendedCardInfoResponse, PaymentAuthorizationExpiringSoonConstraints, PaymentIdType,
+        PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters,
+        PaymentListFiltersV2, PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest,
+        PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
         PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
         PaymentsIncrementalAuthorizationRequest, PaymentsRejectRequest, PaymentsRequest,
         PaymentsResponse, PaymentsRetrieveRequest, PaymentsStartRequest, RedirectionResponse,
@@ -191,6 +191,12 @@ impl ApiEventMetric for PaymentListResponseV2 {
     }
 }
 
+impl ApiEventMetric for PaymentAuthorizationExpiringSoonConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for RedirectionResponse {}
 
 impl ApiEventMetric for PaymentsIncrementalAuthorizationRequest {
@@ -0,0 +1,153 @@
+use common_utils::{custom_serde, events};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{Currency, FrmReviewDecision};
+
+fn default_review_queue_limit() -> u32 {
+    20
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewQueueConstraints {
+    /// The maximum number of payments to return
+    #[serde(default = "default_review_queue_limit")]
+    #[schema(example = 20)]
+    pub limit: u32,
+
+    /// The number of payments to skip
+    #[serde(default)]
+    pub offset: u32,
+
+    /// Only return payments assigned to this reviewer
+    #[schema(max_length = 64, example = "reviewer@example.com")]
+    pub assigned_to: Option<String>,
+}
+
+impl events::ApiEventMetric for FrmReviewQueueConstraints {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FrmReviewQueueItem {
+    /// The identifier for the payment held for review
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The identifier for the payment attempt that was checked
+    #[schema(max_length = 64)]
+    pub attempt_id: String,
+
+    /// The fraud check provider that suggested the review
+    #[schema(example = "signifyd")]
+    pub frm_name: String,
+
+    /// The fraud score returned by the provider
+    pub frm_score: Option<i32>,
+
+    /// The reason returned by the provider for holding the payment
+    #[schema(value_type = Option<Object>)]
+    pub frm_reason: Option<serde_json::Value>,
+
+    /// The amount of the payment in minor units
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<Currency>,
+
+    /// The reviewer the payment is assigned to
+    #[schema(max_length = 64)]
+    pub review_assigned_to: Option<String>,
+
+    /// The time after which the merchant's review policy decides the payment automatically
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub review_deadline: Option<PrimitiveDateTime>,
+
+    /// The decision taken on the payment, if any
+    #[schema(value_type = Option<FrmReviewDecision>)]
+    pub review_decision: Option<FrmReviewDecision>,
+
+    /// The time at which the fraud check was performed
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl events::ApiEventMetric for FrmReviewQueueItem {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct FrmReviewQueueResponse {
+    /// The number of payments returned
+    pub count: usize,
+
+    /// The payments pending review, oldest first
+    pub data: Vec<FrmReviewQueueItem>,
+}
+
+impl events::ApiEventMetric for FrmReviewQueueResponse {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewAssignRequest {
+    /// The reviewer to assign the payment to, `null` removes the current assignment
+    #[schema(max_length = 64, example = "reviewer@example.com")]
+    pub assigned_to: Option<String>,
+}
+
+impl events::ApiEventMetric for FrmReviewAssignRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::FraudCheck)
+    }
+}
+
+/// The action taken on a payment that is still pending review when its deadline expires
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmReviewExpiryAction {
+    Approve,
+    Reject,
+}
+
+impl From<FrmReviewExpiryAction> for FrmReviewDecision {
+    fn from(action: FrmReviewExpiryAction) -> Self {
+        match action {
+            FrmReviewExpiryAction::Approve => Self::AutoApproved,
+            FrmReviewExpiryAction::Reject => Self::AutoRejected,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewPolicy {
+    /// Time (in seconds) a payment may wait for a review before it is decided automatically
+    #[schema(example = 86400)]
+    pub review_window_in_secs: u32,
+
+    /// The decision taken automatically once the review window elapses
+    #[schema(example = "reject")]
+    pub expiry_action: FrmReviewExpiryAction,
+}
+
+impl events::ApiEventMetric for FrmReviewPolicy {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::FraudCheck)
+    }
+}
@@ -0,0 +1,80 @@
+use common_utils::events;
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::enums::FrmPreferredFlowTypes;
+
+/// Decision taken by the native fraud rules engine for a payment
+///
+/// `review` holds the payment for manual review when the rules are evaluated in the `post` flow.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmRuleDecision {
+    #[default]
+    Accept,
+    Review,
+    Decline,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FrmRuleOutput {
+    pub decision: FrmRuleDecision,
+}
+
+impl EuclidDirFilter for FrmRuleOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardBin,
+        DirKeyKind::CardType,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::WalletType,
+        DirKeyKind::MetaData,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::AuthenticationType,
+        DirKeyKind::CaptureMethod,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::BusinessCountry,
+        DirKeyKind::SetupFutureUsage,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrmRulesRecord {
+    pub name: String,
+    pub program: Program<FrmRuleOutput>,
+    pub flow: FrmPreferredFlowTypes,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl events::ApiEventMetric for FrmRulesRecord {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmRulesRequest {
+    pub name: Option<String>,
+    pub program: Program<FrmRuleOutput>,
+    /// Whether the rules are evaluated before or after authorization, defaults to `post`
+    pub flow: Option<FrmPreferredFlowTypes>,
+    /// Points the FRM routing algorithm of the merchant to the native rules engine, replacing any
+    /// FRM connector in use. The rules are only stored when not set.
+    #[serde(default)]
+    pub activate: bool,
+}
+
+impl events::ApiEventMetric for FrmRulesRequest {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
+
+pub type FrmRulesResponse = FrmRulesRecord;
@@ -5,6 +5,7 @@ pub mod api_keys;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod config_audit;
 pub mod connector_onboarding;
 pub mod currency;
 pub mod customers;
@@ -15,6 +16,8 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod files;
+pub mod frm_review;
+pub mod frm_rules;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
@@ -187,7 +187,7 @@ impl CardDetailUpdate {
     }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864")]
@@ -839,6 +839,30 @@ pub struct PaymentMethodDeleteResponse {
     #[schema(example = true)]
     pub deleted: bool,
 }
+
+/// Token lifecycle event sent by the card network for a network token
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct NetworkTokenWebhookRequest {
+    /// Unique identifier of the webhook, used to discard replayed webhooks
+    pub event_id: String,
+    /// Unix timestamp at which the webhook was sent
+    pub timestamp: i64,
+    /// The lifecycle event that occurred on the token
+    pub event_type: NetworkTokenEventType,
+    /// The reference of the token at the token requestor
+    pub token_reference_id: String,
+    /// The updated card art, present for card art updates
+    pub card_art_url: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenEventType {
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+    CardArtUpdated,
+}
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct CustomerDefaultPaymentMethodResponse {
     /// The unique identifier of the Payment method
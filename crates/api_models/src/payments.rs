@@ -474,6 +474,13 @@ pub struct PaymentsRequest {
 
     /// Details required for recurring payment
     pub recurring_details: Option<RecurringDetails>,
+
+    /// Level 2 / Level 3 data of a commercial card payment, passed on to the connectors that support it
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// Identifier of an FX quote shown to the cardholder. The quote is recorded with the payment for reference only; the payment is still authorized and settled in its own amount and currency
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: Option<String>,
 }
 
 impl PaymentsRequest {
@@ -661,6 +668,18 @@ impl PaymentsRequest {
             .transpose()
     }
 
+    pub fn get_l2_l3_data_as_value(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Option<pii::SecretSerdeValue>,
+        common_utils::errors::ParsingError,
+    > {
+        self.l2_l3_data
+            .as_ref()
+            .map(|l2_l3_data| l2_l3_data.encode_to_value().map(Secret::new))
+            .transpose()
+    }
+
     pub fn get_connector_metadata_as_value(
         &self,
     ) -> common_utils::errors::CustomResult<
@@ -3425,6 +3444,43 @@ pub struct PaymentsResponse {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. FRM Metadata is useful for storing additional, structured information on an object related to FRM.
     #[schema(value_type = Option<Object>, example = r#"{ "fulfillment_method" : "deliver", "coverage_request" : "fraud" }"#)]
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the authorization of a manual capture payment is expected to expire at the issuer
+    #[schema(example = "2022-09-17T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+
+    /// Level 2 / Level 3 data of a commercial card payment
+    pub l2_l3_data: Option<L2L3Data>,
+
+    /// Details of the FX quote shown to the cardholder, recorded for reference only
+    pub fx_quote: Option<PaymentFxQuoteDetails>,
+}
+
+/// The amount and currency presented to the cardholder of a payment confirmed with an FX quote.
+/// These details are informational only: they are not sent to the connector, and the payment is
+/// authorized and settled in its own amount and currency.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentFxQuoteDetails {
+    /// Identifier of the quote the payment was confirmed with
+    #[schema(example = "fxq_mbabizu24mvu3mela5njyhpit4")]
+    pub quote_id: String,
+
+    /// The amount presented to the cardholder, in the minor unit of the presentment currency
+    #[schema(value_type = Option<i64>, example = 6112)]
+    pub presentment_amount: Option<MinorUnit>,
+
+    /// The currency presented to the cardholder
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<api_enums::Currency>,
+
+    /// The exchange rate from the settlement currency to the presentment currency, including the markup
+    #[schema(example = "0.9346")]
+    pub rate: Option<String>,
+
+    /// Markup included in the rate, in basis points
+    #[schema(example = 150)]
+    pub markup_bps: Option<i32>,
 }
 
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -3506,6 +3562,41 @@ pub struct PaymentListConstraints {
     pub created_gte: Option<PrimitiveDateTime>,
 }
 
+fn default_authorizations_expiring_within_hours() -> u32 {
+    24
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentAuthorizationExpiringSoonConstraints {
+    /// Number of hours ahead of now within which the authorization is expected to expire
+    #[serde(default = "default_authorizations_expiring_within_hours")]
+    #[schema(example = 24)]
+    pub within_hours: u32,
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentAuthorizationExpiryResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The identifier for the authorized payment attempt
+    pub attempt_id: String,
+    /// The amount held on the customer's payment method
+    #[schema(value_type = i64, example = 6540)]
+    pub amount_capturable: MinorUnit,
+    /// The three letter ISO currency code of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The connector the payment was authorized with
+    pub connector: Option<String>,
+    /// Time at which the authorization is expected to expire at the issuer
+    #[schema(example = "2022-09-17T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub authorization_expires_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PaymentListResponse {
     /// The number of payments included in the list
@@ -3834,6 +3925,82 @@ pub struct OrderDetailsWithAmount {
     pub product_type: Option<ProductType>,
 }
 
+/// Level 2 / Level 3 data of a commercial card payment. Passing it lets commercial, corporate and
+/// purchasing card payments qualify for lower interchange rates.
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3Data {
+    /// Reference of the order given by the customer, such as their purchase order number
+    #[schema(max_length = 25, example = "PO-2024-0042")]
+    pub customer_reference: Option<String>,
+    /// Tax identification number of the merchant
+    #[schema(example = "12-3456789")]
+    pub merchant_tax_id: Option<String>,
+    /// Tax identification number of the customer
+    #[schema(example = "98-7654321")]
+    pub customer_tax_id: Option<String>,
+    /// Total tax amount of the order, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 825)]
+    pub tax_amount: Option<MinorUnit>,
+    /// Total discount applied to the order, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub discount_amount: Option<MinorUnit>,
+    /// Duty paid on the order, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub duty_amount: Option<MinorUnit>,
+    /// Freight or shipping charged for the order, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub freight_amount: Option<MinorUnit>,
+    /// Postal code of the location the goods are shipped from
+    #[schema(example = "94107")]
+    pub ship_from_postal_code: Option<String>,
+    /// Line items of the order
+    pub line_items: Option<Vec<L2L3LineItem>>,
+}
+
+/// Level 3 line item of a commercial card payment
+#[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct L2L3LineItem {
+    /// Description of the product
+    #[schema(max_length = 255, example = "Printer paper")]
+    pub product_name: String,
+    /// Merchant's identifier of the product, such as its SKU
+    #[schema(example = "SKU-1001")]
+    pub product_code: Option<String>,
+    /// Commodity code of the product, such as its UNSPSC or NIGP code
+    #[schema(example = "44121600")]
+    pub commodity_code: Option<String>,
+    /// Quantity of the product purchased
+    #[schema(example = 10)]
+    pub quantity: u16,
+    /// Unit of measure of the quantity, such as `EA` or `BOX`
+    #[schema(example = "BOX")]
+    pub unit_of_measure: Option<String>,
+    /// Price per unit of the product, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1500)]
+    pub unit_amount: MinorUnit,
+    /// Discount applied to the line item, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 0)]
+    pub discount_amount: Option<MinorUnit>,
+    /// Tax charged on the line item, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 825)]
+    pub tax_amount: Option<MinorUnit>,
+}
+
+impl L2L3LineItem {
+    /// Total of the line item: unit amount times quantity, less any discount, plus any tax
+    pub fn get_total_amount(&self) -> MinorUnit {
+        MinorUnit::new(
+            self.unit_amount
+                .get_amount_as_i64()
+                .saturating_mul(i64::from(self.quantity))
+                .saturating_sub(self.discount_amount.unwrap_or_default().get_amount_as_i64())
+                .saturating_add(self.tax_amount.unwrap_or_default().get_amount_as_i64()),
+        )
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProductType {
@@ -4714,6 +4881,7 @@ pub struct PaymentLinkInitiateRequest {
 pub enum PaymentLinkData<'a> {
     PaymentLinkDetails(&'a PaymentLinkDetails),
     PaymentLinkStatusDetails(PaymentLinkStatusDetails),
+    ReusablePaymentLinkAmountDetails(ReusablePaymentLinkAmountDetails),
 }
 
 #[derive(Debug, serde::Serialize, Clone)]
@@ -4844,6 +5012,185 @@ pub enum PaymentLinkStatusWrap {
     IntentStatus(api_enums::IntentStatus),
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum QrCodeImageFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkQrCodeRequest {
+    /// The image format of the QR code
+    #[schema(value_type = Option<QrCodeImageFormat>, example = "svg")]
+    pub format: Option<QrCodeImageFormat>,
+    /// The minimum width and height of the QR code, in pixels
+    #[schema(example = 256)]
+    pub size: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkCreateRequest {
+    /// The business profile under which payments are created through this link
+    pub profile_id: String,
+    /// The currency of the payments created through this link
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The fixed amount charged for every payment. When not provided, the customer enters the
+    /// amount, optionally bounded by `min_amount` and `max_amount`.
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The minimum amount the customer can enter
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    /// The maximum amount the customer can enter
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    /// The number of payments after which the link stops accepting payments. Payments that
+    /// succeeded, are awaiting capture or are being processed count towards the limit, which is
+    /// checked when the link is opened and when a payment is confirmed.
+    #[schema(example = 10)]
+    pub usage_limit: Option<u32>,
+    /// The time after which the link stops accepting payments
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Description shown to the customer and set on every payment
+    pub description: Option<String>,
+    /// The URL to redirect the customer to after a payment
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<Url>,
+    /// Configurations for the payment link page
+    #[schema(value_type = Option<PaymentCreatePaymentLinkConfig>)]
+    pub payment_link_config: Option<PaymentCreatePaymentLinkConfig>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkUpdateRequest {
+    /// The number of payments after which the link stops accepting payments. Payments that
+    /// succeeded, are awaiting capture or are being processed count towards the limit, which is
+    /// checked when the link is opened and when a payment is confirmed.
+    #[schema(example = 10)]
+    pub usage_limit: Option<u32>,
+    /// The time after which the link stops accepting payments
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// Whether the link accepts payments
+    pub is_active: Option<bool>,
+    /// Description shown to the customer and set on every payment
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkResponse {
+    /// Identifier for the reusable payment link
+    pub reusable_payment_link_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    /// The link to share with customers
+    pub link_to_pay: String,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount: Option<MinorUnit>,
+    pub usage_limit: Option<u32>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub is_active: bool,
+    pub description: Option<String>,
+    pub return_url: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReusablePaymentLinkListConstraints {
+    /// limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkListResponse {
+    /// The number of reusable payment links included in the list
+    pub size: usize,
+    /// The list of reusable payment links
+    pub data: Vec<ReusablePaymentLinkResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkPayment {
+    pub payment_id: String,
+    pub payment_link_id: String,
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkPaymentsSummary {
+    /// Number of payments initiated through the link
+    pub payments_initiated: u64,
+    /// Number of payments that succeeded
+    pub payments_succeeded: u64,
+    /// Total amount captured across all payments made through the link
+    #[schema(value_type = i64, example = 6540)]
+    pub amount_collected: MinorUnit,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReusablePaymentLinkPaymentsResponse {
+    pub reusable_payment_link_id: String,
+    pub summary: ReusablePaymentLinkPaymentsSummary,
+    /// The number of payments included in the list
+    pub size: usize,
+    pub data: Vec<ReusablePaymentLinkPayment>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct ReusablePaymentLinkInitiateQuery {
+    /// Amount entered by the customer, in the lowest denomination of the currency
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReusablePaymentLinkInitiateRequest {
+    pub merchant_id: String,
+    pub reusable_payment_link_id: String,
+    /// Amount entered by the customer, in the lowest denomination of the currency
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReusablePaymentLinkAmountDetails {
+    pub currency: api_enums::Currency,
+    pub currency_exponent: u8,
+    pub min_amount: Option<String>,
+    pub max_amount: Option<String>,
+    pub merchant_name: String,
+    pub merchant_logo: String,
+    pub merchant_description: Option<String>,
+    pub theme: String,
+}
+
 #[derive(Debug, Default, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct ExtendedCardInfoResponse {
     // Encrypted customer payment method data
@@ -116,6 +116,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::VoucherType,
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::CardIssuingCountry,
+        DirKeyKind::CardSubtype,
     ];
 }
 
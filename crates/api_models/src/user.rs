@@ -257,3 +257,68 @@ pub struct TotpSecret {
 pub struct VerifyTotpRequest {
     pub totp: Option<Secret<String>>,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct OidcSsoAuthorizeRequest {
+    pub org_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct OidcSsoAuthorizeResponse {
+    /// The URL of the identity provider the user has to be redirected to
+    pub authorization_url: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct OidcSsoCallbackRequest {
+    /// The authorization code returned by the identity provider
+    pub code: Secret<String>,
+    /// The state returned by the identity provider, as received in the authorization URL
+    pub state: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct OidcSsoConfigRequest {
+    /// The issuer URL of the identity provider, used for discovering its endpoints
+    pub issuer_url: String,
+    pub client_id: String,
+    /// Required when SSO is being configured for the first time, the existing secret is retained
+    /// if this is not provided during an update
+    pub client_secret: Option<Secret<String>>,
+    /// The dashboard URL the identity provider redirects to after authentication
+    pub redirect_uri: String,
+    /// The scopes requested from the identity provider, defaults to `openid email profile`
+    pub scopes: Option<Vec<String>>,
+    /// The ID token claim containing the groups of the user, defaults to `groups`
+    pub groups_claim: Option<String>,
+    /// Identity provider groups mapped to roles, the first matching mapping is used
+    pub role_mappings: Vec<OidcRoleMapping>,
+    /// The role assigned to users whose groups don't match any of the role mappings. Users are
+    /// denied access if this is not provided and none of their groups are mapped.
+    pub default_role_id: Option<String>,
+    /// The merchant account users are provisioned in, defaults to the merchant account of the
+    /// user configuring SSO
+    pub provisioning_merchant_id: Option<String>,
+    /// Disallow password and magic link sign in for users of the organization
+    pub is_sso_enforced: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct OidcRoleMapping {
+    pub group: String,
+    pub role_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct OidcSsoConfigResponse {
+    pub org_id: String,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub groups_claim: String,
+    pub role_mappings: Vec<OidcRoleMapping>,
+    pub default_role_id: Option<String>,
+    pub provisioning_merchant_id: String,
+    pub is_sso_enforced: bool,
+}
@@ -183,3 +183,95 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for redelivering all events which could not be delivered within a time range.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryBulkRetryRequest {
+    /// Redeliver events created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Redeliver events created before the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+}
+
+/// The response body for redelivering all events which could not be delivered within a time range.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryBulkRetryResponse {
+    /// The identifier of the job redelivering the events in the background. The outcome of each
+    /// redelivery can be checked by listing the delivery attempts of the events.
+    #[schema(example = "whbr_N62xBuZgXz5eFdUNXcqR")]
+    pub job_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryBulkRetryResponse {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryBulkRetryRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub request: WebhookDeliveryBulkRetryRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryBulkRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+/// The time range for which webhook delivery statistics are computed. Defaults to the last day.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryStatsConstraints {
+    /// Include delivery attempts made after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Include delivery attempts made before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+}
+
+/// Webhook delivery statistics of the webhook endpoint of a business profile.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeliveryStats {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The number of delivery attempts made, including retries.
+    pub total_attempts: i64,
+
+    /// The number of delivery attempts for which the endpoint responded with a 2xx status code.
+    pub successful_attempts: i64,
+
+    /// The number of delivery attempts which failed.
+    pub failed_attempts: i64,
+
+    /// The time since which no webhook could be delivered to the endpoint, if it is failing.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub failing_since: Option<PrimitiveDateTime>,
+
+    /// The time at which the endpoint was disabled due to sustained delivery failures.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub disabled_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryStatsRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub constraints: WebhookDeliveryStatsConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryStatsRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{
+    analytics::alerts, disputes, enums as api_enums, frm_review, mandates, payment_methods,
+    payments, refunds,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -187,6 +190,12 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse, title = "MandateResponse")]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = AnalyticsAlertResponse, title = "AnalyticsAlertResponse")]
+    AnalyticsAlertDetails(Box<alerts::AnalyticsAlertResponse>),
+    #[schema(value_type = FrmReviewQueueItem, title = "FrmReviewQueueItem")]
+    FraudCheckDetails(Box<frm_review::FrmReviewQueueItem>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -304,6 +304,11 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    CustomerEmail,
+    IpAddress,
+    CustomerId,
+    BillingCountry,
+    DeviceFingerprint,
 }
 
 #[derive(
@@ -1069,6 +1074,9 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    AnalyticsAlerts,
+    FraudChecks,
+    PaymentMethods,
 }
 
 #[derive(
@@ -1095,6 +1103,8 @@ pub enum EventType {
     PaymentCancelled,
     PaymentAuthorized,
     PaymentCaptured,
+    /// The authorization of a manual capture payment is about to expire at the issuer
+    PaymentAuthorizationExpiring,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -1105,8 +1115,201 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// The evidence submission deadline of an open dispute is approaching
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
+    /// An analytics alert rule configured by the merchant was breached
+    AnalyticsAlertTriggered,
+    /// A payment held for manual fraud review was approved
+    FrmApproved,
+    /// A payment held for manual fraud review was rejected
+    FrmRejected,
+    /// A payment method was saved for a customer
+    PaymentMethodCreated,
+    /// The details of a saved payment method changed, for example a reissued card
+    PaymentMethodUpdated,
+    /// A saved payment method was deleted
+    PaymentMethodDeleted,
+    /// A different saved payment method was set as the default of the customer
+    DefaultPaymentMethodChanged,
+}
+
+/// The analytics metric an alert rule is evaluated against
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertMetric {
+    /// Percentage of payments that succeeded
+    PaymentSuccessRate,
+    /// Percentage of payment attempts that succeeded at the connector
+    ConnectorSuccessRate,
+}
+
+/// The status of a merchant data encryption key rotation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyRotationStatus {
+    /// Data is being re-encrypted with the new key
+    InProgress,
+    /// All data has been re-encrypted and the previous key has been retired
+    Completed,
+    /// Re-encryption stopped after exhausting its retries and can be resumed
+    Failed,
+}
+
+/// The entities whose encrypted columns are re-encrypted during a key rotation, in the order in
+/// which they are processed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyRotationEntity {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    Customer,
+    Address,
+    PaymentMethod,
+    Event,
+}
+
+impl KeyRotationEntity {
+    /// The entity to be processed after this one, if any
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::MerchantAccount => Some(Self::MerchantConnectorAccount),
+            Self::MerchantConnectorAccount => Some(Self::Customer),
+            Self::Customer => Some(Self::Address),
+            Self::Address => Some(Self::PaymentMethod),
+            Self::PaymentMethod => Some(Self::Event),
+            Self::Event => None,
+        }
+    }
+}
+
+/// The kind of configuration whose changes are recorded in the audit trail
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    BusinessProfile,
+    RoutingAlgorithm,
+    DecisionManagerConfig,
+    ApiKey,
+    Role,
+    UserRole,
+    Blocklist,
+    Gsm,
+    Config,
+    OidcSsoConfig,
+    VelocityRules,
+    FrmRules,
+    FrmReviewPolicy,
+}
+
+/// The kind of change made to a configuration
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// The kind of credential that was used to make a configuration change
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    /// A dashboard user, authenticated with a JWT
+    User,
+    /// A merchant API key
+    ApiKey,
+    /// The admin API key
+    AdminApiKey,
+    /// Any other kind of credential
+    Other,
 }
 
 #[derive(
@@ -1268,6 +1471,33 @@ pub enum PaymentMethodStatus {
     AwaitingData,
 }
 
+/// The state of the network token provisioned for a saved card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// Indicates that the token can be used for payments.
+    Active,
+    /// Indicates that the token was suspended by the card network or the issuer, and the card
+    /// is used for payments until the token is resumed.
+    Suspended,
+    /// Indicates that the token was deleted and can no longer be used.
+    Deleted,
+}
+
 impl From<AttemptStatus> for PaymentMethodStatus {
     fn from(attempt_status: AttemptStatus) -> Self {
         match attempt_status {
@@ -2238,6 +2468,40 @@ pub enum MerchantDecision {
     AutoRefunded,
 }
 
+/// The outcome of the manual review of a payment held by a fraud check
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmReviewDecision {
+    /// Approved by a reviewer
+    Approved,
+    /// Rejected by a reviewer
+    Rejected,
+    /// Approved on expiry of the review deadline
+    AutoApproved,
+    /// Rejected on expiry of the review deadline
+    AutoRejected,
+}
+
+impl FrmReviewDecision {
+    pub fn is_approved(self) -> bool {
+        matches!(self, Self::Approved | Self::AutoApproved)
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2491,6 +2755,7 @@ pub enum TransactionStatus {
     strum::Display,
     strum::EnumString,
     strum::EnumIter,
+    ToSchema,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
@@ -1,5 +1,5 @@
 use common_enums::Currency;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rusty_money::Money;
 
 use crate::{
@@ -26,6 +26,39 @@ pub fn convert(
     }
 }
 
+/// Converts the amount like [`convert`], increasing the converted amount by a markup given in
+/// basis points
+pub fn convert_with_markup(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+    markup_bps: u16,
+) -> Result<Decimal, CurrencyConversionError> {
+    let converted_amount = convert(ex_rates, from_currency, to_currency, amount)?;
+    let markup_factor = Decimal::ONE
+        .checked_add(Decimal::new(i64::from(markup_bps), 4))
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)?;
+    converted_amount
+        .checked_mul(markup_factor)
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+}
+
+/// Converts an amount in the minor unit of the currency to its major unit
+pub fn to_major_unit(amount: i64, currency: Currency) -> Decimal {
+    *Money::from_minor(amount, currency_match(currency)).amount()
+}
+
+/// Converts an amount in the major unit of the currency to its minor unit, rounding to the
+/// nearest minor unit
+pub fn to_minor_unit(amount: Decimal, currency: Currency) -> Result<i64, CurrencyConversionError> {
+    10_i64
+        .checked_pow(currency_match(currency).exponent)
+        .and_then(|factor| amount.checked_mul(Decimal::from(factor)))
+        .and_then(|amount| amount.round().to_i64())
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
@@ -98,4 +131,23 @@ mod tests {
             amount, convert_from, convert_to, res
         );
     }
+
+    #[test]
+    fn conversion_with_markup() {
+        use super::*;
+        let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+        let inr_conversion_rates = CurrencyFactors::new(Decimal::new(80, 0), Decimal::new(125, 4));
+        let usd_conversion_rates = CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
+        conversion.insert(Currency::INR, inr_conversion_rates);
+        conversion.insert(Currency::USD, usd_conversion_rates);
+        let sample_rate = ExchangeRates::new(Currency::USD, conversion);
+        let without_markup =
+            convert_with_markup(&sample_rate, Currency::USD, Currency::INR, 1000, 0)
+                .expect("converted_currency");
+        let with_markup =
+            convert_with_markup(&sample_rate, Currency::USD, Currency::INR, 1000, 250)
+                .expect("converted_currency");
+        assert_eq!(without_markup, Decimal::new(800, 0));
+        assert_eq!(with_markup, Decimal::new(820, 0));
+    }
 }
@@ -0,0 +1,156 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums,
+    schema::{analytics_alert, analytics_alert_rule},
+};
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
+#[diesel(table_name = analytics_alert_rule, primary_key(rule_id))]
+pub struct AnalyticsAlertRule {
+    pub rule_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub name: String,
+    pub metric: enums::AnalyticsAlertMetric,
+    pub connector: Option<String>,
+    pub drop_threshold: f64,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_days: i32,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_alert_rule)]
+pub struct AnalyticsAlertRuleNew {
+    pub rule_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub name: String,
+    pub metric: enums::AnalyticsAlertMetric,
+    pub connector: Option<String>,
+    pub drop_threshold: f64,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_days: i32,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsAlertRuleUpdate {
+    Update {
+        name: Option<String>,
+        drop_threshold: Option<f64>,
+        evaluation_window_in_mins: Option<i32>,
+        baseline_window_in_days: Option<i32>,
+        is_active: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_alert_rule)]
+pub struct AnalyticsAlertRuleUpdateInternal {
+    name: Option<String>,
+    drop_threshold: Option<f64>,
+    evaluation_window_in_mins: Option<i32>,
+    baseline_window_in_days: Option<i32>,
+    is_active: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<AnalyticsAlertRuleUpdate> for AnalyticsAlertRuleUpdateInternal {
+    fn from(value: AnalyticsAlertRuleUpdate) -> Self {
+        match value {
+            AnalyticsAlertRuleUpdate::Update {
+                name,
+                drop_threshold,
+                evaluation_window_in_mins,
+                baseline_window_in_days,
+                is_active,
+            } => Self {
+                name,
+                drop_threshold,
+                evaluation_window_in_mins,
+                baseline_window_in_days,
+                is_active,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
+#[diesel(table_name = analytics_alert, primary_key(alert_id))]
+pub struct AnalyticsAlert {
+    pub alert_id: String,
+    pub rule_id: String,
+    pub merchant_id: String,
+    pub metric: enums::AnalyticsAlertMetric,
+    pub connector: Option<String>,
+    pub observed_value: f64,
+    pub baseline_value: f64,
+    pub drop_threshold: f64,
+    pub window_start: PrimitiveDateTime,
+    pub window_end: PrimitiveDateTime,
+    pub email_sent: bool,
+    pub webhook_sent: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_alert)]
+pub struct AnalyticsAlertNew {
+    pub alert_id: String,
+    pub rule_id: String,
+    pub merchant_id: String,
+    pub metric: enums::AnalyticsAlertMetric,
+    pub connector: Option<String>,
+    pub observed_value: f64,
+    pub baseline_value: f64,
+    pub drop_threshold: f64,
+    pub window_start: PrimitiveDateTime,
+    pub window_end: PrimitiveDateTime,
+    pub email_sent: bool,
+    pub webhook_sent: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsAlertUpdate {
+    NotificationStatusUpdate {
+        email_sent: bool,
+        webhook_sent: bool,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_alert)]
+pub struct AnalyticsAlertUpdateInternal {
+    email_sent: Option<bool>,
+    webhook_sent: Option<bool>,
+}
+
+impl From<AnalyticsAlertUpdate> for AnalyticsAlertUpdateInternal {
+    fn from(value: AnalyticsAlertUpdate) -> Self {
+        match value {
+            AnalyticsAlertUpdate::NotificationStatusUpdate {
+                email_sent,
+                webhook_sent,
+            } => Self {
+                email_sent: Some(email_sent),
+                webhook_sent: Some(webhook_sent),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsAlertTrackingData {
+    pub rule_id: String,
+    pub merchant_id: String,
+}
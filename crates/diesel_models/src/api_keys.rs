@@ -2,7 +2,7 @@ use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{diesel_impl::OptionalDieselArray, enums, schema::api_keys};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Identifiable, Queryable)]
 #[diesel(table_name = api_keys, primary_key(key_id))]
@@ -16,6 +16,12 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// The permission groups the key is restricted to, `None` if the key has full access
+    #[diesel(deserialize_as = OptionalDieselArray<enums::PermissionGroup>)]
+    pub permission_groups: Option<Vec<enums::PermissionGroup>>,
+    /// The CIDR blocks the key may be used from, `None` if the key may be used from anywhere
+    #[diesel(deserialize_as = OptionalDieselArray<String>)]
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +36,8 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<enums::PermissionGroup>>,
+    pub allowed_ips: Option<Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -39,6 +47,8 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        permission_groups: Option<Option<Vec<enums::PermissionGroup>>>,
+        allowed_ips: Option<Option<Vec<String>>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -52,6 +62,8 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Option<Vec<enums::PermissionGroup>>>,
+    pub allowed_ips: Option<Option<Vec<String>>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -62,17 +74,23 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
+                allowed_ips,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
+                allowed_ips,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                permission_groups: None,
+                allowed_ips: None,
             },
         }
     }
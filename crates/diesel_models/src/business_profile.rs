@@ -40,6 +40,10 @@ pub struct BusinessProfile {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+    pub webhook_retry_policy: Option<serde_json::Value>,
+    pub webhook_endpoint_failing_since: Option<time::PrimitiveDateTime>,
+    pub webhook_endpoint_disabled_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -71,6 +75,10 @@ pub struct BusinessProfileNew {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+    pub webhook_retry_policy: Option<serde_json::Value>,
+    pub webhook_endpoint_failing_since: Option<time::PrimitiveDateTime>,
+    pub webhook_endpoint_disabled_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -99,6 +107,10 @@ pub struct BusinessProfileUpdateInternal {
     pub is_connector_agnostic_mit_enabled: Option<bool>,
     pub use_billing_as_payment_method_billing: Option<bool>,
     pub collect_shipping_details_from_wallet_connector: Option<bool>,
+    pub authorization_expiry_policy: Option<serde_json::Value>,
+    pub webhook_retry_policy: Option<serde_json::Value>,
+    pub webhook_endpoint_failing_since: Option<Option<time::PrimitiveDateTime>>,
+    pub webhook_endpoint_disabled_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -124,6 +136,8 @@ pub enum BusinessProfileUpdate {
         extended_card_info_config: Option<pii::SecretSerdeValue>,
         use_billing_as_payment_method_billing: Option<bool>,
         collect_shipping_details_from_wallet_connector: Option<bool>,
+        authorization_expiry_policy: Option<serde_json::Value>,
+        webhook_retry_policy: Option<serde_json::Value>,
     },
     ExtendedCardInfoUpdate {
         is_extended_card_info_enabled: Option<bool>,
@@ -131,6 +145,10 @@ pub enum BusinessProfileUpdate {
     ConnectorAgnosticMitUpdate {
         is_connector_agnostic_mit_enabled: Option<bool>,
     },
+    WebhookEndpointHealthUpdate {
+        webhook_endpoint_failing_since: Option<time::PrimitiveDateTime>,
+        webhook_endpoint_disabled_at: Option<time::PrimitiveDateTime>,
+    },
 }
 
 impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
@@ -157,6 +175,8 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 extended_card_info_config,
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
+                authorization_expiry_policy,
+                webhook_retry_policy,
             } => Self {
                 profile_name,
                 modified_at,
@@ -178,6 +198,8 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 extended_card_info_config,
                 use_billing_as_payment_method_billing,
                 collect_shipping_details_from_wallet_connector,
+                authorization_expiry_policy,
+                webhook_retry_policy,
                 ..Default::default()
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
@@ -192,6 +214,14 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 is_connector_agnostic_mit_enabled,
                 ..Default::default()
             },
+            BusinessProfileUpdate::WebhookEndpointHealthUpdate {
+                webhook_endpoint_failing_since,
+                webhook_endpoint_disabled_at,
+            } => Self {
+                webhook_endpoint_failing_since: Some(webhook_endpoint_failing_since),
+                webhook_endpoint_disabled_at: Some(webhook_endpoint_disabled_at),
+                ..Default::default()
+            },
         }
     }
 }
@@ -225,6 +255,10 @@ impl From<BusinessProfileNew> for BusinessProfile {
             use_billing_as_payment_method_billing: new.use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector: new
                 .collect_shipping_details_from_wallet_connector,
+            authorization_expiry_policy: new.authorization_expiry_policy,
+            webhook_retry_policy: new.webhook_retry_policy,
+            webhook_endpoint_failing_since: new.webhook_endpoint_failing_since,
+            webhook_endpoint_disabled_at: new.webhook_endpoint_disabled_at,
         }
     }
 }
@@ -254,6 +288,10 @@ impl BusinessProfileUpdate {
             is_connector_agnostic_mit_enabled,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            authorization_expiry_policy,
+            webhook_retry_policy,
+            webhook_endpoint_failing_since,
+            webhook_endpoint_disabled_at,
         } = self.into();
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -280,6 +318,12 @@ impl BusinessProfileUpdate {
             extended_card_info_config,
             use_billing_as_payment_method_billing,
             collect_shipping_details_from_wallet_connector,
+            authorization_expiry_policy,
+            webhook_retry_policy,
+            webhook_endpoint_failing_since: webhook_endpoint_failing_since
+                .unwrap_or(source.webhook_endpoint_failing_since),
+            webhook_endpoint_disabled_at: webhook_endpoint_disabled_at
+                .unwrap_or(source.webhook_endpoint_disabled_at),
             ..source
         }
     }
@@ -0,0 +1,36 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::config_audit_log};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = config_audit_log, primary_key(audit_id))]
+pub struct ConfigAuditLog {
+    pub audit_id: String,
+    pub merchant_id: Option<String>,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::AuditAction,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub request_id: Option<String>,
+    pub changes: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = config_audit_log)]
+pub struct ConfigAuditLogNew {
+    pub audit_id: String,
+    pub merchant_id: Option<String>,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::AuditAction,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub request_id: Option<String>,
+    pub changes: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
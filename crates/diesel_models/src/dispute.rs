@@ -1,7 +1,7 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
@@ -135,3 +135,13 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DisputeDeadlineReminderTrackingData {
+    pub merchant_id: String,
+    pub dispute_id: String,
+    pub challenge_required_by: PrimitiveDateTime,
+    // Days on which reminders about the dispute have to be sent, prior to its deadline.
+    pub reminder_days: Vec<u8>,
+}
@@ -68,6 +68,9 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    AnalyticsAlertDetails,
+    FraudCheckDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -27,6 +27,9 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    pub review_assigned_to: Option<String>,
+    pub review_deadline: Option<PrimitiveDateTime>,
+    pub review_decision: Option<storage_enums::FrmReviewDecision>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -49,6 +52,9 @@ pub struct FraudCheckNew {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub review_assigned_to: Option<String>,
+    pub review_deadline: Option<PrimitiveDateTime>,
+    pub review_decision: Option<storage_enums::FrmReviewDecision>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +74,15 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    ReviewAssignmentUpdate {
+        review_assigned_to: Option<String>,
+    },
+    ReviewDeadlineUpdate {
+        review_deadline: Option<PrimitiveDateTime>,
+    },
+    ReviewDecisionUpdate {
+        review_decision: storage_enums::FrmReviewDecision,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -79,8 +94,11 @@ pub struct FraudCheckUpdateInternal {
     frm_score: Option<i32>,
     frm_error: Option<Option<String>>,
     metadata: Option<serde_json::Value>,
-    last_step: FraudCheckLastStep,
+    last_step: Option<FraudCheckLastStep>,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    review_assigned_to: Option<Option<String>>,
+    review_deadline: Option<Option<PrimitiveDateTime>>,
+    review_decision: Option<storage_enums::FrmReviewDecision>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -101,7 +119,7 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_reason,
                 frm_score,
                 metadata,
-                last_step,
+                last_step: Some(last_step),
                 payment_capture_method,
                 ..Default::default()
             },
@@ -111,8 +129,28 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
             } => Self {
                 frm_status: Some(status),
                 frm_error: error_message,
+                last_step: Some(FraudCheckLastStep::default()),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewAssignmentUpdate { review_assigned_to } => Self {
+                review_assigned_to: Some(review_assigned_to),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewDeadlineUpdate { review_deadline } => Self {
+                review_deadline: Some(review_deadline),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewDecisionUpdate { review_decision } => Self {
+                review_decision: Some(review_decision),
                 ..Default::default()
             },
         }
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrmReviewDeadlineTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub expiry_decision: storage_enums::FrmReviewDecision,
+}
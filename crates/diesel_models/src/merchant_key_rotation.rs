@@ -0,0 +1,368 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    address::Address, customers::Customer, encryption::Encryption, enums, events::Event,
+    merchant_account::MerchantAccount, merchant_connector_account::MerchantConnectorAccount,
+    payment_method::PaymentMethod, schema::merchant_key_rotation,
+};
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
+#[diesel(table_name = merchant_key_rotation, primary_key(rotation_id))]
+pub struct MerchantKeyRotation {
+    pub rotation_id: String,
+    pub merchant_id: String,
+    pub from_key_version: i32,
+    pub to_key_version: i32,
+    pub status: enums::KeyRotationStatus,
+    pub current_entity: Option<enums::KeyRotationEntity>,
+    pub last_processed_id: Option<String>,
+    pub records_re_encrypted: i64,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_rotation)]
+pub struct MerchantKeyRotationNew {
+    pub rotation_id: String,
+    pub merchant_id: String,
+    pub from_key_version: i32,
+    pub to_key_version: i32,
+    pub status: enums::KeyRotationStatus,
+    pub current_entity: Option<enums::KeyRotationEntity>,
+    pub last_processed_id: Option<String>,
+    pub records_re_encrypted: i64,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<MerchantKeyRotationNew> for MerchantKeyRotation {
+    fn from(value: MerchantKeyRotationNew) -> Self {
+        Self {
+            rotation_id: value.rotation_id,
+            merchant_id: value.merchant_id,
+            from_key_version: value.from_key_version,
+            to_key_version: value.to_key_version,
+            status: value.status,
+            current_entity: value.current_entity,
+            last_processed_id: value.last_processed_id,
+            records_re_encrypted: value.records_re_encrypted,
+            error_message: None,
+            created_at: value.created_at,
+            modified_at: value.modified_at,
+            completed_at: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MerchantKeyRotationUpdate {
+    ProgressUpdate {
+        current_entity: Option<enums::KeyRotationEntity>,
+        last_processed_id: Option<String>,
+        records_re_encrypted: i64,
+    },
+    StatusUpdate {
+        status: enums::KeyRotationStatus,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_rotation)]
+pub struct MerchantKeyRotationUpdateInternal {
+    status: Option<enums::KeyRotationStatus>,
+    current_entity: Option<Option<enums::KeyRotationEntity>>,
+    last_processed_id: Option<Option<String>>,
+    records_re_encrypted: Option<i64>,
+    error_message: Option<Option<String>>,
+    modified_at: PrimitiveDateTime,
+    completed_at: Option<PrimitiveDateTime>,
+}
+
+impl MerchantKeyRotationUpdateInternal {
+    pub fn apply_changeset(self, source: MerchantKeyRotation) -> MerchantKeyRotation {
+        MerchantKeyRotation {
+            status: self.status.unwrap_or(source.status),
+            current_entity: self.current_entity.unwrap_or(source.current_entity),
+            last_processed_id: self.last_processed_id.unwrap_or(source.last_processed_id),
+            records_re_encrypted: self
+                .records_re_encrypted
+                .unwrap_or(source.records_re_encrypted),
+            error_message: self.error_message.unwrap_or(source.error_message),
+            modified_at: self.modified_at,
+            completed_at: self.completed_at.or(source.completed_at),
+            ..source
+        }
+    }
+}
+
+impl From<MerchantKeyRotationUpdate> for MerchantKeyRotationUpdateInternal {
+    fn from(value: MerchantKeyRotationUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            MerchantKeyRotationUpdate::ProgressUpdate {
+                current_entity,
+                last_processed_id,
+                records_re_encrypted,
+            } => Self {
+                status: None,
+                current_entity: Some(current_entity),
+                last_processed_id: Some(last_processed_id),
+                records_re_encrypted: Some(records_re_encrypted),
+                error_message: None,
+                modified_at: now,
+                completed_at: None,
+            },
+            MerchantKeyRotationUpdate::StatusUpdate {
+                status,
+                error_message,
+            } => Self {
+                status: Some(status),
+                current_entity: None,
+                last_processed_id: None,
+                records_re_encrypted: None,
+                error_message: Some(error_message),
+                modified_at: now,
+                completed_at: (status == enums::KeyRotationStatus::Completed).then_some(now),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerchantKeyRotationTrackingData {
+    pub rotation_id: String,
+    pub merchant_id: String,
+}
+
+/// An encrypted column value as read from the database, along with the value it is to be
+/// replaced with. The column is only updated if it still holds the value that was read.
+#[derive(Clone, Debug)]
+pub struct EncryptedColumn {
+    pub original: Encryption,
+    pub updated: Encryption,
+}
+
+impl EncryptedColumn {
+    fn new(value: Encryption) -> Self {
+        Self {
+            original: value.clone(),
+            updated: value,
+        }
+    }
+
+    fn new_optional(value: Option<Encryption>) -> Option<Self> {
+        value.map(Self::new)
+    }
+
+    pub(crate) fn original(column: &Option<Self>) -> Option<Encryption> {
+        column.as_ref().map(|column| column.original.clone())
+    }
+
+    pub(crate) fn updated(column: &Option<Self>) -> Option<Encryption> {
+        column.as_ref().map(|column| column.updated.clone())
+    }
+}
+
+/// The encrypted columns of a single record of an entity processed during a key rotation
+#[derive(Clone, Debug)]
+pub enum EncryptedRecord {
+    MerchantAccount {
+        merchant_id: String,
+        merchant_name: Option<EncryptedColumn>,
+        merchant_details: Option<EncryptedColumn>,
+    },
+    MerchantConnectorAccount {
+        merchant_connector_id: String,
+        connector_account_details: EncryptedColumn,
+    },
+    Customer {
+        customer_id: String,
+        name: Option<EncryptedColumn>,
+        email: Option<EncryptedColumn>,
+        phone: Option<EncryptedColumn>,
+    },
+    Address {
+        address_id: String,
+        /// Set for addresses of payments, which are stored in Redis for merchants using the KV
+        /// storage scheme
+        payment_id: Option<String>,
+        line1: Option<EncryptedColumn>,
+        line2: Option<EncryptedColumn>,
+        line3: Option<EncryptedColumn>,
+        state: Option<EncryptedColumn>,
+        zip: Option<EncryptedColumn>,
+        first_name: Option<EncryptedColumn>,
+        last_name: Option<EncryptedColumn>,
+        phone_number: Option<EncryptedColumn>,
+        email: Option<EncryptedColumn>,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+        payment_method_data: Option<EncryptedColumn>,
+        payment_method_billing_address: Option<EncryptedColumn>,
+    },
+    Event {
+        event_id: String,
+        request: Option<EncryptedColumn>,
+        response: Option<EncryptedColumn>,
+    },
+}
+
+impl EncryptedRecord {
+    /// The identifier used to order records of an entity, and to resume processing after the
+    /// last record that was processed
+    pub fn record_id(&self) -> &str {
+        match self {
+            Self::MerchantAccount { merchant_id, .. } => merchant_id,
+            Self::MerchantConnectorAccount {
+                merchant_connector_id,
+                ..
+            } => merchant_connector_id,
+            Self::Customer { customer_id, .. } => customer_id,
+            Self::Address { address_id, .. } => address_id,
+            Self::PaymentMethod {
+                payment_method_id, ..
+            } => payment_method_id,
+            Self::Event { event_id, .. } => event_id,
+        }
+    }
+
+    /// All non-null encrypted columns of the record
+    pub fn columns_mut(&mut self) -> Vec<&mut EncryptedColumn> {
+        match self {
+            Self::MerchantAccount {
+                merchant_name,
+                merchant_details,
+                ..
+            } => [merchant_name, merchant_details]
+                .into_iter()
+                .flat_map(Option::as_mut)
+                .collect(),
+            Self::MerchantConnectorAccount {
+                connector_account_details,
+                ..
+            } => vec![connector_account_details],
+            Self::Customer {
+                name, email, phone, ..
+            } => [name, email, phone]
+                .into_iter()
+                .flat_map(Option::as_mut)
+                .collect(),
+            Self::Address {
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                email,
+                ..
+            } => [
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                email,
+            ]
+            .into_iter()
+            .flat_map(Option::as_mut)
+            .collect(),
+            Self::PaymentMethod {
+                payment_method_data,
+                payment_method_billing_address,
+                ..
+            } => [payment_method_data, payment_method_billing_address]
+                .into_iter()
+                .flat_map(Option::as_mut)
+                .collect(),
+            Self::Event {
+                request, response, ..
+            } => [request, response]
+                .into_iter()
+                .flat_map(Option::as_mut)
+                .collect(),
+        }
+    }
+}
+
+impl From<MerchantAccount> for EncryptedRecord {
+    fn from(value: MerchantAccount) -> Self {
+        Self::MerchantAccount {
+            merchant_id: value.merchant_id,
+            merchant_name: EncryptedColumn::new_optional(value.merchant_name),
+            merchant_details: EncryptedColumn::new_optional(value.merchant_details),
+        }
+    }
+}
+
+impl From<MerchantConnectorAccount> for EncryptedRecord {
+    fn from(value: MerchantConnectorAccount) -> Self {
+        Self::MerchantConnectorAccount {
+            merchant_connector_id: value.merchant_connector_id,
+            connector_account_details: EncryptedColumn::new(value.connector_account_details),
+        }
+    }
+}
+
+impl From<Customer> for EncryptedRecord {
+    fn from(value: Customer) -> Self {
+        Self::Customer {
+            customer_id: value.customer_id,
+            name: EncryptedColumn::new_optional(value.name),
+            email: EncryptedColumn::new_optional(value.email),
+            phone: EncryptedColumn::new_optional(value.phone),
+        }
+    }
+}
+
+impl From<Address> for EncryptedRecord {
+    fn from(value: Address) -> Self {
+        Self::Address {
+            address_id: value.address_id,
+            payment_id: value.payment_id,
+            line1: EncryptedColumn::new_optional(value.line1),
+            line2: EncryptedColumn::new_optional(value.line2),
+            line3: EncryptedColumn::new_optional(value.line3),
+            state: EncryptedColumn::new_optional(value.state),
+            zip: EncryptedColumn::new_optional(value.zip),
+            first_name: EncryptedColumn::new_optional(value.first_name),
+            last_name: EncryptedColumn::new_optional(value.last_name),
+            phone_number: EncryptedColumn::new_optional(value.phone_number),
+            email: EncryptedColumn::new_optional(value.email),
+        }
+    }
+}
+
+impl From<PaymentMethod> for EncryptedRecord {
+    fn from(value: PaymentMethod) -> Self {
+        Self::PaymentMethod {
+            payment_method_id: value.payment_method_id,
+            payment_method_data: EncryptedColumn::new_optional(value.payment_method_data),
+            payment_method_billing_address: EncryptedColumn::new_optional(
+                value.payment_method_billing_address,
+            ),
+        }
+    }
+}
+
+impl From<Event> for EncryptedRecord {
+    fn from(value: Event) -> Self {
+        Self::Event {
+            event_id: value.event_id,
+            request: EncryptedColumn::new_optional(value.request),
+            response: EncryptedColumn::new_optional(value.response),
+        }
+    }
+}
@@ -20,6 +20,9 @@ pub struct MerchantKeyStore {
     pub key: Encryption,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    pub previous_key: Option<Encryption>,
+    pub previous_key_version: Option<i32>,
 }
 
 #[derive(
@@ -30,13 +33,51 @@ pub struct MerchantKeyStoreNew {
     pub merchant_id: String,
     pub key: Encryption,
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
 }
 
-#[derive(
-    Clone, Debug, serde::Serialize, serde::Deserialize, AsChangeset, router_derive::DebugAsDisplay,
-)]
+#[derive(Clone, Debug)]
+pub enum MerchantKeyStoreUpdate {
+    /// Replaces the key with a newly generated one, retaining the replaced key until all data
+    /// encrypted with it has been re-encrypted
+    KeyRotated {
+        key: Encryption,
+        key_version: i32,
+        previous_key: Encryption,
+        previous_key_version: i32,
+    },
+    PreviousKeyRetired,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = merchant_key_store)]
 pub struct MerchantKeyStoreUpdateInternal {
-    pub merchant_id: String,
-    pub key: Encryption,
+    key: Option<Encryption>,
+    key_version: Option<i32>,
+    previous_key: Option<Option<Encryption>>,
+    previous_key_version: Option<Option<i32>>,
+}
+
+impl From<MerchantKeyStoreUpdate> for MerchantKeyStoreUpdateInternal {
+    fn from(key_store_update: MerchantKeyStoreUpdate) -> Self {
+        match key_store_update {
+            MerchantKeyStoreUpdate::KeyRotated {
+                key,
+                key_version,
+                previous_key,
+                previous_key_version,
+            } => Self {
+                key: Some(key),
+                key_version: Some(key_version),
+                previous_key: Some(Some(previous_key)),
+                previous_key_version: Some(Some(previous_key_version)),
+            },
+            MerchantKeyStoreUpdate::PreviousKeyRetired => Self {
+                key: None,
+                key_version: None,
+                previous_key: Some(None),
+                previous_key_version: Some(None),
+            },
+        }
+    }
 }
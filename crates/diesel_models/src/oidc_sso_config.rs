@@ -0,0 +1,104 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::oidc_sso_config, DieselArray};
+
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = oidc_sso_config, primary_key(org_id))]
+pub struct OidcSsoConfig {
+    pub org_id: String,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Encryption,
+    pub redirect_uri: String,
+    #[diesel(deserialize_as = DieselArray<String>)]
+    pub scopes: Vec<String>,
+    pub groups_claim: String,
+    pub role_mappings: serde_json::Value,
+    pub default_role_id: Option<String>,
+    pub provisioning_merchant_id: String,
+    pub is_sso_enforced: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = oidc_sso_config)]
+pub struct OidcSsoConfigNew {
+    pub org_id: String,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Encryption,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub groups_claim: String,
+    pub role_mappings: serde_json::Value,
+    pub default_role_id: Option<String>,
+    pub provisioning_merchant_id: String,
+    pub is_sso_enforced: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = oidc_sso_config)]
+pub struct OidcSsoConfigUpdateInternal {
+    issuer_url: String,
+    client_id: String,
+    client_secret: Option<Encryption>,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    groups_claim: String,
+    role_mappings: serde_json::Value,
+    default_role_id: Option<Option<String>>,
+    provisioning_merchant_id: String,
+    is_sso_enforced: bool,
+    last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum OidcSsoConfigUpdate {
+    Update {
+        issuer_url: String,
+        client_id: String,
+        client_secret: Option<Encryption>,
+        redirect_uri: String,
+        scopes: Vec<String>,
+        groups_claim: String,
+        role_mappings: serde_json::Value,
+        default_role_id: Option<String>,
+        provisioning_merchant_id: String,
+        is_sso_enforced: bool,
+    },
+}
+
+impl From<OidcSsoConfigUpdate> for OidcSsoConfigUpdateInternal {
+    fn from(value: OidcSsoConfigUpdate) -> Self {
+        match value {
+            OidcSsoConfigUpdate::Update {
+                issuer_url,
+                client_id,
+                client_secret,
+                redirect_uri,
+                scopes,
+                groups_claim,
+                role_mappings,
+                default_role_id,
+                provisioning_merchant_id,
+                is_sso_enforced,
+            } => Self {
+                issuer_url,
+                client_id,
+                client_secret,
+                redirect_uri,
+                scopes,
+                groups_claim,
+                role_mappings,
+                default_role_id: Some(default_role_id),
+                provisioning_merchant_id,
+                is_sso_enforced,
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
@@ -72,6 +72,13 @@ pub struct PaymentAttempt {
     pub payment_method_billing_address_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub fx_quote_id: Option<String>,
+    pub presentment_amount: Option<i64>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 impl PaymentAttempt {
@@ -90,6 +97,24 @@ pub struct PaymentListFilters {
     pub payment_method: Vec<storage_enums::PaymentMethod>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScheduledCaptureTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub capture_on: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub authorization_expires_at: PrimitiveDateTime,
+}
+
 #[derive(
     Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
 )]
@@ -154,6 +179,13 @@ pub struct PaymentAttemptNew {
     pub payment_method_billing_address_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub fx_quote_id: Option<String>,
+    pub presentment_amount: Option<i64>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 impl PaymentAttemptNew {
@@ -239,6 +271,11 @@ pub enum PaymentAttemptUpdate {
         payment_method_billing_address_id: Option<String>,
         client_source: Option<String>,
         client_version: Option<String>,
+        fx_quote_id: Option<String>,
+        presentment_amount: Option<i64>,
+        presentment_currency: Option<storage_enums::Currency>,
+        fx_rate: Option<String>,
+        fx_markup_bps: Option<i32>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -281,6 +318,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        authorization_expires_at: Option<PrimitiveDateTime>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -396,6 +434,12 @@ pub struct PaymentAttemptUpdateInternal {
     payment_method_billing_address_id: Option<String>,
     client_source: Option<String>,
     client_version: Option<String>,
+    authorization_expires_at: Option<PrimitiveDateTime>,
+    fx_quote_id: Option<String>,
+    presentment_amount: Option<i64>,
+    presentment_currency: Option<storage_enums::Currency>,
+    fx_rate: Option<String>,
+    fx_markup_bps: Option<i32>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -463,6 +507,12 @@ impl PaymentAttemptUpdate {
             fingerprint_id,
             client_source,
             client_version,
+            authorization_expires_at,
+            fx_quote_id,
+            presentment_amount,
+            presentment_currency,
+            fx_rate,
+            fx_markup_bps,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -513,6 +563,12 @@ impl PaymentAttemptUpdate {
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
             client_source: client_source.or(source.client_source),
             client_version: client_version.or(source.client_version),
+            authorization_expires_at: authorization_expires_at.or(source.authorization_expires_at),
+            fx_quote_id: fx_quote_id.or(source.fx_quote_id),
+            presentment_amount: presentment_amount.or(source.presentment_amount),
+            presentment_currency: presentment_currency.or(source.presentment_currency),
+            fx_rate: fx_rate.or(source.fx_rate),
+            fx_markup_bps: fx_markup_bps.or(source.fx_markup_bps),
             ..source
         }
     }
@@ -601,6 +657,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_id,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount,
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -632,6 +693,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 capture_method,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount,
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -698,6 +764,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -719,6 +786,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -59,6 +59,7 @@ pub struct PaymentIntent {
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub l2_l3_data: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(
@@ -113,6 +114,7 @@ pub struct PaymentIntentNew {
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub l2_l3_data: Option<pii::SecretSerdeValue>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,6 +172,7 @@ pub enum PaymentIntentUpdate {
         fingerprint_id: Option<String>,
         request_external_three_ds_authentication: Option<bool>,
         frm_metadata: Option<pii::SecretSerdeValue>,
+        l2_l3_data: Option<pii::SecretSerdeValue>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -243,6 +246,7 @@ pub struct PaymentIntentUpdateInternal {
     pub fingerprint_id: Option<String>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub l2_l3_data: Option<pii::SecretSerdeValue>,
 }
 
 impl PaymentIntentUpdate {
@@ -279,6 +283,7 @@ impl PaymentIntentUpdate {
             fingerprint_id,
             request_external_three_ds_authentication,
             frm_metadata,
+            l2_l3_data,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -318,6 +323,7 @@ impl PaymentIntentUpdate {
                 .or(source.request_external_three_ds_authentication),
 
             frm_metadata: frm_metadata.or(source.frm_metadata),
+            l2_l3_data: l2_l3_data.or(source.l2_l3_data),
             ..source
         }
     }
@@ -348,6 +354,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -371,6 +378,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 fingerprint_id,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
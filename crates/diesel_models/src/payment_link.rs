@@ -1,5 +1,5 @@
 use common_utils::types::MinorUnit;
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -25,6 +25,7 @@ pub struct PaymentLink {
     pub payment_link_config: Option<serde_json::Value>,
     pub description: Option<String>,
     pub profile_id: Option<String>,
+    pub reusable_payment_link_id: Option<String>,
 }
 
 #[derive(
@@ -55,4 +56,30 @@ pub struct PaymentLinkNew {
     pub payment_link_config: Option<serde_json::Value>,
     pub description: Option<String>,
     pub profile_id: Option<String>,
+    pub reusable_payment_link_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum PaymentLinkUpdate {
+    ReusablePaymentLinkUpdate { reusable_payment_link_id: String },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    reusable_payment_link_id: Option<String>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(value: PaymentLinkUpdate) -> Self {
+        match value {
+            PaymentLinkUpdate::ReusablePaymentLinkUpdate {
+                reusable_payment_link_id,
+            } => Self {
+                reusable_payment_link_id: Some(reusable_payment_link_id),
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
 }
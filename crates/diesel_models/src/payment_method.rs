@@ -43,6 +43,10 @@ pub struct PaymentMethod {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_details: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -80,6 +84,10 @@ pub struct PaymentMethodNew {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_details: Option<serde_json::Value>,
 }
 
 impl PaymentMethodNew {
@@ -94,6 +102,14 @@ pub struct TokenizeCoreWorkflow {
     pub pm: storage_enums::PaymentMethod,
 }
 
+// Tracking data by process_tracker
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CardAccountUpdaterTrackingData {
+    pub merchant_id: String,
+    // Number of saved cards of the merchant already checked in the current pass
+    pub offset: i64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum PaymentMethodUpdate {
     MetadataUpdate {
@@ -123,6 +139,16 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    NetworkTokenUpdate {
+        network_token_reference_id: Option<String>,
+        network_token_locker_id: Option<String>,
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+        network_token_details: Option<serde_json::Value>,
+    },
+    EncryptedDataUpdate {
+        payment_method_data: Option<Encryption>,
+        payment_method_billing_address: Option<Encryption>,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -152,6 +178,11 @@ pub struct PaymentMethodUpdateInternal {
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    network_token_reference_id: Option<String>,
+    network_token_locker_id: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    network_token_details: Option<serde_json::Value>,
+    payment_method_billing_address: Option<Encryption>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -170,6 +201,11 @@ impl PaymentMethodUpdateInternal {
             status,
             connector_mandate_details,
             updated_by,
+            network_token_reference_id,
+            network_token_locker_id,
+            network_token_status,
+            network_token_details,
+            payment_method_billing_address,
             ..
         } = self;
 
@@ -183,6 +219,14 @@ impl PaymentMethodUpdateInternal {
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            network_token_reference_id: network_token_reference_id
+                .map_or(source.network_token_reference_id, Some),
+            network_token_locker_id: network_token_locker_id
+                .map_or(source.network_token_locker_id, Some),
+            network_token_status: network_token_status.map_or(source.network_token_status, Some),
+            network_token_details: network_token_details.map_or(source.network_token_details, Some),
+            payment_method_billing_address: payment_method_billing_address
+                .map_or(source.payment_method_billing_address, Some),
             ..source
         }
     }
@@ -203,6 +247,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -218,6 +267,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -231,6 +285,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -247,6 +306,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -260,6 +324,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -280,6 +349,11 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -295,6 +369,55 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id,
+                network_token_locker_id,
+                network_token_status,
+                network_token_details,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id,
+                network_token_locker_id,
+                network_token_status,
+                network_token_details,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::EncryptedDataUpdate {
+                payment_method_data,
+                payment_method_billing_address,
+            } => Self {
+                metadata: None,
+                payment_method_data,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
+                payment_method_billing_address,
             },
         }
     }
@@ -336,6 +459,10 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             payment_method_billing_address: payment_method_new
                 .payment_method_billing_address
                 .clone(),
+            network_token_reference_id: payment_method_new.network_token_reference_id.clone(),
+            network_token_locker_id: payment_method_new.network_token_locker_id.clone(),
+            network_token_status: payment_method_new.network_token_status,
+            network_token_details: payment_method_new.network_token_details.clone(),
         }
     }
 }
@@ -210,6 +210,14 @@ pub enum ProcessTrackerRunner {
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
+    AnalyticsAlertWorkflow,
+    FrmReviewDeadlineWorkflow,
+    MerchantKeyRotationWorkflow,
+    DisputeDeadlineReminderWorkflow,
+    ScheduledCaptureWorkflow,
+    AuthorizationExpiryWorkflow,
+    CardAccountUpdaterWorkflow,
+    WebhookBulkRetryWorkflow,
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 pub mod address;
+pub mod analytics_alert;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
+pub mod config_audit_log;
 pub mod configs;
 
 pub mod authentication;
@@ -22,7 +24,9 @@ pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
+pub mod oidc_sso_config;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
@@ -32,6 +36,7 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
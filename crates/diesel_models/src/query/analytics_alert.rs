@@ -0,0 +1,185 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_alert::{
+        AnalyticsAlert, AnalyticsAlertNew, AnalyticsAlertRule, AnalyticsAlertRuleNew,
+        AnalyticsAlertRuleUpdate, AnalyticsAlertRuleUpdateInternal, AnalyticsAlertUpdate,
+        AnalyticsAlertUpdateInternal,
+    },
+    errors,
+    schema::{analytics_alert::dsl as alert_dsl, analytics_alert_rule::dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsAlertRuleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsAlertRule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsAlertRule {
+    pub async fn find_by_merchant_id_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rule_id.eq(rule_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rule_id: &str,
+        rule_update: AnalyticsAlertRuleUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rule_id.eq(rule_id.to_owned())),
+            AnalyticsAlertRuleUpdateInternal::from(rule_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => {
+                    Self::find_by_merchant_id_rule_id(conn, merchant_id, rule_id).await
+                }
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rule_id.eq(rule_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl AnalyticsAlertNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsAlert> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsAlert {
+    pub async fn find_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            alert_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(alert_dsl::alert_id.eq(alert_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        alert_id: &str,
+        alert_update: AnalyticsAlertUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            alert_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(alert_dsl::alert_id.eq(alert_id.to_owned())),
+            AnalyticsAlertUpdateInternal::from(alert_update),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rule_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(alert_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(alert_dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(rule_id) = rule_id {
+            query = query.filter(alert_dsl::rule_id.eq(rule_id));
+        }
+
+        if let Some(created_after) = created_after {
+            query = query.filter(alert_dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(alert_dsl::created_at.le(created_before));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering analytics alerts by constraints")
+    }
+}
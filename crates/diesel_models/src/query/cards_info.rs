@@ -1,8 +1,17 @@
-use diesel::associations::HasTable;
+use diesel::{associations::HasTable, ExpressionMethods, Table};
 
-use crate::{cards_info::CardInfo, query::generics, PgPooledConn, StorageResult};
+use crate::{
+    cards_info::{CardInfo, UpdateCardInfo},
+    query::generics,
+    schema::cards_info::dsl,
+    PgPooledConn, StorageResult,
+};
 
 impl CardInfo {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Self> {
+        generics::generic_insert(conn, self).await
+    }
+
     pub async fn find_by_iin(conn: &PgPooledConn, card_iin: &str) -> StorageResult<Option<Self>> {
         generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
             conn,
@@ -10,4 +19,30 @@ impl CardInfo {
         )
         .await
     }
+
+    pub async fn find_by_iins(
+        conn: &PgPooledConn,
+        card_iins: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::card_iin.eq_any(card_iins), None, None, None)
+        .await
+    }
+
+    pub async fn update_by_iin(
+        conn: &PgPooledConn,
+        card_iin: String,
+        card_info_update: UpdateCardInfo,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, UpdateCardInfo, _, _>(
+            conn,
+            card_iin,
+            card_info_update,
+        )
+        .await
+    }
 }
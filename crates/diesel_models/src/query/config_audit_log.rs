@@ -0,0 +1,70 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, pg::Pg, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    config_audit_log::{ConfigAuditLog, ConfigAuditLogNew},
+    enums as storage_enums, errors,
+    schema::config_audit_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConfigAuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConfigAuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConfigAuditLog {
+    /// Lists the audit records of a merchant, or the audit records of global configurations when
+    /// `merchant_id` is `None`, most recent first
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: Option<&str>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        resource_type: Option<storage_enums::AuditResourceType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = Self::table().order(dsl::created_at.desc()).into_boxed();
+
+        query = match merchant_id {
+            Some(merchant_id) => query.filter(dsl::merchant_id.eq(merchant_id.to_owned())),
+            None => query.filter(dsl::merchant_id.is_null()),
+        };
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(resource_type) = resource_type {
+            query = query.filter(dsl::resource_type.eq(resource_type));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering config audit logs by constraints")
+    }
+}
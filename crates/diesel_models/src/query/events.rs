@@ -202,6 +202,46 @@ impl Event {
         .await
     }
 
+    pub async fn get_delivery_attempt_count_by_profile_id_constraints(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        is_webhook_notified: Option<bool>,
+    ) -> StorageResult<i64> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .count()
+            .filter(
+                dsl::business_profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::created_at.ge(created_after))
+                    .and(dsl::created_at.le(created_before)),
+            )
+            .into_boxed();
+
+        if let Some(is_webhook_notified) = is_webhook_notified {
+            query = query.filter(dsl::is_webhook_notified.eq(is_webhook_notified));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Failed to get a count of delivery attempts")
+    }
+
     pub async fn update_by_merchant_id_event_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -1,7 +1,8 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use crate::{
-    errors, fraud_check::*, query::generics, schema::fraud_check::dsl, PgPooledConn, StorageResult,
+    enums, errors, fraud_check::*, query::generics, schema::fraud_check::dsl, PgPooledConn,
+    StorageResult,
 };
 
 impl FraudCheckNew {
@@ -65,4 +66,40 @@ impl FraudCheck {
         )
         .await
     }
+
+    pub async fn filter_pending_reviews(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        assigned_to: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::frm_status.eq(enums::FraudCheckStatus::ManualReview))
+            .and(dsl::review_decision.is_null());
+
+        match assigned_to {
+            Some(assigned_to) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::review_assigned_to.eq(assigned_to)),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+        }
+    }
 }
@@ -0,0 +1,373 @@
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, PgExpressionMethods,
+};
+use error_stack::report;
+
+use super::generics;
+use crate::{
+    address::Address,
+    customers::Customer,
+    enums::KeyRotationEntity,
+    errors,
+    events::Event,
+    merchant_account::MerchantAccount,
+    merchant_connector_account::MerchantConnectorAccount,
+    merchant_key_rotation::{
+        EncryptedColumn, EncryptedRecord, MerchantKeyRotation, MerchantKeyRotationNew,
+        MerchantKeyRotationUpdate, MerchantKeyRotationUpdateInternal,
+    },
+    payment_method::PaymentMethod,
+    schema::{
+        address, customers, events, merchant_account, merchant_connector_account,
+        merchant_key_rotation::dsl, payment_methods,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl MerchantKeyRotationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantKeyRotation> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantKeyRotation {
+    pub async fn find_by_merchant_id_rotation_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rotation_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rotation_id.eq(rotation_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_latest_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(1),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(report!(errors::DatabaseError::NotFound))
+    }
+
+    pub async fn update_by_merchant_id_rotation_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        rotation_id: &str,
+        rotation_update: MerchantKeyRotationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::rotation_id.eq(rotation_id.to_owned())),
+            MerchantKeyRotationUpdateInternal::from(rotation_update),
+        )
+        .await
+    }
+}
+
+impl EncryptedRecord {
+    /// Fetches up to `limit` records of the entity belonging to the merchant, ordered by their
+    /// identifiers and starting after `last_processed_id`
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        entity: KeyRotationEntity,
+        merchant_id: &str,
+        last_processed_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        // An empty string sorts before any identifier, so the first batch starts from the
+        // beginning of the table
+        let last_processed_id = last_processed_id.unwrap_or_default();
+
+        match entity {
+            KeyRotationEntity::MerchantAccount => {
+                generics::generic_filter::<merchant_account::table, _, _, MerchantAccount>(
+                    conn,
+                    merchant_account::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(merchant_account::merchant_id.gt(last_processed_id)),
+                    Some(limit),
+                    None,
+                    Some(merchant_account::merchant_id.asc()),
+                )
+                .await
+                .map(|records| records.into_iter().map(Self::from).collect())
+            }
+            KeyRotationEntity::MerchantConnectorAccount => generics::generic_filter::<
+                merchant_connector_account::table,
+                _,
+                _,
+                MerchantConnectorAccount,
+            >(
+                conn,
+                merchant_connector_account::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(merchant_connector_account::merchant_connector_id.gt(last_processed_id)),
+                Some(limit),
+                None,
+                Some(merchant_connector_account::merchant_connector_id.asc()),
+            )
+            .await
+            .map(|records| records.into_iter().map(Self::from).collect()),
+            KeyRotationEntity::Customer => {
+                generics::generic_filter::<customers::table, _, _, Customer>(
+                    conn,
+                    customers::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(customers::customer_id.gt(last_processed_id)),
+                    Some(limit),
+                    None,
+                    Some(customers::customer_id.asc()),
+                )
+                .await
+                .map(|records| records.into_iter().map(Self::from).collect())
+            }
+            KeyRotationEntity::Address => {
+                generics::generic_filter::<address::table, _, _, Address>(
+                    conn,
+                    address::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(address::address_id.gt(last_processed_id)),
+                    Some(limit),
+                    None,
+                    Some(address::address_id.asc()),
+                )
+                .await
+                .map(|records| records.into_iter().map(Self::from).collect())
+            }
+            KeyRotationEntity::PaymentMethod => {
+                generics::generic_filter::<payment_methods::table, _, _, PaymentMethod>(
+                    conn,
+                    payment_methods::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(payment_methods::payment_method_id.gt(last_processed_id)),
+                    Some(limit),
+                    None,
+                    Some(payment_methods::payment_method_id.asc()),
+                )
+                .await
+                .map(|records| records.into_iter().map(Self::from).collect())
+            }
+            KeyRotationEntity::Event => generics::generic_filter::<events::table, _, _, Event>(
+                conn,
+                events::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(events::event_id.gt(last_processed_id)),
+                Some(limit),
+                None,
+                Some(events::event_id.asc()),
+            )
+            .await
+            .map(|records| records.into_iter().map(Self::from).collect()),
+        }
+    }
+
+    /// Writes the updated values of the encrypted columns of the record. Returns `false` without
+    /// updating the record if any of its encrypted columns was modified after it was read.
+    pub async fn update(self, conn: &PgPooledConn, merchant_id: &str) -> StorageResult<bool> {
+        let updated_count = match self {
+            Self::MerchantAccount {
+                merchant_id: _,
+                merchant_name,
+                merchant_details,
+            } => {
+                generics::generic_update::<merchant_account::table, _, _>(
+                    conn,
+                    merchant_account::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(
+                            merchant_account::merchant_name
+                                .is_not_distinct_from(EncryptedColumn::original(&merchant_name)),
+                        )
+                        .and(
+                            merchant_account::merchant_details
+                                .is_not_distinct_from(EncryptedColumn::original(&merchant_details)),
+                        ),
+                    (
+                        merchant_account::merchant_name
+                            .eq(EncryptedColumn::updated(&merchant_name)),
+                        merchant_account::merchant_details
+                            .eq(EncryptedColumn::updated(&merchant_details)),
+                    ),
+                )
+                .await?
+            }
+            Self::MerchantConnectorAccount {
+                merchant_connector_id,
+                connector_account_details,
+            } => {
+                generics::generic_update::<merchant_connector_account::table, _, _>(
+                    conn,
+                    merchant_connector_account::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(
+                            merchant_connector_account::merchant_connector_id
+                                .eq(merchant_connector_id),
+                        )
+                        .and(
+                            merchant_connector_account::connector_account_details
+                                .eq(connector_account_details.original),
+                        ),
+                    merchant_connector_account::connector_account_details
+                        .eq(connector_account_details.updated),
+                )
+                .await?
+            }
+            Self::Customer {
+                customer_id,
+                name,
+                email,
+                phone,
+            } => {
+                generics::generic_update::<customers::table, _, _>(
+                    conn,
+                    customers::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(customers::customer_id.eq(customer_id))
+                        .and(customers::name.is_not_distinct_from(EncryptedColumn::original(&name)))
+                        .and(
+                            customers::email
+                                .is_not_distinct_from(EncryptedColumn::original(&email)),
+                        )
+                        .and(
+                            customers::phone
+                                .is_not_distinct_from(EncryptedColumn::original(&phone)),
+                        ),
+                    (
+                        customers::name.eq(EncryptedColumn::updated(&name)),
+                        customers::email.eq(EncryptedColumn::updated(&email)),
+                        customers::phone.eq(EncryptedColumn::updated(&phone)),
+                    ),
+                )
+                .await?
+            }
+            Self::Address {
+                address_id,
+                payment_id: _,
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                email,
+            } => {
+                generics::generic_update::<address::table, _, _>(
+                    conn,
+                    address::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(address::address_id.eq(address_id))
+                        .and(address::line1.is_not_distinct_from(EncryptedColumn::original(&line1)))
+                        .and(address::line2.is_not_distinct_from(EncryptedColumn::original(&line2)))
+                        .and(address::line3.is_not_distinct_from(EncryptedColumn::original(&line3)))
+                        .and(address::state.is_not_distinct_from(EncryptedColumn::original(&state)))
+                        .and(address::zip.is_not_distinct_from(EncryptedColumn::original(&zip)))
+                        .and(
+                            address::first_name
+                                .is_not_distinct_from(EncryptedColumn::original(&first_name)),
+                        )
+                        .and(
+                            address::last_name
+                                .is_not_distinct_from(EncryptedColumn::original(&last_name)),
+                        )
+                        .and(
+                            address::phone_number
+                                .is_not_distinct_from(EncryptedColumn::original(&phone_number)),
+                        )
+                        .and(
+                            address::email.is_not_distinct_from(EncryptedColumn::original(&email)),
+                        ),
+                    (
+                        address::line1.eq(EncryptedColumn::updated(&line1)),
+                        address::line2.eq(EncryptedColumn::updated(&line2)),
+                        address::line3.eq(EncryptedColumn::updated(&line3)),
+                        address::state.eq(EncryptedColumn::updated(&state)),
+                        address::zip.eq(EncryptedColumn::updated(&zip)),
+                        address::first_name.eq(EncryptedColumn::updated(&first_name)),
+                        address::last_name.eq(EncryptedColumn::updated(&last_name)),
+                        address::phone_number.eq(EncryptedColumn::updated(&phone_number)),
+                        address::email.eq(EncryptedColumn::updated(&email)),
+                    ),
+                )
+                .await?
+            }
+            Self::PaymentMethod {
+                payment_method_id,
+                payment_method_data,
+                payment_method_billing_address,
+            } => {
+                generics::generic_update::<payment_methods::table, _, _>(
+                    conn,
+                    payment_methods::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(payment_methods::payment_method_id.eq(payment_method_id))
+                        .and(
+                            payment_methods::payment_method_data.is_not_distinct_from(
+                                EncryptedColumn::original(&payment_method_data),
+                            ),
+                        )
+                        .and(
+                            payment_methods::payment_method_billing_address.is_not_distinct_from(
+                                EncryptedColumn::original(&payment_method_billing_address),
+                            ),
+                        ),
+                    (
+                        payment_methods::payment_method_data
+                            .eq(EncryptedColumn::updated(&payment_method_data)),
+                        payment_methods::payment_method_billing_address
+                            .eq(EncryptedColumn::updated(&payment_method_billing_address)),
+                    ),
+                )
+                .await?
+            }
+            Self::Event {
+                event_id,
+                request,
+                response,
+            } => {
+                generics::generic_update::<events::table, _, _>(
+                    conn,
+                    events::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(events::event_id.eq(event_id))
+                        .and(
+                            events::request
+                                .is_not_distinct_from(EncryptedColumn::original(&request)),
+                        )
+                        .and(
+                            events::response
+                                .is_not_distinct_from(EncryptedColumn::original(&response)),
+                        ),
+                    (
+                        events::request.eq(EncryptedColumn::updated(&request)),
+                        events::response.eq(EncryptedColumn::updated(&response)),
+                    ),
+                )
+                .await?
+            }
+        };
+
+        Ok(updated_count > 0)
+    }
+}
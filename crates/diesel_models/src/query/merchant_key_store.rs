@@ -1,8 +1,14 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, PgExpressionMethods,
+};
 
 use super::generics;
 use crate::{
-    merchant_key_store::{MerchantKeyStore, MerchantKeyStoreNew},
+    merchant_key_rotation::EncryptedColumn,
+    merchant_key_store::{
+        MerchantKeyStore, MerchantKeyStoreNew, MerchantKeyStoreUpdate,
+        MerchantKeyStoreUpdateInternal,
+    },
     schema::merchant_key_store::dsl,
     PgPooledConn, StorageResult,
 };
@@ -25,6 +31,24 @@ impl MerchantKeyStore {
         .await
     }
 
+    pub async fn update_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key_store_update: MerchantKeyStoreUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            MerchantKeyStoreUpdateInternal::from(key_store_update),
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -54,4 +78,47 @@ impl MerchantKeyStore {
         )
         .await
     }
+
+    /// Fetches up to `limit` key stores ordered by merchant identifier, starting after
+    /// `last_merchant_id`
+    pub async fn list_key_stores_after_merchant_id(
+        conn: &PgPooledConn,
+        last_merchant_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.gt(last_merchant_id.unwrap_or_default()),
+            Some(limit),
+            None,
+            Some(dsl::merchant_id.asc()),
+        )
+        .await
+    }
+
+    /// Writes the keys of the key store wrapped with a new master key. Returns `false` without
+    /// updating the key store if any of its keys was modified after it was read.
+    pub async fn update_master_key_wrapping_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        key: EncryptedColumn,
+        previous_key: Option<EncryptedColumn>,
+    ) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::key.eq(key.original))
+                .and(
+                    dsl::previous_key
+                        .is_not_distinct_from(EncryptedColumn::original(&previous_key)),
+                ),
+            (
+                dsl::key.eq(key.updated),
+                dsl::previous_key.eq(EncryptedColumn::updated(&previous_key)),
+            ),
+        )
+        .await
+        .map(|updated_count| updated_count > 0)
+    }
 }
@@ -0,0 +1,63 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    oidc_sso_config::*, query::generics, schema::oidc_sso_config::dsl, PgPooledConn, StorageResult,
+};
+
+impl OidcSsoConfigNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<OidcSsoConfig> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl OidcSsoConfig {
+    pub async fn find_by_org_id(conn: &PgPooledConn, org_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::org_id.eq(org_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list_sso_enforced_by_org_ids(
+        conn: &PgPooledConn,
+        org_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::org_id
+                .eq_any(org_ids)
+                .and(dsl::is_sso_enforced.eq(true)),
+            None,
+            None,
+            Some(dsl::org_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_org_id(
+        conn: &PgPooledConn,
+        org_id: &str,
+        update: OidcSsoConfigUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::org_id.eq(org_id.to_owned()),
+            OidcSsoConfigUpdateInternal::from(update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_org_id(conn: &PgPooledConn, org_id: &str) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::org_id.eq(org_id.to_owned()),
+        )
+        .await
+    }
+}
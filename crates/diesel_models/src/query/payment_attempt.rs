@@ -212,6 +212,27 @@ impl PaymentAttempt {
         .await
     }
 
+    pub async fn find_authorized_by_merchant_id_authorization_expiry(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        expires_after: time::PrimitiveDateTime,
+        expires_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(enums::AttemptStatus::Authorized))
+                .and(dsl::authorization_expires_at.gt(expires_after))
+                .and(dsl::authorization_expires_at.le(expires_before)),
+            limit,
+            None,
+            Some(dsl::authorization_expires_at.asc()),
+        )
+        .await
+    }
+
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
         pi: &[PaymentIntent],
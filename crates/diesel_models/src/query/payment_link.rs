@@ -1,8 +1,8 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate, PaymentLinkUpdateInternal},
     schema::payment_link::dsl,
     PgPooledConn, StorageResult,
 };
@@ -24,4 +24,41 @@ impl PaymentLink {
         )
         .await
     }
+
+    pub async fn update_by_payment_link_id(
+        conn: &PgPooledConn,
+        payment_link_id: &str,
+        payment_link_update: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_link_id.eq(payment_link_id.to_owned()),
+            PaymentLinkUpdateInternal::from(payment_link_update),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_reusable_payment_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
 }
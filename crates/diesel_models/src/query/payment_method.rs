@@ -53,6 +53,17 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_network_token_reference_id(
+        conn: &PgPooledConn,
+        network_token_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::network_token_reference_id.eq(network_token_reference_id.to_owned()),
+        )
+        .await
+    }
+
     pub async fn find_by_payment_method_id(
         conn: &PgPooledConn,
         payment_method_id: &str,
@@ -83,6 +94,25 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_payment_method(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_method: storage_enums::PaymentMethod,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(payment_method)),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
     pub async fn find_by_customer_id_merchant_id(
         conn: &PgPooledConn,
         customer_id: &str,
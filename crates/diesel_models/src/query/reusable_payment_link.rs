@@ -0,0 +1,141 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    reusable_payment_link::{
+        ReusablePaymentLink, ReusablePaymentLinkNew, ReusablePaymentLinkPaymentAmount,
+        ReusablePaymentLinkUpdate, ReusablePaymentLinkUpdateInternal,
+    },
+    schema::{payment_intent, payment_link, reusable_payment_link::dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl ReusablePaymentLinkNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReusablePaymentLink> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReusablePaymentLink {
+    pub async fn find_by_merchant_id_reusable_payment_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_reusable_payment_link_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        reusable_payment_link_update: ReusablePaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned())),
+            ReusablePaymentLinkUpdateInternal::from(reusable_payment_link_update),
+        )
+        .await
+    }
+
+    /// Fetches the status and amounts of every payment created through the reusable payment link
+    pub async fn get_payment_amounts(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> StorageResult<Vec<ReusablePaymentLinkPaymentAmount>> {
+        let payment_ids = payment_link::table
+            .filter(payment_link::merchant_id.eq(merchant_id.to_owned()).and(
+                payment_link::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned()),
+            ))
+            .select(payment_link::payment_id);
+
+        let query = payment_intent::table
+            .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_intent::payment_id.eq_any(payment_ids))
+            .select((
+                payment_intent::status,
+                payment_intent::amount,
+                payment_intent::amount_captured,
+            ));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<payment_intent::table, _, _>(
+            query.get_results_async::<ReusablePaymentLinkPaymentAmount>(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error fetching payments of reusable payment link")
+    }
+
+    /// Counts the payments created through the reusable payment link that are in one of the given
+    /// statuses, stopping once `limit` payments have been counted
+    pub async fn count_payments_by_statuses(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        statuses: Vec<storage_enums::IntentStatus>,
+        limit: i64,
+    ) -> StorageResult<usize> {
+        let payment_ids = payment_link::table
+            .filter(payment_link::merchant_id.eq(merchant_id.to_owned()).and(
+                payment_link::reusable_payment_link_id.eq(reusable_payment_link_id.to_owned()),
+            ))
+            .select(payment_link::payment_id);
+
+        let query = payment_intent::table
+            .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_intent::payment_id.eq_any(payment_ids))
+            .filter(payment_intent::status.eq_any(statuses))
+            .select(payment_intent::payment_id)
+            .limit(limit);
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<payment_intent::table, _, _>(
+            query.get_results_async::<String>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .map(|payment_ids| payment_ids.len())
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting payments of reusable payment link")
+    }
+}
@@ -1,7 +1,8 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
+    merchant_key_rotation::EncryptedColumn,
     schema::user_key_store::dsl,
     user_key_store::{UserKeyStore, UserKeyStoreNew},
     PgPooledConn, StorageResult,
@@ -21,4 +22,38 @@ impl UserKeyStore {
         )
         .await
     }
+
+    /// Fetches up to `limit` key stores ordered by user identifier, starting after `last_user_id`
+    pub async fn list_key_stores_after_user_id(
+        conn: &PgPooledConn,
+        last_user_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.gt(last_user_id.unwrap_or_default()),
+            Some(limit),
+            None,
+            Some(dsl::user_id.asc()),
+        )
+        .await
+    }
+
+    /// Writes the key of the key store wrapped with a new master key. Returns `false` without
+    /// updating the key store if its key was modified after it was read.
+    pub async fn update_master_key_wrapping_by_user_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        key: EncryptedColumn,
+    ) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::key.eq(key.original)),
+            dsl::key.eq(key.updated),
+        )
+        .await
+        .map(|updated_count| updated_count > 0)
+    }
 }
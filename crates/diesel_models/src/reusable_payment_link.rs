@@ -0,0 +1,98 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::reusable_payment_link};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = reusable_payment_link, primary_key(reusable_payment_link_id))]
+pub struct ReusablePaymentLink {
+    pub reusable_payment_link_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub link_to_pay: String,
+    pub currency: storage_enums::Currency,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub usage_limit: Option<i32>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub is_active: bool,
+    pub description: Option<String>,
+    pub return_url: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkNew {
+    pub reusable_payment_link_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub link_to_pay: String,
+    pub currency: storage_enums::Currency,
+    pub amount: Option<MinorUnit>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+    pub usage_limit: Option<i32>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub is_active: bool,
+    pub description: Option<String>,
+    pub return_url: Option<String>,
+    pub payment_link_config: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReusablePaymentLinkUpdate {
+    Update {
+        usage_limit: Option<i32>,
+        expires_at: Option<PrimitiveDateTime>,
+        is_active: Option<bool>,
+        description: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = reusable_payment_link)]
+pub struct ReusablePaymentLinkUpdateInternal {
+    usage_limit: Option<i32>,
+    expires_at: Option<PrimitiveDateTime>,
+    is_active: Option<bool>,
+    description: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<ReusablePaymentLinkUpdate> for ReusablePaymentLinkUpdateInternal {
+    fn from(value: ReusablePaymentLinkUpdate) -> Self {
+        match value {
+            ReusablePaymentLinkUpdate::Update {
+                usage_limit,
+                expires_at,
+                is_active,
+                description,
+            } => Self {
+                usage_limit,
+                expires_at,
+                is_active,
+                description,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+/// Status and amounts of a payment created through a reusable payment link
+#[derive(Clone, Debug, Queryable)]
+pub struct ReusablePaymentLinkPaymentAmount {
+    pub status: storage_enums::IntentStatus,
+    pub amount: MinorUnit,
+    pub amount_captured: Option<MinorUnit>,
+}
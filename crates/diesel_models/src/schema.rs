@@ -35,6 +35,58 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_alert (alert_id) {
+        #[max_length = 64]
+        alert_id -> Varchar,
+        #[max_length = 64]
+        rule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        metric -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        observed_value -> Float8,
+        baseline_value -> Float8,
+        drop_threshold -> Float8,
+        window_start -> Timestamp,
+        window_end -> Timestamp,
+        email_sent -> Bool,
+        webhook_sent -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_alert_rule (rule_id) {
+        #[max_length = 64]
+        rule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 64]
+        metric -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        drop_threshold -> Float8,
+        evaluation_window_in_mins -> Int4,
+        baseline_window_in_days -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -55,6 +107,8 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permission_groups -> Nullable<Array<Nullable<Text>>>,
+        allowed_ips -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -196,6 +250,10 @@ diesel::table! {
         is_connector_agnostic_mit_enabled -> Nullable<Bool>,
         use_billing_as_payment_method_billing -> Nullable<Bool>,
         collect_shipping_details_from_wallet_connector -> Nullable<Bool>,
+        authorization_expiry_policy -> Nullable<Jsonb>,
+        webhook_retry_policy -> Nullable<Jsonb>,
+        webhook_endpoint_failing_since -> Nullable<Timestamp>,
+        webhook_endpoint_disabled_at -> Nullable<Timestamp>,
     }
 }
 
@@ -258,6 +316,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    config_audit_log (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        resource_type -> Varchar,
+        #[max_length = 255]
+        resource_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        #[max_length = 32]
+        actor_type -> Varchar,
+        #[max_length = 255]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        changes -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -455,6 +539,11 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        #[max_length = 64]
+        review_assigned_to -> Nullable<Varchar>,
+        review_deadline -> Nullable<Timestamp>,
+        #[max_length = 64]
+        review_decision -> Nullable<Varchar>,
     }
 }
 
@@ -678,6 +767,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_rotation (rotation_id) {
+        #[max_length = 64]
+        rotation_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        from_key_version -> Int4,
+        to_key_version -> Int4,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        current_entity -> Nullable<Varchar>,
+        #[max_length = 255]
+        last_processed_id -> Nullable<Varchar>,
+        records_re_encrypted -> Int8,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -687,6 +801,37 @@ diesel::table! {
         merchant_id -> Varchar,
         key -> Bytea,
         created_at -> Timestamp,
+        key_version -> Int4,
+        previous_key -> Nullable<Bytea>,
+        previous_key_version -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    oidc_sso_config (org_id) {
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 255]
+        issuer_url -> Varchar,
+        #[max_length = 255]
+        client_id -> Varchar,
+        client_secret -> Bytea,
+        #[max_length = 255]
+        redirect_uri -> Varchar,
+        scopes -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        groups_claim -> Varchar,
+        role_mappings -> Jsonb,
+        #[max_length = 64]
+        default_role_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        provisioning_merchant_id -> Varchar,
+        is_sso_enforced -> Bool,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
     }
 }
 
@@ -786,6 +931,14 @@ diesel::table! {
         client_source -> Nullable<Varchar>,
         #[max_length = 64]
         client_version -> Nullable<Varchar>,
+        authorization_expires_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        fx_quote_id -> Nullable<Varchar>,
+        presentment_amount -> Nullable<Int8>,
+        presentment_currency -> Nullable<Currency>,
+        #[max_length = 64]
+        fx_rate -> Nullable<Varchar>,
+        fx_markup_bps -> Nullable<Int4>,
     }
 }
 
@@ -855,6 +1008,7 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         request_external_three_ds_authentication -> Nullable<Bool>,
         frm_metadata -> Nullable<Jsonb>,
+        l2_l3_data -> Nullable<Jsonb>,
     }
 }
 
@@ -883,6 +1037,8 @@ diesel::table! {
         description -> Nullable<Varchar>,
         #[max_length = 64]
         profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        reusable_payment_link_id -> Nullable<Varchar>,
     }
 }
 
@@ -939,6 +1095,13 @@ diesel::table! {
         payment_method_billing_address -> Nullable<Bytea>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        #[max_length = 128]
+        network_token_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        network_token_locker_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        network_token_status -> Nullable<Varchar>,
+        network_token_details -> Nullable<Jsonb>,
     }
 }
 
@@ -1095,6 +1258,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    reusable_payment_link (reusable_payment_link_id) {
+        #[max_length = 64]
+        reusable_payment_link_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        link_to_pay -> Varchar,
+        currency -> Currency,
+        amount -> Nullable<Int8>,
+        min_amount -> Nullable<Int8>,
+        max_amount -> Nullable<Int8>,
+        usage_limit -> Nullable<Int4>,
+        expires_at -> Nullable<Timestamp>,
+        is_active -> Bool,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        return_url -> Nullable<Varchar>,
+        payment_link_config -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1225,6 +1418,8 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_alert,
+    analytics_alert_rule,
     api_keys,
     authentication,
     blocklist,
@@ -1233,6 +1428,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     business_profile,
     captures,
     cards_info,
+    config_audit_log,
     configs,
     customers,
     dashboard_metadata,
@@ -1246,7 +1442,9 @@ diesel::allow_tables_to_appear_in_same_query!(
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_rotation,
     merchant_key_store,
+    oidc_sso_config,
     organization,
     payment_attempt,
     payment_intent,
@@ -1256,6 +1454,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     payouts,
     process_tracker,
     refund,
+    reusable_payment_link,
     reverse_lookup,
     roles,
     routing_algorithm,
@@ -75,6 +75,13 @@ pub struct PaymentAttemptBatchNew {
     pub fingerprint_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub fx_quote_id: Option<String>,
+    pub presentment_amount: Option<i64>,
+    pub presentment_currency: Option<Currency>,
+    pub fx_rate: Option<String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 #[allow(dead_code)]
@@ -137,6 +144,12 @@ impl PaymentAttemptBatchNew {
             fingerprint_id: self.fingerprint_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            authorization_expires_at: self.authorization_expires_at,
+            fx_quote_id: self.fx_quote_id,
+            presentment_amount: self.presentment_amount,
+            presentment_currency: self.presentment_currency,
+            fx_rate: self.fx_rate,
+            fx_markup_bps: self.fx_markup_bps,
         }
     }
 }
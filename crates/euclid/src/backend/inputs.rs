@@ -15,6 +15,9 @@ pub struct PaymentMethodInput {
     pub payment_method: Option<enums::PaymentMethod>,
     pub payment_method_type: Option<enums::PaymentMethodType>,
     pub card_network: Option<enums::CardNetwork>,
+    pub card_issuer: Option<String>,
+    pub card_issuing_country: Option<enums::Country>,
+    pub card_subtype: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -139,6 +139,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -179,6 +182,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -220,6 +226,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -261,6 +270,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
@@ -302,6 +314,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -343,6 +358,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -384,6 +402,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -425,6 +446,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -468,6 +492,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -509,6 +536,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -559,6 +589,9 @@ mod test {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
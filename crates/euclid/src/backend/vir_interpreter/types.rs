@@ -78,6 +78,20 @@ impl Context {
             enum_values.insert(EuclidValue::CardNetwork(card_network));
         }
 
+        if let Some(card_issuer) = payment_method.card_issuer {
+            enum_values.insert(EuclidValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+
+        if let Some(card_issuing_country) = payment_method.card_issuing_country {
+            enum_values.insert(EuclidValue::CardIssuingCountry(card_issuing_country));
+        }
+
+        if let Some(card_subtype) = payment_method.card_subtype {
+            enum_values.insert(EuclidValue::CardSubtype(StrValue {
+                value: card_subtype,
+            }));
+        }
+
         if let Some(at) = payment.authentication_type {
             enum_values.insert(EuclidValue::AuthenticationType(at));
         }
@@ -67,6 +67,9 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::BusinessLabel(bl) => bl.value.to_string(),
             Self::SetupFutureUsage(sfu) => sfu.to_string(),
             Self::CardRedirectType(crt) => crt.to_string(),
+            Self::CardIssuer(ci) => ci.value.clone(),
+            Self::CardIssuingCountry(cic) => cic.to_string(),
+            Self::CardSubtype(cs) => cs.value.clone(),
         }
     }
 }
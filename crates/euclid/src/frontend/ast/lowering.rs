@@ -272,6 +272,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::CardRedirectType => lower_enum!(CardRedirectType, value),
 
+        dir::DirKeyKind::CardIssuingCountry => lower_enum!(CardIssuingCountry, value),
+
         dir::DirKeyKind::CardBin => {
             let validation_closure = |st: &String| -> Result<(), AnalysisErrorType> {
                 if st.len() == 6 && st.chars().all(|x| x.is_ascii_digit()) {
@@ -289,6 +291,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::CardIssuer => lower_str!(CardIssuer, value),
+
+        dir::DirKeyKind::CardSubtype => lower_str!(CardSubtype, value),
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
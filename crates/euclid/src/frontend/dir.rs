@@ -311,6 +311,27 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_redirect")]
     CardRedirectType,
+    #[strum(
+        serialize = "card_issuer",
+        detailed_message = "Name of the bank that issued the payment card",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_issuer")]
+    CardIssuer,
+    #[strum(
+        serialize = "card_issuing_country",
+        detailed_message = "Country in which the payment card was issued",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_issuing_country")]
+    CardIssuingCountry,
+    #[strum(
+        serialize = "card_subtype",
+        detailed_message = "Product tier of the payment card - eg. classic, platinum",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_subtype")]
+    CardSubtype,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -358,6 +379,9 @@ impl DirKeyKind {
             Self::BusinessLabel => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
+            Self::CardIssuer => types::DataType::StrValue,
+            Self::CardIssuingCountry => types::DataType::EnumVariant,
+            Self::CardSubtype => types::DataType::StrValue,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -484,6 +508,13 @@ impl DirKeyKind {
                     .map(DirValue::CardRedirectType)
                     .collect(),
             ),
+            Self::CardIssuer => None,
+            Self::CardIssuingCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::CardIssuingCountry)
+                    .collect(),
+            ),
+            Self::CardSubtype => None,
         }
     }
 }
@@ -549,6 +580,12 @@ pub enum DirValue {
     SetupFutureUsage(enums::SetupFutureUsage),
     #[serde(rename = "card_redirect")]
     CardRedirectType(enums::CardRedirectType),
+    #[serde(rename = "card_issuer")]
+    CardIssuer(types::StrValue),
+    #[serde(rename = "card_issuing_country")]
+    CardIssuingCountry(enums::Country),
+    #[serde(rename = "card_subtype")]
+    CardSubtype(types::StrValue),
 }
 
 impl DirValue {
@@ -582,6 +619,9 @@ impl DirValue {
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
+            Self::CardIssuer(_) => (DirKeyKind::CardIssuer, None),
+            Self::CardIssuingCountry(_) => (DirKeyKind::CardIssuingCountry, None),
+            Self::CardSubtype(_) => (DirKeyKind::CardSubtype, None),
         };
 
         DirKey::new(kind, data)
@@ -616,6 +656,9 @@ impl DirValue {
             Self::BusinessLabel(_) => None,
             Self::SetupFutureUsage(_) => None,
             Self::CardRedirectType(_) => None,
+            Self::CardIssuer(_) => None,
+            Self::CardIssuingCountry(_) => None,
+            Self::CardSubtype(_) => None,
         }
     }
 
@@ -661,6 +704,9 @@ impl DirValue {
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
+            (Self::CardIssuer(ci1), Self::CardIssuer(ci2)) => ci1 == ci2,
+            (Self::CardIssuingCountry(c1), Self::CardIssuingCountry(c2)) => c1 == c2,
+            (Self::CardSubtype(cs1), Self::CardSubtype(cs2)) => cs1 == cs2,
             _ => false,
         }
     }
@@ -841,6 +887,9 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(CardIssuer s= "HDFC BANK"),
+            dirval!(CardIssuingCountry = India),
+            dirval!(CardSubtype s= "PLATINUM"),
         ];
 
         for val in values {
@@ -223,6 +223,9 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
+        dir::DirValue::CardIssuer(ci) => EuclidValue::CardIssuer(ci),
+        dir::DirValue::CardIssuingCountry(cic) => EuclidValue::CardIssuingCountry(cic),
+        dir::DirValue::CardSubtype(cs) => EuclidValue::CardSubtype(cs),
     })
 }
 
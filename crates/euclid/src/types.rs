@@ -59,6 +59,12 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "card_issuer")]
+    CardIssuer,
+    #[strum(serialize = "card_issuing_country")]
+    CardIssuingCountry,
+    #[strum(serialize = "card_subtype")]
+    CardSubtype,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -137,6 +143,9 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::CardIssuer => DataType::StrValue,
+            Self::CardIssuingCountry => DataType::EnumVariant,
+            Self::CardSubtype => DataType::StrValue,
         }
     }
 }
@@ -252,6 +261,9 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    CardIssuer(StrValue),
+    CardIssuingCountry(enums::Country),
+    CardSubtype(StrValue),
 }
 
 impl EuclidValue {
@@ -280,6 +292,9 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::CardIssuer(_) => EuclidKey::CardIssuer,
+            Self::CardIssuingCountry(_) => EuclidKey::CardIssuingCountry,
+            Self::CardSubtype(_) => EuclidKey::CardSubtype,
         }
     }
 }
@@ -248,6 +248,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentCurrency => dir_enums::PaymentCurrency::VARIANTS,
         dir::DirKeyKind::BusinessCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BillingCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::CardIssuingCountry => dir_enums::Country::VARIANTS,
         dir::DirKeyKind::BankTransferType => dir_enums::BankTransferType::VARIANTS,
         dir::DirKeyKind::UpiType => dir_enums::UpiType::VARIANTS,
         dir::DirKeyKind::SetupFutureUsage => dir_enums::SetupFutureUsage::VARIANTS,
@@ -263,6 +264,8 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::CardIssuer
+        | dir::DirKeyKind::CardSubtype
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
     };
 
@@ -103,6 +103,15 @@ pub trait PaymentAttemptInterface {
         merchant_connector_id: Option<Vec<String>>,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, errors::StorageError>;
+
+    async fn find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+        &self,
+        merchant_id: &str,
+        expires_after: PrimitiveDateTime,
+        expires_before: PrimitiveDateTime,
+        limit: Option<i64>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -169,6 +178,14 @@ pub struct PaymentAttempt {
     pub fingerprint_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    /// Quote the cardholder accepted to pay in their own currency
+    pub fx_quote_id: Option<String>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 impl PaymentAttempt {
@@ -257,6 +274,14 @@ pub struct PaymentAttemptNew {
     pub fingerprint_id: Option<String>,
     pub client_source: Option<String>,
     pub client_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    /// Quote the cardholder accepted to pay in their own currency
+    pub fx_quote_id: Option<String>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 impl PaymentAttemptNew {
@@ -339,6 +364,11 @@ pub enum PaymentAttemptUpdate {
         payment_method_id: Option<String>,
         client_source: Option<String>,
         client_version: Option<String>,
+        fx_quote_id: Option<String>,
+        presentment_amount: Option<MinorUnit>,
+        presentment_currency: Option<storage_enums::Currency>,
+        fx_rate: Option<String>,
+        fx_markup_bps: Option<i32>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -381,6 +411,7 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        authorization_expires_at: Option<PrimitiveDateTime>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
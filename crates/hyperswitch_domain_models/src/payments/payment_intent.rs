@@ -83,6 +83,7 @@ pub struct PaymentIntentNew {
     pub return_url: Option<String>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub l2_l3_data: Option<pii::SecretSerdeValue>,
     pub connector_id: Option<String>,
     pub shipping_address_id: Option<String>,
     pub billing_address_id: Option<String>,
@@ -167,6 +168,7 @@ pub enum PaymentIntentUpdate {
         order_details: Option<Vec<pii::SecretSerdeValue>>,
         metadata: Option<pii::SecretSerdeValue>,
         frm_metadata: Option<pii::SecretSerdeValue>,
+        l2_l3_data: Option<pii::SecretSerdeValue>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
         updated_by: String,
         fingerprint_id: Option<String>,
@@ -244,6 +246,7 @@ pub struct PaymentIntentUpdateInternal {
     pub session_expiry: Option<PrimitiveDateTime>,
     pub request_external_three_ds_authentication: Option<bool>,
     pub frm_metadata: Option<pii::SecretSerdeValue>,
+    pub l2_l3_data: Option<pii::SecretSerdeValue>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -271,6 +274,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -294,6 +298,7 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
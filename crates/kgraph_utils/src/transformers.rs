@@ -58,6 +58,17 @@ impl IntoContext for BackendInput {
         if let Some(card_network) = self.payment_method.card_network {
             ctx.push(dir::DirValue::CardNetwork(card_network));
         }
+        if let Some(card_issuer) = self.payment_method.card_issuer {
+            ctx.push(dir::DirValue::CardIssuer(StrValue { value: card_issuer }));
+        }
+        if let Some(card_issuing_country) = self.payment_method.card_issuing_country {
+            ctx.push(dir::DirValue::CardIssuingCountry(card_issuing_country));
+        }
+        if let Some(card_subtype) = self.payment_method.card_subtype {
+            ctx.push(dir::DirValue::CardSubtype(StrValue {
+                value: card_subtype,
+            }));
+        }
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
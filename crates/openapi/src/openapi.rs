@@ -80,6 +80,13 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_create,
+        routes::payment_link::reusable_payment_link_retrieve,
+        routes::payment_link::reusable_payment_link_list,
+        routes::payment_link::reusable_payment_link_update,
+        routes::payment_link::reusable_payment_link_payments_list,
+        routes::payment_link::payment_link_qr_code,
+        routes::payment_link::reusable_payment_link_qr_code,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
 
@@ -159,6 +166,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::retrieve_velocity_rules,
+        routes::blocklist::update_velocity_rules,
 
         // Routes for payouts
         routes::payouts::payouts_create,
@@ -179,6 +188,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::bulk_retry_webhook_delivery_attempts,
+        routes::webhook_events::get_webhook_delivery_stats,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -230,6 +241,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
+        api_models::enums::PermissionGroup,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
@@ -261,6 +273,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::admin::WebhookRetryPolicy,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
@@ -351,6 +364,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayAddressParameters,
         api_models::payments::AmountInfo,
         api_models::payments::ProductType,
+        api_models::payments::L2L3Data,
+        api_models::payments::L2L3LineItem,
+        api_models::payments::PaymentFxQuoteDetails,
         api_models::payments::GooglePayWalletData,
         api_models::payments::PayPalWalletData,
         api_models::payments::PaypalRedirection,
@@ -484,6 +500,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
+        api_models::payments::ReusablePaymentLinkCreateRequest,
+        api_models::payments::ReusablePaymentLinkUpdateRequest,
+        api_models::payments::ReusablePaymentLinkResponse,
+        api_models::payments::ReusablePaymentLinkListResponse,
+        api_models::payments::ReusablePaymentLinkPayment,
+        api_models::payments::ReusablePaymentLinkPaymentsSummary,
+        api_models::payments::ReusablePaymentLinkPaymentsResponse,
+        api_models::payments::QrCodeImageFormat,
         api_models::payments::ExtendedCardInfoResponse,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
@@ -515,11 +539,18 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
+        api_models::blocklist::VelocityRulesConfig,
+        api_models::blocklist::VelocityRule,
+        api_models::blocklist::VelocityLimit,
+        api_models::blocklist::VelocityRuleAction,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookDeliveryBulkRetryRequest,
+        api_models::webhook_events::WebhookDeliveryBulkRetryResponse,
+        api_models::webhook_events::WebhookEndpointDeliveryStats,
         api_models::enums::WebhookDeliveryAttempt,
     )),
     modifiers(&SecurityAddon)
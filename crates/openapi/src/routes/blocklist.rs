@@ -57,3 +57,29 @@ pub async fn remove_entry_from_blocklist() {}
     security(("api_key" = []))
 )]
 pub async fn list_blocked_payment_methods() {}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/velocity_rules",
+    responses(
+        (status = 200, description = "Velocity rules of the merchant", body = VelocityRulesConfig),
+    ),
+    tag = "Blocklist",
+    operation_id = "Retrieve velocity rules for a particular merchant",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_velocity_rules() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/velocity_rules",
+    request_body = VelocityRulesConfig,
+    responses(
+        (status = 200, description = "Velocity rules updated", body = VelocityRulesConfig),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Update velocity rules for a particular merchant",
+    security(("api_key" = []))
+)]
+pub async fn update_velocity_rules() {}
@@ -17,3 +17,142 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Reusable Payment Link - Create
+///
+/// To create a payment link that can be paid by any number of customers, for a fixed amount or an amount entered by the customer
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable",
+    request_body = ReusablePaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link created", body = ReusablePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Link",
+    operation_id = "Create a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_create() {}
+
+/// Reusable Payment Link - Retrieve
+///
+/// To retrieve the properties of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment link retrieved", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_retrieve() {}
+
+/// Reusable Payment Link - List
+///
+/// To list the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of reusable payment links to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of reusable payment links to skip"),
+    ),
+    responses(
+        (status = 200, description = "Reusable payment links retrieved", body = ReusablePaymentLinkListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payment Link",
+    operation_id = "List all Reusable Payment Links",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_list() {}
+
+/// Reusable Payment Link - Update
+///
+/// To update the usage limit, expiry, description or active state of a reusable payment link
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    request_body = ReusablePaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link updated", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_update() {}
+
+/// Reusable Payment Link - List Payments
+///
+/// To list the payments made through a reusable payment link, along with a summary of all its payments
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}/payments",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of payments to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of payments to skip"),
+    ),
+    responses(
+        (status = 200, description = "Payments of the reusable payment link retrieved", body = ReusablePaymentLinkPaymentsResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "List Payments of a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_payments_list() {}
+
+/// Payment Link - QR Code
+///
+/// To retrieve the QR code of a payment link as a PNG or SVG image
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}/qr_code",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link"),
+        ("format" = Option<QrCodeImageFormat>, Query, description = "The image format of the QR code, defaults to png"),
+        ("size" = Option<u32>, Query, description = "The minimum width and height of the QR code, in pixels"),
+    ),
+    responses(
+        (status = 200, description = "QR code of the payment link"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve the QR Code of a Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn payment_link_qr_code() {}
+
+/// Reusable Payment Link - QR Code
+///
+/// To retrieve the QR code of a reusable payment link as a PNG or SVG image
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}/qr_code",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+        ("format" = Option<QrCodeImageFormat>, Query, description = "The image format of the QR code, defaults to png"),
+        ("size" = Option<u32>, Query, description = "The minimum width and height of the QR code, in pixels"),
+    ),
+    responses(
+        (status = 200, description = "QR code of the reusable payment link"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve the QR Code of a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+pub async fn reusable_payment_link_qr_code() {}
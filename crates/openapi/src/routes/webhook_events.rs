@@ -92,3 +92,44 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Bulk Retry
+///
+/// Redeliver all Events created within the specified time range which could not be delivered. The
+/// Events are redelivered in the background by a job, whose identifier is returned.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id_or_profile_id}/retry_failed",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+    ),
+    request_body = WebhookDeliveryBulkRetryRequest,
+    responses(
+        (status = 200, description = "The redelivery of the Events was scheduled", body = WebhookDeliveryBulkRetryResponse),
+    ),
+    tag = "Event",
+    operation_id = "Redeliver all Events which could not be delivered",
+    security(("admin_api_key" = []))
+)]
+pub fn bulk_retry_webhook_delivery_attempts() {}
+
+/// Events - Delivery Stats
+///
+/// Retrieve webhook delivery statistics of the webhook endpoints of the Merchant Account or
+/// Business Profile.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/delivery_stats",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Include delivery attempts made after the specified time, defaults to a day before `created_before`"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Include delivery attempts made before the specified time, defaults to the current time"),
+    ),
+    responses(
+        (status = 200, description = "Delivery statistics retrieved successfully", body = Vec<WebhookEndpointDeliveryStats>),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve webhook delivery statistics",
+    security(("admin_api_key" = []))
+)]
+pub fn get_webhook_delivery_stats() {}
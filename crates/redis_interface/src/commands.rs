@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, SetsInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -30,6 +30,23 @@ use crate::{
     types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SaddReply, SetnxReply},
 };
 
+/// A `TTL` of `-1` denotes a key which exists without an expiry
+const INCREMENT_WITH_EXPIRY_SCRIPT: &str = r#"
+local value = redis.call('INCRBY', KEYS[1], ARGV[1])
+if redis.call('TTL', KEYS[1]) == -1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+end
+return value
+"#;
+
+const SADD_WITH_EXPIRY_SCRIPT: &str = r#"
+redis.call('SADD', KEYS[1], ARGV[1])
+if redis.call('TTL', KEYS[1]) == -1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+end
+return redis.call('SCARD', KEYS[1])
+"#;
+
 impl super::RedisConnectionPool {
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key<V>(&self, key: &str, value: V) -> CustomResult<(), errors::RedisError>
@@ -461,6 +478,66 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_set_cardinality(&self, key: &str) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .scard(key)
+            .await
+            .change_context(errors::RedisError::GetSetCardinalityFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn is_set_member<V>(
+        &self,
+        key: &str,
+        member: V,
+    ) -> CustomResult<bool, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .sismember(key, member)
+            .await
+            .change_context(errors::RedisError::SetIsMemberFailed)
+    }
+
+    /// Increments the value of the key by the given amount, returning the updated value.
+    /// A key which does not exist is treated as having the value `0`, the expiry is set when the
+    /// key has none so that the counter and its expiry are updated atomically.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_by_with_expiry(
+        &self,
+        key: &str,
+        amount: i64,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .eval(INCREMENT_WITH_EXPIRY_SCRIPT, key, vec![amount, seconds])
+            .await
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
+    /// Adds the member to the set, returning the number of members of the set.
+    /// The expiry is set when the set has none so that the set and its expiry are updated
+    /// atomically.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn sadd_with_expiry(
+        &self,
+        key: &str,
+        member: &str,
+        seconds: i64,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .eval(
+                SADD_WITH_EXPIRY_SCRIPT,
+                key,
+                vec![RedisValue::from(member), RedisValue::from(seconds)],
+            )
+            .await
+            .change_context(errors::RedisError::SetAddMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -689,6 +766,8 @@ impl super::RedisConnectionPool {
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
 
+    use fred::interfaces::KeysInterface;
+
     use crate::{errors::RedisError, RedisConnectionPool, RedisEntryId, RedisSettings};
 
     #[tokio::test]
@@ -764,4 +843,91 @@ mod tests {
 
         assert!(is_success);
     }
+
+    #[tokio::test]
+    async fn test_increment_key_by_with_expiry() {
+        let (values, ttl_after_first, ttl_after_second) = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let key = "test_increment_key_by_with_expiry";
+                let _ = pool.delete_key(key).await;
+
+                // Act
+                let first = pool.increment_key_by_with_expiry(key, 5, 60).await.unwrap();
+                pool.set_expiry(key, 30).await.unwrap();
+                let ttl_after_first: i64 = pool.pool.ttl(key).await.unwrap();
+                let second = pool.increment_key_by_with_expiry(key, 3, 60).await.unwrap();
+                let ttl_after_second: i64 = pool.pool.ttl(key).await.unwrap();
+                let _ = pool.delete_key(key).await;
+
+                // Assert Setup
+                ((first, second), ttl_after_first, ttl_after_second)
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert_eq!(values, (5, 8));
+        // The expiry of an existing counter is not extended by later increments
+        assert!(ttl_after_first <= 30 && ttl_after_second <= 30 && ttl_after_second > 0);
+    }
+
+    #[tokio::test]
+    async fn test_increment_key_by_with_expiry_sets_missing_expiry() {
+        let ttl = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let key = "test_increment_key_by_with_expiry_sets_missing_expiry";
+                let _ = pool.delete_key(key).await;
+                let _: i64 = pool.pool.incr_by(key, 1).await.unwrap();
+
+                // Act
+                pool.increment_key_by_with_expiry(key, 1, 60).await.unwrap();
+                let ttl: i64 = pool.pool.ttl(key).await.unwrap();
+                let _ = pool.delete_key(key).await;
+
+                // Assert Setup
+                ttl
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert!(ttl > 0 && ttl <= 60);
+    }
+
+    #[tokio::test]
+    async fn test_sadd_with_expiry() {
+        let (cardinalities, ttl) = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let key = "test_sadd_with_expiry";
+                let _ = pool.delete_key(key).await;
+
+                // Act
+                let first = pool.sadd_with_expiry(key, "card_1", 60).await.unwrap();
+                let repeated = pool.sadd_with_expiry(key, "card_1", 60).await.unwrap();
+                let second = pool.sadd_with_expiry(key, "card_2", 60).await.unwrap();
+                let ttl: i64 = pool.pool.ttl(key).await.unwrap();
+                let _ = pool.delete_key(key).await;
+
+                // Assert Setup
+                ((first, repeated, second), ttl)
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert_eq!(cardinalities, (1, 1, 2));
+        assert!(ttl > 0 && ttl <= 60);
+    }
 }
@@ -52,6 +52,12 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to add members to set in Redis")]
     SetAddMembersFailed,
+    #[error("Failed to get the number of members of set in Redis")]
+    GetSetCardinalityFailed,
+    #[error("Failed to check membership of set in Redis")]
+    SetIsMemberFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
     #[error("The requested value was not found in Redis")]
@@ -13,14 +13,14 @@ pub mod routes {
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
         GenerateReportRequest, GetApiEventFiltersRequest, GetApiEventMetricRequest,
-        GetAuthEventMetricRequest, GetDisputeMetricRequest, GetPaymentFiltersRequest,
-        GetPaymentMetricRequest, GetRefundFilterRequest, GetRefundMetricRequest,
-        GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetAuthEventMetricRequest, GetConnectorEventMetricRequest, GetDisputeMetricRequest,
+        GetPaymentFiltersRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
+        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
     };
     use error_stack::ResultExt;
 
     use crate::{
-        core::api_locking,
+        core::{analytics_alerts, api_locking},
         db::user::UserInterface,
         routes::AppState,
         services::{
@@ -84,6 +84,10 @@ pub mod routes {
                         web::resource("connector_event_logs")
                             .route(web::get().to(get_connector_events)),
                     )
+                    .service(
+                        web::resource("metrics/connector_events")
+                            .route(web::post().to(get_connector_event_metrics)),
+                    )
                     .service(
                         web::resource("outgoing_webhook_event_logs")
                             .route(web::get().to(get_outgoing_webhook_events)),
@@ -110,6 +114,18 @@ pub mod routes {
                         web::resource("metrics/disputes")
                             .route(web::post().to(get_dispute_metrics)),
                     )
+                    .service(
+                        web::resource("alerts/rules")
+                            .route(web::post().to(create_alert_rule))
+                            .route(web::get().to(list_alert_rules)),
+                    )
+                    .service(
+                        web::resource("alerts/rules/{rule_id}")
+                            .route(web::get().to(retrieve_alert_rule))
+                            .route(web::post().to(update_alert_rule))
+                            .route(web::delete().to(delete_alert_rule)),
+                    )
+                    .service(web::resource("alerts").route(web::get().to(list_alerts)))
             }
             route
         }
@@ -642,6 +658,44 @@ pub mod routes {
         .await
     }
 
+    /// Connector event metrics are only available when analytics is backed by ClickHouse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetConnectorEventMetricRequest` element.
+    pub async fn get_connector_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetConnectorEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetConnectorEventMetricRequest");
+        let flow = AnalyticsFlow::GetConnectorEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::connector_events::get_connector_event_metrics(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_global_search_results(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -759,4 +813,138 @@ pub mod routes {
         ))
         .await
     }
+
+    pub async fn create_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::alerts::AnalyticsAlertRuleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                analytics_alerts::create_alert_rule(state, auth.merchant_account, req)
+            },
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_alert_rules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListAlertRules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| {
+                analytics_alerts::list_alert_rules(state, auth.merchant_account.merchant_id)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn retrieve_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::RetrieveAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, rule_id, _| {
+                analytics_alerts::retrieve_alert_rule(
+                    state,
+                    auth.merchant_account.merchant_id,
+                    rule_id,
+                )
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn update_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+        json_payload: web::Json<api_models::analytics::alerts::AnalyticsAlertRuleUpdateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateAlertRule;
+        let rule_id = path.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                analytics_alerts::update_alert_rule(
+                    state,
+                    auth.merchant_account.merchant_id,
+                    rule_id.clone(),
+                    req,
+                )
+            },
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: AuthenticationData, rule_id, _| {
+                analytics_alerts::delete_alert_rule(
+                    state,
+                    auth.merchant_account.merchant_id,
+                    rule_id,
+                )
+            },
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_alerts(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query: web::Query<api_models::analytics::alerts::AnalyticsAlertListConstraints>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListAlerts;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query.into_inner(),
+            |state, auth: AuthenticationData, constraints, _| {
+                analytics_alerts::list_alerts(state, auth.merchant_account.merchant_id, constraints)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
 }
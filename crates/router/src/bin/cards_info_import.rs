@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+use router::{
+    configs::settings::Settings,
+    core::{
+        cards_info,
+        errors::{self, RouterResult},
+    },
+    logger, routes, services,
+    types::api,
+};
+use tokio::sync::oneshot;
+
+/// Imports a CSV file of card BIN records into the BIN database.
+///
+/// The file must have a header row naming its columns, of which only `card_iin` is required.
+#[derive(clap::Parser)]
+struct Args {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<PathBuf>,
+
+    /// CSV file of card BIN records to import.
+    #[arg(short = 'i', long, value_name = "FILE")]
+    input: PathBuf,
+
+    /// Name of the provider of the BIN records, stored as the last updated provider of the BINs.
+    #[arg(short = 'p', long)]
+    provider: String,
+
+    /// Only compare the records with the BIN database, without storing them.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[tokio::main]
+async fn main() -> RouterResult<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = Settings::with_config_path(args.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate router configuration");
+
+    let _guard = router_env::setup(
+        &conf.log,
+        router_env::service_name!(),
+        [router_env::service_name!()],
+    );
+
+    let file = std::fs::read(&args.input)
+        .change_context(errors::ApiErrorResponse::MissingFile)
+        .attach_printable_lazy(|| format!("Failed to read {}", args.input.display()))?;
+
+    let api_client = Box::new(
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(&conf.locker),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    );
+    let (redis_shutdown_signal_tx, _redis_shutdown_signal_rx) = oneshot::channel();
+    let state = Box::pin(routes::AppState::new(
+        conf,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+
+    logger::info!(input = %args.input.display(), "Starting card information import");
+    let summary = cards_info::import_cards_info(
+        &*state.store,
+        api::CardInfoImportRequest {
+            provider: args.provider,
+            dry_run: args.dry_run,
+            file,
+        },
+    )
+    .await?;
+    for error in &summary.errors {
+        logger::warn!(?error, "Failed to import card information record");
+    }
+    logger::info!(
+        provider = %summary.provider,
+        dry_run = summary.dry_run,
+        total_records = summary.total_records,
+        inserted = summary.inserted,
+        updated = summary.updated,
+        unchanged = summary.unchanged,
+        failed = summary.errors.len(),
+        "Completed card information import"
+    );
+
+    Ok(())
+}
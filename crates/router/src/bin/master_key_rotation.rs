@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use router::{
+    configs::settings::Settings,
+    core::{
+        errors::{self, RouterResult},
+        master_key_rotation,
+    },
+    logger, routes, services,
+};
+use tokio::sync::oneshot;
+
+/// Re-wraps all merchant and user key stores with the current master encryption key.
+///
+/// The application configuration must specify the new key as `secrets.master_enc_key` and the
+/// key being replaced as `secrets.previous_master_enc_key`.
+#[derive(clap::Parser)]
+struct Args {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    config_path: Option<PathBuf>,
+
+    /// Number of key stores fetched and re-wrapped together.
+    #[arg(short = 'b', long, default_value_t = master_key_rotation::MASTER_KEY_ROTATION_DEFAULT_BATCH_SIZE)]
+    batch_size: i64,
+}
+
+#[tokio::main]
+async fn main() -> RouterResult<()> {
+    let args = <Args as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = Settings::with_config_path(args.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate router configuration");
+
+    let _guard = router_env::setup(
+        &conf.log,
+        router_env::service_name!(),
+        [router_env::service_name!()],
+    );
+
+    let api_client = Box::new(
+        services::ProxyClient::new(
+            conf.proxy.clone(),
+            services::proxy_bypass_urls(&conf.locker),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    );
+    let (redis_shutdown_signal_tx, _redis_shutdown_signal_rx) = oneshot::channel();
+    let state = Box::pin(routes::AppState::new(
+        conf,
+        redis_shutdown_signal_tx,
+        api_client,
+    ))
+    .await;
+
+    let previous_master_key = state
+        .conf
+        .secrets
+        .get_inner()
+        .previous_master_enc_key
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Previous master encryption key is not configured")
+        .and_then(|previous_master_enc_key| {
+            hex::decode(previous_master_enc_key.expose())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to decode previous master key from hex")
+        })?;
+
+    logger::info!("Starting master key rotation");
+    let summary = master_key_rotation::rotate_master_key(
+        &*state.store,
+        &previous_master_key,
+        args.batch_size,
+    )
+    .await?;
+    logger::info!(?summary, "Completed master key rotation");
+
+    Ok(())
+}
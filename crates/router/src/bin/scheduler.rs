@@ -280,6 +280,55 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
                         )
                     }
                 }
+                storage::ProcessTrackerRunner::AnalyticsAlertWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::analytics_alert::AnalyticsAlertWorkflow))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run analytics alert workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::FrmReviewDeadlineWorkflow => {
+                    #[cfg(feature = "frm")]
+                    {
+                        Ok(Box::new(
+                            workflows::frm_review_deadline::FrmReviewDeadlineWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "frm"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run manual review deadline workflow when frm feature is \
+                                 disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow => Ok(Box::new(
+                    workflows::merchant_key_rotation::MerchantKeyRotationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline_reminder::DisputeDeadlineReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow => Ok(Box::new(
+                    workflows::card_account_updater::CardAccountUpdaterWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WebhookBulkRetryWorkflow => Ok(Box::new(
+                    workflows::webhook_bulk_retry::WebhookBulkRetryWorkflow,
+                )),
             }
         };
 
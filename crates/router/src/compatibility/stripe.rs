@@ -1,6 +1,9 @@
 pub mod app;
+pub mod checkout_sessions;
 pub mod customers;
+pub mod disputes;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -21,6 +24,8 @@ impl StripeApis {
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state.clone()))
+            .service(app::Disputes::server(state.clone()))
+            .service(app::CheckoutSessions::server(state))
     }
 }
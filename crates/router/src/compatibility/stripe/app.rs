@@ -1,7 +1,10 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use super::{
+    checkout_sessions::*, customers::*, disputes::*, payment_intents::*, payment_methods::*,
+    refunds::*, setup_intents::*, webhooks::*,
+};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -112,12 +115,45 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(web::resource("").route(web::get().to(payment_method_list)))
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/detach").route(web::post().to(payment_method_detach)))
+    }
+}
+
+pub struct Disputes;
+
+impl Disputes {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(dispute_list)))
+            .service(
+                web::resource("/{dispute_id}")
+                    .route(web::get().to(dispute_retrieve))
+                    .route(web::post().to(dispute_update)),
+            )
+            .service(web::resource("/{dispute_id}/close").route(web::post().to(dispute_close)))
+    }
+}
+
+pub struct CheckoutSessions;
+
+impl CheckoutSessions {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/checkout/sessions")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(checkout_session_create)))
+            .service(web::resource("/{session_id}").route(web::get().to(checkout_session_retrieve)))
+            .service(
+                web::resource("/{session_id}/expire")
+                    .route(web::post().to(checkout_session_expire)),
+            )
     }
 }
@@ -0,0 +1,182 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking::GetLockingInput, payments},
+    logger,
+    routes::{self, payments::get_or_generate_payment_id},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api as api_types,
+};
+
+/// Checkout sessions are payments created with a payment link, the payment id is used as the
+/// session id.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+pub async fn checkout_session_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeCheckoutSessionRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let mut create_payment_req: payment_types::PaymentsRequest = match payload.try_into() {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    if let Err(err) = get_or_generate_payment_id(&mut create_payment_req) {
+        return api::log_and_return_error_response(err);
+    }
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCheckoutSessionResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth, req, req_state| {
+            payments::payments_core::<api_types::Authorize, api_types::PaymentsResponse, _, _, _>(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                api_types::HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
+pub async fn checkout_session_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(path.into_inner()),
+        merchant_id: None,
+        force_sync: false,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: None,
+        expand_captures: None,
+    };
+
+    let flow = Flow::PaymentsRetrieve;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCheckoutSessionResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload, req_state| {
+            payments::payments_core::<api_types::PSync, api_types::PaymentsResponse, _, _, _>(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentStatus,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                api_types::HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel, payment_id))]
+pub async fn checkout_session_expire(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", &payment_id.clone());
+
+    let payload = payment_types::PaymentsCancelRequest {
+        payment_id,
+        cancellation_reason: Some("checkout_session_expired".to_string()),
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCancel;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeCheckoutSessionResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            payments::payments_core::<api_types::Void, api_types::PaymentsResponse, _, _, _>(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentCancel,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                api_types::HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
+        locking_action,
+    ))
+    .await
+}
@@ -0,0 +1,325 @@
+use api_models::payments;
+use common_utils::{
+    date_time,
+    ext_traits::StringExt,
+    pii::{Email, SecretSerdeValue},
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types::api::enums as api_enums};
+
+const CLIENT_REFERENCE_ID_METADATA_KEY: &str = "client_reference_id";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutSessionMode {
+    #[default]
+    Payment,
+    Setup,
+    Subscription,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeCheckoutSessionRequest {
+    #[serde(default)]
+    pub mode: StripeCheckoutSessionMode,
+    #[serde(default)]
+    pub line_items: Vec<StripeLineItem>,
+    pub success_url: Option<url::Url>,
+    pub customer: Option<String>,
+    pub customer_email: Option<Email>,
+    pub client_reference_id: Option<String>,
+    pub expires_at: Option<i64>,
+    pub metadata: Option<SecretSerdeValue>,
+    pub payment_intent_data: Option<StripeCheckoutPaymentIntentData>,
+    // cancel_url: Option<url::Url>, // not used
+    // payment_method_types: Option<Vec<String>>, // not used
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeLineItem {
+    pub price_data: StripePriceData,
+    pub quantity: u16,
+    // price: Option<String>, // not used
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripePriceData {
+    pub currency: String,
+    pub unit_amount: i64,
+    pub product_data: StripeProductData,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeProductData {
+    pub name: String,
+    // description: Option<String>, // not used
+    // images: Option<Vec<String>>, // not used
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeCheckoutPaymentIntentData {
+    pub description: Option<String>,
+    pub capture_method: Option<api_enums::CaptureMethod>,
+    pub setup_future_usage: Option<api_enums::FutureUsage>,
+    pub statement_descriptor: Option<String>,
+}
+
+impl TryFrom<StripeCheckoutSessionRequest> for payments::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeCheckoutSessionRequest) -> errors::RouterResult<Self> {
+        if !matches!(item.mode, StripeCheckoutSessionMode::Payment) {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only checkout sessions in `payment` mode are supported".to_string(),
+            }
+            .into());
+        }
+
+        let (amount, currency) = get_amount_and_currency(&item.line_items)?;
+
+        let session_expiry = item
+            .expires_at
+            .map(|expires_at| {
+                let now = date_time::now().assume_utc().unix_timestamp();
+                u32::try_from(expires_at.saturating_sub(now)).map_err(|_| {
+                    errors::ApiErrorResponse::InvalidRequestData {
+                        message: "expires_at must be a timestamp in the future".to_string(),
+                    }
+                })
+            })
+            .transpose()?;
+
+        let metadata = match item.client_reference_id {
+            Some(client_reference_id) => {
+                let mut metadata = item
+                    .metadata
+                    .map(ExposeInterface::expose)
+                    .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+                metadata
+                    .as_object_mut()
+                    .ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "metadata".to_string(),
+                        expected_format: "object".to_string(),
+                    })?
+                    .insert(
+                        CLIENT_REFERENCE_ID_METADATA_KEY.to_string(),
+                        serde_json::Value::String(client_reference_id),
+                    );
+                Some(masking::Secret::new(metadata))
+            }
+            None => item.metadata,
+        };
+
+        let order_details = item
+            .line_items
+            .into_iter()
+            .map(|line_item| payments::OrderDetailsWithAmount {
+                product_name: line_item.price_data.product_data.name,
+                quantity: line_item.quantity,
+                amount: line_item.price_data.unit_amount,
+                ..Default::default()
+            })
+            .collect();
+
+        let payment_intent_data = item.payment_intent_data;
+
+        Ok(Self {
+            amount: Some(MinorUnit::new(amount).into()),
+            currency: Some(currency),
+            customer_id: item.customer,
+            email: item.customer_email,
+            return_url: item.success_url,
+            order_details: Some(order_details),
+            metadata,
+            payment_link: Some(true),
+            session_expiry,
+            description: payment_intent_data
+                .as_ref()
+                .and_then(|data| data.description.clone()),
+            capture_method: payment_intent_data
+                .as_ref()
+                .and_then(|data| data.capture_method),
+            setup_future_usage: payment_intent_data
+                .as_ref()
+                .and_then(|data| data.setup_future_usage),
+            statement_descriptor_name: payment_intent_data
+                .and_then(|data| data.statement_descriptor),
+            ..Self::default()
+        })
+    }
+}
+
+/// The amount of a session is the total of its line items, which must all be in the same currency
+fn get_amount_and_currency(
+    line_items: &[StripeLineItem],
+) -> errors::RouterResult<(i64, api_enums::Currency)> {
+    let mut currencies = line_items
+        .iter()
+        .map(|line_item| line_item.price_data.currency.to_uppercase());
+    let currency = currencies
+        .next()
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "line_items",
+        })?;
+    if currencies.any(|other| other != currency) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "All line items must be in the same currency".to_string(),
+        })?
+    }
+    let currency = currency.parse_enum("currency").change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "currency",
+        },
+    )?;
+
+    let amount = line_items
+        .iter()
+        .try_fold(0_i64, |total, line_item| {
+            line_item
+                .price_data
+                .unit_amount
+                .checked_mul(i64::from(line_item.quantity))
+                .and_then(|line_total| total.checked_add(line_total))
+        })
+        .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "line_items",
+        })?;
+
+    Ok((amount, currency))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutSessionStatus {
+    Open,
+    Complete,
+    Expired,
+}
+
+impl From<api_enums::IntentStatus> for StripeCheckoutSessionStatus {
+    fn from(status: api_enums::IntentStatus) -> Self {
+        match status {
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::Processing => Self::Complete,
+            api_enums::IntentStatus::Cancelled => Self::Expired,
+            api_enums::IntentStatus::Failed
+            | api_enums::IntentStatus::RequiresCustomerAction
+            | api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresPaymentMethod
+            | api_enums::IntentStatus::RequiresConfirmation => Self::Open,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCheckoutPaymentStatus {
+    Paid,
+    Unpaid,
+}
+
+impl From<api_enums::IntentStatus> for StripeCheckoutPaymentStatus {
+    fn from(status: api_enums::IntentStatus) -> Self {
+        match status {
+            api_enums::IntentStatus::Succeeded | api_enums::IntentStatus::PartiallyCaptured => {
+                Self::Paid
+            }
+            _ => Self::Unpaid,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeCheckoutSessionResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub mode: &'static str,
+    pub amount_total: i64,
+    pub currency: String,
+    pub customer: Option<String>,
+    pub customer_email: Option<Email>,
+    pub client_reference_id: Option<String>,
+    pub payment_intent: String,
+    pub payment_status: StripeCheckoutPaymentStatus,
+    pub status: StripeCheckoutSessionStatus,
+    pub success_url: Option<String>,
+    /// The hosted payment link, only returned when the session is created
+    pub url: Option<String>,
+    pub created: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub metadata: Option<SecretSerdeValue>,
+}
+
+impl From<payments::PaymentsResponse> for StripeCheckoutSessionResponse {
+    fn from(resp: payments::PaymentsResponse) -> Self {
+        let payment_id = resp.payment_id.unwrap_or_default();
+        let client_reference_id = resp.metadata.as_ref().and_then(|metadata| {
+            metadata
+                .peek()
+                .get(CLIENT_REFERENCE_ID_METADATA_KEY)
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string)
+        });
+        Self {
+            id: payment_id.clone(),
+            object: "checkout.session",
+            mode: "payment",
+            amount_total: resp.amount.get_amount_as_i64(),
+            currency: resp.currency.to_lowercase(),
+            customer: resp.customer_id,
+            customer_email: resp.email.map(|inner| inner.into()),
+            client_reference_id,
+            payment_intent: payment_id,
+            payment_status: StripeCheckoutPaymentStatus::from(resp.status),
+            status: StripeCheckoutSessionStatus::from(resp.status),
+            success_url: resp.return_url,
+            url: resp.payment_link.map(|payment_link| payment_link.link),
+            created: resp.created.map(|t| t.assume_utc().unix_timestamp()),
+            expires_at: resp.expires_on.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: resp.metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn line_item(currency: &str, unit_amount: i64, quantity: u16) -> StripeLineItem {
+        StripeLineItem {
+            price_data: StripePriceData {
+                currency: currency.to_string(),
+                unit_amount,
+                product_data: StripeProductData {
+                    name: "shirt".to_string(),
+                },
+            },
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_amount_is_total_of_line_items() {
+        let (amount, currency) =
+            get_amount_and_currency(&[line_item("usd", 1000, 2), line_item("USD", 250, 1)])
+                .unwrap();
+        assert_eq!(amount, 2250);
+        assert_eq!(currency, api_enums::Currency::USD);
+    }
+
+    #[test]
+    fn test_line_items_in_different_currencies_are_rejected() {
+        assert!(
+            get_amount_and_currency(&[line_item("usd", 1000, 1), line_item("eur", 1000, 1)])
+                .is_err()
+        );
+        assert!(get_amount_and_currency(&[]).is_err());
+    }
+}
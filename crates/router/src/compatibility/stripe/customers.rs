@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::{customers as customer_types, payment_methods},
 };
 
@@ -46,7 +46,7 @@ pub async fn customer_create(
         |state, auth, req, _| {
             customers::create_customer(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -80,7 +80,7 @@ pub async fn customer_retrieve(
         |state, auth, req, _| {
             customers::retrieve_customer(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -123,7 +123,7 @@ pub async fn customer_update(
         |state, auth, req, _| {
             customers::update_customer(state, auth.merchant_account, req, auth.key_store)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -157,7 +157,7 @@ pub async fn customer_delete(
         |state, auth, req, _| {
             customers::delete_customer(state, auth.merchant_account, req, auth.key_store)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -196,7 +196,7 @@ pub async fn list_customer_payment_method_api(
                 Some(customer_id.as_str()),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
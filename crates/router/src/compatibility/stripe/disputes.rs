@@ -0,0 +1,171 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::disputes as dispute_models;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, disputes},
+    logger, routes,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api::disputes as dispute_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+pub async fn dispute_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripeDisputeListConstraints>,
+) -> HttpResponse {
+    let payload = match dispute_models::DisputeListConstraints::try_from(query_payload.into_inner())
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let flow = Flow::DisputesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| disputes::retrieve_disputes_list(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth(Permission::DisputeRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| disputes::retrieve_dispute(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth(Permission::DisputeRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+pub async fn dispute_update(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let stripe_payload: types::StripeDisputeUpdateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?stripe_payload);
+
+    // Evidence is submitted to the connector right away, it can not be staged on the dispute
+    if stripe_payload.submit == Some(false) {
+        return api::log_and_return_error_response(report!(
+            errors::StripeErrorCode::InvalidRequestData {
+                message: "Staging dispute evidence is not supported, pass `submit=true`"
+                    .to_string(),
+            }
+        ));
+    }
+
+    let payload = dispute_models::SubmitEvidenceRequest::from((
+        path.into_inner(),
+        stripe_payload.evidence.unwrap_or_default(),
+    ));
+    let flow = Flow::DisputesEvidenceSubmit;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| {
+            disputes::submit_evidence(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth(Permission::DisputeWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_close(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| {
+            disputes::accept_dispute(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth(Permission::DisputeWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
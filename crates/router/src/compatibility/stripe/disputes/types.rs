@@ -0,0 +1,229 @@
+use api_models::{
+    disputes::{DisputeListConstraints, DisputeResponse, SubmitEvidenceRequest},
+    enums::DisputeStatus,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::core::errors;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeDisputeListConstraints {
+    pub limit: Option<i64>,
+    pub payment_intent: Option<String>,
+    pub created: Option<i64>,
+    #[serde(rename = "created[lt]")]
+    pub created_lt: Option<i64>,
+    #[serde(rename = "created[gt]")]
+    pub created_gt: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    // charge: Option<String>, // not used
+    // starting_after: Option<String>, // not used
+    // ending_before: Option<String>, // not used
+}
+
+impl TryFrom<StripeDisputeListConstraints> for DisputeListConstraints {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeDisputeListConstraints) -> Result<Self, Self::Error> {
+        Ok(Self {
+            limit: item.limit,
+            profile_id: None,
+            dispute_status: None,
+            dispute_stage: None,
+            reason: None,
+            connector: None,
+            received_time: from_timestamp_to_datetime(item.created)?,
+            received_time_lt: from_timestamp_to_datetime(item.created_lt)?,
+            received_time_gt: from_timestamp_to_datetime(item.created_gt)?,
+            received_time_lte: from_timestamp_to_datetime(item.created_lte)?,
+            received_time_gte: from_timestamp_to_datetime(item.created_gte)?,
+        })
+    }
+}
+
+#[inline]
+fn from_timestamp_to_datetime(
+    time: Option<i64>,
+) -> Result<Option<PrimitiveDateTime>, errors::ApiErrorResponse> {
+    if let Some(time) = time {
+        let time = time::OffsetDateTime::from_unix_timestamp(time).map_err(|_| {
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "Error while converting timestamp".to_string(),
+            }
+        })?;
+
+        Ok(Some(PrimitiveDateTime::new(time.date(), time.time())))
+    } else {
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeDisputeUpdateRequest {
+    pub evidence: Option<StripeDisputeEvidence>,
+    pub submit: Option<bool>,
+    // metadata: Option<pii::SecretSerdeValue>, // not used
+}
+
+/// Evidence fields holding files take the identifiers of files uploaded through the files API
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StripeDisputeEvidence {
+    pub access_activity_log: Option<String>,
+    pub billing_address: Option<String>,
+    pub cancellation_policy: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub customer_communication: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub customer_signature: Option<String>,
+    pub duplicate_charge_documentation: Option<String>,
+    pub product_description: Option<String>,
+    pub receipt: Option<String>,
+    pub refund_policy: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub service_date: Option<String>,
+    pub service_documentation: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_documentation: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_file: Option<String>,
+    pub uncategorized_text: Option<String>,
+    // duplicate_charge_explanation: Option<String>, // not used
+    // duplicate_charge_id: Option<String>, // not used
+}
+
+impl From<(String, StripeDisputeEvidence)> for SubmitEvidenceRequest {
+    fn from((dispute_id, evidence): (String, StripeDisputeEvidence)) -> Self {
+        Self {
+            dispute_id,
+            access_activity_log: evidence.access_activity_log,
+            billing_address: evidence.billing_address,
+            cancellation_policy: evidence.cancellation_policy,
+            cancellation_policy_disclosure: evidence.cancellation_policy_disclosure,
+            cancellation_rebuttal: evidence.cancellation_rebuttal,
+            customer_communication: evidence.customer_communication,
+            customer_email_address: evidence.customer_email_address,
+            customer_name: evidence.customer_name,
+            customer_purchase_ip: evidence.customer_purchase_ip,
+            customer_signature: evidence.customer_signature,
+            product_description: evidence.product_description,
+            receipt: evidence.receipt,
+            refund_policy: evidence.refund_policy,
+            refund_policy_disclosure: evidence.refund_policy_disclosure,
+            refund_refusal_explanation: evidence.refund_refusal_explanation,
+            service_date: evidence.service_date,
+            service_documentation: evidence.service_documentation,
+            shipping_address: evidence.shipping_address,
+            shipping_carrier: evidence.shipping_carrier,
+            shipping_date: evidence.shipping_date,
+            shipping_documentation: evidence.shipping_documentation,
+            shipping_tracking_number: evidence.shipping_tracking_number,
+            invoice_showing_distinct_transactions: evidence.duplicate_charge_documentation,
+            recurring_transaction_agreement: None,
+            uncategorized_file: evidence.uncategorized_file,
+            uncategorized_text: evidence.uncategorized_text,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: String,
+    pub currency: String,
+    pub payment_intent: String,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+    pub created: i64,
+    pub evidence_details: StripeDisputeEvidenceDetails,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+    pub has_evidence: bool,
+    pub past_due: bool,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeDisputeListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeDisputeResponse>,
+}
+
+impl From<DisputeResponse> for StripeDisputeResponse {
+    fn from(res: DisputeResponse) -> Self {
+        let evidence_details = StripeDisputeEvidenceDetails {
+            due_by: res
+                .challenge_required_by
+                .map(|due_by| due_by.assume_utc().unix_timestamp()),
+            has_evidence: matches!(
+                res.dispute_status,
+                DisputeStatus::DisputeChallenged
+                    | DisputeStatus::DisputeWon
+                    | DisputeStatus::DisputeLost
+            ),
+            past_due: res.dispute_status == DisputeStatus::DisputeExpired,
+        };
+        Self {
+            id: res.dispute_id,
+            object: "dispute",
+            amount: res.amount,
+            currency: res.currency,
+            payment_intent: res.payment_id,
+            reason: res.connector_reason,
+            status: StripeDisputeStatus::from(res.dispute_status),
+            created: res.created_at.assume_utc().unix_timestamp(),
+            evidence_details,
+        }
+    }
+}
+
+impl From<DisputeStatus> for StripeDisputeStatus {
+    fn from(status: DisputeStatus) -> Self {
+        match status {
+            DisputeStatus::DisputeOpened => Self::WarningNeedsResponse,
+            DisputeStatus::DisputeExpired => Self::Lost,
+            DisputeStatus::DisputeAccepted => Self::Lost,
+            DisputeStatus::DisputeCancelled => Self::WarningClosed,
+            DisputeStatus::DisputeChallenged => Self::WarningUnderReview,
+            DisputeStatus::DisputeWon => Self::Won,
+            DisputeStatus::DisputeLost => Self::Lost,
+        }
+    }
+}
+
+impl From<Vec<DisputeResponse>> for StripeDisputeListResponse {
+    fn from(res: Vec<DisputeResponse>) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/disputes",
+            has_more: false,
+            data: res.into_iter().map(From::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, errors as core_errors, mandate, payment_methods::cards},
+    routes,
+    services::{self, api, authentication as auth, authorization::permissions::Permission},
+    types::{
+        api::{mandates, payment_methods},
+        domain,
+    },
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
+pub async fn payment_method_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripePaymentMethodListConstraints>,
+) -> HttpResponse {
+    let customer_id = query_payload.into_inner().customer;
+    let payload = payment_methods::PaymentMethodListRequest::default();
+    let flow = Flow::CustomerPaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| {
+            cards::do_list_customer_pm_fetch_customer_if_not_passed(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                Some(req),
+                Some(customer_id.as_str()),
+            )
+        },
+        &auth::ApiKeyAuth(Permission::CustomerRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| {
+            cards::retrieve_payment_method(state, req, auth.key_store, auth.merchant_account)
+        },
+        &auth::ApiKeyAuth(Permission::CustomerRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Detaches a payment method by deleting it; like in Stripe, a detached payment method can no
+/// longer be used for payments. Mandates were revoked through this endpoint before payment methods
+/// were supported, so the mandate is revoked instead if the ID is that of a mandate.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodDetachResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, _| {
+            detach_payment_method(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn detach_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    pm: payment_methods::PaymentMethodId,
+) -> core_errors::RouterResponse<types::DetachedPaymentMethod> {
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &pm.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await;
+
+    match mandate {
+        Ok(mandate) => {
+            let response = mandate::revoke_mandate(
+                state,
+                merchant_account,
+                key_store,
+                mandates::MandateId {
+                    mandate_id: mandate.mandate_id,
+                },
+            )
+            .await?;
+            map_json_response(response, types::DetachedPaymentMethod::Mandate)
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let response =
+                cards::delete_payment_method(state, merchant_account, pm, key_store).await?;
+            map_json_response(response, types::DetachedPaymentMethod::PaymentMethod)
+        }
+        Err(error) => Err(error
+            .change_context(core_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find mandate with the ID of the detached payment method")),
+    }
+}
+
+fn map_json_response<T, R>(
+    response: services::ApplicationResponse<T>,
+    map: impl FnOnce(T) -> R,
+) -> core_errors::RouterResponse<R> {
+    match response {
+        services::ApplicationResponse::Json(response) => {
+            Ok(services::ApplicationResponse::Json(map(response)))
+        }
+        _ => Err(report!(core_errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while detaching payment method"),
+    }
+}
@@ -0,0 +1,138 @@
+use api_models::payment_methods as api_types;
+use common_utils::pii;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripePaymentMethodListConstraints {
+    pub customer: String,
+    // type: Option<String>, // not used
+    // limit: Option<i64>, // not used
+}
+
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: Option<String>,
+    pub card: Option<StripeCard>,
+    pub customer: Option<String>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct StripeCard {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub fingerprint: Option<masking::Secret<String>>,
+    pub funding: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePaymentMethodResponse>,
+}
+
+impl From<api_types::CardDetailFromLocker> for StripeCard {
+    fn from(card: api_types::CardDetailFromLocker) -> Self {
+        Self {
+            brand: card
+                .card_network
+                .map(|network| network.to_string().to_lowercase())
+                .or(card.scheme.map(|scheme| scheme.to_lowercase())),
+            country: card.issuer_country,
+            last4: card.last4_digits,
+            exp_month: card.expiry_month,
+            exp_year: card.expiry_year,
+            fingerprint: card.card_fingerprint,
+            funding: card.card_type.map(|card_type| card_type.to_lowercase()),
+        }
+    }
+}
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(pm: api_types::PaymentMethodResponse) -> Self {
+        Self {
+            id: pm.payment_method_id,
+            object: "payment_method",
+            stype: pm.payment_method.map(|pm| pm.to_string()),
+            card: pm.card.map(From::from),
+            customer: pm.customer_id,
+            created: pm.created.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: pm.metadata,
+        }
+    }
+}
+
+impl From<api_types::CustomerPaymentMethod> for StripePaymentMethodResponse {
+    fn from(pm: api_types::CustomerPaymentMethod) -> Self {
+        Self {
+            id: pm.payment_method_id,
+            object: "payment_method",
+            stype: Some(pm.payment_method.to_string()),
+            card: pm.card.map(From::from),
+            customer: Some(pm.customer_id),
+            created: pm.created.map(|t| t.assume_utc().unix_timestamp()),
+            metadata: pm.metadata,
+        }
+    }
+}
+
+// A detached payment method is deleted, Stripe returns it without a customer
+impl From<api_types::PaymentMethodDeleteResponse> for StripePaymentMethodResponse {
+    fn from(res: api_types::PaymentMethodDeleteResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            ..Default::default()
+        }
+    }
+}
+
+/// The payment method or mandate detached through the detach endpoint
+#[derive(Debug, Serialize)]
+pub enum DetachedPaymentMethod {
+    PaymentMethod(api_types::PaymentMethodDeleteResponse),
+    Mandate(api_models::mandates::MandateRevokedResponse),
+}
+
+impl common_utils::events::ApiEventMetric for DetachedPaymentMethod {}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum StripePaymentMethodDetachResponse {
+    PaymentMethod(StripePaymentMethodResponse),
+    // Revoked mandates are returned as before payment methods were supported
+    Mandate(api_models::mandates::MandateRevokedResponse),
+}
+
+impl From<DetachedPaymentMethod> for StripePaymentMethodDetachResponse {
+    fn from(detached: DetachedPaymentMethod) -> Self {
+        match detached {
+            DetachedPaymentMethod::PaymentMethod(res) => Self::PaymentMethod(res.into()),
+            DetachedPaymentMethod::Mandate(res) => Self::Mandate(res),
+        }
+    }
+}
+
+impl From<api_types::CustomerPaymentMethodsListResponse> for StripePaymentMethodListResponse {
+    fn from(res: api_types::CustomerPaymentMethodsListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/payment_methods",
+            has_more: false,
+            data: res
+                .customer_payment_methods
+                .into_iter()
+                .map(From::from)
+                .collect(),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, refunds},
     logger, routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds as refund_types,
 };
 
@@ -51,7 +51,7 @@ pub async fn refund_create(
         |state, auth, req, _| {
             refunds::refund_create_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -101,7 +101,7 @@ pub async fn refund_retrieve_with_gateway_creds(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -143,7 +143,7 @@ pub async fn refund_retrieve(
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -175,7 +175,7 @@ pub async fn refund_update(
         &req,
         create_refund_update_req,
         |state, auth, req, _| refunds::refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1,5 +1,5 @@
 use api_models::{
-    enums::{DisputeStatus, MandateStatus},
+    enums::MandateStatus,
     webhooks::{self as api},
 };
 use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
@@ -8,7 +8,8 @@ use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    disputes::types::StripeDisputeResponse, payment_intents::types::StripePaymentIntentResponse,
+    refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{
@@ -81,16 +82,9 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
-}
-
-#[derive(Serialize, Debug)]
-pub struct StripeDisputeResponse {
-    pub id: String,
-    pub amount: String,
-    pub currency: String,
-    pub payment_intent: String,
-    pub reason: Option<String>,
-    pub status: StripeDisputeStatus,
+    AnalyticsAlert(Box<api_models::analytics::alerts::AnalyticsAlertResponse>),
+    FraudCheck(Box<api_models::frm_review::FrmReviewQueueItem>),
+    PaymentMethod(Box<api_models::payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -109,32 +103,6 @@ pub enum StripeMandateStatus {
     Pending,
 }
 
-#[derive(Serialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum StripeDisputeStatus {
-    WarningNeedsResponse,
-    WarningUnderReview,
-    WarningClosed,
-    NeedsResponse,
-    UnderReview,
-    ChargeRefunded,
-    Won,
-    Lost,
-}
-
-impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
-    fn from(res: api_models::disputes::DisputeResponse) -> Self {
-        Self {
-            id: res.dispute_id,
-            amount: res.amount,
-            currency: res.currency,
-            payment_intent: res.payment_id,
-            reason: res.connector_reason,
-            status: StripeDisputeStatus::from(res.dispute_status),
-        }
-    }
-}
-
 impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     fn from(res: api_models::mandates::MandateResponse) -> Self {
         Self {
@@ -156,20 +124,6 @@ impl From<MandateStatus> for StripeMandateStatus {
     }
 }
 
-impl From<DisputeStatus> for StripeDisputeStatus {
-    fn from(status: DisputeStatus) -> Self {
-        match status {
-            DisputeStatus::DisputeOpened => Self::WarningNeedsResponse,
-            DisputeStatus::DisputeExpired => Self::Lost,
-            DisputeStatus::DisputeAccepted => Self::Lost,
-            DisputeStatus::DisputeCancelled => Self::WarningClosed,
-            DisputeStatus::DisputeChallenged => Self::WarningUnderReview,
-            DisputeStatus::DisputeWon => Self::Won,
-            DisputeStatus::DisputeLost => Self::Lost,
-        }
-    }
-}
-
 fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
@@ -188,8 +142,16 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::AnalyticsAlertTriggered => "analytics_alert.triggered",
+        api_models::enums::EventType::FrmApproved => "fraud_check.approved",
+        api_models::enums::EventType::FrmRejected => "fraud_check.rejected",
+        api_models::enums::EventType::PaymentMethodCreated => "payment_method.attached",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
+        api_models::enums::EventType::PaymentMethodDeleted => "payment_method.detached",
+        api_models::enums::EventType::DefaultPaymentMethodChanged => "customer.updated",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -197,6 +159,9 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         }
         // stripe treats partially captured payments as succeeded.
         api_models::enums::EventType::PaymentCaptured => "payment_intent.succeeded",
+        api_models::enums::EventType::PaymentAuthorizationExpiring => {
+            "payment_intent.authorization_expiring"
+        }
     }
 }
 
@@ -237,6 +202,15 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::AnalyticsAlertDetails(alert) => {
+                Self::AnalyticsAlert(alert)
+            }
+            api::OutgoingWebhookContent::FraudCheckDetails(fraud_check) => {
+                Self::FraudCheck(fraud_check)
+            }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod(payment_method)
+            }
         }
     }
 }
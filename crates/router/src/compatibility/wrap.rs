@@ -165,6 +165,18 @@ where
                         ),
                     }
                 }
+                api::PaymentLinkAction::PaymentLinkAmountForm(payment_link_data) => {
+                    match api::get_payment_link_amount_form(payment_link_data) {
+                        Ok(rendered_html) => api::http_response_html_data(rendered_html),
+                        Err(_) => api::http_response_err(
+                            r#"{
+                                "error": {
+                                    "message": "Error while rendering payment link amount page"
+                                }
+                            }"#,
+                        ),
+                    }
+                }
             }
         }
         Err(error) => api::log_and_return_error_response(error),
@@ -8128,9 +8128,65 @@ impl Default for super::settings::ApiKeys {
             // hashes of API keys
             hash_key: String::new().into(),
 
+            // Number of proxies in front of the application that append to the `X-Forwarded-For`
+            // header
+            trusted_proxy_count: 1,
+
             // Specifies the number of days before API key expiry when email reminders should be sent
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
         }
     }
 }
+
+impl Default for super::settings::DisputeSettings {
+    fn default() -> Self {
+        Self {
+            // Specifies the number of days before the evidence submission deadline of a dispute
+            // when reminders should be sent
+            deadline_reminder_days: vec![7, 3, 1],
+        }
+    }
+}
+
+impl Default for super::settings::NetworkTokenization {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: super::settings::NetworkTokenProviderKind::Mock,
+            card_networks: HashSet::from([
+                enums::CardNetwork::Visa,
+                enums::CardNetwork::Mastercard,
+            ]),
+            token_requestor_base_url: String::new(),
+            token_requestor_api_key: masking::Secret::default(),
+            webhook_secret: masking::Secret::default(),
+        }
+    }
+}
+
+impl Default for super::settings::CardAccountUpdaterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: super::settings::CardAccountUpdaterProviderKind::Mock,
+            mock_updates_file: "config/mock-card-account-updates.json".to_string(),
+            batch_size: 100,
+            expiry_window_in_days: 30,
+            run_interval_in_hours: 24,
+        }
+    }
+}
+
+impl Default for super::settings::AuthorizationExpirySettings {
+    fn default() -> Self {
+        Self {
+            default_validity_in_days: 7,
+            card_networks: HashMap::from([
+                ("discover".to_string(), 10),
+                ("unionpay".to_string(), 30),
+            ]),
+            connectors: HashMap::new(),
+        }
+    }
+}
@@ -113,9 +113,11 @@ impl SecretsHandler for settings::ApiKeys {
 
         #[cfg(feature = "email")]
         let expiry_reminder_days = api_keys.expiry_reminder_days.clone();
+        let trusted_proxy_count = api_keys.trusted_proxy_count;
 
         Ok(value.transition_state(|_| Self {
             hash_key,
+            trusted_proxy_count,
             #[cfg(feature = "email")]
             expiry_reminder_days,
         }))
@@ -197,6 +199,28 @@ impl SecretsHandler for settings::PaymentMethodAuth {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::NetworkTokenization {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let network_tokenization = value.get_inner();
+
+        let (token_requestor_api_key, webhook_secret) = tokio::try_join!(
+            secret_management_client
+                .get_secret(network_tokenization.token_requestor_api_key.clone()),
+            secret_management_client.get_secret(network_tokenization.webhook_secret.clone()),
+        )?;
+
+        Ok(value.transition_state(|network_tokenization| Self {
+            token_requestor_api_key,
+            webhook_secret,
+            ..network_tokenization
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::Secrets {
     async fn convert_to_raw_secret(
@@ -204,11 +228,26 @@ impl SecretsHandler for settings::Secrets {
         secret_management_client: &dyn SecretManagementInterface,
     ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
         let secrets = value.get_inner();
-        let (jwt_secret, admin_api_key, recon_admin_api_key, master_enc_key) = tokio::try_join!(
+        let (
+            jwt_secret,
+            admin_api_key,
+            recon_admin_api_key,
+            master_enc_key,
+            previous_master_enc_key,
+        ) = tokio::try_join!(
             secret_management_client.get_secret(secrets.jwt_secret.clone()),
             secret_management_client.get_secret(secrets.admin_api_key.clone()),
             secret_management_client.get_secret(secrets.recon_admin_api_key.clone()),
-            secret_management_client.get_secret(secrets.master_enc_key.clone())
+            secret_management_client.get_secret(secrets.master_enc_key.clone()),
+            async {
+                match secrets.previous_master_enc_key.clone() {
+                    Some(previous_master_enc_key) => secret_management_client
+                        .get_secret(previous_master_enc_key)
+                        .await
+                        .map(Some),
+                    None => Ok(None),
+                }
+            }
         )?;
 
         Ok(value.transition_state(|_| Self {
@@ -216,6 +255,7 @@ impl SecretsHandler for settings::Secrets {
             admin_api_key,
             recon_admin_api_key,
             master_enc_key,
+            previous_master_enc_key,
         }))
     }
 }
@@ -302,6 +342,14 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt payment method auth configs");
 
+    #[allow(clippy::expect_used)]
+    let network_tokenization = settings::NetworkTokenization::convert_to_raw_secret(
+        conf.network_tokenization,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt network tokenization configs");
+
     Settings {
         server: conf.server,
         master_database,
@@ -338,6 +386,9 @@ pub(crate) async fn fetch_raw_secrets(
         mandates: conf.mandates,
         network_transaction_id_supported_connectors: conf
             .network_transaction_id_supported_connectors,
+        network_tokenization,
+        network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
+        card_account_updater: conf.card_account_updater,
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
@@ -367,5 +418,7 @@ pub(crate) async fn fetch_raw_secrets(
         cors: conf.cors,
         unmasked_headers: conf.unmasked_headers,
         saved_payment_methods: conf.saved_payment_methods,
+        disputes: conf.disputes,
+        authorization_expiry: conf.authorization_expiry,
     }
 }
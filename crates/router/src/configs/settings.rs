@@ -93,6 +93,9 @@ pub struct Settings<S: SecretState> {
     pub cors: CorsSettings,
     pub mandates: Mandates,
     pub network_transaction_id_supported_connectors: NetworkTransactionIdSupportedConnectors,
+    pub network_tokenization: SecretStateContainer<NetworkTokenization, S>,
+    pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
+    pub card_account_updater: CardAccountUpdaterSettings,
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
@@ -121,6 +124,8 @@ pub struct Settings<S: SecretState> {
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
     pub saved_payment_methods: EligiblePaymentMethods,
+    pub disputes: DisputeSettings,
+    pub authorization_expiry: AuthorizationExpirySettings,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -144,6 +149,10 @@ pub struct KvConfig {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct PaymentLink {
     pub sdk_url: String,
+    /// Serve payment links only on the router's base URL or the custom domain registered on the
+    /// business profile of the payment link
+    #[serde(default)]
+    pub validate_host: bool,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -160,6 +169,27 @@ pub struct ForexApi {
     pub api_timeout: u64,
     /// in ms
     pub redis_lock_timeout: u64,
+    /// Providers the rates are fetched from, in the order they are tried. Defaults to Open
+    /// Exchange Rates followed by apilayer when not configured.
+    pub providers: Vec<ForexProviderKind>,
+    /// Path of the JSON file read by the `static_file` provider
+    pub static_rates_file: Option<PathBuf>,
+    /// Key of the config read by the `database` provider
+    pub database_rates_key: Option<String>,
+    /// in s, time for which an FX quote locks its rate
+    pub quote_ttl: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ForexProviderKind {
+    OpenExchangeRates,
+    ApiLayer,
+    /// Rates read from a JSON file, for offline environments
+    StaticFile,
+    /// Rates read from the configs table, for internal treasury rates
+    Database,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -268,6 +298,62 @@ pub struct NetworkTransactionIdSupportedConnectors {
     pub connector_list: HashSet<enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct NetworkTokenization {
+    // Provisions network tokens for the cards saved for future usage
+    pub enabled: bool,
+    // Service the network tokens are provisioned through
+    pub provider: NetworkTokenProviderKind,
+    // Card networks the network tokens are provisioned for
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub card_networks: HashSet<enums::CardNetwork>,
+    // Base URL of the token requestor service, used by the `token_requestor` provider
+    pub token_requestor_base_url: String,
+    // API key of the token requestor service, used by the `token_requestor` provider
+    pub token_requestor_api_key: Secret<String>,
+    // Secret the token lifecycle webhooks of the provider are signed with
+    pub webhook_secret: Secret<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NetworkTokenProviderKind {
+    Mock,
+    TokenRequestor,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenizationSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardAccountUpdaterSettings {
+    // Periodically checks the saved cards of merchants for reissued card numbers and expiry dates
+    pub enabled: bool,
+    // Service the card account updates are fetched from
+    pub provider: CardAccountUpdaterProviderKind,
+    // Path of the file the `mock` provider reads card account updates from
+    pub mock_updates_file: String,
+    // Number of payment methods checked in a single run of the workflow
+    pub batch_size: i64,
+    // Cards expiring within these many days are submitted for an update
+    pub expiry_window_in_days: u16,
+    // Time between two complete passes over the saved cards of a merchant
+    pub run_interval_in_hours: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardAccountUpdaterProviderKind {
+    Mock,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SupportedPaymentMethodsForMandate(
     pub HashMap<enums::PaymentMethod, SupportedPaymentMethodTypesForMandate>,
@@ -390,6 +476,9 @@ pub struct Secrets {
     pub admin_api_key: Secret<String>,
     pub recon_admin_api_key: Secret<String>,
     pub master_enc_key: Secret<String>,
+    /// The master encryption key replaced by a master key rotation. Key stores that have not been
+    /// re-wrapped with `master_enc_key` yet are decrypted with this key.
+    pub previous_master_enc_key: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -628,11 +717,37 @@ pub struct ApiKeys {
     /// hashes of API keys
     pub hash_key: Secret<String>,
 
+    /// Number of proxies in front of the application that append the address of their peer to
+    /// the `X-Forwarded-For` header. The client IP address checked against the IP allowlist of an
+    /// API key is the entry appended by the outermost trusted proxy, so this must be at least 1.
+    pub trusted_proxy_count: usize,
+
     // Specifies the number of days before API key expiry when email reminders should be sent
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DisputeSettings {
+    // Specifies the number of days before the evidence submission deadline of a dispute when
+    // reminders should be sent
+    pub deadline_reminder_days: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationExpirySettings {
+    // Number of days an authorization is held by the issuer when its card network has no
+    // configured validity
+    pub default_validity_in_days: u16,
+    // Number of days an authorization is held by the issuer, keyed by the lowercase card network
+    pub card_networks: HashMap<String, u16>,
+    // Number of days a connector holds an authorization for, when it releases authorizations
+    // before the card network does
+    pub connectors: HashMap<String, u16>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DelayedSessionConfig {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -689,6 +804,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("forex_api.providers")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
@@ -745,6 +861,7 @@ impl Settings<SecuredSecret> {
 
         self.lock_settings.validate()?;
         self.events.validate()?;
+        self.card_account_updater.validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
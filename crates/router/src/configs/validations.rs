@@ -22,7 +22,23 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "Master encryption key must not be empty".into(),
             ))
-        })
+        })?;
+
+        when(
+            self.previous_master_enc_key
+                .as_ref()
+                .is_some_and(|previous_master_enc_key| {
+                    previous_master_enc_key.is_default_or_empty()
+                        || previous_master_enc_key.peek() == self.master_enc_key.peek()
+                }),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Previous master encryption key must not be empty or the same as the master \
+                     encryption key"
+                        .into(),
+                ))
+            },
+        )
     }
 }
 
@@ -142,6 +158,14 @@ impl super::settings::ApiKeys {
             ))
         })?;
 
+        // The client IP address of requests made with IP-restricted API keys is read from the
+        // entry appended by the outermost trusted proxy, there is none when the count is zero
+        when(self.trusted_proxy_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "API key trusted proxy count must be at least 1".into(),
+            ))
+        })?;
+
         #[cfg(feature = "email")]
         when(self.expiry_reminder_days.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
@@ -180,3 +204,21 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::CardAccountUpdaterSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater batch_size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.enabled && self.run_interval_in_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card account updater run_interval_in_hours must not be 0".into(),
+            ))
+        })
+    }
+}
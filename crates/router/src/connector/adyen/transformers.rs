@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 #[cfg(feature = "payouts")]
 use api_models::payouts::PayoutMethodData;
 use api_models::{enums, payments, webhooks};
@@ -119,6 +121,80 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    #[serde(flatten)]
+    enhanced_scheme_data: Option<EnhancedSchemeData>,
+}
+
+/// Level 2 / level 3 card data, sent as flat `enhancedSchemeData.*` keys in the additional data
+/// https://docs.adyen.com/payment-methods/cards/enhanced-scheme-data/l2-l3
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct EnhancedSchemeData {
+    #[serde(rename = "enhancedSchemeData.customerReference")]
+    customer_reference: Option<String>,
+    #[serde(rename = "enhancedSchemeData.totalTaxAmount")]
+    total_tax_amount: Option<String>,
+    #[serde(rename = "enhancedSchemeData.freightAmount")]
+    freight_amount: Option<String>,
+    #[serde(rename = "enhancedSchemeData.dutyAmount")]
+    duty_amount: Option<String>,
+    #[serde(rename = "enhancedSchemeData.shipFromPostalCode")]
+    ship_from_postal_code: Option<String>,
+    /// Line items, keyed as `enhancedSchemeData.itemDetailLine<n>.<field>`
+    #[serde(flatten)]
+    item_detail_lines: BTreeMap<String, String>,
+}
+
+impl From<&payments::L2L3Data> for EnhancedSchemeData {
+    fn from(l2_l3_data: &payments::L2L3Data) -> Self {
+        let mut item_detail_lines = BTreeMap::new();
+        for (index, line_item) in l2_l3_data.line_items.iter().flatten().enumerate() {
+            let prefix = format!(
+                "enhancedSchemeData.itemDetailLine{}",
+                index.saturating_add(1)
+            );
+            let fields = [
+                ("description", Some(line_item.product_name.clone())),
+                ("productCode", line_item.product_code.clone()),
+                ("commodityCode", line_item.commodity_code.clone()),
+                ("quantity", Some(line_item.quantity.to_string())),
+                ("unitOfMeasure", line_item.unit_of_measure.clone()),
+                (
+                    "unitPrice",
+                    Some(line_item.unit_amount.get_amount_as_i64().to_string()),
+                ),
+                (
+                    "discountAmount",
+                    line_item
+                        .discount_amount
+                        .map(|amount| amount.get_amount_as_i64().to_string()),
+                ),
+                (
+                    "totalAmount",
+                    Some(line_item.get_total_amount().get_amount_as_i64().to_string()),
+                ),
+            ];
+            for (field, value) in fields {
+                if let Some(value) = value {
+                    item_detail_lines.insert(format!("{prefix}.{field}"), value);
+                }
+            }
+        }
+        Self {
+            customer_reference: l2_l3_data.customer_reference.clone(),
+            total_tax_amount: l2_l3_data
+                .tax_amount
+                .map(|amount| amount.get_amount_as_i64().to_string()),
+            freight_amount: l2_l3_data
+                .freight_amount
+                .map(|amount| amount.get_amount_as_i64().to_string()),
+            duty_amount: l2_l3_data
+                .duty_amount
+                .map(|amount| amount.get_amount_as_i64().to_string()),
+            ship_from_postal_code: l2_l3_data.ship_from_postal_code.clone(),
+            item_detail_lines,
+        }
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -1701,6 +1777,11 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
         recurring_detail_reference: None,
         recurring_shopper_reference: None,
         recurring_processing_model: None,
+        enhanced_scheme_data: item
+            .request
+            .l2_l3_data
+            .as_ref()
+            .map(EnhancedSchemeData::from),
         ..AdditionalData::default()
     })
 }
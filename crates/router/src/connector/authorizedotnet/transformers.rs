@@ -232,13 +232,128 @@ struct TransactionRequest {
     currency_code: String,
     payment: PaymentDetails,
     order: Order,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<LineItems>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<ExtendedAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    po_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer: Option<CustomerDetails>,
     bill_to: Option<BillTo>,
     processing_options: Option<ProcessingOptions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     subsequent_auth_information: Option<SubsequentAuthInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ship_from: Option<ShipFrom>,
     authorization_indicator_type: Option<AuthorizationIndicator>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItems {
+    line_item: Vec<LineItem>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    item_id: String,
+    name: String,
+    quantity: u16,
+    unit_price: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtendedAmount {
+    amount: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFrom {
+    zip: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerDetails {
+    tax_id: String,
+}
+
+/// Level 2 / level 3 fields of the transaction request, in the order the connector expects them
+struct L2L3Details {
+    line_items: Option<LineItems>,
+    tax: Option<ExtendedAmount>,
+    duty: Option<ExtendedAmount>,
+    shipping: Option<ExtendedAmount>,
+    po_number: Option<String>,
+    customer: Option<CustomerDetails>,
+    ship_from: Option<ShipFrom>,
+}
+
+impl TryFrom<(Option<&api_models::payments::L2L3Data>, enums::Currency)> for L2L3Details {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (l2_l3_data, currency): (Option<&api_models::payments::L2L3Data>, enums::Currency),
+    ) -> Result<Self, Self::Error> {
+        let to_extended_amount = |amount: Option<common_utils::types::MinorUnit>| {
+            amount
+                .map(|amount| {
+                    utils::to_currency_base_unit_asf64(amount.get_amount_as_i64(), currency)
+                        .map(|amount| ExtendedAmount { amount })
+                })
+                .transpose()
+        };
+        let line_items = l2_l3_data
+            .and_then(|data| data.line_items.as_ref())
+            .map(|line_items| {
+                line_items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, line_item)| {
+                        Ok(LineItem {
+                            // The connector limits both the item id and name to 31 characters
+                            item_id: line_item
+                                .product_code
+                                .clone()
+                                .unwrap_or_else(|| index.saturating_add(1).to_string())
+                                .chars()
+                                .take(31)
+                                .collect(),
+                            name: line_item.product_name.chars().take(31).collect(),
+                            quantity: line_item.quantity,
+                            unit_price: utils::to_currency_base_unit_asf64(
+                                line_item.unit_amount.get_amount_as_i64(),
+                                currency,
+                            )?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Self::Error>>()
+                    .map(|line_item| LineItems { line_item })
+            })
+            .transpose()?;
+        Ok(Self {
+            line_items,
+            tax: to_extended_amount(l2_l3_data.and_then(|data| data.tax_amount))?,
+            duty: to_extended_amount(l2_l3_data.and_then(|data| data.duty_amount))?,
+            shipping: to_extended_amount(l2_l3_data.and_then(|data| data.freight_amount))?,
+            po_number: l2_l3_data.and_then(|data| data.customer_reference.clone()),
+            customer: l2_l3_data
+                .and_then(|data| data.customer_tax_id.clone())
+                .map(|tax_id| CustomerDetails { tax_id }),
+            ship_from: l2_l3_data
+                .and_then(|data| data.ship_from_postal_code.clone())
+                .map(|zip| ShipFrom { zip }),
+        })
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessingOptions {
@@ -374,6 +489,10 @@ impl TryFrom<&AuthorizedotnetRouterData<&types::PaymentsAuthorizeRouterData>>
                 zip: address.zip.clone(),
                 country: address.country,
             });
+        let l2_l3_details = L2L3Details::try_from((
+            item.router_data.request.l2_l3_data.as_ref(),
+            item.router_data.request.currency,
+        ))?;
         let transaction_request = TransactionRequest {
             transaction_type: TransactionType::try_from(item.router_data.request.capture_method)?,
             amount: item.amount,
@@ -382,9 +501,16 @@ impl TryFrom<&AuthorizedotnetRouterData<&types::PaymentsAuthorizeRouterData>>
             order: Order {
                 description: item.router_data.connector_request_reference_id.clone(),
             },
+            line_items: l2_l3_details.line_items,
+            tax: l2_l3_details.tax,
+            duty: l2_l3_details.duty,
+            shipping: l2_l3_details.shipping,
+            po_number: l2_l3_details.po_number,
+            customer: l2_l3_details.customer,
             bill_to,
             processing_options,
             subsequent_auth_information,
+            ship_from: l2_l3_details.ship_from,
             authorization_indicator_type,
         };
 
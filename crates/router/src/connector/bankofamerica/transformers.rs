@@ -1,6 +1,6 @@
 use api_models::payments;
 use base64::Engine;
-use common_utils::{ext_traits::ValueExt, pii};
+use common_utils::{ext_traits::ValueExt, pii, types::MinorUnit};
 use error_stack::ResultExt;
 use masking::{ExposeInterface, PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
@@ -82,6 +82,10 @@ pub struct BankOfAmericaPaymentsRequest {
     consumer_authentication_information: Option<BankOfAmericaConsumerAuthInformation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     merchant_defined_information: Option<Vec<MerchantDefinedInformation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buyer_information: Option<BuyerInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merchant_information: Option<MerchantInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -247,6 +251,8 @@ pub struct FluidData {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(flatten)]
+    l2_l3_information: Option<L2L3OrderInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -254,6 +260,168 @@ pub struct OrderInformationWithBill {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    l2_l3_amounts: Option<L2L3Amounts>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2L3Amounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2L3OrderInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_details: Option<ShippingDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    purchase_order_number: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingDetails {
+    ship_from_postal_code: String,
+}
+
+fn to_optional_base_unit(
+    amount: Option<MinorUnit>,
+    currency: enums::Currency,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| utils::to_currency_base_unit(amount.get_amount_as_i64(), currency))
+        .transpose()
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuyerInformation {
+    vat_registration_number: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantInformation {
+    vat_registration_number: String,
+}
+
+fn get_buyer_information(l2_l3_data: Option<&payments::L2L3Data>) -> Option<BuyerInformation> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.customer_tax_id.clone())
+        .map(|vat_registration_number| BuyerInformation {
+            vat_registration_number,
+        })
+}
+
+fn get_merchant_information(
+    l2_l3_data: Option<&payments::L2L3Data>,
+) -> Option<MerchantInformation> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.merchant_tax_id.clone())
+        .map(|vat_registration_number| MerchantInformation {
+            vat_registration_number,
+        })
+}
+
+// Level 2 / Level 3 data lets commercial card payments qualify for lower interchange rates
+fn get_l2_l3_data(
+    l2_l3_data: Option<&payments::L2L3Data>,
+    currency: enums::Currency,
+) -> Result<
+    (Option<L2L3Amounts>, Option<L2L3OrderInformation>),
+    error_stack::Report<errors::ConnectorError>,
+> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok((None, None));
+    };
+    let l2_l3_amounts = L2L3Amounts {
+        tax_amount: to_optional_base_unit(l2_l3_data.tax_amount, currency)?,
+        discount_amount: to_optional_base_unit(l2_l3_data.discount_amount, currency)?,
+        duty_amount: to_optional_base_unit(l2_l3_data.duty_amount, currency)?,
+        freight_amount: to_optional_base_unit(l2_l3_data.freight_amount, currency)?,
+    };
+    let line_items = l2_l3_data
+        .line_items
+        .as_ref()
+        .map(|line_items| {
+            line_items
+                .iter()
+                .map(|line_item| {
+                    Ok(LineItem {
+                        product_name: line_item.product_name.clone(),
+                        product_code: line_item.product_code.clone(),
+                        commodity_code: line_item.commodity_code.clone(),
+                        quantity: line_item.quantity,
+                        unit_of_measure: line_item.unit_of_measure.clone(),
+                        unit_price: utils::to_currency_base_unit(
+                            line_item.unit_amount.get_amount_as_i64(),
+                            currency,
+                        )?,
+                        discount_amount: to_optional_base_unit(
+                            line_item.discount_amount,
+                            currency,
+                        )?,
+                        tax_amount: to_optional_base_unit(line_item.tax_amount, currency)?,
+                        total_amount: utils::to_currency_base_unit(
+                            line_item.get_total_amount().get_amount_as_i64(),
+                            currency,
+                        )?,
+                    })
+                })
+                .collect::<Result<Vec<_>, error_stack::Report<errors::ConnectorError>>>()
+        })
+        .transpose()?;
+    let l2_l3_information = L2L3OrderInformation {
+        line_items,
+        invoice_details: l2_l3_data
+            .customer_reference
+            .clone()
+            .map(|customer_reference| InvoiceDetails {
+                purchase_order_number: customer_reference,
+            }),
+        shipping_details: l2_l3_data
+            .ship_from_postal_code
+            .clone()
+            .map(|ship_from_postal_code| ShippingDetails {
+                ship_from_postal_code,
+            }),
+    };
+    Ok((Some(l2_l3_amounts), Some(l2_l3_information)))
 }
 
 #[derive(Debug, Serialize)]
@@ -500,24 +668,31 @@ pub enum TransactionType {
 }
 
 impl
-    From<(
+    TryFrom<(
         &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
         Option<BillTo>,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>,
             Option<BillTo>,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let (l2_l3_amounts, l2_l3_information) = get_l2_l3_data(
+            item.router_data.request.l2_l3_data.as_ref(),
+            item.router_data.request.currency,
+        )?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                l2_l3_amounts,
             },
             bill_to,
-        }
+            l2_l3_information,
+        })
     }
 }
 
@@ -537,8 +712,10 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                l2_l3_amounts: None,
             },
             bill_to: Some(bill_to),
+            l2_l3_information: None,
         }
     }
 }
@@ -893,6 +1070,8 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            buyer_information: None,
+            merchant_information: None,
         })
     }
 }
@@ -912,7 +1091,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::try_from(&ccard)?;
         let processing_information = ProcessingInformation::try_from((item, None, None))?;
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -927,6 +1106,10 @@ impl
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
             consumer_authentication_information: None,
         })
     }
@@ -949,7 +1132,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -976,6 +1159,10 @@ impl
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
             consumer_authentication_information: Some(BankOfAmericaConsumerAuthInformation {
                 ucaf_collection_indicator,
                 cavv: None,
@@ -1003,7 +1190,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, Some(bill_to)));
+        let order_information = OrderInformationWithBill::try_from((item, Some(bill_to)))?;
         let payment_information = PaymentInformation::from(&google_pay_data);
         let processing_information =
             ProcessingInformation::try_from((item, Some(PaymentSolution::GooglePay), None))?;
@@ -1019,6 +1206,10 @@ impl
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
             consumer_authentication_information: None,
         })
     }
@@ -1056,7 +1247,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                                     let bill_to =
                                         build_bill_to(item.router_data.get_billing()?, email)?;
                                     let order_information: OrderInformationWithBill =
-                                        OrderInformationWithBill::from((item, Some(bill_to)));
+                                        OrderInformationWithBill::try_from((item, Some(bill_to)))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1087,6 +1278,12 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsAuthorizeRouterData>>
                                         payment_information,
                                         order_information,
                                         merchant_defined_information,
+                                        buyer_information: get_buyer_information(
+                                            item.router_data.request.l2_l3_data.as_ref(),
+                                        ),
+                                        merchant_information: get_merchant_information(
+                                            item.router_data.request.l2_l3_data.as_ref(),
+                                        ),
                                         client_reference_information,
                                         consumer_authentication_information: Some(
                                             BankOfAmericaConsumerAuthInformation {
@@ -1243,7 +1440,7 @@ impl
                 .ok()
                 .and_then(|billing_details| build_bill_to(billing_details, email_id).ok())
         });
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(MandatePaymentInformation { payment_instrument });
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -1257,6 +1454,10 @@ impl
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
             consumer_authentication_information: None,
         })
     }
@@ -1818,6 +2019,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            l2_l3_amounts: None,
         };
 
         match redirect_response.params {
@@ -1836,6 +2038,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    l2_l3_information: None,
                 };
                 Ok(Self::AuthEnrollment(BankOfAmericaAuthEnrollmentRequest {
                     payment_information,
@@ -2481,6 +2684,8 @@ impl<F>
 #[serde(rename_all = "camelCase")]
 pub struct OrderInformation {
     amount_details: Amount,
+    #[serde(flatten)]
+    l2_l3_information: Option<L2L3OrderInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -2490,6 +2695,10 @@ pub struct BankOfAmericaCaptureRequest {
     client_reference_information: ClientReferenceInformation,
     #[serde(skip_serializing_if = "Option::is_none")]
     merchant_defined_information: Option<Vec<MerchantDefinedInformation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buyer_information: Option<BuyerInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merchant_information: Option<MerchantInformation>,
 }
 
 impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCaptureRouterData>>
@@ -2503,17 +2712,27 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCaptureRouterData>>
             value.router_data.request.metadata.clone().map(|metadata| {
                 Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
             });
+        let (l2_l3_amounts, l2_l3_information) = get_l2_l3_data(
+            value.router_data.request.l2_l3_data.as_ref(),
+            value.router_data.request.currency,
+        )?;
         Ok(Self {
             order_information: OrderInformation {
                 amount_details: Amount {
                     total_amount: value.amount.to_owned(),
                     currency: value.router_data.request.currency,
+                    l2_l3_amounts,
                 },
+                l2_l3_information,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(value.router_data.connector_request_reference_id.clone()),
             },
             merchant_defined_information,
+            buyer_information: get_buyer_information(value.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                value.router_data.request.l2_l3_data.as_ref(),
+            ),
         })
     }
 }
@@ -2558,6 +2777,7 @@ impl TryFrom<&BankOfAmericaRouterData<&types::PaymentsCancelRouterData>>
                             field_name: "Currency",
                         },
                     )?,
+                    l2_l3_amounts: None,
                 },
                 reason: value
                     .router_data
@@ -2592,7 +2812,9 @@ impl<F> TryFrom<&BankOfAmericaRouterData<&types::RefundsRouterData<F>>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    l2_l3_amounts: None,
                 },
+                l2_l3_information: None,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.request.refund_id.clone()),
@@ -2869,6 +3091,8 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::Card)> for BankOfAmericaPa
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            buyer_information: None,
+            merchant_information: None,
         })
     }
 }
@@ -2926,6 +3150,8 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::ApplePayWalletData)>
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: None,
+            merchant_information: None,
             consumer_authentication_information,
         })
     }
@@ -2953,6 +3179,8 @@ impl TryFrom<(&types::SetupMandateRouterData, domain::GooglePayWalletData)>
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: None,
+            merchant_information: None,
             consumer_authentication_information: None,
         })
     }
@@ -2991,8 +3219,10 @@ impl TryFrom<&types::SetupMandateRouterData> for OrderInformationWithBill {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                l2_l3_amounts: None,
             },
             bill_to: Some(bill_to),
+            l2_l3_information: None,
         })
     }
 }
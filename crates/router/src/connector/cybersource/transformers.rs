@@ -6,7 +6,7 @@ use api_models::{
 };
 use base64::Engine;
 use common_enums::FutureUsage;
-use common_utils::{ext_traits::ValueExt, pii};
+use common_utils::{ext_traits::ValueExt, pii, types::MinorUnit};
 use error_stack::ResultExt;
 use masking::{ExposeInterface, PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
@@ -71,8 +71,10 @@ impl TryFrom<&types::SetupMandateRouterData> for CybersourceZeroMandateRequest {
             amount_details: Amount {
                 total_amount: "0".to_string(),
                 currency: item.request.currency,
+                l2_l3_amounts: None,
             },
             bill_to: Some(bill_to),
+            l2_l3_information: None,
         };
         let (action_list, action_token_types, authorization_options) = (
             Some(vec![CybersourceActionsList::TokenCreate]),
@@ -238,6 +240,10 @@ pub struct CybersourcePaymentsRequest {
     consumer_authentication_information: Option<CybersourceConsumerAuthInformation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     merchant_defined_information: Option<Vec<MerchantDefinedInformation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buyer_information: Option<BuyerInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merchant_information: Option<MerchantInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -336,6 +342,19 @@ pub struct TokenizedCard {
     transaction_type: TransactionType,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenizedCard {
+    number: cards::CardNumber,
+    expiration_month: Secret<String>,
+    expiration_year: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cryptogram: Option<Secret<String>>,
+    transaction_type: TransactionType,
+    #[serde(rename = "type")]
+    card_type: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplePayTokenizedCard {
@@ -355,6 +374,12 @@ pub struct ApplePayPaymentInformation {
     tokenized_card: TokenizedCard,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenPaymentInformation {
+    tokenized_card: NetworkTokenizedCard,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MandatePaymentInformation {
@@ -385,6 +410,7 @@ pub enum PaymentInformation {
     ApplePay(ApplePayPaymentInformation),
     ApplePayToken(ApplePayTokenPaymentInformation),
     MandatePayment(MandatePaymentInformation),
+    NetworkToken(NetworkTokenPaymentInformation),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -406,6 +432,8 @@ pub struct Card {
 pub struct OrderInformationWithBill {
     amount_details: Amount,
     bill_to: Option<BillTo>,
+    #[serde(flatten)]
+    l2_l3_information: Option<L2L3OrderInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -425,6 +453,168 @@ pub struct OrderInformation {
 pub struct Amount {
     total_amount: String,
     currency: api_models::enums::Currency,
+    #[serde(flatten)]
+    l2_l3_amounts: Option<L2L3Amounts>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2L3Amounts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duty_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freight_amount: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2L3OrderInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line_items: Option<Vec<LineItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invoice_details: Option<InvoiceDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping_details: Option<ShippingDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineItem {
+    product_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commodity_code: Option<String>,
+    quantity: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_of_measure: Option<String>,
+    unit_price: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_amount: Option<String>,
+    total_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceDetails {
+    purchase_order_number: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShippingDetails {
+    ship_from_postal_code: String,
+}
+
+fn to_optional_base_unit(
+    amount: Option<MinorUnit>,
+    currency: enums::Currency,
+) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+    amount
+        .map(|amount| utils::to_currency_base_unit(amount.get_amount_as_i64(), currency))
+        .transpose()
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuyerInformation {
+    vat_registration_number: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantInformation {
+    vat_registration_number: String,
+}
+
+fn get_buyer_information(l2_l3_data: Option<&payments::L2L3Data>) -> Option<BuyerInformation> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.customer_tax_id.clone())
+        .map(|vat_registration_number| BuyerInformation {
+            vat_registration_number,
+        })
+}
+
+fn get_merchant_information(
+    l2_l3_data: Option<&payments::L2L3Data>,
+) -> Option<MerchantInformation> {
+    l2_l3_data
+        .and_then(|l2_l3_data| l2_l3_data.merchant_tax_id.clone())
+        .map(|vat_registration_number| MerchantInformation {
+            vat_registration_number,
+        })
+}
+
+// Level 2 / Level 3 data lets commercial card payments qualify for lower interchange rates
+fn get_l2_l3_data(
+    l2_l3_data: Option<&payments::L2L3Data>,
+    currency: enums::Currency,
+) -> Result<
+    (Option<L2L3Amounts>, Option<L2L3OrderInformation>),
+    error_stack::Report<errors::ConnectorError>,
+> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok((None, None));
+    };
+    let l2_l3_amounts = L2L3Amounts {
+        tax_amount: to_optional_base_unit(l2_l3_data.tax_amount, currency)?,
+        discount_amount: to_optional_base_unit(l2_l3_data.discount_amount, currency)?,
+        duty_amount: to_optional_base_unit(l2_l3_data.duty_amount, currency)?,
+        freight_amount: to_optional_base_unit(l2_l3_data.freight_amount, currency)?,
+    };
+    let line_items = l2_l3_data
+        .line_items
+        .as_ref()
+        .map(|line_items| {
+            line_items
+                .iter()
+                .map(|line_item| {
+                    Ok(LineItem {
+                        product_name: line_item.product_name.clone(),
+                        product_code: line_item.product_code.clone(),
+                        commodity_code: line_item.commodity_code.clone(),
+                        quantity: line_item.quantity,
+                        unit_of_measure: line_item.unit_of_measure.clone(),
+                        unit_price: utils::to_currency_base_unit(
+                            line_item.unit_amount.get_amount_as_i64(),
+                            currency,
+                        )?,
+                        discount_amount: to_optional_base_unit(
+                            line_item.discount_amount,
+                            currency,
+                        )?,
+                        tax_amount: to_optional_base_unit(line_item.tax_amount, currency)?,
+                        total_amount: utils::to_currency_base_unit(
+                            line_item.get_total_amount().get_amount_as_i64(),
+                            currency,
+                        )?,
+                    })
+                })
+                .collect::<Result<Vec<_>, error_stack::Report<errors::ConnectorError>>>()
+        })
+        .transpose()?;
+    let l2_l3_information = L2L3OrderInformation {
+        line_items,
+        invoice_details: l2_l3_data
+            .customer_reference
+            .clone()
+            .map(|customer_reference| InvoiceDetails {
+                purchase_order_number: customer_reference,
+            }),
+        shipping_details: l2_l3_data
+            .ship_from_postal_code
+            .clone()
+            .map(|ship_from_postal_code| ShippingDetails {
+                ship_from_postal_code,
+            }),
+    };
+    Ok((Some(l2_l3_amounts), Some(l2_l3_information)))
 }
 
 #[derive(Debug, Serialize)]
@@ -444,6 +634,8 @@ pub enum PaymentSolution {
 pub enum TransactionType {
     #[serde(rename = "1")]
     ApplePay,
+    #[serde(rename = "3")]
+    NetworkToken,
 }
 
 impl From<PaymentSolution> for String {
@@ -727,24 +919,31 @@ impl
 }
 
 impl
-    From<(
+    TryFrom<(
         &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
         BillTo,
     )> for OrderInformationWithBill
 {
-    fn from(
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
         (item, bill_to): (
             &CybersourceRouterData<&types::PaymentsAuthorizeRouterData>,
             BillTo,
         ),
-    ) -> Self {
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let (l2_l3_amounts, l2_l3_information) = get_l2_l3_data(
+            item.router_data.request.l2_l3_data.as_ref(),
+            item.router_data.request.currency,
+        )?;
+        Ok(Self {
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                l2_l3_amounts,
             },
             bill_to: Some(bill_to),
-        }
+            l2_l3_information,
+        })
     }
 }
 
@@ -764,8 +963,10 @@ impl
             amount_details: Amount {
                 total_amount: item.amount.to_owned(),
                 currency: item.router_data.request.currency,
+                l2_l3_amounts: None,
             },
             bill_to: Some(bill_to),
+            l2_l3_information: None,
         }
     }
 }
@@ -827,7 +1028,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
         let card_issuer = ccard.get_card_issuer();
         let card_type = match card_issuer {
@@ -835,15 +1036,30 @@ impl
             Err(_) => None,
         };
 
-        let payment_information = PaymentInformation::Cards(CardPaymentInformation {
-            card: Card {
-                number: ccard.card_number,
-                expiration_month: ccard.card_exp_month,
-                expiration_year: ccard.card_exp_year,
-                security_code: Some(ccard.card_cvc),
-                card_type: card_type.clone(),
-            },
-        });
+        // Network tokens are sent as tokenized cards, they do not carry a security code
+        let payment_information = match &item.router_data.request.network_token_data {
+            Some(network_token_data) => {
+                PaymentInformation::NetworkToken(NetworkTokenPaymentInformation {
+                    tokenized_card: NetworkTokenizedCard {
+                        number: ccard.card_number,
+                        expiration_month: ccard.card_exp_month,
+                        expiration_year: ccard.card_exp_year,
+                        cryptogram: network_token_data.cryptogram.clone(),
+                        transaction_type: TransactionType::NetworkToken,
+                        card_type: card_type.clone(),
+                    },
+                })
+            }
+            None => PaymentInformation::Cards(CardPaymentInformation {
+                card: Card {
+                    number: ccard.card_number,
+                    expiration_month: ccard.card_exp_month,
+                    expiration_year: ccard.card_exp_year,
+                    security_code: Some(ccard.card_cvc),
+                    card_type: card_type.clone(),
+                },
+            }),
+        };
 
         let processing_information = ProcessingInformation::try_from((item, None, card_type))?;
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -859,6 +1075,10 @@ impl
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
         })
     }
 }
@@ -936,6 +1156,8 @@ impl
             client_reference_information,
             consumer_authentication_information,
             merchant_defined_information,
+            buyer_information: None,
+            merchant_information: None,
         })
     }
 }
@@ -957,7 +1179,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let processing_information = ProcessingInformation::try_from((
             item,
             Some(PaymentSolution::ApplePay),
@@ -1002,6 +1224,10 @@ impl
                 specification_version: None,
             }),
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
         })
     }
 }
@@ -1021,7 +1247,7 @@ impl
     ) -> Result<Self, Self::Error> {
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
 
         let payment_information = PaymentInformation::GooglePay(GooglePayPaymentInformation {
             fluid_data: FluidData {
@@ -1046,6 +1272,10 @@ impl
             client_reference_information,
             consumer_authentication_information: None,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
         })
     }
 }
@@ -1082,7 +1312,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                     let bill_to =
                                         build_bill_to(item.router_data.get_billing()?, email)?;
                                     let order_information =
-                                        OrderInformationWithBill::from((item, bill_to));
+                                        OrderInformationWithBill::try_from((item, bill_to))?;
                                     let processing_information =
                                         ProcessingInformation::try_from((
                                             item,
@@ -1123,6 +1353,12 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsAuthorizeRouterData>>
                                         order_information,
                                         client_reference_information,
                                         merchant_defined_information,
+                                        buyer_information: get_buyer_information(
+                                            item.router_data.request.l2_l3_data.as_ref(),
+                                        ),
+                                        merchant_information: get_merchant_information(
+                                            item.router_data.request.l2_l3_data.as_ref(),
+                                        ),
                                         consumer_authentication_information: Some(
                                             CybersourceConsumerAuthInformation {
                                                 ucaf_collection_indicator,
@@ -1224,7 +1460,7 @@ impl
         };
         let email = item.router_data.request.get_email()?;
         let bill_to = build_bill_to(item.router_data.get_billing()?, email)?;
-        let order_information = OrderInformationWithBill::from((item, bill_to));
+        let order_information = OrderInformationWithBill::try_from((item, bill_to))?;
         let payment_information =
             PaymentInformation::MandatePayment(MandatePaymentInformation { payment_instrument });
         let client_reference_information = ClientReferenceInformation::from(item);
@@ -1238,6 +1474,10 @@ impl
             order_information,
             client_reference_information,
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
             consumer_authentication_information: None,
         })
     }
@@ -1309,6 +1549,10 @@ pub struct CybersourcePaymentsCaptureRequest {
     client_reference_information: ClientReferenceInformation,
     #[serde(skip_serializing_if = "Option::is_none")]
     merchant_defined_information: Option<Vec<MerchantDefinedInformation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buyer_information: Option<BuyerInformation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merchant_information: Option<MerchantInformation>,
 }
 
 #[derive(Debug, Serialize)]
@@ -1329,6 +1573,10 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCaptureRouterData>>
             item.router_data.request.metadata.clone().map(|metadata| {
                 Vec::<MerchantDefinedInformation>::foreign_from(metadata.peek().to_owned())
             });
+        let (l2_l3_amounts, l2_l3_information) = get_l2_l3_data(
+            item.router_data.request.l2_l3_data.as_ref(),
+            item.router_data.request.currency,
+        )?;
         Ok(Self {
             processing_information: ProcessingInformation {
                 capture_options: Some(CaptureOptions {
@@ -1346,13 +1594,19 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCaptureRouterData>>
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    l2_l3_amounts,
                 },
                 bill_to: None,
+                l2_l3_information,
             },
             client_reference_information: ClientReferenceInformation {
                 code: Some(item.router_data.connector_request_reference_id.clone()),
             },
             merchant_defined_information,
+            buyer_information: get_buyer_information(item.router_data.request.l2_l3_data.as_ref()),
+            merchant_information: get_merchant_information(
+                item.router_data.request.l2_l3_data.as_ref(),
+            ),
         })
     }
 }
@@ -1433,6 +1687,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsCancelRouterData>> for Cyber
                             field_name: "Currency",
                         },
                     )?,
+                    l2_l3_amounts: None,
                 },
                 reason: value
                     .router_data
@@ -2013,6 +2268,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                     field_name: "currency",
                 },
             )?,
+            l2_l3_amounts: None,
         };
 
         match redirect_response.params {
@@ -2031,6 +2287,7 @@ impl TryFrom<&CybersourceRouterData<&types::PaymentsPreProcessingRouterData>>
                 let order_information = OrderInformationWithBill {
                     amount_details,
                     bill_to: Some(bill_to),
+                    l2_l3_information: None,
                 };
                 Ok(Self::AuthEnrollment(CybersourceAuthEnrollmentRequest {
                     payment_information,
@@ -2686,6 +2943,7 @@ impl<F> TryFrom<&CybersourceRouterData<&types::RefundsRouterData<F>>> for Cybers
                 amount_details: Amount {
                     total_amount: item.amount.clone(),
                     currency: item.router_data.request.currency,
+                    l2_l3_amounts: None,
                 },
             },
             client_reference_information: ClientReferenceInformation {
@@ -2913,6 +3171,7 @@ impl TryFrom<&CybersourceRouterData<&types::PayoutsRouterData<api::PoFulfill>>>
                     amount_details: Amount {
                         total_amount: item.amount.to_owned(),
                         currency: item.router_data.request.destination_currency,
+                        l2_l3_amounts: None,
                     },
                 };
 
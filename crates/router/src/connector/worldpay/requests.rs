@@ -106,6 +106,48 @@ pub struct Instruction {
     pub value: PaymentValue,
     pub narrative: InstructionNarrative,
     pub payment_instrument: PaymentInstrument,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level23_data: Option<Level23Data>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Level23Data {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_acceptor_tax_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sales_tax: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duty_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_from_postal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<Level23Item>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Level23Item {
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_code: Option<String>,
+    pub quantity: u16,
+    pub unit_cost: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_of_measure: Option<String>,
+    pub item_total: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_discount_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_amount: Option<i64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
@@ -12,6 +12,48 @@ use crate::{
     types::{self, domain, PaymentsAuthorizeData, PaymentsResponseData},
 };
 
+impl From<&api_models::payments::L2L3Data> for Level23Data {
+    fn from(l2_l3_data: &api_models::payments::L2L3Data) -> Self {
+        Self {
+            customer_reference: l2_l3_data.customer_reference.clone(),
+            card_acceptor_tax_id: l2_l3_data.merchant_tax_id.clone(),
+            sales_tax: l2_l3_data
+                .tax_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            discount_amount: l2_l3_data
+                .discount_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            shipping_amount: l2_l3_data
+                .freight_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            duty_amount: l2_l3_data
+                .duty_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            ship_from_postal_code: l2_l3_data.ship_from_postal_code.clone(),
+            items: l2_l3_data.line_items.as_ref().map(|line_items| {
+                line_items
+                    .iter()
+                    .map(|line_item| Level23Item {
+                        description: line_item.product_name.clone(),
+                        product_code: line_item.product_code.clone(),
+                        commodity_code: line_item.commodity_code.clone(),
+                        quantity: line_item.quantity,
+                        unit_cost: line_item.unit_amount.get_amount_as_i64(),
+                        unit_of_measure: line_item.unit_of_measure.clone(),
+                        item_total: line_item.get_total_amount().get_amount_as_i64(),
+                        item_discount_amount: line_item
+                            .discount_amount
+                            .map(|amount| amount.get_amount_as_i64()),
+                        tax_amount: line_item
+                            .tax_amount
+                            .map(|amount| amount.get_amount_as_i64()),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct WorldpayRouterData<T> {
     amount: i64,
@@ -147,6 +189,12 @@ impl
                     item.router_data.request.payment_method_data.clone(),
                 )?,
                 debt_repayment: None,
+                level23_data: item
+                    .router_data
+                    .request
+                    .l2_l3_data
+                    .as_ref()
+                    .map(Level23Data::from),
             },
             merchant: Merchant {
                 entity: item
@@ -30,6 +30,15 @@ pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
+/// Maximum number of velocity rules a merchant can configure
+pub const MAX_VELOCITY_RULES: usize = 20;
+
+/// Maximum length of the name of a velocity rule
+pub const MAX_VELOCITY_RULE_NAME_LENGTH: usize = 32;
+
+/// Name of the native rules based fraud engine, used in place of an FRM connector name
+pub const NATIVE_FRM_ENGINE_NAME: &str = "hyperswitch_rules";
+
 // String literals
 pub(crate) const NO_ERROR_MESSAGE: &str = "No error message";
 pub(crate) const NO_ERROR_CODE: &str = "No error code";
@@ -48,6 +57,8 @@ pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::STANDARD;
 pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
+pub(crate) const BASE64_ENGINE_URL_SAFE_NO_PAD: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::URL_SAFE_NO_PAD;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
@@ -124,3 +135,8 @@ pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
 //max_amount allowed is 999999999 in minor units
 pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
+
+/// Default time (in seconds) for which an FX quote locks its rate
+pub const DEFAULT_FX_QUOTE_TTL_IN_SECS: i64 = 900;
+/// Number of decimal places the rate of an FX quote is rounded to
+pub const FX_QUOTE_RATE_DECIMAL_PLACES: u32 = 6;
@@ -12,3 +12,12 @@ pub const TOTP_VALIDITY_DURATION_IN_SECONDS: u64 = 30;
 pub const TOTP_TOLERANCE: u8 = 1;
 pub const MAX_PASSWORD_LENGTH: usize = 70;
 pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const OIDC_SSO_STATE_PREFIX: &str = "OIDC_SSO_STATE_";
+/// Duration in seconds within which the user has to complete authentication with the identity provider.
+pub const OIDC_SSO_STATE_EXPIRY_IN_SECS: i64 = 600;
+/// Cookie binding an in-progress authorization to the browser which started it.
+pub const OIDC_SSO_STATE_COOKIE_NAME: &str = "oidc_sso_state";
+/// Length of the state, nonce and PKCE code verifier.
+pub const OIDC_RANDOM_TOKEN_LENGTH: usize = 64;
+pub const OIDC_DEFAULT_SCOPES: [&str; 3] = ["openid", "email", "profile"];
+pub const OIDC_DEFAULT_GROUPS_CLAIM: &str = "groups";
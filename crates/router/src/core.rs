@@ -1,4 +1,6 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics_alerts;
 pub mod api_keys;
 pub mod api_locking;
 pub mod authentication;
@@ -6,6 +8,7 @@ pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
+pub mod config_audit;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -21,6 +24,8 @@ pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
 pub mod mandate;
+pub mod master_key_rotation;
+pub mod merchant_key_rotation;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
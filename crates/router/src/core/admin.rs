@@ -20,13 +20,14 @@ use uuid::Uuid;
 use crate::{
     consts,
     core::{
+        config_audit,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::helpers,
         routing::helpers as routing_helpers,
         utils as core_utils,
     },
     db::StorageInterface,
-    routes::{metrics, AppState},
+    routes::{app::ReqState, metrics, AppState},
     services::{self, api as service_api},
     types::{
         self, api,
@@ -52,6 +53,7 @@ pub fn create_merchant_publishable_key() -> String {
 pub async fn create_merchant_account(
     state: AppState,
     req: api::MerchantAccountCreate,
+    req_state: ReqState,
 ) -> RouterResponse<api::MerchantAccountResponse> {
     let db = state.store.as_ref();
     let master_key = db.get_master_key();
@@ -113,6 +115,9 @@ pub async fn create_merchant_account(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decrypt data from key store")?,
         created_at: date_time::now(),
+        key_version: 1,
+        previous_key: None,
+        previous_key_version: None,
     };
 
     let enable_payment_response_hash = req.enable_payment_response_hash.unwrap_or(true);
@@ -278,11 +283,25 @@ pub async fn create_merchant_account(
     })
     .ok();
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(merchant_account)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response = api::MerchantAccountResponse::try_from(merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&response.merchant_id),
+            resource_type: storage::enums::AuditResourceType::MerchantAccount,
+            resource_id: &response.merchant_id,
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 #[cfg(feature = "olap")]
@@ -402,6 +421,7 @@ pub async fn update_business_profile_cascade(
     state: AppState,
     merchant_account_update: api::MerchantAccountUpdate,
     merchant_id: String,
+    req_state: ReqState,
 ) -> RouterResult<()> {
     if merchant_account_update.return_url.is_some()
         || merchant_account_update.webhook_details.is_some()
@@ -442,6 +462,8 @@ pub async fn update_business_profile_cascade(
             extended_card_info_config: None,
             use_billing_as_payment_method_billing: None,
             collect_shipping_details_from_wallet_connector: None,
+            authorization_expiry_policy: None,
+            webhook_retry_policy: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -452,6 +474,7 @@ pub async fn update_business_profile_cascade(
                 profile_id,
                 &merchant_id,
                 business_profile_update.clone(),
+                req_state.clone(),
             )
             .await
         });
@@ -466,6 +489,7 @@ pub async fn merchant_account_update(
     state: AppState,
     merchant_id: &String,
     req: api::MerchantAccountUpdate,
+    req_state: ReqState,
 ) -> RouterResponse<api::MerchantAccountResponse> {
     let db = state.store.as_ref();
     let key_store = db
@@ -541,8 +565,24 @@ pub async fn merchant_account_update(
         None
     };
 
+    let merchant_account_before = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .and_then(|merchant_account| {
+            api::MerchantAccountResponse::try_from(merchant_account)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while generating response")
+        })?;
+
     // Update the business profile, This is for backwards compatibility
-    update_business_profile_cascade(state.clone(), req.clone(), merchant_id.to_string()).await?;
+    update_business_profile_cascade(
+        state.clone(),
+        req.clone(),
+        merchant_id.to_string(),
+        req_state.clone(),
+    )
+    .await?;
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
         merchant_name: req
@@ -609,19 +649,48 @@ pub async fn merchant_account_update(
 
     // If there are any new business labels generated, create business profile
 
-    Ok(service_api::ApplicationResponse::Json(
-        api::MerchantAccountResponse::try_from(response)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response = api::MerchantAccountResponse::try_from(response)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(merchant_id),
+            resource_type: storage::enums::AuditResourceType::MerchantAccount,
+            resource_id: merchant_id,
+            action: storage::enums::AuditAction::Update,
+            before: Some(&merchant_account_before),
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
     state: AppState,
     merchant_id: String,
+    req_state: ReqState,
 ) -> RouterResponse<api::MerchantAccountDeleteResponse> {
     let mut is_deleted = false;
     let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account_before = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .and_then(|merchant_account| {
+            api::MerchantAccountResponse::try_from(merchant_account)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while generating response")
+        })?;
+
     let is_merchant_account_deleted = db
         .delete_merchant_account_by_merchant_id(&merchant_id)
         .await
@@ -652,6 +721,22 @@ pub async fn merchant_account_delete(
     }
     .ok();
 
+    if is_deleted {
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_id),
+                resource_type: storage::enums::AuditResourceType::MerchantAccount,
+                resource_id: &merchant_id,
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&merchant_account_before),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     let response = api::MerchantAccountDeleteResponse {
         merchant_id,
         deleted: is_deleted,
@@ -736,6 +821,7 @@ pub async fn create_payment_connector(
     state: AppState,
     req: api::MerchantConnectorCreate,
     merchant_id: &String,
+    req_state: ReqState,
 ) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
     let store = state.store.as_ref();
     #[cfg(feature = "dummy_connector")]
@@ -1033,7 +1119,22 @@ pub async fn create_payment_connector(
         ],
     );
 
-    let mca_response = mca.try_into()?;
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(merchant_id),
+            resource_type: storage::enums::AuditResourceType::MerchantConnectorAccount,
+            resource_id: &mca_response.merchant_connector_id,
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&mca_response),
+        },
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -1158,6 +1259,7 @@ pub async fn update_payment_connector(
     merchant_id: &str,
     merchant_connector_id: &str,
     req: api_models::admin::MerchantConnectorUpdate,
+    req_state: ReqState,
 ) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
     let db = state.store.as_ref();
     let key_store = db
@@ -1296,6 +1398,7 @@ pub async fn update_payment_connector(
         .attach_printable("Missing `profile_id` in merchant connector account")?;
 
     let request_connector_label = req.connector_label;
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
 
     let updated_mca = db
         .update_merchant_connector_account(mca, payment_connector.into(), &key_store)
@@ -1310,7 +1413,21 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(merchant_id),
+            resource_type: storage::enums::AuditResourceType::MerchantConnectorAccount,
+            resource_id: merchant_connector_id,
+            action: storage::enums::AuditAction::Update,
+            before: Some(&mca_before),
+            after: Some(&response),
+        },
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -1319,6 +1436,7 @@ pub async fn delete_payment_connector(
     state: AppState,
     merchant_id: String,
     merchant_connector_id: String,
+    req_state: ReqState,
 ) -> RouterResponse<api::MerchantConnectorDeleteResponse> {
     let db = state.store.as_ref();
     let key_store = db
@@ -1331,7 +1449,7 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             &merchant_id,
             &merchant_connector_id,
@@ -1341,6 +1459,7 @@ pub async fn delete_payment_connector(
         .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
             id: merchant_connector_id.clone(),
         })?;
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
 
     let is_deleted = db
         .delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -1352,6 +1471,22 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    if is_deleted {
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_id),
+                resource_type: storage::enums::AuditResourceType::MerchantConnectorAccount,
+                resource_id: &merchant_connector_id,
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&mca_before),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1533,10 +1668,23 @@ pub async fn create_business_profile(
     state: AppState,
     request: api::BusinessProfileCreate,
     merchant_id: &str,
+    req_state: ReqState,
 ) -> RouterResponse<api_models::admin::BusinessProfileResponse> {
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+
+    if let Some(domain_name) = request
+        .payment_link_config
+        .as_ref()
+        .and_then(|payment_link_config| payment_link_config.domain_name.as_deref())
+    {
+        helpers::validate_payment_link_domain_name(domain_name)?;
+    }
+
+    if let Some(webhook_retry_policy) = &request.webhook_retry_policy {
+        helpers::validate_webhook_retry_policy(webhook_retry_policy)?;
+    }
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1570,10 +1718,24 @@ pub async fn create_business_profile(
             .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     }
 
-    Ok(service_api::ApplicationResponse::Json(
-        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+    let response = api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(merchant_id),
+            resource_type: storage::enums::AuditResourceType::BusinessProfile,
+            resource_id: &response.profile_id,
+            action: storage::enums::AuditAction::Create,
+            before: None,
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn list_business_profile(
@@ -1618,15 +1780,43 @@ pub async fn delete_business_profile(
     state: AppState,
     profile_id: String,
     merchant_id: &str,
+    req_state: ReqState,
 ) -> RouterResponse<bool> {
     let db = state.store.as_ref();
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
     let delete_result = db
         .delete_business_profile_by_profile_id_merchant_id(&profile_id, merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
-            id: profile_id,
+            id: profile_id.clone(),
         })?;
 
+    if delete_result {
+        let business_profile_before =
+            api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(merchant_id),
+                resource_type: storage::enums::AuditResourceType::BusinessProfile,
+                resource_id: &profile_id,
+                action: storage::enums::AuditAction::Delete,
+                before: Some(&business_profile_before),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
@@ -1635,6 +1825,7 @@ pub async fn update_business_profile(
     profile_id: &str,
     merchant_id: &str,
     request: api::BusinessProfileUpdate,
+    req_state: ReqState,
 ) -> RouterResponse<api::BusinessProfileResponse> {
     let db = state.store.as_ref();
     let business_profile = db
@@ -1654,6 +1845,18 @@ pub async fn update_business_profile(
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
 
+    if let Some(domain_name) = request
+        .payment_link_config
+        .as_ref()
+        .and_then(|payment_link_config| payment_link_config.domain_name.as_deref())
+    {
+        helpers::validate_payment_link_domain_name(domain_name)?;
+    }
+
+    if let Some(webhook_retry_policy) = &request.webhook_retry_policy {
+        helpers::validate_webhook_retry_policy(webhook_retry_policy)?;
+    }
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1733,19 +1936,74 @@ pub async fn update_business_profile(
         use_billing_as_payment_method_billing: request.use_billing_as_payment_method_billing,
         collect_shipping_details_from_wallet_connector: request
             .collect_shipping_details_from_wallet_connector,
+        authorization_expiry_policy: request
+            .authorization_expiry_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "authorization_expiry_policy",
+            })?,
+        webhook_retry_policy: request
+            .webhook_retry_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "webhook_retry_policy",
+            })?,
     };
 
-    let updated_business_profile = db
+    let business_profile_before =
+        api_models::admin::BusinessProfileResponse::foreign_try_from(business_profile.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let mut updated_business_profile = db
         .update_business_profile_by_profile_id(business_profile, business_profile_update)
         .await
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
             id: profile_id.to_owned(),
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
+    // Updating the webhook details re-enables an endpoint disabled due to delivery failures
+    if request.webhook_details.is_some()
+        && updated_business_profile
+            .webhook_endpoint_disabled_at
+            .is_some()
+    {
+        updated_business_profile = db
+            .update_business_profile_by_profile_id(
+                updated_business_profile,
+                storage::business_profile::BusinessProfileUpdate::WebhookEndpointHealthUpdate {
+                    webhook_endpoint_failing_since: None,
+                    webhook_endpoint_disabled_at: None,
+                },
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_owned(),
+            })?;
+    }
+
+    let response =
         api_models::admin::BusinessProfileResponse::foreign_try_from(updated_business_profile)
-            .change_context(errors::ApiErrorResponse::InternalServerError)?,
-    ))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(merchant_id),
+            resource_type: storage::enums::AuditResourceType::BusinessProfile,
+            resource_id: profile_id,
+            action: storage::enums::AuditAction::Update,
+            before: Some(&business_profile_before),
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn extended_card_info_toggle(
@@ -0,0 +1,695 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    alerts as alert_types,
+    payments::{PaymentFilters, PaymentMetrics},
+    GetPaymentMetricRequest, TimeRange,
+};
+use common_utils::{date_time, fp_utils::when};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils,
+};
+
+const ANALYTICS_ALERT_TAG: &str = "ANALYTICS_ALERT";
+const ANALYTICS_ALERT_NAME: &str = "ANALYTICS_ALERT_EVALUATION";
+const ANALYTICS_ALERT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::AnalyticsAlertWorkflow;
+
+const MAX_RULE_NAME_LENGTH: usize = 64;
+const MIN_EVALUATION_WINDOW_IN_MINS: u32 = 5;
+const MAX_EVALUATION_WINDOW_IN_MINS: u32 = 1440;
+const MAX_BASELINE_WINDOW_IN_DAYS: u32 = 90;
+
+#[instrument(skip_all)]
+pub async fn create_alert_rule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: alert_types::AnalyticsAlertRuleCreateRequest,
+) -> RouterResponse<alert_types::AnalyticsAlertRuleResponse> {
+    let db = state.store.as_ref();
+
+    validate_alert_rule_name(&request.name)?;
+    validate_alert_rule_parameters(
+        Some(request.drop_threshold),
+        request.evaluation_window_in_mins,
+        request.baseline_window_in_days,
+    )?;
+
+    if let Some(profile_id) = request.profile_id.as_ref() {
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_owned(),
+            })?;
+
+        when(
+            business_profile.merchant_id != merchant_account.merchant_id,
+            || {
+                Err(errors::ApiErrorResponse::AccessForbidden {
+                    resource: profile_id.to_owned(),
+                })
+            },
+        )?;
+    }
+
+    let now = date_time::now();
+    let rule = storage::AnalyticsAlertRuleNew {
+        rule_id: utils::generate_id(consts::ID_LENGTH, "alert_rule"),
+        merchant_id: merchant_account.merchant_id,
+        profile_id: request.profile_id,
+        name: request.name,
+        metric: request.metric,
+        connector: request.connector.map(|connector| connector.to_string()),
+        drop_threshold: request.drop_threshold,
+        evaluation_window_in_mins: i32::try_from(request.evaluation_window_in_mins)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        baseline_window_in_days: i32::try_from(request.baseline_window_in_days)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        is_active: true,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let rule = db
+        .insert_analytics_alert_rule(rule)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Alert rule with the given ID already exists".to_string(),
+        })?;
+
+    add_alert_evaluation_task(db, &rule).await?;
+
+    Ok(ApplicationResponse::Json(rule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_alert_rules(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<Vec<alert_types::AnalyticsAlertRuleResponse>> {
+    let rules = state
+        .store
+        .list_analytics_alert_rules_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list analytics alert rules")?;
+
+    Ok(ApplicationResponse::Json(
+        rules.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_alert_rule(
+    state: AppState,
+    merchant_id: String,
+    rule_id: String,
+) -> RouterResponse<alert_types::AnalyticsAlertRuleResponse> {
+    let rule = state
+        .store
+        .find_analytics_alert_rule_by_merchant_id_rule_id(&merchant_id, &rule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(rule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_alert_rule(
+    state: AppState,
+    merchant_id: String,
+    rule_id: String,
+    request: alert_types::AnalyticsAlertRuleUpdateRequest,
+) -> RouterResponse<alert_types::AnalyticsAlertRuleResponse> {
+    let db = state.store.as_ref();
+    let existing_rule = db
+        .find_analytics_alert_rule_by_merchant_id_rule_id(&merchant_id, &rule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    let evaluation_window_in_mins = request
+        .evaluation_window_in_mins
+        .map(i32::try_from)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`evaluation_window_in_mins` is out of range".to_string(),
+        })?;
+    let baseline_window_in_days = request
+        .baseline_window_in_days
+        .map(i32::try_from)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`baseline_window_in_days` is out of range".to_string(),
+        })?;
+
+    request
+        .name
+        .as_deref()
+        .map(validate_alert_rule_name)
+        .transpose()?;
+    validate_alert_rule_parameters(
+        request.drop_threshold,
+        u32::try_from(evaluation_window_in_mins.unwrap_or(existing_rule.evaluation_window_in_mins))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        u32::try_from(baseline_window_in_days.unwrap_or(existing_rule.baseline_window_in_days))
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    )?;
+
+    let rule = db
+        .update_analytics_alert_rule_by_merchant_id_rule_id(
+            &merchant_id,
+            &rule_id,
+            storage::AnalyticsAlertRuleUpdate::Update {
+                name: request.name,
+                drop_threshold: request.drop_threshold,
+                evaluation_window_in_mins,
+                baseline_window_in_days,
+                is_active: request.is_active,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    let is_evaluation_window_updated =
+        existing_rule.evaluation_window_in_mins != rule.evaluation_window_in_mins;
+    match (existing_rule.is_active, rule.is_active) {
+        (true, false) => revoke_alert_evaluation_task(db, &rule.rule_id, "RULE_DISABLED").await?,
+        (false, true) => reschedule_alert_evaluation_task(db, &rule).await?,
+        (true, true) if is_evaluation_window_updated => {
+            reschedule_alert_evaluation_task(db, &rule).await?
+        }
+        (true, true) | (false, false) => {}
+    }
+
+    Ok(ApplicationResponse::Json(rule.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_alert_rule(
+    state: AppState,
+    merchant_id: String,
+    rule_id: String,
+) -> RouterResponse<alert_types::AnalyticsAlertRuleDeleteResponse> {
+    let db = state.store.as_ref();
+    let rule = db
+        .delete_analytics_alert_rule_by_merchant_id_rule_id(&merchant_id, &rule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    revoke_alert_evaluation_task(db, &rule.rule_id, "RULE_DELETED").await?;
+
+    Ok(ApplicationResponse::Json(
+        alert_types::AnalyticsAlertRuleDeleteResponse {
+            rule_id: rule.rule_id,
+            deleted: true,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_alerts(
+    state: AppState,
+    merchant_id: String,
+    constraints: alert_types::AnalyticsAlertListConstraints,
+) -> RouterResponse<Vec<alert_types::AnalyticsAlertResponse>> {
+    let alerts = state
+        .store
+        .list_analytics_alerts_by_merchant_id_constraints(
+            &merchant_id,
+            constraints.rule_id,
+            constraints.created_after,
+            constraints.created_before,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list analytics alerts")?;
+
+    Ok(ApplicationResponse::Json(
+        alerts.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+fn validate_alert_rule_name(name: &str) -> RouterResult<()> {
+    when(
+        name.trim().is_empty() || name.chars().count() > MAX_RULE_NAME_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`name` must be between 1 and {MAX_RULE_NAME_LENGTH} characters"),
+            }))
+        },
+    )
+}
+
+fn validate_alert_rule_parameters(
+    drop_threshold: Option<f64>,
+    evaluation_window_in_mins: u32,
+    baseline_window_in_days: u32,
+) -> RouterResult<()> {
+    if let Some(drop_threshold) = drop_threshold {
+        when(!(drop_threshold > 0.0 && drop_threshold <= 100.0), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`drop_threshold` must be greater than 0 and at most 100".to_string(),
+            }))
+        })?;
+    }
+
+    when(
+        !(MIN_EVALUATION_WINDOW_IN_MINS..=MAX_EVALUATION_WINDOW_IN_MINS)
+            .contains(&evaluation_window_in_mins),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`evaluation_window_in_mins` must be between \
+                     {MIN_EVALUATION_WINDOW_IN_MINS} and {MAX_EVALUATION_WINDOW_IN_MINS}"
+                ),
+            }))
+        },
+    )?;
+
+    when(
+        !(1..=MAX_BASELINE_WINDOW_IN_DAYS).contains(&baseline_window_in_days)
+            || u64::from(baseline_window_in_days) * 24 * 60 <= u64::from(evaluation_window_in_mins),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`baseline_window_in_days` must be between 1 and \
+                     {MAX_BASELINE_WINDOW_IN_DAYS} and longer than the evaluation window"
+                ),
+            }))
+        },
+    )
+}
+
+fn generate_task_id_for_alert_evaluation_workflow(rule_id: &str) -> String {
+    format!("{ANALYTICS_ALERT_RUNNER}_{ANALYTICS_ALERT_NAME}_{rule_id}")
+}
+
+fn get_next_evaluation_time(rule: &storage::AnalyticsAlertRule) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::minutes(i64::from(
+        rule.evaluation_window_in_mins,
+    )))
+}
+
+#[instrument(skip_all)]
+async fn add_alert_evaluation_task(
+    db: &dyn crate::db::StorageInterface,
+    rule: &storage::AnalyticsAlertRule,
+) -> RouterResult<()> {
+    let tracking_data = storage::AnalyticsAlertTrackingData {
+        rule_id: rule.rule_id.clone(),
+        merchant_id: rule.merchant_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_alert_evaluation_workflow(&rule.rule_id),
+        ANALYTICS_ALERT_NAME,
+        ANALYTICS_ALERT_RUNNER,
+        [ANALYTICS_ALERT_TAG],
+        tracking_data,
+        get_next_evaluation_time(rule),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct analytics alert process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting analytics alert evaluation task to process_tracker: \
+                 rule_id: {}",
+                rule.rule_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "AnalyticsAlert")],
+    );
+
+    Ok(())
+}
+
+/// Schedules the next evaluation of the rule, one evaluation window from now.
+#[instrument(skip_all)]
+pub async fn reschedule_alert_evaluation_task(
+    db: &dyn crate::db::StorageInterface,
+    rule: &storage::AnalyticsAlertRule,
+) -> RouterResult<()> {
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(get_next_evaluation_time(rule)),
+        tracking_data: None,
+        business_status: Some("PENDING".to_string()),
+        status: Some(storage_enums::ProcessTrackerStatus::New),
+        updated_at: Some(date_time::now()),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![generate_task_id_for_alert_evaluation_workflow(
+            &rule.rule_id,
+        )],
+        updated_process_tracker_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to reschedule analytics alert evaluation task")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn revoke_alert_evaluation_task(
+    db: &dyn crate::db::StorageInterface,
+    rule_id: &str,
+    business_status: &str,
+) -> RouterResult<()> {
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::StatusUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(business_status.to_string()),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![generate_task_id_for_alert_evaluation_workflow(rule_id)],
+        updated_process_tracker_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to revoke analytics alert evaluation task")?;
+
+    Ok(())
+}
+
+/// Computes the value of the rule's metric over the evaluation and baseline windows and returns
+/// the alert to be raised, if the metric has dropped by at least the configured threshold.
+#[instrument(skip_all)]
+pub async fn evaluate_alert_rule(
+    state: &AppState,
+    rule: &storage::AnalyticsAlertRule,
+) -> RouterResult<Option<storage::AnalyticsAlertNew>> {
+    let window_end = date_time::now();
+    let window_start = window_end.saturating_sub(time::Duration::minutes(i64::from(
+        rule.evaluation_window_in_mins,
+    )));
+    let baseline_start = window_end.saturating_sub(time::Duration::days(i64::from(
+        rule.baseline_window_in_days,
+    )));
+
+    let observed_value = get_alert_metric_value(
+        state,
+        rule,
+        TimeRange {
+            start_time: window_start,
+            end_time: Some(window_end),
+        },
+    )
+    .await?;
+    let baseline_value = get_alert_metric_value(
+        state,
+        rule,
+        TimeRange {
+            start_time: baseline_start,
+            end_time: Some(window_start),
+        },
+    )
+    .await?;
+
+    let (observed_value, baseline_value) = match (observed_value, baseline_value) {
+        (Some(observed_value), Some(baseline_value)) => (observed_value, baseline_value),
+        _ => {
+            logger::debug!(
+                rule_id = %rule.rule_id,
+                "Not enough data to evaluate analytics alert rule"
+            );
+            return Ok(None);
+        }
+    };
+
+    if baseline_value - observed_value < rule.drop_threshold {
+        return Ok(None);
+    }
+
+    Ok(Some(storage::AnalyticsAlertNew {
+        alert_id: utils::generate_id(consts::ID_LENGTH, "alert"),
+        rule_id: rule.rule_id.clone(),
+        merchant_id: rule.merchant_id.clone(),
+        metric: rule.metric,
+        connector: rule.connector.clone(),
+        observed_value,
+        baseline_value,
+        drop_threshold: rule.drop_threshold,
+        window_start,
+        window_end,
+        email_sent: false,
+        webhook_sent: false,
+        created_at: window_end,
+    }))
+}
+
+async fn get_alert_metric_value(
+    state: &AppState,
+    rule: &storage::AnalyticsAlertRule,
+    time_range: TimeRange,
+) -> RouterResult<Option<f64>> {
+    let metric = match rule.metric {
+        enums::AnalyticsAlertMetric::PaymentSuccessRate => PaymentMetrics::PaymentSuccessRate,
+        enums::AnalyticsAlertMetric::ConnectorSuccessRate => PaymentMetrics::ConnectorSuccessRate,
+    };
+
+    let connector = rule
+        .connector
+        .as_deref()
+        .map(|connector| {
+            connector
+                .parse::<api_models::enums::Connector>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Invalid connector in alert rule: {connector}"))
+        })
+        .transpose()?;
+
+    let request = GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names: vec![],
+        filters: PaymentFilters {
+            connector: connector.into_iter().collect(),
+            ..Default::default()
+        },
+        metrics: HashSet::from([metric]),
+        distribution: None,
+        delta: false,
+    };
+
+    let response =
+        crate::analytics::payments::get_metrics(&state.pool, rule.merchant_id.as_str(), request)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch payment metrics for analytics alert rule")?;
+
+    Ok(response
+        .query_data
+        .into_iter()
+        .find_map(|bucket| match rule.metric {
+            enums::AnalyticsAlertMetric::PaymentSuccessRate => bucket.values.payment_success_rate,
+            enums::AnalyticsAlertMetric::ConnectorSuccessRate => {
+                bucket.values.connector_success_rate
+            }
+        }))
+}
+
+/// Persists the alert and notifies the merchant by email and outgoing webhook.
+#[instrument(skip_all)]
+pub async fn raise_alert(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    rule: &storage::AnalyticsAlertRule,
+    alert: storage::AnalyticsAlertNew,
+) -> RouterResult<storage::AnalyticsAlert> {
+    let db = state.store.as_ref();
+    let alert = db
+        .insert_analytics_alert(alert)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert analytics alert")?;
+
+    #[cfg(feature = "email")]
+    let email_sent = send_alert_email(state, merchant_account, rule, &alert)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to send analytics alert email"))
+        .is_ok();
+    #[cfg(not(feature = "email"))]
+    let email_sent = false;
+
+    let webhook_sent = trigger_alert_webhook(state, merchant_account, key_store, rule, &alert)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to trigger analytics alert webhook"))
+        .unwrap_or(false);
+
+    db.update_analytics_alert_by_merchant_id_alert_id(
+        &alert.merchant_id,
+        &alert.alert_id,
+        storage::AnalyticsAlertUpdate::NotificationStatusUpdate {
+            email_sent,
+            webhook_sent,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update analytics alert notification status")
+}
+
+#[cfg(feature = "email")]
+async fn send_alert_email(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    rule: &storage::AnalyticsAlertRule,
+    alert: &storage::AnalyticsAlert,
+) -> RouterResult<()> {
+    use crate::{services::email::types::AnalyticsAlertNotification, utils::OptionExt};
+
+    let email_id = merchant_account
+        .merchant_details
+        .clone()
+        .parse_value::<api::MerchantDetails>("MerchantDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .primary_email
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Merchant does not have a primary email configured".to_string(),
+        })?;
+
+    let email_contents = AnalyticsAlertNotification {
+        recipient_email: domain::UserEmail::from_pii_email(email_id)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert recipient's email to UserEmail")?,
+        subject: "Analytics Alert Triggered",
+        rule_name: rule.name.clone(),
+        alert: alert.clone(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send analytics alert email")?;
+
+    Ok(())
+}
+
+async fn trigger_alert_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    rule: &storage::AnalyticsAlertRule,
+    alert: &storage::AnalyticsAlert,
+) -> RouterResult<bool> {
+    let profile_id = match rule
+        .profile_id
+        .clone()
+        .or(merchant_account.default_profile.clone())
+    {
+        Some(profile_id) => profile_id,
+        None => {
+            logger::debug!(
+                rule_id = %rule.rule_id,
+                "No business profile found for analytics alert rule; skipping outgoing webhook"
+            );
+            return Ok(false);
+        }
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        enums::EventType::AnalyticsAlertTriggered,
+        enums::EventClass::AnalyticsAlerts,
+        alert.alert_id.clone(),
+        enums::EventObjectType::AnalyticsAlertDetails,
+        api::OutgoingWebhookContent::AnalyticsAlertDetails(Box::new(
+            alert_types::AnalyticsAlertResponse::foreign_from(alert.clone()),
+        )),
+        Some(alert.created_at),
+    )
+    .await?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_alert_rule_name() {
+        assert!(validate_alert_rule_name("Stripe auth rate drop").is_ok());
+        assert!(validate_alert_rule_name(&"a".repeat(MAX_RULE_NAME_LENGTH)).is_ok());
+        assert!(validate_alert_rule_name(&"a".repeat(MAX_RULE_NAME_LENGTH + 1)).is_err());
+        assert!(validate_alert_rule_name("  ").is_err());
+    }
+
+    #[test]
+    fn test_validate_alert_rule_parameters() {
+        assert!(validate_alert_rule_parameters(Some(15.0), 30, 7).is_ok());
+        // Only the windows are validated when the threshold is not being updated
+        assert!(validate_alert_rule_parameters(None, 30, 7).is_ok());
+
+        assert!(validate_alert_rule_parameters(Some(0.0), 30, 7).is_err());
+        assert!(validate_alert_rule_parameters(Some(100.5), 30, 7).is_err());
+        assert!(validate_alert_rule_parameters(Some(f64::NAN), 30, 7).is_err());
+
+        assert!(
+            validate_alert_rule_parameters(None, MIN_EVALUATION_WINDOW_IN_MINS - 1, 7).is_err()
+        );
+        assert!(
+            validate_alert_rule_parameters(None, MAX_EVALUATION_WINDOW_IN_MINS + 1, 7).is_err()
+        );
+
+        assert!(validate_alert_rule_parameters(None, 30, 0).is_err());
+        assert!(validate_alert_rule_parameters(None, 30, MAX_BASELINE_WINDOW_IN_DAYS + 1).is_err());
+        // The baseline window must be longer than the evaluation window
+        assert!(validate_alert_rule_parameters(None, MAX_EVALUATION_WINDOW_IN_MINS, 1).is_err());
+    }
+}
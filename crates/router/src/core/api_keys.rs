@@ -1,6 +1,9 @@
+use std::net::IpAddr;
+
 use common_utils::date_time;
 #[cfg(feature = "email")]
-use diesel_models::{api_keys::ApiKey, enums as storage_enums};
+use diesel_models::api_keys::ApiKey;
+use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, tracing};
@@ -8,10 +11,16 @@ use router_env::{instrument, tracing};
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    routes::{metrics, AppState},
+    core::{
+        config_audit,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::{app::ReqState, metrics, AppState},
     services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    types::{
+        api, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
     utils,
 };
 
@@ -113,6 +122,7 @@ pub async fn create_api_key(
     state: AppState,
     api_key: api::CreateApiKeyRequest,
     merchant_id: String,
+    req_state: ReqState,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
     let api_key_config = state.conf.api_keys.get_inner();
     let store = state.store.as_ref();
@@ -128,6 +138,11 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    validate_api_key_restrictions(
+        api_key.permission_groups.as_deref(),
+        api_key.allowed_ips.as_deref(),
+    )?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -140,6 +155,10 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permission_groups: api_key.permission_groups,
+        allowed_ips: api_key
+            .allowed_ips
+            .filter(|allowed_ips| !allowed_ips.is_empty()),
     };
 
     let api_key = store
@@ -148,6 +167,20 @@ pub async fn create_api_key(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert new API key")?;
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_id),
+            resource_type: storage_enums::AuditResourceType::ApiKey,
+            resource_id: &api_key.key_id,
+            action: storage_enums::AuditAction::Create,
+            before: None,
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key.clone())),
+        },
+    )
+    .await;
+
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
@@ -263,11 +296,24 @@ pub async fn retrieve_api_key(
 pub async fn update_api_key(
     state: AppState,
     api_key: api::UpdateApiKeyRequest,
+    req_state: ReqState,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let merchant_id = api_key.merchant_id.clone();
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    validate_api_key_restrictions(
+        api_key.permission_groups.as_deref(),
+        api_key.allowed_ips.as_deref(),
+    )?;
+
+    let api_key_before = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -277,6 +323,20 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_id),
+            resource_type: storage_enums::AuditResourceType::ApiKey,
+            resource_id: &key_id,
+            action: storage_enums::AuditAction::Update,
+            before: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key_before)),
+            after: Some(&api::RetrieveApiKeyResponse::foreign_from(api_key.clone())),
+        },
+    )
+    .await;
+
     #[cfg(feature = "email")]
     {
         let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
@@ -398,13 +458,38 @@ pub async fn revoke_api_key(
     state: AppState,
     merchant_id: &str,
     key_id: &str,
+    req_state: ReqState,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
+    let api_key_before = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?;
+
     let revoked = store
         .revoke_api_key(merchant_id, key_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    if revoked {
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(merchant_id),
+                resource_type: storage_enums::AuditResourceType::ApiKey,
+                resource_id: key_id,
+                action: storage_enums::AuditAction::Delete,
+                before: api_key_before
+                    .map(api::RetrieveApiKeyResponse::foreign_from)
+                    .as_ref(),
+                after: None,
+            },
+        )
+        .await;
+    }
+
     metrics::API_KEY_REVOKED.add(&metrics::CONTEXT, 1, &[]);
 
     #[cfg(feature = "email")]
@@ -487,6 +572,86 @@ fn generate_task_id_for_api_key_expiry_workflow(key_id: &str) -> String {
     format!("{API_KEY_EXPIRY_RUNNER}_{API_KEY_EXPIRY_NAME}_{key_id}")
 }
 
+fn validate_api_key_restrictions(
+    permission_groups: Option<&[storage_enums::PermissionGroup]>,
+    allowed_ips: Option<&[String]>,
+) -> RouterResult<()> {
+    if permission_groups.is_some_and(|permission_groups| permission_groups.is_empty()) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`permission_groups` must contain at least one permission group".to_string(),
+        }));
+    }
+
+    if let Some(invalid_ip) = allowed_ips
+        .unwrap_or_default()
+        .iter()
+        .find(|allowed_ip| parse_ip_network(allowed_ip).is_none())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`{invalid_ip}` in `allowed_ips` is not a valid IP address or CIDR block"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Checks whether the IP address belongs to any of the IP addresses or CIDR blocks in the
+/// allowlist of an API key.
+pub fn is_ip_address_allowed(allowed_ips: &[String], ip_address: IpAddr) -> bool {
+    allowed_ips
+        .iter()
+        .filter_map(|allowed_ip| parse_ip_network(allowed_ip))
+        .any(|(network, prefix_len)| is_ip_address_in_network(network, prefix_len, ip_address))
+}
+
+/// Parses an IP address or a CIDR block into the network address and the prefix length. An IP
+/// address is treated as a CIDR block containing only that address.
+fn parse_ip_network(ip_network: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix_len) = match ip_network.trim().split_once('/') {
+        Some((address, prefix_len)) => (address, Some(prefix_len)),
+        None => (ip_network.trim(), None),
+    };
+    let address = address.parse::<IpAddr>().ok()?;
+    let max_prefix_len = match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+
+    match prefix_len {
+        Some(prefix_len) => prefix_len
+            .parse::<u8>()
+            .ok()
+            .filter(|prefix_len| *prefix_len <= max_prefix_len)
+            .map(|prefix_len| (address, prefix_len)),
+        None => Some((address, max_prefix_len)),
+    }
+}
+
+fn is_ip_address_in_network(network: IpAddr, prefix_len: u8, ip_address: IpAddr) -> bool {
+    match (network, ip_address) {
+        (IpAddr::V4(network), IpAddr::V4(ip_address)) => {
+            let mask = u32::MAX
+                .checked_shl(u32::from(32_u8.saturating_sub(prefix_len)))
+                .unwrap_or(0);
+            u32::from(network) & mask == u32::from(ip_address) & mask
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip_address)) => {
+            let mask = u128::MAX
+                .checked_shl(u32::from(128_u8.saturating_sub(prefix_len)))
+                .unwrap_or(0);
+            u128::from(network) & mask == u128::from(ip_address) & mask
+        }
+        // Requests over IPv6 may carry IPv4 addresses mapped to IPv6 addresses
+        (IpAddr::V4(_), IpAddr::V6(ip_address)) => ip_address
+            .to_ipv4_mapped()
+            .map(|ip_address| is_ip_address_in_network(network, prefix_len, IpAddr::V4(ip_address)))
+            .unwrap_or(false),
+        (IpAddr::V6(_), IpAddr::V4(_)) => false,
+    }
+}
+
 impl From<&str> for PlaintextApiKey {
     fn from(s: &str) -> Self {
         Self(s.to_owned().into())
@@ -532,4 +697,32 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[test]
+    fn test_ip_address_allowlist() {
+        let allowed_ips = vec![
+            "192.168.1.0/24".to_string(),
+            "10.0.0.1".to_string(),
+            "2001:db8::/32".to_string(),
+        ];
+        let is_allowed =
+            |ip_address: &str| is_ip_address_allowed(&allowed_ips, ip_address.parse().unwrap());
+
+        assert!(is_allowed("192.168.1.42"));
+        assert!(is_allowed("10.0.0.1"));
+        assert!(is_allowed("2001:db8::1"));
+        assert!(is_allowed("::ffff:192.168.1.7"));
+        assert!(!is_allowed("192.168.2.1"));
+        assert!(!is_allowed("10.0.0.2"));
+        assert!(!is_allowed("2001:db9::1"));
+    }
+
+    #[test]
+    fn test_parse_ip_network() {
+        assert!(parse_ip_network("0.0.0.0/0").is_some());
+        assert!(parse_ip_network("::/0").is_some());
+        assert!(parse_ip_network("192.168.1.0/33").is_none());
+        assert!(parse_ip_network("192.168.1/24").is_none());
+        assert!(parse_ip_network("not an ip").is_none());
+    }
 }
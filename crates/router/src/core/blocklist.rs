@@ -1,33 +1,71 @@
 pub mod transformers;
 pub mod utils;
+pub mod velocity;
 
 use api_models::blocklist as api_blocklist;
 
 use crate::{
-    core::errors::{self, RouterResponse},
-    routes::AppState,
+    core::{
+        config_audit,
+        errors::{self, RouterResponse},
+    },
+    routes::{app::ReqState, AppState},
     services,
-    types::domain,
+    types::{domain, storage::enums as storage_enums},
 };
 
 pub async fn add_entry_to_blocklist(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::AddToBlocklistRequest,
+    req_state: ReqState,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(&state, merchant_account.merchant_id, body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    let blocklist_entry =
+        utils::insert_entry_into_blocklist(&state, merchant_account.merchant_id.clone(), body)
+            .await?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::Blocklist,
+            resource_id: &blocklist_entry.fingerprint_id,
+            action: storage_enums::AuditAction::Create,
+            before: None,
+            after: Some(&blocklist_entry),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
 }
 
 pub async fn remove_entry_from_blocklist(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::DeleteFromBlocklistRequest,
+    req_state: ReqState,
 ) -> RouterResponse<api_blocklist::DeleteFromBlocklistResponse> {
-    utils::delete_entry_from_blocklist(&state, merchant_account.merchant_id, body)
-        .await
-        .map(services::ApplicationResponse::Json)
+    let blocklist_entry =
+        utils::delete_entry_from_blocklist(&state, merchant_account.merchant_id.clone(), body)
+            .await?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::Blocklist,
+            resource_id: &blocklist_entry.fingerprint_id,
+            action: storage_enums::AuditAction::Delete,
+            before: Some(&blocklist_entry),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(blocklist_entry))
 }
 
 pub async fn list_blocklist_entries(
@@ -49,3 +87,52 @@ pub async fn toggle_blocklist_guard(
         .await
         .map(services::ApplicationResponse::Json)
 }
+
+pub async fn retrieve_velocity_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<api_blocklist::VelocityRulesConfig> {
+    let velocity_rules = velocity::get_velocity_rules(&state, &merchant_account.merchant_id)
+        .await?
+        .unwrap_or(api_blocklist::VelocityRulesConfig { rules: Vec::new() });
+
+    Ok(services::ApplicationResponse::Json(velocity_rules))
+}
+
+pub async fn update_velocity_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::VelocityRulesConfig,
+    req_state: ReqState,
+) -> RouterResponse<api_blocklist::VelocityRulesConfig> {
+    let existing_rules =
+        velocity::get_velocity_rules(&state, &merchant_account.merchant_id).await?;
+
+    velocity::upsert_velocity_rules(
+        &state,
+        &merchant_account.merchant_id,
+        &body,
+        existing_rules.is_some(),
+    )
+    .await?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::VelocityRules,
+            resource_id: &velocity::get_velocity_rules_key(&merchant_account.merchant_id),
+            action: if existing_rules.is_some() {
+                storage_enums::AuditAction::Update
+            } else {
+                storage_enums::AuditAction::Create
+            },
+            before: existing_rules.as_ref(),
+            after: Some(&body),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(body))
+}
@@ -1,11 +1,14 @@
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::{self, GenerateDigest, SignMessage},
+    errors::CustomResult,
+};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
 
-use super::{errors, transformers::generate_fingerprint, AppState};
+use super::{errors, transformers::generate_fingerprint, velocity, AppState};
 use crate::{
     consts,
     core::{
@@ -13,7 +16,7 @@ use crate::{
         payments::PaymentData,
     },
     logger,
-    types::{domain, storage, transformers::ForeignInto},
+    types::{self, domain, storage, transformers::ForeignInto},
     utils,
 };
 
@@ -38,6 +41,19 @@ pub async fn delete_entry_from_blocklist(
             .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
                 message: "no blocklist record for the given fingerprint id was found".to_string(),
             })?,
+
+        request => {
+            let (data_kind, value) = get_customer_data_to_block(&request)?;
+            let fingerprint_id =
+                generate_customer_data_fingerprint(state, &merchant_id, data_kind, &value).await?;
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, &fingerprint_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("no blocklist record for the given {data_kind} was found"),
+                })?
+        }
     };
 
     Ok(blocklist_entry.foreign_into())
@@ -145,7 +161,7 @@ pub async fn insert_entry_into_blocklist(
     let blocklist_entry = match &to_block {
         api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 &merchant_id,
@@ -156,7 +172,7 @@ pub async fn insert_entry_into_blocklist(
 
         api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 &merchant_id,
@@ -202,10 +218,128 @@ pub async fn insert_entry_into_blocklist(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to add fingerprint to blocklist")?
         }
+
+        request => {
+            let (data_kind, value) = get_customer_data_to_block(request)?;
+            let fingerprint_id =
+                generate_customer_data_fingerprint(state, &merchant_id, data_kind, &value).await?;
+            duplicate_check_insert_entry(&fingerprint_id, state, &merchant_id, data_kind).await?
+        }
     };
     Ok(blocklist_entry.foreign_into())
 }
 
+/// Provides the kind and the normalized value of the customer data to be blocked
+fn get_customer_data_to_block(
+    request: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(common_enums::BlocklistDataKind, String)> {
+    match request {
+        api_blocklist::BlocklistRequest::CustomerEmail(email) => Ok((
+            common_enums::BlocklistDataKind::CustomerEmail,
+            email.peek().trim().to_lowercase(),
+        )),
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => Ok((
+            common_enums::BlocklistDataKind::IpAddress,
+            ip_address.to_string(),
+        )),
+        api_blocklist::BlocklistRequest::CustomerId(customer_id) => Ok((
+            common_enums::BlocklistDataKind::CustomerId,
+            customer_id.clone(),
+        )),
+        api_blocklist::BlocklistRequest::BillingCountry(country) => Ok((
+            common_enums::BlocklistDataKind::BillingCountry,
+            country.to_string(),
+        )),
+        api_blocklist::BlocklistRequest::DeviceFingerprint(device_fingerprint) => {
+            if device_fingerprint.len() == 64
+                && device_fingerprint.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                Ok((
+                    common_enums::BlocklistDataKind::DeviceFingerprint,
+                    device_fingerprint.to_lowercase(),
+                ))
+            } else {
+                Err(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "data".to_string(),
+                    expected_format: "a 64 character hex string".to_string(),
+                }
+                .into())
+            }
+        }
+        api_blocklist::BlocklistRequest::CardBin(_)
+        | api_blocklist::BlocklistRequest::ExtendedCardBin(_)
+        | api_blocklist::BlocklistRequest::Fingerprint(_) => {
+            Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("card data is not customer data")
+        }
+    }
+}
+
+/// Generates the fingerprint against which customer data is blocked.
+///
+/// Billing countries are stored as is, all other customer data is stored as a keyed hash so that
+/// no personal data is persisted in the blocklist.
+pub async fn generate_customer_data_fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
+    value: &str,
+) -> RouterResult<String> {
+    if data_kind == common_enums::BlocklistDataKind::BillingCountry {
+        return Ok(value.to_string());
+    }
+
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+    hash_with_fingerprint_secret(
+        &merchant_fingerprint_secret,
+        &format!("{data_kind}:{value}"),
+    )
+}
+
+pub fn hash_with_fingerprint_secret(
+    merchant_fingerprint_secret: &str,
+    value: &str,
+) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(merchant_fingerprint_secret.as_bytes(), value.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to hash blocklist data")
+}
+
+/// Generates a fingerprint of the device from the browser information of the payment
+pub fn generate_device_fingerprint(browser_info: &types::BrowserInformation) -> Option<String> {
+    let user_agent = browser_info.user_agent.as_ref()?;
+    let device_attributes = [
+        user_agent.to_string(),
+        browser_info.accept_header.clone().unwrap_or_default(),
+        browser_info.language.clone().unwrap_or_default(),
+        browser_info
+            .color_depth
+            .map(|color_depth| color_depth.to_string())
+            .unwrap_or_default(),
+        browser_info
+            .screen_height
+            .map(|screen_height| screen_height.to_string())
+            .unwrap_or_default(),
+        browser_info
+            .screen_width
+            .map(|screen_width| screen_width.to_string())
+            .unwrap_or_default(),
+        browser_info
+            .time_zone
+            .map(|time_zone| time_zone.to_string())
+            .unwrap_or_default(),
+    ]
+    .join("|");
+
+    crypto::Sha256
+        .generate_digest(device_attributes.as_bytes())
+        .map(hex::encode)
+        .map_err(|error| logger::error!(?error, "failed to generate device fingerprint"))
+        .ok()
+}
+
 pub async fn get_merchant_fingerprint_secret(
     state: &AppState,
     merchant_id: &str,
@@ -244,21 +378,21 @@ fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
     format!("fingerprint_secret_{merchant_id}")
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
+    fingerprint_id: &str,
     state: &AppState,
     merchant_id: &str,
     data_kind: common_enums::BlocklistDataKind,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: format!("provided {data_kind} is already blocked"),
             }
             .into());
         }
@@ -276,14 +410,14 @@ async fn duplicate_check_insert_bin(
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_string(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error inserting pm blocklist item")
+        .attach_printable("error inserting blocklist item")
 }
 
 async fn delete_card_bin_blocklist_entry(
@@ -300,18 +434,63 @@ async fn delete_card_bin_blocklist_entry(
         })
 }
 
-pub async fn validate_data_for_blocklist<F>(
+/// Fingerprints of the details of a payment which a merchant can block
+pub struct PaymentFingerprints {
+    pub card_number: Option<String>,
+    pub card_bin: Option<String>,
+    pub extended_card_bin: Option<String>,
+    pub customer_data: Vec<String>,
+    pub ip_address: Option<String>,
+    pub device: Option<String>,
+    pub velocity_card: Option<String>,
+}
+
+impl PaymentFingerprints {
+    pub fn get_velocity_check_data<'a>(
+        &'a self,
+        customer_id: Option<&'a str>,
+        amount: common_utils::types::MinorUnit,
+    ) -> velocity::VelocityCheckData<'a> {
+        velocity::VelocityCheckData {
+            card_fingerprint: self.velocity_card.as_deref(),
+            ip_address_fingerprint: self.ip_address.as_deref(),
+            customer_id,
+            amount,
+        }
+    }
+}
+
+pub async fn is_blocklist_guard_enabled(state: &AppState, merchant_id: &str) -> bool {
+    let blocklist_guard_enabled = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_blocklist_guard_key(merchant_id),
+            Some("false".to_string()),
+        )
+        .await;
+
+    match blocklist_guard_enabled {
+        Ok(config) => serde_json::from_str(&config.config).unwrap_or(false),
+
+        // If it is not present in db we are defaulting it to false
+        Err(inner) => {
+            if !inner.current_context().is_db_not_found() {
+                logger::error!("Error fetching guard blocklist enabled config {:?}", inner);
+            }
+            false
+        }
+    }
+}
+
+pub async fn generate_payment_fingerprints<F>(
     state: &AppState,
-    merchant_account: &domain::MerchantAccount,
-    payment_data: &mut PaymentData<F>,
-) -> CustomResult<bool, errors::ApiErrorResponse>
+    merchant_id: &str,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<PaymentFingerprints>
 where
-    F: Send + Clone,
+    F: Clone,
 {
-    let db = &state.store;
-    let merchant_id = &merchant_account.merchant_id;
-    let merchant_fingerprint_secret =
-        get_merchant_fingerprint_secret(state, merchant_id.as_str()).await?;
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
 
     // Hashed Fingerprint to check whether or not this payment should be blocked.
     let card_number_fingerprint = if let Some(api_models::payments::PaymentMethodData::Card(card)) =
@@ -360,30 +539,117 @@ where
                 _ => None,
             });
 
-    //validating the payment method.
-    let mut blocklist_futures = Vec::new();
-    if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            card_number_fingerprint,
-        ));
-    }
+    let browser_info = payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .map(serde_json::from_value::<types::BrowserInformation>)
+        .transpose()
+        .map_err(|error| logger::error!(?error, "failed to parse browser information"))
+        .ok()
+        .flatten();
+
+    let device_fingerprint = browser_info.as_ref().and_then(generate_device_fingerprint);
+
+    let billing_country = payment_data
+        .address
+        .get_payment_method_billing()
+        .or(payment_data.address.get_payment_billing())
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+
+    let ip_address_fingerprint = browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| {
+            hash_with_fingerprint_secret(
+                &merchant_fingerprint_secret,
+                &format!(
+                    "{}:{ip_address}",
+                    common_enums::BlocklistDataKind::IpAddress
+                ),
+            )
+        })
+        .transpose()?;
+
+    // Hashed customer data to check whether or not this payment should be blocked.
+    let customer_data_fingerprints = [
+        payment_data.email.as_ref().map(|email| {
+            (
+                common_enums::BlocklistDataKind::CustomerEmail,
+                email.peek().trim().to_lowercase(),
+            )
+        }),
+        payment_data
+            .payment_intent
+            .customer_id
+            .clone()
+            .map(|customer_id| (common_enums::BlocklistDataKind::CustomerId, customer_id)),
+        device_fingerprint.clone().map(|device_fingerprint| {
+            (
+                common_enums::BlocklistDataKind::DeviceFingerprint,
+                device_fingerprint,
+            )
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(data_kind, value)| {
+        hash_with_fingerprint_secret(
+            &merchant_fingerprint_secret,
+            &format!("{data_kind}:{value}"),
+        )
+    })
+    .chain(ip_address_fingerprint.clone().map(Ok))
+    .chain(billing_country.map(|country| Ok(country.to_string())))
+    .collect::<RouterResult<Vec<_>>>()?;
 
-    if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(
-            db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-                merchant_id,
-                card_bin_fingerprint,
-            ),
-        );
-    }
+    let velocity_card_fingerprint = payment_data
+        .payment_method_data
+        .as_ref()
+        .and_then(|pm_data| match pm_data {
+            api_models::payments::PaymentMethodData::Card(card) => Some(card),
+            _ => None,
+        })
+        .map(|card| {
+            hash_with_fingerprint_secret(
+                &merchant_fingerprint_secret,
+                &format!("card:{}", card.card_number.get_card_no()),
+            )
+        })
+        .transpose()?;
+
+    Ok(PaymentFingerprints {
+        card_number: card_number_fingerprint,
+        card_bin: card_bin_fingerprint,
+        extended_card_bin: extended_card_bin_fingerprint,
+        customer_data: customer_data_fingerprints,
+        ip_address: ip_address_fingerprint,
+        device: device_fingerprint,
+        velocity_card: velocity_card_fingerprint,
+    })
+}
 
-    if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
-            merchant_id,
-            extended_card_bin_fingerprint,
-        ));
-    }
+/// Checks whether any of the fingerprints of the payment is blocked by the merchant
+pub async fn is_payment_blocklisted(
+    state: &AppState,
+    merchant_id: &str,
+    fingerprints: &PaymentFingerprints,
+) -> bool {
+    let db = &state.store;
+
+    //validating the payment method.
+    let blocklist_futures = [
+        fingerprints.card_number.as_ref(),
+        fingerprints.card_bin.as_ref(),
+        fingerprints.extended_card_bin.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(fingerprints.customer_data.iter())
+    .map(|fingerprint_id| {
+        db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+    });
 
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
@@ -398,57 +664,156 @@ where
             }
         }
     }
-    if should_payment_be_blocked {
-        // Update db for attempt and intent status.
-        db.update_payment_intent(
-            payment_data.payment_intent.clone(),
-            storage::PaymentIntentUpdate::RejectUpdate {
-                status: common_enums::IntentStatus::Failed,
-                merchant_decision: Some(MerchantDecision::Rejected.to_string()),
-                updated_by: merchant_account.storage_scheme.to_string(),
-            },
-            merchant_account.storage_scheme,
+    should_payment_be_blocked
+}
+
+pub async fn validate_data_for_blocklist<F>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> CustomResult<bool, errors::ApiErrorResponse>
+where
+    F: Send + Clone,
+{
+    let merchant_id = &merchant_account.merchant_id;
+    let fingerprints = generate_payment_fingerprints(state, merchant_id, payment_data).await?;
+
+    if is_payment_blocklisted(state, merchant_id, &fingerprints).await {
+        logger::warn!(device_fingerprint = ?fingerprints.device, "Payment blocked by blocklist");
+        return reject_blocked_payment(
+            state,
+            merchant_account,
+            payment_data,
+            "This payment method is blocked",
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-        .attach_printable(
-            "Failed to update status in Payment Intent to failed due to it being blocklisted",
-        )?;
-
-        // If payment is blocked not showing connector details
-        let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
-            status: common_enums::AttemptStatus::Failure,
-            error_code: Some(Some("HE-03".to_string())),
-            error_message: Some(Some("This payment method is blocked".to_string())),
+        .await;
+    }
+
+    let velocity_action = velocity::evaluate_velocity_rules(
+        state,
+        merchant_id,
+        &fingerprints.get_velocity_check_data(
+            payment_data.payment_intent.customer_id.as_deref(),
+            payment_data.payment_attempt.amount,
+        ),
+    )
+    .await;
+
+    match velocity_action {
+        Some(api_blocklist::VelocityRuleAction::Block) => {
+            logger::warn!(device_fingerprint = ?fingerprints.device, "Payment blocked by velocity rules");
+            return reject_blocked_payment(
+                state,
+                merchant_account,
+                payment_data,
+                "This payment was blocked by velocity rules",
+            )
+            .await;
+        }
+        Some(api_blocklist::VelocityRuleAction::Force3ds) => {
+            force_three_ds_authentication(state, merchant_account, payment_data).await?;
+        }
+        Some(api_blocklist::VelocityRuleAction::Flag) => {
+            logger::warn!(device_fingerprint = ?fingerprints.device, "Payment flagged by velocity rules");
+        }
+        None => {}
+    }
+
+    payment_data.payment_attempt.fingerprint_id = generate_payment_fingerprint(
+        state,
+        payment_data.payment_attempt.merchant_id.clone(),
+        payment_data.payment_method_data.clone(),
+    )
+    .await?;
+    Ok(false)
+}
+
+async fn reject_blocked_payment<F>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    error_message: &str,
+) -> CustomResult<bool, errors::ApiErrorResponse>
+where
+    F: Send + Clone,
+{
+    let db = &state.store;
+    // Update db for attempt and intent status.
+    db.update_payment_intent(
+        payment_data.payment_intent.clone(),
+        storage::PaymentIntentUpdate::RejectUpdate {
+            status: common_enums::IntentStatus::Failed,
+            merchant_decision: Some(MerchantDecision::Rejected.to_string()),
             updated_by: merchant_account.storage_scheme.to_string(),
-        };
-        db.update_payment_attempt_with_attempt_id(
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Intent to failed due to it being blocklisted",
+    )?;
+
+    // If payment is blocked not showing connector details
+    let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
+        status: common_enums::AttemptStatus::Failure,
+        error_code: Some(Some("HE-03".to_string())),
+        error_message: Some(Some(error_message.to_string())),
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    db.update_payment_attempt_with_attempt_id(
+        payment_data.payment_attempt.clone(),
+        attempt_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Attempt to failed, due to it being blocklisted",
+    )?;
+
+    Err(errors::ApiErrorResponse::PaymentBlockedError {
+        code: 200,
+        message: error_message.to_string(),
+        status: "Failed".to_string(),
+        reason: "Blocked".to_string(),
+    }
+    .into())
+}
+
+/// Forces 3DS authentication for card payments which exceeded a velocity limit
+async fn force_three_ds_authentication<F>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> CustomResult<(), errors::ApiErrorResponse>
+where
+    F: Send + Clone,
+{
+    if !matches!(
+        payment_data.payment_method_data,
+        Some(api_models::payments::PaymentMethodData::Card(_))
+    ) || payment_data.payment_attempt.authentication_type
+        == Some(common_enums::AuthenticationType::ThreeDs)
+    {
+        return Ok(());
+    }
+
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
             payment_data.payment_attempt.clone(),
-            attempt_update,
+            storage::PaymentAttemptUpdate::AuthenticationTypeUpdate {
+                authentication_type: common_enums::AuthenticationType::ThreeDs,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
             merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
-        .attach_printable(
-            "Failed to update status in Payment Attempt to failed, due to it being blocklisted",
-        )?;
-
-        Err(errors::ApiErrorResponse::PaymentBlockedError {
-            code: 200,
-            message: "This payment method is blocked".to_string(),
-            status: "Failed".to_string(),
-            reason: "Blocked".to_string(),
-        }
-        .into())
-    } else {
-        payment_data.payment_attempt.fingerprint_id = generate_payment_fingerprint(
-            state,
-            payment_data.payment_attempt.merchant_id.clone(),
-            payment_data.payment_method_data.clone(),
-        )
-        .await?;
-        Ok(false)
-    }
+        .attach_printable("Failed to force 3DS authentication in Payment Attempt")?;
+
+    Ok(())
 }
 
 pub async fn generate_payment_fingerprint(
@@ -0,0 +1,522 @@
+use std::collections::HashSet;
+
+use api_models::blocklist as api_blocklist;
+use common_utils::{errors::CustomResult, ext_traits::StringExt, types::MinorUnit};
+use error_stack::ResultExt;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+
+use super::errors;
+use crate::{
+    consts,
+    core::errors::RouterResult,
+    logger,
+    routes::{metrics, AppState},
+    types::storage,
+};
+
+/// Details of the payment against which velocity rules are evaluated
+pub struct VelocityCheckData<'a> {
+    pub card_fingerprint: Option<&'a str>,
+    pub ip_address_fingerprint: Option<&'a str>,
+    pub customer_id: Option<&'a str>,
+    pub amount: MinorUnit,
+}
+
+/// Provides the identifier for the specific merchant's velocity rules config
+#[inline(always)]
+pub fn get_velocity_rules_key(merchant_id: &str) -> String {
+    format!("velocity_rules_{merchant_id}")
+}
+
+fn get_velocity_counter_key(merchant_id: &str, rule_name: &str, subject: &str) -> String {
+    format!("velocity_{merchant_id}_{rule_name}_{subject}")
+}
+
+pub async fn get_velocity_rules(
+    state: &AppState,
+    merchant_id: &str,
+) -> RouterResult<Option<api_blocklist::VelocityRulesConfig>> {
+    let velocity_rules = state
+        .store
+        .find_config_by_key(&get_velocity_rules_key(merchant_id))
+        .await;
+
+    match velocity_rules {
+        Ok(config) => config
+            .config
+            .parse_struct("VelocityRulesConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse velocity rules")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch velocity rules"),
+    }
+}
+
+pub async fn upsert_velocity_rules(
+    state: &AppState,
+    merchant_id: &str,
+    velocity_rules: &api_blocklist::VelocityRulesConfig,
+    exists: bool,
+) -> RouterResult<()> {
+    validate_velocity_rules(velocity_rules)?;
+
+    let key = get_velocity_rules_key(merchant_id);
+    let config = serde_json::to_string(velocity_rules)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize velocity rules")?;
+
+    if exists {
+        state
+            .store
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update velocity rules")?;
+    } else {
+        state
+            .store
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert velocity rules")?;
+    }
+
+    Ok(())
+}
+
+fn validate_velocity_rules(
+    velocity_rules: &api_blocklist::VelocityRulesConfig,
+) -> RouterResult<()> {
+    let invalid_request =
+        |message: String| errors::ApiErrorResponse::InvalidRequestData { message };
+
+    if velocity_rules.rules.len() > consts::MAX_VELOCITY_RULES {
+        Err(invalid_request(format!(
+            "at most {} velocity rules can be configured",
+            consts::MAX_VELOCITY_RULES
+        )))?
+    }
+
+    let mut rule_names = HashSet::new();
+    for rule in &velocity_rules.rules {
+        if rule.name.is_empty()
+            || rule.name.len() > consts::MAX_VELOCITY_RULE_NAME_LENGTH
+            || !rule
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            Err(invalid_request(format!(
+                "velocity rule name `{}` must be 1 to {} alphanumeric characters, `_` or `-`",
+                rule.name,
+                consts::MAX_VELOCITY_RULE_NAME_LENGTH
+            )))?
+        }
+
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(invalid_request(format!(
+                "velocity rule name `{}` is used more than once",
+                rule.name
+            )))?
+        }
+
+        if rule.window_in_secs == 0 {
+            Err(invalid_request(format!(
+                "window_in_secs of velocity rule `{}` must be greater than zero",
+                rule.name
+            )))?
+        }
+
+        let is_limit_zero = match &rule.limit {
+            api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts } => *max_attempts == 0,
+            api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards } => *max_cards == 0,
+            api_blocklist::VelocityLimit::AmountPerCustomer { max_amount } => {
+                max_amount.get_amount_as_i64() <= 0
+            }
+        };
+        if is_limit_zero {
+            Err(invalid_request(format!(
+                "limit of velocity rule `{}` must be greater than zero",
+                rule.name
+            )))?
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the payment against the velocity rules of the merchant, returning the most severe
+/// action among the rules whose limits are exceeded.
+///
+/// Velocity checks fail open, no action is taken when the rules or the redis connection cannot
+/// be fetched, and a rule which cannot be evaluated is skipped.
+pub async fn evaluate_velocity_rules(
+    state: &AppState,
+    merchant_id: &str,
+    velocity_check_data: &VelocityCheckData<'_>,
+) -> Option<api_blocklist::VelocityRuleAction> {
+    let velocity_rules = match get_velocity_rules(state, merchant_id).await {
+        Ok(Some(velocity_rules)) => velocity_rules,
+        Ok(None) => return None,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch velocity rules");
+            return None;
+        }
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return None;
+        }
+    };
+
+    let mut action = None;
+    for rule in velocity_rules.rules {
+        let is_limit_exceeded =
+            match is_velocity_limit_exceeded(&redis_conn, merchant_id, &rule, velocity_check_data)
+                .await
+            {
+                Ok(is_limit_exceeded) => is_limit_exceeded,
+                Err(error) => {
+                    logger::error!(?error, rule = %rule.name, "Failed to evaluate velocity rule");
+                    false
+                }
+            };
+
+        if is_limit_exceeded {
+            logger::warn!(
+                rule = %rule.name,
+                action = ?rule.action,
+                "Velocity rule triggered"
+            );
+            metrics::VELOCITY_RULE_TRIGGERED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "action",
+                    format!("{:?}", rule.action),
+                )],
+            );
+            action = action.max(Some(rule.action));
+        }
+    }
+
+    action
+}
+
+async fn is_velocity_limit_exceeded(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    rule: &api_blocklist::VelocityRule,
+    velocity_check_data: &VelocityCheckData<'_>,
+) -> CustomResult<bool, RedisError> {
+    let window_in_secs = i64::from(rule.window_in_secs);
+
+    match &rule.limit {
+        api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts } => {
+            let Some(card_fingerprint) = velocity_check_data.card_fingerprint else {
+                return Ok(false);
+            };
+            let key = get_velocity_counter_key(merchant_id, &rule.name, card_fingerprint);
+            let attempts = redis_conn
+                .increment_key_by_with_expiry(&key, 1, window_in_secs)
+                .await?;
+            Ok(attempts > i64::from(*max_attempts))
+        }
+
+        api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards } => {
+            let (Some(ip_address_fingerprint), Some(card_fingerprint)) = (
+                velocity_check_data.ip_address_fingerprint,
+                velocity_check_data.card_fingerprint,
+            ) else {
+                return Ok(false);
+            };
+            let key = get_velocity_counter_key(merchant_id, &rule.name, ip_address_fingerprint);
+            let cards = redis_conn
+                .sadd_with_expiry(&key, card_fingerprint, window_in_secs)
+                .await?;
+            Ok(cards > usize::try_from(*max_cards).unwrap_or(usize::MAX))
+        }
+
+        api_blocklist::VelocityLimit::AmountPerCustomer { max_amount } => {
+            let Some(customer_id) = velocity_check_data.customer_id else {
+                return Ok(false);
+            };
+            let amount = velocity_check_data.amount.get_amount_as_i64();
+            let key = get_velocity_counter_key(merchant_id, &rule.name, customer_id);
+            let total_amount = redis_conn
+                .increment_key_by_with_expiry(&key, amount, window_in_secs)
+                .await?;
+            Ok(total_amount > max_amount.get_amount_as_i64())
+        }
+    }
+}
+
+/// Checks the payment against the velocity rules of the merchant without recording it, returning
+/// the rules whose limits the payment exceeds.
+///
+/// Like [`evaluate_velocity_rules`], this fails open and reports no exceeded rules when the rules
+/// or their counters cannot be read.
+///
+/// `is_payment_recorded` denotes that the counters already account for this payment, which is the
+/// case when the blocklist guard of the merchant is enabled.
+pub async fn get_exceeded_velocity_rules(
+    state: &AppState,
+    merchant_id: &str,
+    velocity_check_data: &VelocityCheckData<'_>,
+    is_payment_recorded: bool,
+) -> Vec<api_blocklist::VelocityRule> {
+    let velocity_rules = match get_velocity_rules(state, merchant_id).await {
+        Ok(Some(velocity_rules)) => velocity_rules,
+        Ok(None) => return Vec::new(),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch velocity rules");
+            return Vec::new();
+        }
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return Vec::new();
+        }
+    };
+
+    let mut exceeded_rules = Vec::new();
+    for rule in velocity_rules.rules {
+        match peek_velocity_limit_exceeded(
+            &redis_conn,
+            merchant_id,
+            &rule,
+            velocity_check_data,
+            is_payment_recorded,
+        )
+        .await
+        {
+            Ok(true) => exceeded_rules.push(rule),
+            Ok(false) => {}
+            Err(error) => {
+                logger::error!(?error, rule = %rule.name, "Failed to check velocity rule");
+            }
+        }
+    }
+
+    exceeded_rules
+}
+
+async fn peek_velocity_limit_exceeded(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    rule: &api_blocklist::VelocityRule,
+    velocity_check_data: &VelocityCheckData<'_>,
+    is_payment_recorded: bool,
+) -> CustomResult<bool, RedisError> {
+    match &rule.limit {
+        api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts } => {
+            let Some(card_fingerprint) = velocity_check_data.card_fingerprint else {
+                return Ok(false);
+            };
+            let key = get_velocity_counter_key(merchant_id, &rule.name, card_fingerprint);
+            let attempts = redis_conn
+                .get_key::<Option<i64>>(&key)
+                .await?
+                .unwrap_or_default();
+            let attempts = if is_payment_recorded {
+                attempts
+            } else {
+                attempts.saturating_add(1)
+            };
+            Ok(attempts > i64::from(*max_attempts))
+        }
+
+        api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards } => {
+            let (Some(ip_address_fingerprint), Some(card_fingerprint)) = (
+                velocity_check_data.ip_address_fingerprint,
+                velocity_check_data.card_fingerprint,
+            ) else {
+                return Ok(false);
+            };
+            let key = get_velocity_counter_key(merchant_id, &rule.name, ip_address_fingerprint);
+            let cards = redis_conn.get_set_cardinality(&key).await?;
+            let cards = if redis_conn.is_set_member(&key, card_fingerprint).await? {
+                cards
+            } else {
+                cards.saturating_add(1)
+            };
+            Ok(cards > usize::try_from(*max_cards).unwrap_or(usize::MAX))
+        }
+
+        api_blocklist::VelocityLimit::AmountPerCustomer { max_amount } => {
+            let Some(customer_id) = velocity_check_data.customer_id else {
+                return Ok(false);
+            };
+            let key = get_velocity_counter_key(merchant_id, &rule.name, customer_id);
+            let total_amount = redis_conn
+                .get_key::<Option<i64>>(&key)
+                .await?
+                .unwrap_or_default();
+            let total_amount = if is_payment_recorded {
+                total_amount
+            } else {
+                total_amount.saturating_add(velocity_check_data.amount.get_amount_as_i64())
+            };
+            Ok(total_amount > max_amount.get_amount_as_i64())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    fn rule(name: &str, limit: api_blocklist::VelocityLimit) -> api_blocklist::VelocityRule {
+        api_blocklist::VelocityRule {
+            name: name.to_string(),
+            limit,
+            window_in_secs: 3600,
+            action: api_blocklist::VelocityRuleAction::Block,
+        }
+    }
+
+    #[test]
+    fn test_validate_velocity_rules() {
+        let valid_rules = api_blocklist::VelocityRulesConfig {
+            rules: vec![
+                rule(
+                    "attempts_per_card",
+                    api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts: 5 },
+                ),
+                rule(
+                    "amount-per-customer",
+                    api_blocklist::VelocityLimit::AmountPerCustomer {
+                        max_amount: MinorUnit::new(100000),
+                    },
+                ),
+            ],
+        };
+        assert!(validate_velocity_rules(&valid_rules).is_ok());
+
+        let duplicate_names = api_blocklist::VelocityRulesConfig {
+            rules: vec![
+                rule(
+                    "cards_per_ip",
+                    api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards: 3 },
+                ),
+                rule(
+                    "cards_per_ip",
+                    api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards: 5 },
+                ),
+            ],
+        };
+        assert!(validate_velocity_rules(&duplicate_names).is_err());
+
+        let invalid_name = api_blocklist::VelocityRulesConfig {
+            rules: vec![rule(
+                "cards per ip",
+                api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards: 3 },
+            )],
+        };
+        assert!(validate_velocity_rules(&invalid_name).is_err());
+
+        let zero_limit = api_blocklist::VelocityRulesConfig {
+            rules: vec![rule(
+                "attempts_per_card",
+                api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts: 0 },
+            )],
+        };
+        assert!(validate_velocity_rules(&zero_limit).is_err());
+    }
+
+    #[test]
+    fn test_velocity_rule_action_severity() {
+        let action = Some(api_blocklist::VelocityRuleAction::Force3ds)
+            .max(Some(api_blocklist::VelocityRuleAction::Flag));
+        assert_eq!(action, Some(api_blocklist::VelocityRuleAction::Force3ds));
+
+        let action = action.max(Some(api_blocklist::VelocityRuleAction::Block));
+        assert_eq!(action, Some(api_blocklist::VelocityRuleAction::Block));
+    }
+
+    #[tokio::test]
+    async fn test_is_velocity_limit_exceeded() {
+        let redis_conn = RedisConnectionPool::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("failed to create redis connection pool");
+        let merchant_id = "merchant_velocity_test";
+        let rules = [
+            rule(
+                "attempts_per_card",
+                api_blocklist::VelocityLimit::AttemptsPerCard { max_attempts: 2 },
+            ),
+            rule(
+                "cards_per_ip",
+                api_blocklist::VelocityLimit::DistinctCardsPerIp { max_cards: 1 },
+            ),
+            rule(
+                "amount_per_customer",
+                api_blocklist::VelocityLimit::AmountPerCustomer {
+                    max_amount: MinorUnit::new(250),
+                },
+            ),
+        ];
+        let counter_keys = [
+            ("attempts_per_card", "card_1"),
+            ("attempts_per_card", "card_2"),
+            ("cards_per_ip", "ip_1"),
+            ("amount_per_customer", "customer_1"),
+        ]
+        .map(|(rule_name, subject)| get_velocity_counter_key(merchant_id, rule_name, subject));
+        for key in &counter_keys {
+            let _ = redis_conn.delete_key(key).await;
+        }
+
+        let payment = |card_fingerprint| VelocityCheckData {
+            card_fingerprint: Some(card_fingerprint),
+            ip_address_fingerprint: Some("ip_1"),
+            customer_id: Some("customer_1"),
+            amount: MinorUnit::new(100),
+        };
+        let mut results = Vec::new();
+        for card_fingerprint in ["card_1", "card_1", "card_2"] {
+            let mut exceeded = Vec::new();
+            for rule in &rules {
+                exceeded.push(
+                    is_velocity_limit_exceeded(
+                        &redis_conn,
+                        merchant_id,
+                        rule,
+                        &payment(card_fingerprint),
+                    )
+                    .await
+                    .unwrap(),
+                );
+            }
+            results.push(exceeded);
+        }
+
+        assert_eq!(
+            results,
+            vec![
+                vec![false, false, false],
+                vec![false, false, false],
+                vec![false, true, true],
+            ]
+        );
+
+        for key in &counter_keys {
+            let _ = redis_conn.delete_key(key).await;
+        }
+    }
+}
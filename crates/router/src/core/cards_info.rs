@@ -1,15 +1,23 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
 use common_utils::fp_utils::when;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+use time::PrimitiveDateTime;
 
 use crate::{
     core::{
-        errors::{self, RouterResponse},
+        errors::{self, RouterResponse, RouterResult},
         payments::helpers,
     },
+    db::StorageInterface,
     routes,
     services::ApplicationResponse,
-    types::{domain, transformers::ForeignFrom},
+    types::{api, domain, storage, storage::enums, transformers::ForeignFrom},
 };
 
 fn verify_iin_length(card_iin: &str) -> Result<(), errors::ApiErrorResponse> {
@@ -46,3 +54,413 @@ pub async fn retrieve_card_info(
         api_models::cards_info::CardInfoResponse::foreign_from(card_info),
     ))
 }
+
+/// The BIN database records the issuing country either as an ISO 3166 code or as the country name
+/// (eg. `UNITED STATES OF AMERICA`), depending on the provider the record was imported from.
+pub fn parse_card_issuing_country(country: &str) -> Option<enums::Country> {
+    let country = country.trim();
+    let normalize = |name: &str| -> String {
+        name.chars()
+            .filter(|character| character.is_ascii_alphanumeric())
+            .map(|character| character.to_ascii_lowercase())
+            .collect()
+    };
+    enums::CountryAlpha2::from_str(country)
+        .map(enums::Country::from_alpha2)
+        .or_else(|_| enums::CountryAlpha3::from_str(country).map(enums::Country::from_alpha3))
+        .ok()
+        .or_else(|| {
+            let country = normalize(country);
+            enums::Country::iter().find(|variant| normalize(&variant.to_string()) == country)
+        })
+}
+
+/// Number of imported BIN records diffed against the stored records together
+const CARDS_INFO_IMPORT_BATCH_SIZE: usize = 1000;
+
+/// A BIN record read from an import file. Empty columns leave the stored value unchanged.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct CardInfoRecord {
+    card_iin: String,
+    card_issuer: Option<String>,
+    card_network: Option<enums::CardNetwork>,
+    card_type: Option<String>,
+    card_subtype: Option<String>,
+    card_issuing_country: Option<String>,
+    bank_code_id: Option<String>,
+    bank_code: Option<String>,
+    country_code: Option<String>,
+}
+
+impl CardInfoRecord {
+    /// Returns the fields of the record that differ from the stored record, or all the fields
+    /// present in the record if the BIN is not stored yet
+    fn get_changed_fields(&self, stored: Option<&storage::CardInfo>) -> Vec<String> {
+        fn is_changed<T: PartialEq>(new: &Option<T>, old: Option<&Option<T>>) -> bool {
+            new.is_some() && old.map_or(true, |old| new != old)
+        }
+
+        [
+            (
+                "card_issuer",
+                is_changed(&self.card_issuer, stored.map(|ci| &ci.card_issuer)),
+            ),
+            (
+                "card_network",
+                is_changed(&self.card_network, stored.map(|ci| &ci.card_network)),
+            ),
+            (
+                "card_type",
+                is_changed(&self.card_type, stored.map(|ci| &ci.card_type)),
+            ),
+            (
+                "card_subtype",
+                is_changed(&self.card_subtype, stored.map(|ci| &ci.card_subtype)),
+            ),
+            (
+                "card_issuing_country",
+                is_changed(
+                    &self.card_issuing_country,
+                    stored.map(|ci| &ci.card_issuing_country),
+                ),
+            ),
+            (
+                "bank_code_id",
+                is_changed(&self.bank_code_id, stored.map(|ci| &ci.bank_code_id)),
+            ),
+            (
+                "bank_code",
+                is_changed(&self.bank_code, stored.map(|ci| &ci.bank_code)),
+            ),
+            (
+                "country_code",
+                is_changed(&self.country_code, stored.map(|ci| &ci.country_code)),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, is_changed)| *is_changed)
+        .map(|(field, _)| field.to_string())
+        .collect()
+    }
+
+    fn into_card_info(self, provider: &str, now: PrimitiveDateTime) -> storage::CardInfo {
+        storage::CardInfo {
+            card_iin: self.card_iin,
+            card_issuer: self.card_issuer,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_subtype: self.card_subtype,
+            card_issuing_country: self.card_issuing_country,
+            bank_code_id: self.bank_code_id,
+            bank_code: self.bank_code,
+            country_code: self.country_code,
+            date_created: now,
+            last_updated: Some(now),
+            last_updated_provider: Some(provider.to_string()),
+        }
+    }
+
+    fn into_card_info_update(
+        self,
+        provider: &str,
+        now: PrimitiveDateTime,
+    ) -> storage::UpdateCardInfo {
+        storage::UpdateCardInfo {
+            card_issuer: self.card_issuer,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_subtype: self.card_subtype,
+            card_issuing_country: self.card_issuing_country,
+            bank_code_id: self.bank_code_id,
+            bank_code: self.bank_code,
+            country_code: self.country_code,
+            last_updated: Some(now),
+            last_updated_provider: Some(provider.to_string()),
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn import_cards_info_core(
+    state: routes::AppState,
+    request: api::CardInfoImportRequest,
+) -> RouterResponse<api::CardInfoImportResponse> {
+    import_cards_info(&*state.store, request)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Imports BIN records from a CSV file into the BIN database.
+///
+/// The file must have a header row naming the columns, of which only `card_iin` is required. BINs
+/// that are not stored yet are inserted, and stored BINs are updated with the non-empty columns of
+/// the record, recording the provider as the `last_updated_provider` of the BIN. Records that do
+/// not change a stored BIN are left untouched, so a file can be re-imported after a failure. With
+/// `dry_run` set, the records are only diffed against the stored BINs.
+#[instrument(skip_all)]
+pub async fn import_cards_info(
+    db: &dyn StorageInterface,
+    request: api::CardInfoImportRequest,
+) -> RouterResult<api::CardInfoImportResponse> {
+    when(request.provider.trim().is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "provider must not be empty".to_string(),
+        })
+    })?;
+
+    let mut response = api::CardInfoImportResponse {
+        provider: request.provider.clone(),
+        dry_run: request.dry_run,
+        total_records: 0,
+        inserted: 0,
+        updated: 0,
+        unchanged: 0,
+        errors: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(request.file.as_slice());
+    let headers = reader
+        .headers()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Failed to read the header row of the file".to_string(),
+        })?
+        .clone();
+
+    let mut seen_card_iins = HashSet::new();
+    let mut batch = Vec::new();
+    for string_record in reader.records() {
+        response.total_records = response.total_records.saturating_add(1);
+        let string_record = match string_record {
+            Ok(string_record) => string_record,
+            Err(error) => {
+                response.errors.push(api::CardInfoImportError {
+                    line: error.position().map_or(0, |position| position.line()),
+                    card_iin: None,
+                    error: error.to_string(),
+                });
+                continue;
+            }
+        };
+        let line = string_record
+            .position()
+            .map_or(0, |position| position.line());
+        let record = match string_record.deserialize::<CardInfoRecord>(Some(&headers)) {
+            Ok(record) => record,
+            Err(error) => {
+                response.errors.push(api::CardInfoImportError {
+                    line,
+                    card_iin: string_record.get(0).map(ToString::to_string),
+                    error: error.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let import_error = if verify_iin_length(&record.card_iin).is_err()
+            || !record.card_iin.chars().all(|c| c.is_ascii_digit())
+        {
+            Some("card_iin must be a 6 or 8 digit number")
+        } else if !seen_card_iins.insert(record.card_iin.clone()) {
+            Some("Duplicate record for the card_iin")
+        } else {
+            None
+        };
+        if let Some(import_error) = import_error {
+            response.errors.push(api::CardInfoImportError {
+                line,
+                card_iin: Some(record.card_iin),
+                error: import_error.to_string(),
+            });
+            continue;
+        }
+
+        batch.push((line, record));
+        if batch.len() >= CARDS_INFO_IMPORT_BATCH_SIZE {
+            import_cards_info_batch(db, &request, std::mem::take(&mut batch), &mut response)
+                .await?;
+        }
+    }
+    if !batch.is_empty() {
+        import_cards_info_batch(db, &request, batch, &mut response).await?;
+    }
+
+    logger::info!(
+        provider = %response.provider,
+        dry_run = response.dry_run,
+        total_records = response.total_records,
+        inserted = response.inserted,
+        updated = response.updated,
+        unchanged = response.unchanged,
+        failed = response.errors.len(),
+        "Imported card information"
+    );
+
+    Ok(response)
+}
+
+async fn import_cards_info_batch(
+    db: &dyn StorageInterface,
+    request: &api::CardInfoImportRequest,
+    batch: Vec<(u64, CardInfoRecord)>,
+    response: &mut api::CardInfoImportResponse,
+) -> RouterResult<()> {
+    let mut stored_cards_info: HashMap<String, storage::CardInfo> = db
+        .find_card_info_by_iins(
+            batch
+                .iter()
+                .map(|(_, record)| record.card_iin.clone())
+                .collect(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the stored card information")?
+        .into_iter()
+        .map(|card_info| (card_info.card_iin.clone(), card_info))
+        .collect();
+    let now = common_utils::date_time::now();
+
+    for (line, record) in batch {
+        let card_iin = record.card_iin.clone();
+        let stored_card_info = stored_cards_info.remove(&card_iin);
+        let changed_fields = record.get_changed_fields(stored_card_info.as_ref());
+
+        let (change_type, previous_provider) = match stored_card_info {
+            None => (api::CardInfoChangeType::Inserted, None),
+            Some(_) if changed_fields.is_empty() => {
+                response.unchanged = response.unchanged.saturating_add(1);
+                continue;
+            }
+            Some(stored_card_info) => (
+                api::CardInfoChangeType::Updated,
+                stored_card_info.last_updated_provider,
+            ),
+        };
+
+        if !request.dry_run {
+            let result = match change_type {
+                api::CardInfoChangeType::Inserted => db
+                    .add_card_info(record.into_card_info(&request.provider, now))
+                    .await
+                    .map(|_| ()),
+                api::CardInfoChangeType::Updated => db
+                    .update_card_info(
+                        card_iin.clone(),
+                        record.into_card_info_update(&request.provider, now),
+                    )
+                    .await
+                    .map(|_| ()),
+            };
+            if let Err(error) = result {
+                logger::error!(?error, %card_iin, "Failed to store card information");
+                response.errors.push(api::CardInfoImportError {
+                    line,
+                    card_iin: Some(card_iin),
+                    error: "Failed to store the record".to_string(),
+                });
+                continue;
+            }
+        }
+
+        match change_type {
+            api::CardInfoChangeType::Inserted => {
+                response.inserted = response.inserted.saturating_add(1)
+            }
+            api::CardInfoChangeType::Updated => {
+                response.updated = response.updated.saturating_add(1)
+            }
+        }
+        response.changes.push(api::CardInfoChange {
+            card_iin,
+            change_type,
+            changed_fields,
+            previous_provider,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::cards_info::CardsInfoInterface;
+
+    #[tokio::test]
+    async fn test_import_cards_info_inserts_and_updates_records() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let now = common_utils::date_time::now();
+        db.cards_info.lock().await.push(storage::CardInfo {
+            card_iin: "424242".to_string(),
+            card_issuer: Some("Old Bank".to_string()),
+            card_network: Some(enums::CardNetwork::Visa),
+            card_type: Some("CREDIT".to_string()),
+            card_subtype: None,
+            card_issuing_country: Some("UNITEDSTATES".to_string()),
+            bank_code_id: None,
+            bank_code: None,
+            country_code: None,
+            date_created: now,
+            last_updated: None,
+            last_updated_provider: Some("old_provider".to_string()),
+        });
+
+        let file =
+            "card_iin,card_issuer,card_network,card_type,card_subtype,card_issuing_country\n\
+            424242,New Bank,Visa,CREDIT,,\n\
+            555555,Some Bank,Mastercard,DEBIT,PREPAID,INDIA\n\
+            12345,Invalid Bank,,,,\n\
+            555555,Duplicate Bank,,,,\n";
+        let request = |dry_run| api::CardInfoImportRequest {
+            provider: "new_provider".to_string(),
+            dry_run,
+            file: file.as_bytes().to_vec(),
+        };
+
+        let diff = import_cards_info(&db, request(true))
+            .await
+            .expect("Failed to diff card information");
+        assert_eq!(diff.total_records, 4);
+        assert_eq!((diff.inserted, diff.updated, diff.unchanged), (1, 1, 0));
+        assert_eq!(diff.errors.len(), 2);
+        assert_eq!(diff.changes[0].changed_fields, vec!["card_issuer"]);
+        assert_eq!(
+            diff.changes[0].previous_provider.as_deref(),
+            Some("old_provider")
+        );
+        assert_eq!(db.cards_info.lock().await.len(), 1);
+
+        let summary = import_cards_info(&db, request(false))
+            .await
+            .expect("Failed to import card information");
+        assert_eq!((summary.inserted, summary.updated), (1, 1));
+
+        let updated = db.get_card_info("424242").await.unwrap().unwrap();
+        assert_eq!(updated.card_issuer.as_deref(), Some("New Bank"));
+        assert_eq!(
+            updated.card_issuing_country.as_deref(),
+            Some("UNITEDSTATES")
+        );
+        assert_eq!(
+            updated.last_updated_provider.as_deref(),
+            Some("new_provider")
+        );
+        let inserted = db.get_card_info("555555").await.unwrap().unwrap();
+        assert_eq!(inserted.card_subtype.as_deref(), Some("PREPAID"));
+
+        let reimport = import_cards_info(&db, request(false))
+            .await
+            .expect("Failed to re-import card information");
+        assert_eq!(
+            (reimport.inserted, reimport.updated, reimport.unchanged),
+            (0, 0, 2)
+        );
+    }
+}
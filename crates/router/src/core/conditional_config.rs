@@ -6,15 +6,19 @@ use common_utils::ext_traits::{Encode, StringExt, ValueExt};
 use diesel_models::configs;
 use error_stack::ResultExt;
 use euclid::frontend::ast;
+use router_env::logger;
 
 use super::routing::helpers::{
     get_payment_config_routing_id, update_merchant_active_algorithm_ref,
 };
 use crate::{
-    core::errors::{self, RouterResponse},
-    routes::AppState,
+    core::{
+        config_audit,
+        errors::{self, RouterResponse},
+    },
+    routes::{app::ReqState, AppState},
     services::api as service_api,
-    types::domain,
+    types::{domain, storage::enums as storage_enums},
     utils::OptionExt,
 };
 
@@ -23,6 +27,7 @@ pub async fn upsert_conditional_config(
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
     request: DecisionManager,
+    req_state: ReqState,
 ) -> RouterResponse<DecisionManagerRecord> {
     let db = state.store.as_ref();
     let (name, prog) = match request {
@@ -79,7 +84,7 @@ pub async fn upsert_conditional_config(
                 .attach_printable("The Payment Config Key Not Found")?;
 
             let new_algo = DecisionManagerRecord {
-                name: previous_record.name,
+                name: previous_record.name.clone(),
                 program: prog,
                 modified_at: timestamp,
                 created_at: previous_record.created_at,
@@ -99,6 +104,20 @@ pub async fn upsert_conditional_config(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error serializing the config")?;
 
+            config_audit::record_config_change(
+                &state,
+                &req_state,
+                config_audit::ConfigChange {
+                    merchant_id: Some(&merchant_account.merchant_id),
+                    resource_type: storage_enums::AuditResourceType::DecisionManagerConfig,
+                    resource_id: &key,
+                    action: storage_enums::AuditAction::Update,
+                    before: Some(&previous_record),
+                    after: Some(&new_algo),
+                },
+            )
+            .await;
+
             algo_id.update_conditional_config_id(key);
             update_merchant_active_algorithm_ref(db, &key_store, algo_id)
                 .await
@@ -134,6 +153,20 @@ pub async fn upsert_conditional_config(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error fetching the config")?;
 
+            config_audit::record_config_change(
+                &state,
+                &req_state,
+                config_audit::ConfigChange {
+                    merchant_id: Some(&merchant_account.merchant_id),
+                    resource_type: storage_enums::AuditResourceType::DecisionManagerConfig,
+                    resource_id: &key,
+                    action: storage_enums::AuditAction::Create,
+                    before: None,
+                    after: Some(&new_rec),
+                },
+            )
+            .await;
+
             algo_id.update_conditional_config_id(key);
             update_merchant_active_algorithm_ref(db, &key_store, algo_id)
                 .await
@@ -152,6 +185,7 @@ pub async fn delete_conditional_config(
     state: AppState,
     key_store: domain::MerchantKeyStore,
     merchant_account: domain::MerchantAccount,
+    req_state: ReqState,
 ) -> RouterResponse<()> {
     let db = state.store.as_ref();
     let key = get_payment_config_routing_id(&merchant_account.merchant_id);
@@ -169,10 +203,40 @@ pub async fn delete_conditional_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update deleted algorithm ref")?;
 
+    // The previous record is only needed for the audit trail, and failing to read it does not
+    // fail the deletion
+    let previous_record = db
+        .find_config_by_key(&key)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch config for audit"))
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<DecisionManagerRecord>("DecisionManagerRecord")
+                .map_err(|error| logger::error!(?error, "Failed to parse config for audit"))
+                .ok()
+        });
+
     db.delete_config_by_key(&key)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to delete routing config from DB")?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::DecisionManagerConfig,
+            resource_id: &key,
+            action: storage_enums::AuditAction::Delete,
+            before: previous_record.as_ref(),
+            after: None,
+        },
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::StatusOk)
 }
 
@@ -0,0 +1,477 @@
+use api_models::config_audit as config_audit_api;
+use common_utils::generate_id;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, AppState, AppStateInfo},
+    services::{authentication::AuthenticationType, ApplicationResponse},
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const CONFIG_AUDIT_LOG_LIST_DEFAULT_LIMIT: i64 = 100;
+const CONFIG_AUDIT_LOG_LIST_MAX_LIMIT: i64 = 1000;
+const CONFIG_AUDIT_LOG_EXPORT_MAX_LIMIT: i64 = 10000;
+
+const CONFIG_AUDIT_LOG_EXPORT_HEADER: [&str; 9] = [
+    "audit_id",
+    "created_at",
+    "resource_type",
+    "resource_id",
+    "action",
+    "actor_type",
+    "actor_id",
+    "request_id",
+    "changes",
+];
+
+/// Fields holding secrets that are not wrapped in `Secret`, whose values are never recorded
+const CONFIG_AUDIT_SENSITIVE_FIELDS: [&str; 1] = ["payment_response_hash_key"];
+const CONFIG_AUDIT_REDACTED_VALUE: &str = "*** redacted ***";
+
+/// A change made to a configuration, recorded in the audit trail
+pub struct ConfigChange<'a, T: Serialize> {
+    /// The merchant whose configuration was changed, `None` for global configurations
+    pub merchant_id: Option<&'a str>,
+    pub resource_type: enums::AuditResourceType,
+    pub resource_id: &'a str,
+    pub action: enums::AuditAction,
+    /// The configuration before the change, `None` if it was created
+    pub before: Option<&'a T>,
+    /// The configuration after the change, `None` if it was deleted
+    pub after: Option<&'a T>,
+}
+
+/// Records a configuration change in the audit trail along with the actor who made it.
+///
+/// Only the fields that changed are recorded. Values of fields wrapped in `Secret`, and of the
+/// fields in [`CONFIG_AUDIT_SENSITIVE_FIELDS`], are redacted, so that a change to a secret is
+/// recorded without the secret itself. Failures are logged and
+/// not propagated, so that the change made by the request is not reported as failed.
+#[instrument(skip_all, fields(resource_type = %change.resource_type, action = %change.action))]
+pub async fn record_config_change<T: Serialize>(
+    state: &AppState,
+    req_state: &ReqState,
+    change: ConfigChange<'_, T>,
+) {
+    let changes = match get_redacted_changes(change.before, change.after) {
+        Ok(changes) => changes,
+        Err(error) => {
+            logger::error!(?error, "Failed to compute configuration changes for audit");
+            return;
+        }
+    };
+
+    // Updates that do not change anything are not recorded
+    if changes.is_empty() && change.action == enums::AuditAction::Update {
+        return;
+    }
+
+    let (actor_type, actor_id) = get_actor(req_state.auth_type.as_ref());
+    let audit_id = generate_id(consts::ID_LENGTH, "audit");
+    let changes = Value::Object(changes);
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ConfigChanged {
+            audit_id: audit_id.clone(),
+            resource_type: change.resource_type,
+            resource_id: change.resource_id.to_owned(),
+            action: change.action,
+            actor_type,
+            actor_id: actor_id.clone(),
+            changes: changes.clone(),
+        }))
+        .emit();
+
+    let config_audit_log = storage::ConfigAuditLogNew {
+        audit_id,
+        merchant_id: change.merchant_id.map(ToOwned::to_owned),
+        resource_type: change.resource_type,
+        resource_id: change.resource_id.to_owned(),
+        action: change.action,
+        actor_type,
+        actor_id,
+        request_id: state.get_request_id(),
+        changes,
+        created_at: common_utils::date_time::now(),
+    };
+
+    if let Err(error) = state.store.insert_config_audit_log(config_audit_log).await {
+        logger::error!(?error, "Failed to insert configuration audit record");
+    }
+}
+
+fn get_actor(auth_type: Option<&AuthenticationType>) -> (enums::AuditActorType, Option<String>) {
+    match auth_type {
+        Some(AuthenticationType::ApiKey { key_id, .. }) => {
+            (enums::AuditActorType::ApiKey, Some(key_id.clone()))
+        }
+        Some(AuthenticationType::AdminApiKey) => (enums::AuditActorType::AdminApiKey, None),
+        Some(AuthenticationType::MerchantJwt { user_id, .. }) => {
+            (enums::AuditActorType::User, user_id.clone())
+        }
+        Some(AuthenticationType::UserJwt { user_id })
+        | Some(AuthenticationType::SinglePurposeJWT { user_id, .. }) => {
+            (enums::AuditActorType::User, Some(user_id.clone()))
+        }
+        Some(AuthenticationType::MerchantId { .. })
+        | Some(AuthenticationType::PublishableKey { .. })
+        | Some(AuthenticationType::WebhookAuth { .. })
+        | Some(AuthenticationType::NoAuth)
+        | None => (enums::AuditActorType::Other, None),
+    }
+}
+
+/// Computes the fields that differ between `before` and `after`, keyed by their dot separated
+/// path, with the `before` and `after` values of each field.
+///
+/// The comparison is made on the unmasked values so that changes to secrets are detected, while
+/// the recorded values are taken from the masked serialization.
+fn get_redacted_changes<T: Serialize>(
+    before: Option<&T>,
+    after: Option<&T>,
+) -> Result<Map<String, Value>, serde_json::Error> {
+    let raw_before = serde_json::to_value(before)?;
+    let raw_after = serde_json::to_value(after)?;
+    let mut masked_before = masking::masked_serialize(&before)?;
+    let mut masked_after = masking::masked_serialize(&after)?;
+    redact_sensitive_fields(&mut masked_before);
+    redact_sensitive_fields(&mut masked_after);
+
+    let mut changes = Map::new();
+    collect_changes(
+        None,
+        &raw_before,
+        &raw_after,
+        Some(&masked_before),
+        Some(&masked_after),
+        &mut changes,
+    );
+
+    Ok(changes)
+}
+
+fn collect_changes(
+    path: Option<&str>,
+    raw_before: &Value,
+    raw_after: &Value,
+    masked_before: Option<&Value>,
+    masked_after: Option<&Value>,
+    changes: &mut Map<String, Value>,
+) {
+    if raw_before == raw_after {
+        return;
+    }
+
+    let empty = Map::new();
+    let fields = match (raw_before, raw_after) {
+        (Value::Object(before), Value::Object(after)) => Some((before, after)),
+        (Value::Object(before), Value::Null) => Some((before, &empty)),
+        (Value::Null, Value::Object(after)) => Some((&empty, after)),
+        _ => None,
+    };
+
+    match fields {
+        Some((before, after)) => {
+            let keys = before
+                .keys()
+                .chain(after.keys().filter(|key| !before.contains_key(*key)));
+            for key in keys {
+                let child_path = match path {
+                    Some(path) => format!("{path}.{key}"),
+                    None => key.clone(),
+                };
+                collect_changes(
+                    Some(&child_path),
+                    before.get(key).unwrap_or(&Value::Null),
+                    after.get(key).unwrap_or(&Value::Null),
+                    get_masked_field(masked_before, key),
+                    get_masked_field(masked_after, key),
+                    changes,
+                );
+            }
+        }
+        None => {
+            // Values that are not objects, such as a serialized enum, are recorded as a whole
+            changes.insert(
+                path.unwrap_or("value").to_owned(),
+                serde_json::json!({
+                    "before": masked_before.cloned().unwrap_or(Value::Null),
+                    "after": masked_after.cloned().unwrap_or(Value::Null),
+                }),
+            );
+        }
+    }
+}
+
+fn redact_sensitive_fields(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if !CONFIG_AUDIT_SENSITIVE_FIELDS.contains(&key.as_str()) {
+                    redact_sensitive_fields(field);
+                } else if !field.is_null() {
+                    *field = Value::String(CONFIG_AUDIT_REDACTED_VALUE.to_owned());
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_sensitive_fields),
+        _ => {}
+    }
+}
+
+/// Fetches a field of a masked object. A masked secret replaces the whole object with a string,
+/// in which case the string is used for every field of the object.
+fn get_masked_field<'a>(masked: Option<&'a Value>, key: &str) -> Option<&'a Value> {
+    match masked {
+        Some(Value::Object(fields)) => fields.get(key),
+        Some(Value::Null) | None => None,
+        Some(masked) => Some(masked),
+    }
+}
+
+async fn list_config_audit_logs_by_constraints(
+    state: &AppState,
+    merchant_id: Option<&str>,
+    constraints: config_audit_api::ConfigAuditLogListConstraints,
+    max_limit: i64,
+) -> RouterResult<Vec<storage::ConfigAuditLog>> {
+    if let (Some(start_time), Some(end_time)) = (constraints.start_time, constraints.end_time) {
+        if start_time > end_time {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`start_time` must not be later than `end_time`".to_string(),
+            })?
+        }
+    }
+
+    let limit = match constraints.limit {
+        Some(limit) if i64::from(limit) <= max_limit => i64::from(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`limit` must not exceed {max_limit}"),
+        })?,
+        None => CONFIG_AUDIT_LOG_LIST_DEFAULT_LIMIT.min(max_limit),
+    };
+
+    state
+        .store
+        .list_config_audit_logs_by_merchant_id_constraints(
+            merchant_id,
+            constraints.start_time,
+            constraints.end_time,
+            constraints.resource_type,
+            Some(limit),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list configuration audit records")
+}
+
+/// Lists the recorded configuration changes of a merchant, or of global configurations when
+/// `merchant_id` is `None`
+#[instrument(skip(state))]
+pub async fn list_config_audit_logs(
+    state: AppState,
+    merchant_id: Option<String>,
+    constraints: config_audit_api::ConfigAuditLogListConstraints,
+) -> RouterResponse<config_audit_api::ConfigAuditLogListResponse> {
+    let data = list_config_audit_logs_by_constraints(
+        &state,
+        merchant_id.as_deref(),
+        constraints,
+        CONFIG_AUDIT_LOG_LIST_MAX_LIMIT,
+    )
+    .await?
+    .into_iter()
+    .map(config_audit_api::ConfigAuditLogResponse::foreign_from)
+    .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        config_audit_api::ConfigAuditLogListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Exports the recorded configuration changes of a merchant, or of global configurations when
+/// `merchant_id` is `None`, as a CSV file
+#[instrument(skip(state))]
+pub async fn export_config_audit_logs(
+    state: AppState,
+    merchant_id: Option<String>,
+    constraints: config_audit_api::ConfigAuditLogListConstraints,
+) -> RouterResponse<serde_json::Value> {
+    let config_audit_logs = list_config_audit_logs_by_constraints(
+        &state,
+        merchant_id.as_deref(),
+        constraints,
+        CONFIG_AUDIT_LOG_EXPORT_MAX_LIMIT,
+    )
+    .await?;
+
+    let mut csv = CONFIG_AUDIT_LOG_EXPORT_HEADER.join(",");
+    csv.push('\n');
+    for config_audit_log in config_audit_logs {
+        let record = [
+            config_audit_log.audit_id,
+            config_audit_log
+                .created_at
+                .assume_utc()
+                .format(&time::format_description::well_known::Iso8601::DEFAULT)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to format configuration audit record timestamp")?,
+            config_audit_log.resource_type.to_string(),
+            config_audit_log.resource_id,
+            config_audit_log.action.to_string(),
+            config_audit_log.actor_type.to_string(),
+            config_audit_log.actor_id.unwrap_or_default(),
+            config_audit_log.request_id.unwrap_or_default(),
+            config_audit_log.changes.to_string(),
+        ];
+        csv.push_str(
+            &record
+                .iter()
+                .map(|field| escape_csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    Ok(ApplicationResponse::FileData((
+        csv.into_bytes(),
+        mime::TEXT_CSV,
+    )))
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct ConnectorConfig {
+        name: String,
+        api_key: Secret<String>,
+        metadata: Option<Secret<Value>>,
+        settings: Settings,
+    }
+
+    #[derive(Serialize)]
+    struct Settings {
+        enabled: bool,
+        retries: u8,
+    }
+
+    fn connector_config(api_key: &str, retries: u8) -> ConnectorConfig {
+        ConnectorConfig {
+            name: "stripe".to_string(),
+            api_key: Secret::new(api_key.to_string()),
+            metadata: Some(Secret::new(serde_json::json!({ "account": api_key }))),
+            settings: Settings {
+                enabled: true,
+                retries,
+            },
+        }
+    }
+
+    #[test]
+    fn test_redacted_changes_mask_secrets() {
+        let before = connector_config("sk_old", 1);
+        let after = connector_config("sk_new", 2);
+
+        let changes = get_redacted_changes(Some(&before), Some(&after)).unwrap();
+        let serialized = Value::Object(changes.clone()).to_string();
+
+        assert_eq!(changes.len(), 3);
+        assert!(changes.contains_key("api_key"));
+        assert!(changes.contains_key("metadata.account"));
+        assert_eq!(
+            changes.get("settings.retries"),
+            Some(&serde_json::json!({ "before": 1, "after": 2 }))
+        );
+        assert!(!changes.contains_key("name"));
+        assert!(!serialized.contains("sk_old"));
+        assert!(!serialized.contains("sk_new"));
+    }
+
+    #[test]
+    fn test_redacted_changes_on_create_and_delete() {
+        let config = connector_config("sk_test", 1);
+
+        let created = get_redacted_changes(None, Some(&config)).unwrap();
+        assert_eq!(
+            created.get("name"),
+            Some(&serde_json::json!({ "before": null, "after": "stripe" }))
+        );
+        assert!(created.contains_key("settings.enabled"));
+
+        let deleted = get_redacted_changes(Some(&config), None).unwrap();
+        assert_eq!(
+            deleted.get("settings.retries"),
+            Some(&serde_json::json!({ "before": 1, "after": null }))
+        );
+        assert!(!Value::Object(deleted).to_string().contains("sk_test"));
+    }
+
+    #[test]
+    fn test_redacted_changes_redact_sensitive_fields() {
+        #[derive(Serialize)]
+        struct MerchantConfig {
+            payment_response_hash_key: Option<String>,
+            return_url: Option<String>,
+        }
+
+        let before = MerchantConfig {
+            payment_response_hash_key: Some("hash_key_old".to_string()),
+            return_url: None,
+        };
+        let after = MerchantConfig {
+            payment_response_hash_key: Some("hash_key_new".to_string()),
+            return_url: Some("https://example.com".to_string()),
+        };
+
+        let changes = get_redacted_changes(Some(&before), Some(&after)).unwrap();
+        let serialized = Value::Object(changes.clone()).to_string();
+
+        assert_eq!(
+            changes.get("payment_response_hash_key"),
+            Some(&serde_json::json!({
+                "before": CONFIG_AUDIT_REDACTED_VALUE,
+                "after": CONFIG_AUDIT_REDACTED_VALUE,
+            }))
+        );
+        assert!(changes.contains_key("return_url"));
+        assert!(!serialized.contains("hash_key_old"));
+        assert!(!serialized.contains("hash_key_new"));
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(
+            escape_csv_field(r#"{"a":"b","c":1}"#),
+            r#""{""a"":""b"",""c"":1}""#
+        );
+    }
+}
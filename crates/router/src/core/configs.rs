@@ -1,13 +1,20 @@
 use error_stack::ResultExt;
 
 use crate::{
-    core::errors::{self, utils::StorageErrorExt, RouterResponse},
-    routes::AppState,
+    core::{
+        config_audit,
+        errors::{self, utils::StorageErrorExt, RouterResponse},
+    },
+    routes::{app::ReqState, AppState},
     services::ApplicationResponse,
-    types::{api, transformers::ForeignInto},
+    types::{api, storage::enums as storage_enums, transformers::ForeignInto},
 };
 
-pub async fn set_config(state: AppState, config: api::Config) -> RouterResponse<api::Config> {
+pub async fn set_config(
+    state: AppState,
+    config: api::Config,
+    req_state: ReqState,
+) -> RouterResponse<api::Config> {
     let store = state.store.as_ref();
     let config = store
         .insert_config(diesel_models::configs::ConfigNew {
@@ -17,8 +24,23 @@ pub async fn set_config(state: AppState, config: api::Config) -> RouterResponse<
         .await
         .to_duplicate_response(errors::ApiErrorResponse::DuplicateConfig)
         .attach_printable("Unknown error, while setting config key")?;
+    let config: api::Config = config.foreign_into();
 
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: None,
+            resource_type: storage_enums::AuditResourceType::Config,
+            resource_id: &config.key,
+            action: storage_enums::AuditAction::Create,
+            before: None,
+            after: Some(&config),
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(config))
 }
 
 pub async fn read_config(state: AppState, key: &str) -> RouterResponse<api::Config> {
@@ -33,20 +55,62 @@ pub async fn read_config(state: AppState, key: &str) -> RouterResponse<api::Conf
 pub async fn update_config(
     state: AppState,
     config_update: &api::ConfigUpdate,
+    req_state: ReqState,
 ) -> RouterResponse<api::Config> {
     let store = state.store.as_ref();
-    let config = store
+    let config_before: api::Config = store
+        .find_config_by_key(&config_update.key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .foreign_into();
+    let config: api::Config = store
         .update_config_by_key(&config_update.key, config_update.foreign_into())
         .await
-        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?;
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .foreign_into();
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: None,
+            resource_type: storage_enums::AuditResourceType::Config,
+            resource_id: &config.key,
+            action: storage_enums::AuditAction::Update,
+            before: Some(&config_before),
+            after: Some(&config),
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(config))
 }
 
-pub async fn config_delete(state: AppState, key: String) -> RouterResponse<api::Config> {
+pub async fn config_delete(
+    state: AppState,
+    key: String,
+    req_state: ReqState,
+) -> RouterResponse<api::Config> {
     let store = state.store.as_ref();
-    let config = store
+    let config: api::Config = store
         .delete_config_by_key(&key)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?;
-    Ok(ApplicationResponse::Json(config.foreign_into()))
+        .to_not_found_response(errors::ApiErrorResponse::ConfigNotFound)?
+        .foreign_into();
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: None,
+            resource_type: storage_enums::AuditResourceType::Config,
+            resource_id: &key,
+            action: storage_enums::AuditAction::Delete,
+            before: Some(&config),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(config))
 }
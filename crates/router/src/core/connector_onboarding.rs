@@ -56,7 +56,7 @@ pub async fn sync_onboarding_status(
     state: AppState,
     user_from_token: auth::UserFromToken,
     request: api::OnboardingSyncRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> RouterResponse<api::OnboardingStatus> {
     utils::check_if_connector_exists(&state, &request.connector_id, &user_from_token.merchant_id)
         .await?;
@@ -89,6 +89,7 @@ pub async fn sync_onboarding_status(
                     user_from_token.merchant_id,
                     request.connector_id.to_owned(),
                     auth_details,
+                    req_state,
                 )
                 .await?;
 
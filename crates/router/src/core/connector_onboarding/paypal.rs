@@ -8,6 +8,7 @@ use crate::{
         admin,
         errors::{ApiErrorResponse, RouterResult},
     },
+    routes::app::ReqState,
     services::{send_request, ApplicationResponse, Request},
     types::{self as oss_types, api as oss_api_types, api::connector_onboarding as types},
     utils::connector_onboarding as utils,
@@ -143,6 +144,7 @@ pub async fn update_mca(
     merchant_id: String,
     connector_id: String,
     auth_details: oss_types::ConnectorAuthType,
+    req_state: ReqState,
 ) -> RouterResult<oss_api_types::MerchantConnectorResponse> {
     let connector_auth_json = auth_details
         .encode_to_value()
@@ -162,9 +164,14 @@ pub async fn update_mca(
         connector_webhook_details: None,
         pm_auth_config: None,
     };
-    let mca_response =
-        admin::update_payment_connector(state.clone(), &merchant_id, &connector_id, request)
-            .await?;
+    let mca_response = admin::update_payment_connector(
+        state.clone(),
+        &merchant_id,
+        &connector_id,
+        request,
+        req_state,
+    )
+    .await?;
 
     match mca_response {
         ApplicationResponse::Json(mca_data) => Ok(mca_data),
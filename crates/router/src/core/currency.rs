@@ -1,8 +1,14 @@
-use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use api_models::{currency as currency_types, enums};
+use common_utils::{errors::CustomResult, fp_utils::when, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::logger;
 
 use crate::{
-    core::errors::ApiErrorResponse,
+    consts,
+    core::{
+        cards_info,
+        errors::{ApiErrorResponse, RouterResponse, RouterResult},
+    },
     services::ApplicationResponse,
     utils::currency::{self, convert_currency, get_forex_rates},
     AppState,
@@ -28,6 +34,7 @@ pub async fn retrieve_forex(
 
 pub async fn convert_forex(
     state: AppState,
+    merchant_id: String,
     amount: i64,
     to_currency: String,
     from_currency: String,
@@ -38,6 +45,7 @@ pub async fn convert_forex(
     Ok(ApplicationResponse::Json(
         Box::pin(convert_currency(
             state.clone(),
+            &merchant_id,
             amount,
             to_currency,
             from_currency,
@@ -46,3 +54,262 @@ pub async fn convert_forex(
         .change_context(ApiErrorResponse::InternalServerError)?,
     ))
 }
+
+fn get_fx_quote_key(merchant_id: &str, quote_id: &str) -> String {
+    format!("fx_quote_{merchant_id}_{quote_id}")
+}
+
+/// Suggests the currency of the cardholder from the issuing country of the card in the BIN
+/// database
+async fn get_cardholder_currency(state: &AppState, card_bin: &str) -> Option<enums::Currency> {
+    let card_info = state
+        .store
+        .get_card_info(card_bin)
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch card information for the BIN"))
+        .ok()
+        .flatten()?;
+
+    card_info
+        .card_issuing_country
+        .as_deref()
+        .and_then(cards_info::parse_card_issuing_country)
+        .and_then(|country| country.to_alpha2().get_local_currency())
+}
+
+/// Quotes the amount of a payment in the currency of the cardholder, locking the exchange rate
+/// for the configured time to live
+pub async fn create_fx_quote(
+    state: AppState,
+    merchant_id: String,
+    request: currency_types::FxQuoteRequest,
+) -> RouterResponse<currency_types::FxQuoteResponse> {
+    let presentment_currency = match request.presentment_currency {
+        Some(presentment_currency) => presentment_currency,
+        None => {
+            let card_bin =
+                request
+                    .card_bin
+                    .as_deref()
+                    .ok_or(ApiErrorResponse::MissingRequiredField {
+                        field_name: "presentment_currency or card_bin",
+                    })?;
+            get_cardholder_currency(&state, card_bin).await.ok_or(
+                ApiErrorResponse::PreconditionFailed {
+                    message: "Unable to determine the currency of the cardholder from the card BIN"
+                        .to_string(),
+                },
+            )?
+        }
+    };
+    when(presentment_currency == request.currency, || {
+        Err(ApiErrorResponse::PreconditionFailed {
+            message: "The cardholder's currency is the same as the payment currency".to_string(),
+        })
+    })?;
+
+    let conversion = currency::convert_amount(
+        &state,
+        &merchant_id,
+        request.amount.get_amount_as_i64(),
+        request.currency,
+        presentment_currency,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the amount to the presentment currency")?;
+
+    let presentment_amount =
+        currency_conversion::conversion::to_minor_unit(conversion.amount, presentment_currency)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert the presentment amount to its minor unit")?;
+    let rate = conversion
+        .amount
+        .checked_div(currency_conversion::conversion::to_major_unit(
+            request.amount.get_amount_as_i64(),
+            request.currency,
+        ))
+        .ok_or(ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero".to_string(),
+        })?;
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let quote_ttl = if forex_api.quote_ttl > 0 {
+        forex_api.quote_ttl
+    } else {
+        consts::DEFAULT_FX_QUOTE_TTL_IN_SECS
+    };
+    let quote = currency_types::FxQuoteResponse {
+        quote_id: common_utils::generate_id(consts::ID_LENGTH, "fxq"),
+        amount: request.amount,
+        currency: request.currency,
+        presentment_amount: MinorUnit::new(presentment_amount),
+        presentment_currency,
+        rate: rate
+            .round_dp(consts::FX_QUOTE_RATE_DECIMAL_PLACES)
+            .to_string(),
+        markup_bps: conversion.markup_bps,
+        rate_source: conversion.rate_source.map(|source| source.to_string()),
+        rate_timestamp: conversion.rate_timestamp,
+        expires_at: common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(quote_ttl)),
+    };
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_fx_quote_key(&merchant_id, &quote.quote_id),
+            &quote,
+            quote_ttl,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the FX quote")?;
+
+    Ok(ApplicationResponse::Json(quote))
+}
+
+async fn find_fx_quote(
+    state: &AppState,
+    merchant_id: &str,
+    quote_id: &str,
+) -> RouterResult<currency_types::FxQuoteResponse> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key(&get_fx_quote_key(merchant_id, quote_id), "FxQuoteResponse")
+        .await
+        .change_context(ApiErrorResponse::GenericNotFoundError {
+            message: "FX quote not found or expired".to_string(),
+        })
+}
+
+pub async fn retrieve_fx_quote(
+    state: AppState,
+    merchant_id: String,
+    quote_id: String,
+) -> RouterResponse<currency_types::FxQuoteResponse> {
+    find_fx_quote(&state, &merchant_id, &quote_id)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+fn get_fx_quote_consumed_key(merchant_id: &str, quote_id: &str) -> String {
+    format!("fx_quote_consumed_{merchant_id}_{quote_id}")
+}
+
+/// Ensures the quote was issued for the net amount of the payment, including any surcharge, and
+/// for the currency of the payment
+fn validate_fx_quote_for_payment(
+    quote: &currency_types::FxQuoteResponse,
+    net_amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<()> {
+    when(
+        quote.amount != net_amount || quote.currency != currency,
+        || {
+            Err(report!(ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "FX quote {} was issued for {} {}, not the {} {} of the payment",
+                    quote.quote_id, quote.amount, quote.currency, net_amount, currency
+                ),
+            }))
+        },
+    )
+}
+
+/// Fetches the quote a payment is being confirmed with, ensuring it is still valid and was issued
+/// for the net amount and currency of the payment, and marks it as consumed by the payment so that
+/// it cannot be used to confirm any other payment
+pub async fn consume_fx_quote_for_payment(
+    state: &AppState,
+    merchant_id: &str,
+    quote_id: &str,
+    payment_id: &str,
+    net_amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<currency_types::FxQuoteResponse> {
+    let quote = find_fx_quote(state, merchant_id, quote_id).await?;
+    validate_fx_quote_for_payment(&quote, net_amount, currency)?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let consumed_key = get_fx_quote_consumed_key(merchant_id, quote_id);
+    let remaining_ttl = (quote.expires_at - common_utils::date_time::now())
+        .whole_seconds()
+        .max(1);
+
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(&consumed_key, payment_id, Some(remaining_ttl))
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the FX quote as consumed")?
+    {
+        redis_interface::SetnxReply::KeySet => Ok(quote),
+        redis_interface::SetnxReply::KeyNotSet => {
+            // Confirming the same payment again, for instance after a failed attempt, can reuse
+            // the quote it already consumed
+            let consumed_by = redis_conn
+                .get_key::<Option<String>>(&consumed_key)
+                .await
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment that consumed the FX quote")?;
+            when(consumed_by.as_deref() != Some(payment_id), || {
+                Err(report!(ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "FX quote {quote_id} has already been used by another payment"
+                    ),
+                }))
+            })?;
+            Ok(quote)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    fn get_fx_quote() -> currency_types::FxQuoteResponse {
+        currency_types::FxQuoteResponse {
+            quote_id: "fxq_test".to_string(),
+            amount: MinorUnit::new(6540),
+            currency: enums::Currency::USD,
+            presentment_amount: MinorUnit::new(6112),
+            presentment_currency: enums::Currency::EUR,
+            rate: "0.934557".to_string(),
+            markup_bps: 150,
+            rate_source: None,
+            rate_timestamp: 0,
+            expires_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_validate_fx_quote_for_payment() {
+        let quote = get_fx_quote();
+
+        assert!(
+            validate_fx_quote_for_payment(&quote, MinorUnit::new(6540), enums::Currency::USD)
+                .is_ok()
+        );
+        // The net amount includes the surcharge, which the quote must have been issued for
+        assert!(
+            validate_fx_quote_for_payment(&quote, MinorUnit::new(6740), enums::Currency::USD)
+                .is_err()
+        );
+        assert!(
+            validate_fx_quote_for_payment(&quote, MinorUnit::new(6540), enums::Currency::GBP)
+                .is_err()
+        );
+    }
+}
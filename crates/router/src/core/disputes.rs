@@ -1,27 +1,40 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::{Encode, ValueExt};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+    errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
     metrics,
 };
 use crate::{
-    core::{files, payments, utils as core_utils},
-    routes::AppState,
+    core::{files, payments, utils as core_utils, webhooks as webhooks_core},
+    db::StorageInterface,
+    routes::{metrics as router_metrics, AppState},
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
-        AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
-        DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
+        AcceptDisputeRequestData, AcceptDisputeResponse, BrowserInformation,
+        DefendDisputeRequestData, DefendDisputeResponse, SubmitEvidenceRequestData,
+        SubmitEvidenceResponse,
     },
 };
 
+const DISPUTE_DEADLINE_REMINDER_TAG: &str = "DISPUTE";
+const DISPUTE_DEADLINE_REMINDER_NAME: &str = "DISPUTE_DEADLINE_REMINDER";
+const DISPUTE_DEADLINE_REMINDER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow;
+
+const MAX_EXPIRING_SOON_WINDOW_DAYS: u32 = 90;
+
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
     state: AppState,
@@ -463,3 +476,449 @@ pub async fn delete_evidence(
         })?;
     Ok(services::ApplicationResponse::StatusOk)
 }
+
+#[instrument(skip(state))]
+pub async fn retrieve_disputes_expiring_soon(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: dispute_models::DisputeExpiringSoonConstraints,
+) -> RouterResponse<Vec<dispute_models::DisputeResponse>> {
+    common_utils::fp_utils::when(
+        constraints.within_days == 0 || constraints.within_days > MAX_EXPIRING_SOON_WINDOW_DAYS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "within_days must be between 1 and {MAX_EXPIRING_SOON_WINDOW_DAYS}"
+                ),
+            })
+        },
+    )?;
+
+    let now = date_time::now();
+    let deadline_before =
+        now.saturating_add(time::Duration::days(i64::from(constraints.within_days)));
+    let disputes = state
+        .store
+        .find_open_disputes_by_merchant_id_challenge_deadline(
+            &merchant_account.merchant_id,
+            now,
+            deadline_before,
+            constraints.limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes expiring soon")?;
+    let disputes_list = disputes
+        .into_iter()
+        .map(dispute_models::DisputeResponse::foreign_from)
+        .collect();
+    Ok(services::ApplicationResponse::Json(disputes_list))
+}
+
+/// Builds an evidence submission for the dispute from the data already held for the disputed
+/// payment, along with any evidence files attached to the dispute. The draft can be reviewed and
+/// submitted as is through the submit evidence API.
+#[instrument(skip(state))]
+pub async fn retrieve_evidence_draft(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::SubmitEvidenceRequest> {
+    let db = &*state.store;
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence cannot be drafted because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let billing_address = find_payment_address(
+        db,
+        &merchant_account,
+        &key_store,
+        &dispute.payment_id,
+        payment_attempt
+            .payment_method_billing_address_id
+            .as_deref()
+            .or(payment_intent.billing_address_id.as_deref()),
+    )
+    .await?;
+    let shipping_address = find_payment_address(
+        db,
+        &merchant_account,
+        &key_store,
+        &dispute.payment_id,
+        payment_intent.shipping_address_id.as_deref(),
+    )
+    .await?;
+
+    let customer = match payment_intent.customer_id.as_deref() {
+        Some(customer_id) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                &merchant_account.merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the customer of the disputed payment")?,
+        None => None,
+    };
+
+    let three_ds_result = match payment_attempt.authentication_id.clone() {
+        Some(authentication_id) => db
+            .find_authentication_by_merchant_id_authentication_id(
+                merchant_account.merchant_id.clone(),
+                authentication_id,
+            )
+            .await
+            .map_err(|error| {
+                logger::warn!(
+                    ?error,
+                    "Failed to fetch authentication of the disputed payment"
+                )
+            })
+            .ok()
+            .map(|authentication| transformers::get_three_ds_result(&authentication)),
+        None => None,
+    };
+    let additional_card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+    let three_ds_result = three_ds_result.or_else(|| {
+        additional_card_info
+            .as_ref()
+            .and_then(|card_info| card_info.authentication_data.as_ref())
+            .map(ToString::to_string)
+    });
+    let payment_checks = additional_card_info
+        .as_ref()
+        .and_then(|card_info| card_info.payment_checks.as_ref());
+
+    let customer_purchase_ip = payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<BrowserInformation>("BrowserInformation")
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    let customer_name = customer
+        .as_ref()
+        .and_then(|customer| customer.name.clone())
+        .map(|name| name.into_inner().expose())
+        .or_else(|| {
+            billing_address
+                .as_ref()
+                .and_then(api_models::payments::AddressDetails::get_optional_full_name)
+                .map(ExposeInterface::expose)
+        });
+    let customer_email_address = customer
+        .and_then(|customer| customer.email)
+        .map(|email| email.into_inner().expose());
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::SubmitEvidenceRequest {
+            dispute_id: dispute.dispute_id,
+            billing_address: billing_address
+                .as_ref()
+                .and_then(transformers::format_address),
+            shipping_address: shipping_address
+                .as_ref()
+                .and_then(transformers::format_address),
+            customer_name,
+            customer_email_address,
+            customer_purchase_ip,
+            product_description: payment_intent.description,
+            cancellation_policy: dispute_evidence.cancellation_policy,
+            customer_communication: dispute_evidence.customer_communication,
+            customer_signature: dispute_evidence.customer_signature,
+            receipt: dispute_evidence.receipt,
+            refund_policy: dispute_evidence.refund_policy,
+            service_documentation: dispute_evidence.service_documentation,
+            shipping_documentation: dispute_evidence.shipping_documentation,
+            invoice_showing_distinct_transactions: dispute_evidence
+                .invoice_showing_distinct_transactions,
+            recurring_transaction_agreement: dispute_evidence.recurring_transaction_agreement,
+            uncategorized_file: dispute_evidence.uncategorized_file,
+            uncategorized_text: transformers::get_evidence_statement(
+                three_ds_result,
+                payment_checks,
+            ),
+            ..Default::default()
+        },
+    ))
+}
+
+async fn find_payment_address(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    address_id: Option<&str>,
+) -> RouterResult<Option<api_models::payments::AddressDetails>> {
+    let Some(address_id) = address_id else {
+        return Ok(None);
+    };
+    db.find_address_by_merchant_id_payment_id_address_id(
+        &merchant_account.merchant_id,
+        payment_id,
+        address_id,
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while fetching the address of the disputed payment")
+    .map(|address| Some(address.into()))
+}
+
+/// Schedules reminders about the evidence submission deadline of an open dispute, replacing any
+/// reminders that were scheduled for a previous deadline of the dispute.
+///
+/// Failures are logged, since the dispute itself has already been recorded.
+#[instrument(skip_all)]
+pub async fn schedule_deadline_reminders(state: &AppState, dispute: &storage::Dispute) {
+    if let Err(error) = schedule_deadline_reminders_inner(state, dispute).await {
+        logger::error!(
+            dispute_id = %dispute.dispute_id,
+            ?error,
+            "Failed to schedule dispute deadline reminders"
+        );
+    }
+}
+
+async fn schedule_deadline_reminders_inner(
+    state: &AppState,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let is_open = dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened;
+    let Some(challenge_required_by) = dispute.challenge_required_by.filter(|_| is_open) else {
+        return Ok(());
+    };
+
+    let now = date_time::now();
+    // Only reminders that are still due are tracked, so that the retry count of the task always
+    // points at the next reminder to be sent
+    let reminder_days = transformers::get_due_reminder_days(
+        &state.conf.disputes.deadline_reminder_days,
+        challenge_required_by,
+        now,
+    );
+    let Some(schedule_time) = reminder_days
+        .first()
+        .map(|reminder_day| get_reminder_time(challenge_required_by, *reminder_day))
+    else {
+        return Ok(());
+    };
+
+    let tracking_data = storage::DisputeDeadlineReminderTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+        challenge_required_by,
+        reminder_days,
+    };
+    let db = state.store.as_ref();
+    let task_id = generate_task_id_for_deadline_reminder_workflow(&dispute.dispute_id);
+    let existing_task = db
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute deadline reminder task")?;
+
+    if existing_task.is_some() {
+        let tracking_data = tracking_data
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize dispute deadline reminder tracking data")?;
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: Some("Pending".to_string()),
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![task_id],
+            updated_process_tracker_data,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule dispute deadline reminder task")?;
+        router_metrics::TASKS_RESET_COUNT.add(
+            &router_metrics::CONTEXT,
+            1,
+            &[router_metrics::request::add_attributes(
+                "flow",
+                "DisputeDeadlineReminder",
+            )],
+        );
+    } else {
+        let process_tracker_entry = storage::ProcessTrackerNew::new(
+            task_id,
+            DISPUTE_DEADLINE_REMINDER_NAME,
+            DISPUTE_DEADLINE_REMINDER_RUNNER,
+            [DISPUTE_DEADLINE_REMINDER_TAG],
+            tracking_data,
+            schedule_time,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct dispute deadline reminder process tracker task")?;
+        db.insert_process(process_tracker_entry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to insert dispute deadline reminder task to process_tracker",
+            )?;
+        router_metrics::TASKS_ADDED_COUNT.add(
+            &router_metrics::CONTEXT,
+            1,
+            &[router_metrics::request::add_attributes(
+                "flow",
+                "DisputeDeadlineReminder",
+            )],
+        );
+    }
+
+    Ok(())
+}
+
+/// Notifies the merchant through an outgoing webhook that the evidence submission deadline of
+/// the dispute is approaching, `days_left` days before the deadline. Each reminder of a dispute is
+/// delivered as a separate event.
+pub async fn trigger_deadline_reminder_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+    days_left: u8,
+) -> RouterResult<()> {
+    let Some(profile_id) = dispute
+        .profile_id
+        .clone()
+        .or(merchant_account.default_profile.clone())
+    else {
+        logger::debug!(
+            dispute_id = %dispute.dispute_id,
+            "No business profile found for dispute; skipping outgoing webhook"
+        );
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+
+    webhooks_core::create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::DisputeDeadlineApproaching,
+        storage_enums::EventClass::Disputes,
+        dispute.dispute_id.clone(),
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(
+            dispute_models::DisputeResponse::foreign_from(dispute.clone()),
+        )),
+        Some(dispute.created_at),
+        get_deadline_reminder_idempotent_event_id(&dispute.dispute_id, days_left),
+    )
+    .await
+}
+
+#[inline]
+fn get_deadline_reminder_idempotent_event_id(dispute_id: &str, days_left: u8) -> String {
+    webhooks_core::utils::get_idempotent_event_id(
+        &format!("{dispute_id}_{days_left}"),
+        storage_enums::EventType::DisputeDeadlineApproaching,
+        storage_enums::WebhookDeliveryAttempt::InitialAttempt,
+    )
+}
+
+#[inline]
+pub fn get_reminder_time(
+    challenge_required_by: time::PrimitiveDateTime,
+    reminder_day: u8,
+) -> time::PrimitiveDateTime {
+    challenge_required_by.saturating_sub(time::Duration::days(i64::from(reminder_day)))
+}
+
+#[inline]
+fn generate_task_id_for_deadline_reminder_workflow(dispute_id: &str) -> String {
+    format!("{DISPUTE_DEADLINE_REMINDER_RUNNER}_{DISPUTE_DEADLINE_REMINDER_NAME}_{dispute_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deadline_reminder_idempotent_event_ids_are_distinct() {
+        let first_reminder = get_deadline_reminder_idempotent_event_id("dp_1", 7);
+        let second_reminder = get_deadline_reminder_idempotent_event_id("dp_1", 3);
+
+        assert_ne!(first_reminder, second_reminder);
+        // Repeating the same reminder is still deduplicated
+        assert_eq!(
+            first_reminder,
+            get_deadline_reminder_idempotent_event_id("dp_1", 7)
+        );
+    }
+}
@@ -1,13 +1,15 @@
 use api_models::disputes::EvidenceType;
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
+use masking::PeekInterface;
+use time::PrimitiveDateTime;
 
 use crate::{
     core::{errors, files::helpers::retrieve_file_and_provider_file_id_from_file_id},
     routes::AppState,
     types::{
         api::{self, DisputeEvidence},
-        domain,
+        domain, storage,
         transformers::ForeignFrom,
         SubmitEvidenceRequestData,
     },
@@ -392,3 +394,133 @@ pub async fn get_dispute_evidence_vec(
     }
     Ok(dispute_evidence_blocks)
 }
+
+/// Returns the configured reminder days whose reminders are still due before the deadline,
+/// ordered from the furthest to the nearest reminder
+pub fn get_due_reminder_days(
+    reminder_days: &[u8],
+    challenge_required_by: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> Vec<u8> {
+    let mut due_reminder_days: Vec<u8> = reminder_days
+        .iter()
+        .copied()
+        .filter(|reminder_day| super::get_reminder_time(challenge_required_by, *reminder_day) > now)
+        .collect();
+    due_reminder_days.sort_unstable_by(|a, b| b.cmp(a));
+    due_reminder_days.dedup();
+    due_reminder_days
+}
+
+pub fn format_address(address: &api_models::payments::AddressDetails) -> Option<String> {
+    let parts: Vec<String> = [
+        address.line1.as_ref().map(|line| line.peek().clone()),
+        address.line2.as_ref().map(|line| line.peek().clone()),
+        address.line3.as_ref().map(|line| line.peek().clone()),
+        address.city.clone(),
+        address.state.as_ref().map(|state| state.peek().clone()),
+        address.zip.as_ref().map(|zip| zip.peek().clone()),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|part| !part.trim().is_empty())
+    .collect();
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+pub fn get_three_ds_result(authentication: &storage::Authentication) -> String {
+    let mut three_ds_result = format!("status {}", authentication.authentication_status);
+    if let Some(trans_status) = &authentication.trans_status {
+        three_ds_result.push_str(&format!(", transaction status {trans_status}"));
+    }
+    if let Some(eci) = &authentication.eci {
+        three_ds_result.push_str(&format!(", ECI {eci}"));
+    }
+    three_ds_result
+}
+
+/// Summarizes the authentication and verification results of the disputed payment as an
+/// evidence statement
+pub fn get_evidence_statement(
+    three_ds_result: Option<String>,
+    payment_checks: Option<&serde_json::Value>,
+) -> Option<String> {
+    let statements: Vec<String> = [
+        three_ds_result.map(|result| format!("3D Secure authentication: {result}")),
+        payment_checks.map(|checks| format!("Address and card security code checks: {checks}")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    (!statements.is_empty()).then(|| statements.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_due_reminder_days_skips_past_reminders() {
+        let challenge_required_by = datetime!(2024-06-20 0:00);
+
+        assert_eq!(
+            get_due_reminder_days(
+                &[1, 7, 3, 3],
+                challenge_required_by,
+                datetime!(2024-06-10 0:00)
+            ),
+            vec![7, 3, 1]
+        );
+        assert_eq!(
+            get_due_reminder_days(
+                &[7, 3, 1],
+                challenge_required_by,
+                datetime!(2024-06-15 0:00)
+            ),
+            vec![3, 1]
+        );
+        assert!(get_due_reminder_days(
+            &[7, 3, 1],
+            challenge_required_by,
+            datetime!(2024-06-19 12:00)
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_format_address_skips_missing_parts() {
+        let address = api_models::payments::AddressDetails {
+            line1: Some(Secret::new("1467 Harrison Street".to_string())),
+            line2: Some(Secret::new(" ".to_string())),
+            city: Some("San Fransico".to_string()),
+            zip: Some(Secret::new("94122".to_string())),
+            country: Some(api_models::enums::CountryAlpha2::US),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_address(&address).unwrap(),
+            "1467 Harrison Street, San Fransico, 94122, US"
+        );
+        assert!(format_address(&api_models::payments::AddressDetails::default()).is_none());
+    }
+
+    #[test]
+    fn test_get_evidence_statement() {
+        let payment_checks = serde_json::json!({"cvc_check": "pass"});
+
+        assert_eq!(
+            get_evidence_statement(Some("status success".to_string()), Some(&payment_checks))
+                .unwrap(),
+            "3D Secure authentication: status success\nAddress and card security code checks: {\"cvc_check\":\"pass\"}"
+        );
+        assert!(get_evidence_statement(None, None).is_none());
+    }
+}
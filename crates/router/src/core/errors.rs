@@ -256,6 +256,30 @@ pub enum VaultError {
     GenerateFingerprintFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    #[error("Network tokenization is not supported for the card network")]
+    CardNetworkNotSupported,
+    #[error("Failed to send the request to the network token provider")]
+    ApiError,
+    #[error("Failed to deserialize the response of the network token provider")]
+    ResponseDeserializationFailed,
+    #[error("Failed to save the network token in card vault")]
+    SaveNetworkTokenFailed,
+    #[error("Failed to fetch the network token from card vault")]
+    FetchNetworkTokenFailed,
+    #[error("Failed to update the network token of the payment method")]
+    UpdatePaymentMethodFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CardAccountUpdaterError {
+    #[error("Failed to read the card account updates")]
+    FetchUpdatesFailed,
+    #[error("Failed to deserialize the card account updates")]
+    ResponseDeserializationFailed,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum AwsKmsError {
     #[error("Failed to base64 decode input data")]
@@ -294,6 +318,8 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Webhook endpoint has been disabled due to sustained delivery failures")]
+    WebhookEndpointDisabled,
 }
 
 impl WebhooksFlowError {
@@ -302,7 +328,8 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointDisabled => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
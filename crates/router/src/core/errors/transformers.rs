@@ -304,6 +304,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::ExtendedCardInfoNotFound => {
                 AER::NotFound(ApiError::new("IR", 27, "Extended card info does not exist", None))
             }
+            Self::ApiKeyAccessForbidden { message } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 28, message.to_string(), None))
+            }
         }
     }
 }
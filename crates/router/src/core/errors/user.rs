@@ -70,6 +70,16 @@ pub enum UserErrors {
     TotpNotSetup,
     #[error("InvalidTOTP")]
     InvalidTotp,
+    #[error("SsoNotConfigured")]
+    SsoNotConfigured,
+    #[error("SsoEnforced")]
+    SsoEnforced,
+    #[error("SsoAuthenticationFailed")]
+    SsoAuthenticationFailed,
+    #[error("SsoRoleMappingNotFound")]
+    SsoRoleMappingNotFound,
+    #[error("InvalidSsoConfig: {0}")]
+    InvalidSsoConfig(String),
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -179,6 +189,27 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::InvalidTotp => {
                 AER::BadRequest(ApiError::new(sub_code, 37, self.get_error_message(), None))
             }
+            Self::SsoNotConfigured => {
+                AER::BadRequest(ApiError::new(sub_code, 38, self.get_error_message(), None))
+            }
+            Self::SsoEnforced => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                39,
+                self.get_error_message(),
+                None,
+            )),
+            Self::SsoAuthenticationFailed => {
+                AER::Unauthorized(ApiError::new(sub_code, 40, self.get_error_message(), None))
+            }
+            Self::SsoRoleMappingNotFound => AER::ForbiddenCommonResource(ApiError::new(
+                sub_code,
+                41,
+                self.get_error_message(),
+                None,
+            )),
+            Self::InvalidSsoConfig(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 42, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -217,6 +248,11 @@ impl UserErrors {
             Self::RoleNameAlreadyExists => "Role name already exists",
             Self::TotpNotSetup => "TOTP not setup",
             Self::InvalidTotp => "Invalid TOTP",
+            Self::SsoNotConfigured => "SSO is not configured for this organization",
+            Self::SsoEnforced => "Sign in using SSO is required for this organization",
+            Self::SsoAuthenticationFailed => "SSO authentication failed",
+            Self::SsoRoleMappingNotFound => "None of the user's groups are mapped to a role",
+            Self::InvalidSsoConfig(error_message) => error_message,
         }
     }
 }
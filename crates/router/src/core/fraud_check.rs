@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
 use api_models::{admin::FrmConfigs, enums as api_enums};
 use common_enums::CaptureMethod;
@@ -18,6 +18,7 @@ use self::{
 };
 use super::errors::{ConnectorErrorExt, RouterResponse};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult},
         payments::{self, flows::ConstructFlowSpecificData, operations::BoxedOperation},
@@ -46,6 +47,8 @@ use crate::{
 };
 pub mod flows;
 pub mod operation;
+pub mod review_queue;
+pub mod rules;
 pub mod types;
 
 #[instrument(skip_all)]
@@ -67,16 +70,30 @@ where
     // To construct connector flow specific api
     dyn Connector: services::api::ConnectorIntegration<F, Req, frm_types::FraudCheckResponseData>,
 {
-    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
-        state,
-        merchant_account,
-        payment_data,
-        &frm_data.connector_details.connector_name,
-        None,
-        key_store,
-        false,
-    )
-    .await?;
+    let is_native_frm_engine =
+        frm_data.connector_details.connector_name == consts::NATIVE_FRM_ENGINE_NAME;
+    let merchant_connector_account = if is_native_frm_engine {
+        // The native rules engine has no connector account, it is evaluated in-process
+        payments::helpers::MerchantConnectorAccountType::CacheVal(
+            api_models::admin::MerchantConnectorDetails {
+                connector_account_details: masking::Secret::new(
+                    serde_json::json!({ "auth_type": "NoKey" }),
+                ),
+                metadata: None,
+            },
+        )
+    } else {
+        payments::construct_profile_id_and_get_mca(
+            state,
+            merchant_account,
+            payment_data,
+            &frm_data.connector_details.connector_name,
+            None,
+            key_store,
+            false,
+        )
+        .await?
+    };
 
     frm_data
         .payment_attempt
@@ -105,6 +122,24 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
+    if is_native_frm_engine {
+        // Rules are evaluated only in the checkout or sale step, the later steps carry forward the
+        // decision which has already been recorded on the fraud check
+        let response = if frm_data.fraud_check.last_step == FraudCheckLastStep::Processing {
+            rules::perform_frm_rules_evaluation(state, merchant_account, payment_data).await?
+        } else {
+            frm_types::FraudCheckResponseData::TransactionResponse {
+                resource_id: oss_types::ResponseId::NoResponseId,
+                status: frm_data.fraud_check.frm_status,
+                connector_metadata: frm_data.fraud_check.metadata.clone(),
+                reason: frm_data.fraud_check.frm_reason.clone(),
+                score: frm_data.fraud_check.frm_score,
+            }
+        };
+        router_data.response = Ok(response);
+        return Ok(router_data);
+    }
+
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
     let router_data_res = router_data
@@ -154,6 +189,36 @@ where
             .await
             .attach_printable("Could not find profile id from business details")?;
 
+            if rules::is_native_frm_engine(&frm_routing_algorithm_struct) {
+                return match rules::get_frm_rules(db, &merchant_account.merchant_id).await? {
+                    Some(frm_rules) => {
+                        // Fraud rules apply to every gateway and payment method, so the ones of
+                        // the payment are the ones the check is enabled for
+                        let frm_configs_object = FrmConfigsObject {
+                            frm_enabled_gateway: payment_data
+                                .payment_attempt
+                                .connector
+                                .as_deref()
+                                .and_then(|connector| {
+                                    api_enums::Connector::from_str(connector).ok()
+                                }),
+                            frm_enabled_pm: payment_data.payment_attempt.payment_method,
+                            frm_preferred_flow_type: frm_rules.flow,
+                        };
+                        Ok((
+                            true,
+                            Some(frm_routing_algorithm_struct),
+                            Some(profile_id.to_string()),
+                            Some(frm_configs_object),
+                        ))
+                    }
+                    None => {
+                        logger::debug!("Cannot find fraud rules for the native FRM engine");
+                        Ok((false, None, None, None))
+                    }
+                };
+            }
+
             let merchant_connector_account_from_db_option = db
                 .find_merchant_connector_account_by_profile_id_connector_name(
                     &profile_id,
@@ -531,6 +596,13 @@ where
                         frm_router_data.to_owned(),
                     )
                     .await?;
+                if matches!(frm_suggestion, Some(FrmSuggestion::FrmManualReview)) {
+                    review_queue::schedule_review_deadline(
+                        state,
+                        updated_frm_data.fraud_check.clone(),
+                    )
+                    .await;
+                }
                 return Ok(Some(updated_frm_data));
             }
         }
@@ -670,6 +742,13 @@ pub async fn frm_fulfillment_core(
                 .await
                 .change_context(invalid_request_error.to_owned())?;
             match existing_fraud_check {
+                Some(fraud_check) if fraud_check.frm_name == consts::NATIVE_FRM_ENGINE_NAME => {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Fulfillment is not supported for payments checked by fraud rules"
+                            .to_string(),
+                    }
+                    .into())
+                }
                 Some(fraud_check) => {
                     if (matches!(fraud_check.frm_transaction_type, FraudCheckType::PreFrm)
                         && fraud_check.last_step == FraudCheckLastStep::TransactionOrRecordRefund)
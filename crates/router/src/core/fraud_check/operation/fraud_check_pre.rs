@@ -106,6 +106,9 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
                     modified_at: common_utils::date_time::now(),
                     last_step: FraudCheckLastStep::Processing,
                     payment_capture_method: payment_data.payment_attempt.capture_method,
+                    review_assigned_to: None,
+                    review_deadline: None,
+                    review_decision: None,
                 })
                 .await
             }
@@ -0,0 +1,454 @@
+use api_models::frm_review::{
+    FrmReviewAssignRequest, FrmReviewPolicy, FrmReviewQueueConstraints, FrmReviewQueueItem,
+    FrmReviewQueueResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    fp_utils::when,
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
+
+use crate::{
+    core::{
+        config_audit,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, AppState},
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const FRM_REVIEW_DEADLINE_TAG: &str = "FRM_REVIEW";
+const FRM_REVIEW_DEADLINE_NAME: &str = "FRM_REVIEW_DEADLINE";
+const FRM_REVIEW_DEADLINE_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::FrmReviewDeadlineWorkflow;
+
+const MAX_REVIEW_QUEUE_LIMIT: u32 = 100;
+const MIN_REVIEW_WINDOW_IN_SECS: u32 = 60;
+const MAX_REVIEW_WINDOW_IN_SECS: u32 = 30 * 24 * 60 * 60;
+
+/// Provides the identifier for the specific merchant's manual review policy
+#[inline(always)]
+pub fn get_frm_review_policy_key(merchant_id: &str) -> String {
+    format!("frm_review_policy_{merchant_id}")
+}
+
+fn is_pending_review(fraud_check: &storage::FraudCheck) -> bool {
+    fraud_check.frm_status == storage_enums::FraudCheckStatus::ManualReview
+        && fraud_check.review_decision.is_none()
+}
+
+#[instrument(skip_all)]
+pub async fn list_review_queue(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: FrmReviewQueueConstraints,
+) -> RouterResponse<FrmReviewQueueResponse> {
+    let limit = constraints.limit.min(MAX_REVIEW_QUEUE_LIMIT);
+
+    let data: Vec<FrmReviewQueueItem> = state
+        .store
+        .list_pending_fraud_check_reviews(
+            &merchant_account.merchant_id,
+            constraints.assigned_to,
+            i64::from(limit),
+            i64::from(constraints.offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payments pending manual review")?
+        .into_iter()
+        .map(FrmReviewQueueItem::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(FrmReviewQueueResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn assign_reviewer(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_id: String,
+    request: FrmReviewAssignRequest,
+) -> RouterResponse<FrmReviewQueueItem> {
+    let db = state.store.as_ref();
+    let fraud_check = db
+        .find_fraud_check_by_payment_id(payment_id, merchant_account.merchant_id.clone())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    when(!is_pending_review(&fraud_check), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payment is not pending manual review".to_string(),
+        })
+    })?;
+
+    let fraud_check = db
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check,
+            storage::FraudCheckUpdate::ReviewAssignmentUpdate {
+                review_assigned_to: request.assigned_to,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to assign reviewer to the payment")?;
+
+    Ok(ApplicationResponse::Json(FrmReviewQueueItem::foreign_from(
+        fraud_check,
+    )))
+}
+
+pub async fn get_frm_review_policy(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<FrmReviewPolicy>> {
+    let policy = db
+        .find_config_by_key(&get_frm_review_policy_key(merchant_id))
+        .await;
+
+    match policy {
+        Ok(config) => config
+            .config
+            .parse_struct("FrmReviewPolicy")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse manual review policy")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch manual review policy"),
+    }
+}
+
+pub async fn upsert_frm_review_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    policy: FrmReviewPolicy,
+    req_state: ReqState,
+) -> RouterResponse<FrmReviewPolicy> {
+    let db = state.store.as_ref();
+
+    when(
+        !(MIN_REVIEW_WINDOW_IN_SECS..=MAX_REVIEW_WINDOW_IN_SECS)
+            .contains(&policy.review_window_in_secs),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "review_window_in_secs must be between {MIN_REVIEW_WINDOW_IN_SECS} and \
+                     {MAX_REVIEW_WINDOW_IN_SECS}"
+                ),
+            })
+        },
+    )?;
+
+    let key = get_frm_review_policy_key(&merchant_account.merchant_id);
+    let previous_policy = get_frm_review_policy(db, &merchant_account.merchant_id).await?;
+
+    let config = policy
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize manual review policy")?;
+
+    let action = if previous_policy.is_some() {
+        db.update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update manual review policy")?;
+        storage_enums::AuditAction::Update
+    } else {
+        db.insert_config(storage::ConfigNew {
+            key: key.clone(),
+            config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert manual review policy")?;
+        storage_enums::AuditAction::Create
+    };
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::FrmReviewPolicy,
+            resource_id: &key,
+            action,
+            before: previous_policy.as_ref(),
+            after: Some(&policy),
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(policy))
+}
+
+pub async fn retrieve_frm_review_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<FrmReviewPolicy> {
+    let policy = get_frm_review_policy(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Manual review policy was not found for the merchant")?;
+
+    Ok(ApplicationResponse::Json(policy))
+}
+
+/// Deletes the manual review policy of the merchant. Payments already scheduled for an automatic
+/// decision are decided as per the policy in effect when they were held for review.
+pub async fn delete_frm_review_policy(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req_state: ReqState,
+) -> RouterResponse<()> {
+    let key = get_frm_review_policy_key(&merchant_account.merchant_id);
+    let previous_policy = get_frm_review_policy(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Manual review policy was not found for the merchant")?;
+
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete manual review policy")?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::FrmReviewPolicy,
+            resource_id: &key,
+            action: storage_enums::AuditAction::Delete,
+            before: Some(&previous_policy),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Sets the review deadline of a payment held for manual review and schedules its automatic
+/// decision, if the merchant has configured a manual review policy.
+///
+/// Failures are logged and do not affect the payment.
+#[instrument(skip_all)]
+pub async fn schedule_review_deadline(state: &AppState, fraud_check: storage::FraudCheck) {
+    let result: RouterResult<()> = async {
+        let db = state.store.as_ref();
+        let Some(policy) = get_frm_review_policy(db, &fraud_check.merchant_id).await? else {
+            return Ok(());
+        };
+
+        let review_deadline = common_utils::date_time::now().saturating_add(
+            time::Duration::seconds(i64::from(policy.review_window_in_secs)),
+        );
+
+        let tracking_data = storage::FrmReviewDeadlineTrackingData {
+            merchant_id: fraud_check.merchant_id.clone(),
+            payment_id: fraud_check.payment_id.clone(),
+            expiry_decision: policy.expiry_action.into(),
+        };
+
+        let process_tracker_entry = storage::ProcessTrackerNew::new(
+            scheduler::utils::get_process_tracker_id(
+                FRM_REVIEW_DEADLINE_RUNNER,
+                FRM_REVIEW_DEADLINE_NAME,
+                &fraud_check.payment_id,
+                &fraud_check.merchant_id,
+            ),
+            FRM_REVIEW_DEADLINE_NAME,
+            FRM_REVIEW_DEADLINE_RUNNER,
+            [FRM_REVIEW_DEADLINE_TAG],
+            tracking_data,
+            review_deadline,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct manual review deadline process tracker task")?;
+
+        db.insert_process(process_tracker_entry)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert manual review deadline task to process_tracker")?;
+
+        db.update_fraud_check_response_with_attempt_id(
+            fraud_check.clone(),
+            storage::FraudCheckUpdate::ReviewDeadlineUpdate {
+                review_deadline: Some(review_deadline),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update review deadline of the fraud check")?;
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            payment_id = %fraud_check.payment_id,
+            ?error,
+            "Failed to schedule manual review deadline"
+        );
+    }
+}
+
+/// Records the decision taken on a payment held for manual review and notifies the merchant
+/// through an outgoing webhook. Payments that are not pending review are left untouched.
+///
+/// Failures are logged, since the payment itself has already been approved or rejected.
+#[instrument(skip_all)]
+pub async fn record_review_decision(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    decision: storage_enums::FrmReviewDecision,
+) {
+    if let Err(error) =
+        record_review_decision_inner(state, merchant_account, key_store, payment_id, decision).await
+    {
+        logger::error!(
+            %payment_id,
+            ?error,
+            "Failed to record manual review decision"
+        );
+    }
+}
+
+async fn record_review_decision_inner(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    decision: storage_enums::FrmReviewDecision,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let Some(fraud_check) = db
+        .find_fraud_check_by_payment_id_if_present(
+            payment_id.to_owned(),
+            merchant_account.merchant_id.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch fraud check")?
+        .filter(is_pending_review)
+    else {
+        return Ok(());
+    };
+
+    let fraud_check = db
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check,
+            storage::FraudCheckUpdate::ReviewDecisionUpdate {
+                review_decision: decision,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record manual review decision")?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let Some(profile_id) = payment_intent.profile_id else {
+        logger::debug!("No business profile found for the payment; skipping outgoing webhook");
+        return Ok(());
+    };
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+
+    let created_at = fraud_check.created_at;
+    let event_type = if decision.is_approved() {
+        storage_enums::EventType::FrmApproved
+    } else {
+        storage_enums::EventType::FrmRejected
+    };
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::FraudChecks,
+        fraud_check.payment_id.clone(),
+        storage_enums::EventObjectType::FraudCheckDetails,
+        api::OutgoingWebhookContent::FraudCheckDetails(Box::new(FrmReviewQueueItem::foreign_from(
+            fraud_check,
+        ))),
+        Some(created_at),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::frm_review::FrmReviewExpiryAction;
+
+    use super::*;
+
+    #[test]
+    fn test_expiry_action_maps_to_automatic_decision() {
+        assert_eq!(
+            storage_enums::FrmReviewDecision::from(FrmReviewExpiryAction::Approve),
+            storage_enums::FrmReviewDecision::AutoApproved
+        );
+        assert_eq!(
+            storage_enums::FrmReviewDecision::from(FrmReviewExpiryAction::Reject),
+            storage_enums::FrmReviewDecision::AutoRejected
+        );
+        assert!(storage_enums::FrmReviewDecision::AutoApproved.is_approved());
+        assert!(!storage_enums::FrmReviewDecision::Rejected.is_approved());
+    }
+
+    #[test]
+    fn test_review_policy_rejects_unknown_fields() {
+        let policy: FrmReviewPolicy =
+            serde_json::from_str(r#"{"review_window_in_secs":3600,"expiry_action":"approve"}"#)
+                .unwrap();
+        assert_eq!(policy.review_window_in_secs, 3600);
+        assert_eq!(policy.expiry_action, FrmReviewExpiryAction::Approve);
+
+        let policy = serde_json::from_str::<FrmReviewPolicy>(
+            r#"{"review_window_in_secs":3600,"expiry_action":"approve","notify":true}"#,
+        );
+        assert!(policy.is_err());
+    }
+}
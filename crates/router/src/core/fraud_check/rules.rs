@@ -0,0 +1,552 @@
+use api_models::{
+    enums as api_enums,
+    frm_rules::{
+        FrmRuleDecision, FrmRuleOutput, FrmRulesRecord, FrmRulesRequest, FrmRulesResponse,
+    },
+};
+use common_utils::ext_traits::{Encode, StringExt};
+use error_stack::ResultExt;
+use euclid::{
+    backend::{self, EuclidBackend},
+    frontend::ast,
+};
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
+
+use crate::{
+    consts,
+    core::{
+        blocklist::{utils as blocklist_utils, velocity},
+        config_audit,
+        errors::{self, RouterResponse, RouterResult},
+        payments::{self, routing::make_dsl_input},
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, AppState},
+    services::api as service_api,
+    types::{
+        api::routing::FrmRoutingAlgorithm,
+        domain,
+        fraud_check::FraudCheckResponseData,
+        storage::{self, enums as storage_enums},
+        ResponseId,
+    },
+};
+
+/// Provides the identifier for the specific merchant's native fraud rules
+#[inline(always)]
+pub fn get_frm_rules_key(merchant_id: &str) -> String {
+    format!("frm_rules_{merchant_id}")
+}
+
+/// Checks whether the FRM routing algorithm of the merchant points to the native rules engine
+pub fn is_native_frm_engine(frm_routing_algorithm: &FrmRoutingAlgorithm) -> bool {
+    frm_routing_algorithm.data == consts::NATIVE_FRM_ENGINE_NAME
+}
+
+pub async fn get_frm_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<FrmRulesRecord>> {
+    let frm_rules = db.find_config_by_key(&get_frm_rules_key(merchant_id)).await;
+
+    match frm_rules {
+        Ok(config) => config
+            .config
+            .parse_struct("FrmRulesRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse fraud rules")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch fraud rules"),
+    }
+}
+
+pub async fn upsert_frm_rules(
+    state: AppState,
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+    request: FrmRulesRequest,
+    req_state: ReqState,
+) -> RouterResponse<FrmRulesResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.merchant_id.as_str();
+
+    ast::lowering::lower_program(request.program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+
+    let key = get_frm_rules_key(merchant_id);
+    let activate = request.activate;
+    let previous_record = get_frm_rules(db, merchant_id).await?;
+    let record = build_frm_rules_record(
+        request,
+        previous_record.as_ref(),
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize fraud rules")?;
+
+    let action = if previous_record.is_some() {
+        db.update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update fraud rules")?;
+        storage_enums::AuditAction::Update
+    } else {
+        db.insert_config(storage::ConfigNew {
+            key: key.clone(),
+            config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert fraud rules")?;
+        storage_enums::AuditAction::Create
+    };
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::FrmRules,
+            resource_id: &key,
+            action,
+            before: previous_record.as_ref(),
+            after: Some(&record),
+        },
+    )
+    .await;
+
+    if should_activate_native_frm_engine(activate, merchant_account.frm_routing_algorithm.as_ref())
+    {
+        activate_native_frm_engine(db, &key_store).await?;
+    }
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+fn build_frm_rules_record(
+    request: FrmRulesRequest,
+    previous_record: Option<&FrmRulesRecord>,
+    timestamp: i64,
+) -> RouterResult<FrmRulesRecord> {
+    Ok(match previous_record {
+        Some(previous_record) => FrmRulesRecord {
+            name: request.name.unwrap_or_else(|| previous_record.name.clone()),
+            program: request.program,
+            flow: request.flow.unwrap_or_else(|| previous_record.flow.clone()),
+            created_at: previous_record.created_at,
+            modified_at: timestamp,
+        },
+        None => FrmRulesRecord {
+            name: request
+                .name
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField { field_name: "name" })?,
+            program: request.program,
+            flow: request
+                .flow
+                .unwrap_or(api_enums::FrmPreferredFlowTypes::Post),
+            created_at: timestamp,
+            modified_at: timestamp,
+        },
+    })
+}
+
+/// The native rules engine replaces the FRM connector of the merchant only when asked to
+fn should_activate_native_frm_engine(
+    activate: bool,
+    frm_routing_algorithm: Option<&serde_json::Value>,
+) -> bool {
+    activate
+        && !frm_routing_algorithm
+            .and_then(|value| serde_json::from_value::<FrmRoutingAlgorithm>(value.clone()).ok())
+            .is_some_and(|frm_routing_algorithm| is_native_frm_engine(&frm_routing_algorithm))
+}
+
+/// Points the FRM routing algorithm of the merchant to the native rules engine
+async fn activate_native_frm_engine(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let frm_routing_algorithm = FrmRoutingAlgorithm {
+        data: consts::NATIVE_FRM_ENGINE_NAME.to_string(),
+        algorithm_type: "single".to_string(),
+    }
+    .encode_to_value()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed converting frm routing algorithm to json value")?;
+
+    let merchant_account_update = storage::MerchantAccountUpdate::Update {
+        merchant_name: None,
+        merchant_details: None,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        payment_response_hash_key: None,
+        redirect_to_merchant_with_http_post: None,
+        publishable_key: None,
+        locker_id: None,
+        metadata: None,
+        routing_algorithm: None,
+        primary_business_details: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: Some(frm_routing_algorithm),
+        payout_routing_algorithm: None,
+        default_profile: None,
+        payment_link_config: None,
+    };
+
+    db.update_specific_fields_in_merchant(
+        &key_store.merchant_id,
+        merchant_account_update,
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update frm routing algorithm in merchant account")?;
+
+    Ok(())
+}
+
+pub async fn retrieve_frm_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<FrmRulesResponse> {
+    let record = get_frm_rules(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Fraud rules were not found for the merchant")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+/// Deletes the native fraud rules of the merchant, which disables fraud checks for the merchant
+/// while its FRM routing algorithm points to the native rules engine.
+pub async fn delete_frm_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req_state: ReqState,
+) -> RouterResponse<()> {
+    let key = get_frm_rules_key(&merchant_account.merchant_id);
+    let previous_record = get_frm_rules(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Fraud rules were not found for the merchant")?;
+
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete fraud rules")?;
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::FrmRules,
+            resource_id: &key,
+            action: storage_enums::AuditAction::Delete,
+            before: Some(&previous_record),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+pub fn get_frm_status_for_decision(decision: FrmRuleDecision) -> storage_enums::FraudCheckStatus {
+    match decision {
+        FrmRuleDecision::Accept => storage_enums::FraudCheckStatus::Legit,
+        FrmRuleDecision::Review => storage_enums::FraudCheckStatus::ManualReview,
+        FrmRuleDecision::Decline => storage_enums::FraudCheckStatus::Fraud,
+    }
+}
+
+/// Evaluates the native fraud rules of the merchant against the payment.
+///
+/// Apart from the payment attributes, the rules can match on the following metadata keys:
+/// `frm_blocklist_hit` (`true` or `false`), `frm_velocity_action` (the most severe action among
+/// the exceeded velocity rules, or `none`) and `frm_velocity_rule_<rule name>` (`exceeded`).
+#[instrument(skip_all)]
+pub async fn perform_frm_rules_evaluation<F>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &payments::PaymentData<F>,
+) -> RouterResult<FraudCheckResponseData>
+where
+    F: Clone,
+{
+    let merchant_id = merchant_account.merchant_id.as_str();
+    let Some(record) = get_frm_rules(&*state.store, merchant_id).await? else {
+        logger::warn!("Fraud rules not found, accepting the payment");
+        return Ok(FraudCheckResponseData::TransactionResponse {
+            resource_id: ResponseId::NoResponseId,
+            status: get_frm_status_for_decision(FrmRuleDecision::Accept),
+            connector_metadata: None,
+            reason: None,
+            score: None,
+        });
+    };
+
+    let fingerprints =
+        blocklist_utils::generate_payment_fingerprints(state, merchant_id, payment_data).await?;
+    let is_blocklisted =
+        blocklist_utils::is_payment_blocklisted(state, merchant_id, &fingerprints).await;
+
+    // Counters already account for the payment when the blocklist guard recorded it
+    let is_payment_recorded = blocklist_utils::is_blocklist_guard_enabled(state, merchant_id).await;
+    let exceeded_velocity_rules = velocity::get_exceeded_velocity_rules(
+        state,
+        merchant_id,
+        &fingerprints.get_velocity_check_data(
+            payment_data.payment_intent.customer_id.as_deref(),
+            payment_data.payment_attempt.amount,
+        ),
+        is_payment_recorded,
+    )
+    .await;
+    let velocity_action = exceeded_velocity_rules
+        .iter()
+        .map(|rule| rule.action)
+        .max()
+        .map_or_else(|| "none".to_string(), |action| action.to_string());
+
+    let mut backend_input = make_dsl_input(payment_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct input for fraud rules")?;
+    backend_input
+        .metadata
+        .get_or_insert_with(Default::default)
+        .extend(
+            [
+                ("frm_blocklist_hit".to_string(), is_blocklisted.to_string()),
+                ("frm_velocity_action".to_string(), velocity_action.clone()),
+            ]
+            .into_iter()
+            .chain(exceeded_velocity_rules.iter().map(|rule| {
+                (
+                    format!("frm_velocity_rule_{}", rule.name),
+                    "exceeded".to_string(),
+                )
+            })),
+        );
+
+    let interpreter: backend::VirInterpreterBackend<FrmRuleOutput> =
+        backend::VirInterpreterBackend::with_program(record.program)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error initializing fraud rules interpreter")?;
+    let output = interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing fraud rules")?;
+    let decision = output.connector_selection.decision;
+
+    logger::debug!(
+        rule_name = ?output.rule_name,
+        %decision,
+        is_blocklisted,
+        %velocity_action,
+        "Evaluated fraud rules"
+    );
+
+    Ok(FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status: get_frm_status_for_decision(decision),
+        connector_metadata: Some(serde_json::json!({
+            "engine": consts::NATIVE_FRM_ENGINE_NAME,
+            "rules_name": record.name,
+            "matched_rule": output.rule_name,
+            "decision": decision,
+            "blocklist_hit": is_blocklisted,
+            "exceeded_velocity_rules": exceeded_velocity_rules
+                .iter()
+                .map(|rule| rule.name.as_str())
+                .collect::<Vec<_>>(),
+        })),
+        reason: output.rule_name.map(serde_json::Value::String),
+        score: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_frm_status_for_decision() {
+        assert_eq!(
+            get_frm_status_for_decision(FrmRuleDecision::Accept),
+            storage_enums::FraudCheckStatus::Legit
+        );
+        assert_eq!(
+            get_frm_status_for_decision(FrmRuleDecision::Review),
+            storage_enums::FraudCheckStatus::ManualReview
+        );
+        assert_eq!(
+            get_frm_status_for_decision(FrmRuleDecision::Decline),
+            storage_enums::FraudCheckStatus::Fraud
+        );
+    }
+
+    #[test]
+    fn test_is_native_frm_engine() {
+        let native = FrmRoutingAlgorithm {
+            data: consts::NATIVE_FRM_ENGINE_NAME.to_string(),
+            algorithm_type: "single".to_string(),
+        };
+        assert!(is_native_frm_engine(&native));
+
+        let signifyd = FrmRoutingAlgorithm {
+            data: "signifyd".to_string(),
+            algorithm_type: "single".to_string(),
+        };
+        assert!(!is_native_frm_engine(&signifyd));
+    }
+
+    fn blocklist_program() -> ast::Program<FrmRuleOutput> {
+        serde_json::from_value(serde_json::json!({
+            "defaultSelection": { "decision": "accept" },
+            "rules": [{
+                "name": "decline_blocklisted",
+                "connectorSelection": { "decision": "decline" },
+                "statements": [{
+                    "condition": [{
+                        "lhs": "metadata",
+                        "comparison": "equal",
+                        "value": {
+                            "type": "metadata_variant",
+                            "value": { "key": "frm_blocklist_hit", "value": "true" }
+                        },
+                        "metadata": {}
+                    }],
+                    "nested": null
+                }]
+            }],
+            "metadata": {}
+        }))
+        .unwrap()
+    }
+
+    fn frm_rules_request(
+        name: Option<&str>,
+        flow: Option<api_enums::FrmPreferredFlowTypes>,
+    ) -> FrmRulesRequest {
+        FrmRulesRequest {
+            name: name.map(str::to_string),
+            program: blocklist_program(),
+            flow,
+            activate: false,
+        }
+    }
+
+    #[test]
+    fn test_build_frm_rules_record() {
+        let record =
+            build_frm_rules_record(frm_rules_request(Some("rules"), None), None, 10).unwrap();
+        assert_eq!(record.name, "rules");
+        assert!(matches!(
+            record.flow,
+            api_enums::FrmPreferredFlowTypes::Post
+        ));
+        assert_eq!((record.created_at, record.modified_at), (10, 10));
+
+        let updated = build_frm_rules_record(
+            frm_rules_request(None, Some(api_enums::FrmPreferredFlowTypes::Pre)),
+            Some(&record),
+            20,
+        )
+        .unwrap();
+        assert_eq!(updated.name, "rules");
+        assert!(matches!(
+            updated.flow,
+            api_enums::FrmPreferredFlowTypes::Pre
+        ));
+        assert_eq!((updated.created_at, updated.modified_at), (10, 20));
+
+        assert!(build_frm_rules_record(frm_rules_request(None, None), None, 10).is_err());
+    }
+
+    #[test]
+    fn test_should_activate_native_frm_engine() {
+        let native = serde_json::json!({
+            "data": consts::NATIVE_FRM_ENGINE_NAME,
+            "type": "single",
+        });
+        let signifyd = serde_json::json!({ "data": "signifyd", "type": "single" });
+
+        assert!(!should_activate_native_frm_engine(false, None));
+        assert!(!should_activate_native_frm_engine(false, Some(&signifyd)));
+        assert!(should_activate_native_frm_engine(true, None));
+        assert!(should_activate_native_frm_engine(true, Some(&signifyd)));
+        assert!(!should_activate_native_frm_engine(true, Some(&native)));
+    }
+
+    #[test]
+    fn test_frm_rules_decision_on_metadata() {
+        let interpreter: backend::VirInterpreterBackend<FrmRuleOutput> =
+            backend::VirInterpreterBackend::with_program(blocklist_program()).unwrap();
+        let input = |is_blocklisted: bool| backend::inputs::BackendInput {
+            metadata: Some(
+                [("frm_blocklist_hit".to_string(), is_blocklisted.to_string())]
+                    .into_iter()
+                    .collect(),
+            ),
+            payment: backend::inputs::PaymentInput {
+                amount: 100,
+                currency: api_enums::Currency::USD,
+                authentication_type: None,
+                card_bin: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: backend::inputs::PaymentMethodInput {
+                payment_method: None,
+                payment_method_type: None,
+                card_network: None,
+                card_issuer: None,
+                card_issuing_country: None,
+                card_subtype: None,
+            },
+            mandate: backend::inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+
+        let output = interpreter.execute(input(true)).unwrap();
+        assert_eq!(
+            output.connector_selection.decision,
+            FrmRuleDecision::Decline
+        );
+        assert_eq!(output.rule_name.as_deref(), Some("decline_blocklisted"));
+
+        let output = interpreter.execute(input(false)).unwrap();
+        assert_eq!(output.connector_selection.decision, FrmRuleDecision::Accept);
+        assert_eq!(output.rule_name, None);
+    }
+}
@@ -1,31 +1,65 @@
 use api_models::gsm as gsm_api_types;
 use diesel_models::gsm as storage;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
-        errors,
+        config_audit, errors,
         errors::{RouterResponse, StorageErrorExt},
     },
     db::gsm::GsmInterface,
+    routes::app::ReqState,
     services,
-    types::transformers::ForeignInto,
+    types::{storage::enums as storage_enums, transformers::ForeignInto},
     AppState,
 };
 
+fn get_gsm_rule_audit_id(
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+    code: &str,
+    message: &str,
+) -> String {
+    format!("{connector}:{flow}:{sub_flow}:{code}:{message}")
+}
+
 #[instrument(skip_all)]
 pub async fn create_gsm_rule(
     state: AppState,
     gsm_rule: gsm_api_types::GsmCreateRequest,
+    req_state: ReqState,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
-    GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
+    let gsm: gsm_api_types::GsmResponse = GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: "GSM with given key already exists in our records".to_string(),
-        })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+        })?
+        .foreign_into();
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: None,
+            resource_type: storage_enums::AuditResourceType::Gsm,
+            resource_id: &get_gsm_rule_audit_id(
+                &gsm.connector,
+                &gsm.flow,
+                &gsm.sub_flow,
+                &gsm.code,
+                &gsm.message,
+            ),
+            action: storage_enums::AuditAction::Create,
+            before: None,
+            after: Some(&gsm),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
@@ -53,6 +87,7 @@ pub async fn retrieve_gsm_rule(
 pub async fn update_gsm_rule(
     state: AppState,
     gsm_request: gsm_api_types::GsmUpdateRequest,
+    req_state: ReqState,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
     let gsm_api_types::GsmUpdateRequest {
@@ -68,7 +103,23 @@ pub async fn update_gsm_rule(
         unified_code,
         unified_message,
     } = gsm_request;
-    GsmInterface::update_gsm_rule(
+    let resource_id =
+        get_gsm_rule_audit_id(&connector.to_string(), &flow, &sub_flow, &code, &message);
+    let gsm_before: gsm_api_types::GsmResponse = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?
+    .foreign_into();
+
+    let gsm: gsm_api_types::GsmResponse = GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
         flow,
@@ -88,14 +139,31 @@ pub async fn update_gsm_rule(
     .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
         message: "GSM with given key does not exist in our records".to_string(),
     })
-    .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .attach_printable("Failed while updating Gsm rule")?
+    .foreign_into();
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: None,
+            resource_type: storage_enums::AuditResourceType::Gsm,
+            resource_id: &resource_id,
+            action: storage_enums::AuditAction::Update,
+            before: Some(&gsm_before),
+            after: Some(&gsm),
+        },
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm))
 }
 
 #[instrument(skip_all)]
 pub async fn delete_gsm_rule(
     state: AppState,
     gsm_request: gsm_api_types::GsmDeleteRequest,
+    req_state: ReqState,
 ) -> RouterResponse<gsm_api_types::GsmDeleteResponse> {
     let db = state.store.as_ref();
     let gsm_api_types::GsmDeleteRequest {
@@ -105,6 +173,19 @@ pub async fn delete_gsm_rule(
         code,
         message,
     } = gsm_request;
+    let gsm_before: Option<gsm_api_types::GsmResponse> = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch GSM rule for audit"))
+    .ok()
+    .map(ForeignInto::foreign_into);
+
     match GsmInterface::delete_gsm_rule(
         db,
         connector.to_string(),
@@ -121,6 +202,26 @@ pub async fn delete_gsm_rule(
     {
         Ok(is_deleted) => {
             if is_deleted {
+                config_audit::record_config_change(
+                    &state,
+                    &req_state,
+                    config_audit::ConfigChange {
+                        merchant_id: None,
+                        resource_type: storage_enums::AuditResourceType::Gsm,
+                        resource_id: &get_gsm_rule_audit_id(
+                            &connector.to_string(),
+                            &flow,
+                            &sub_flow,
+                            &code,
+                            &message,
+                        ),
+                        action: storage_enums::AuditAction::Delete,
+                        before: gsm_before.as_ref(),
+                        after: None,
+                    },
+                )
+                .await;
+
                 Ok(services::ApplicationResponse::Json(
                     gsm_api_types::GsmDeleteResponse {
                         gsm_rule_delete: true,
@@ -0,0 +1,284 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        merchant_key_rotation::re_encrypt,
+    },
+    db::StorageInterface,
+    types::storage,
+};
+
+/// Number of key stores fetched and re-wrapped together, unless overridden
+pub const MASTER_KEY_ROTATION_DEFAULT_BATCH_SIZE: i64 = 100;
+
+#[derive(Debug, Default)]
+pub struct MasterKeyRotationSummary {
+    /// Number of merchant key stores re-wrapped with the current master key
+    pub merchant_key_stores_re_wrapped: u64,
+    /// Number of user key stores re-wrapped with the current master key
+    pub user_key_stores_re_wrapped: u64,
+    /// Number of key stores that were modified while being re-wrapped. Such key stores have been
+    /// written by the application with the current master key, and need not be re-wrapped.
+    pub key_stores_skipped: u64,
+}
+
+/// Re-wraps every merchant key store and user key store with the current master key.
+///
+/// The rotation is safe to run while the application is serving traffic, provided the
+/// application is configured with the new key as `master_enc_key` and the replaced key as
+/// `previous_master_enc_key`: key stores that have not been re-wrapped yet are decrypted with the
+/// previous master key, and key stores written meanwhile are never overwritten. Key stores that
+/// are already wrapped with the current master key are left untouched, so the rotation can be
+/// re-run after a failure. Once it completes, `previous_master_enc_key` can be removed from the
+/// configuration.
+#[instrument(skip_all)]
+pub async fn rotate_master_key(
+    db: &dyn StorageInterface,
+    previous_master_key: &[u8],
+    batch_size: i64,
+) -> RouterResult<MasterKeyRotationSummary> {
+    let master_key = db.get_master_key();
+    let mut summary = MasterKeyRotationSummary::default();
+
+    let mut last_merchant_id = None;
+    loop {
+        let key_stores = db
+            .list_merchant_key_stores_after_merchant_id(last_merchant_id.clone(), batch_size)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant key stores to re-wrap")?;
+
+        let is_last_batch = i64::try_from(key_stores.len())
+            .map(|key_stores_count| key_stores_count < batch_size)
+            .unwrap_or(false);
+        last_merchant_id = key_stores
+            .last()
+            .map(|key_store| key_store.merchant_id.clone())
+            .or(last_merchant_id);
+
+        for key_store in key_stores {
+            let merchant_id = key_store.merchant_id;
+            let key = storage::EncryptedColumn {
+                updated: re_encrypt(&key_store.key, previous_master_key, master_key)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to re-wrap key of merchant {merchant_id}")
+                    })?,
+                original: key_store.key,
+            };
+            let previous_key = match key_store.previous_key {
+                Some(previous_key) => Some(storage::EncryptedColumn {
+                    updated: re_encrypt(&previous_key, previous_master_key, master_key)
+                        .attach_printable_lazy(|| {
+                            format!("Failed to re-wrap previous key of merchant {merchant_id}")
+                        })?,
+                    original: previous_key,
+                }),
+                None => None,
+            };
+
+            let is_modified = key.updated != key.original
+                || previous_key
+                    .as_ref()
+                    .is_some_and(|previous_key| previous_key.updated != previous_key.original);
+            if !is_modified {
+                continue;
+            }
+
+            let is_updated = db
+                .update_merchant_key_store_master_key_wrapping(&merchant_id, key, previous_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Failed to update key store of merchant {merchant_id}")
+                })?;
+
+            if is_updated {
+                summary.merchant_key_stores_re_wrapped += 1;
+            } else {
+                logger::debug!(%merchant_id, "Skipped merchant key store modified during rotation");
+                summary.key_stores_skipped += 1;
+            }
+        }
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    let mut last_user_id = None;
+    loop {
+        let key_stores = db
+            .list_user_key_stores_after_user_id(last_user_id.clone(), batch_size)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch user key stores to re-wrap")?;
+
+        let is_last_batch = i64::try_from(key_stores.len())
+            .map(|key_stores_count| key_stores_count < batch_size)
+            .unwrap_or(false);
+        last_user_id = key_stores
+            .last()
+            .map(|key_store| key_store.user_id.clone())
+            .or(last_user_id);
+
+        for key_store in key_stores {
+            let user_id = key_store.user_id;
+            let key = storage::EncryptedColumn {
+                updated: re_encrypt(&key_store.key, previous_master_key, master_key)
+                    .attach_printable_lazy(|| format!("Failed to re-wrap key of user {user_id}"))?,
+                original: key_store.key,
+            };
+
+            if key.updated == key.original {
+                continue;
+            }
+
+            let is_updated = db
+                .update_user_key_store_master_key_wrapping(&user_id, key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Failed to update key store of user {user_id}")
+                })?;
+
+            if is_updated {
+                summary.user_key_stores_re_wrapped += 1;
+            } else {
+                logger::debug!(%user_id, "Skipped user key store modified during rotation");
+                summary.key_stores_skipped += 1;
+            }
+        }
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use common_utils::crypto::{DecodeMessage, EncodeMessage, GcmAes256};
+    use diesel_models::{
+        encryption::Encryption, merchant_key_store::MerchantKeyStore, user_key_store::UserKeyStore,
+    };
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::MasterKeyInterface;
+
+    #[tokio::test]
+    async fn test_rotate_master_key_re_wraps_key_stores() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let previous_master_key = [7_u8; 32];
+        let master_key = db.get_master_key().to_vec();
+        let merchant_key = [42_u8; 32];
+
+        let wrap = |key: &[u8]| {
+            Encryption::new(
+                GcmAes256
+                    .encode_message(key, &merchant_key)
+                    .expect("Failed to wrap key")
+                    .into(),
+            )
+        };
+
+        let now = common_utils::date_time::now();
+        db.merchant_key_store.lock().await.extend([
+            MerchantKeyStore {
+                merchant_id: "merchant_old".to_string(),
+                key: wrap(&previous_master_key),
+                created_at: now,
+                key_version: 2,
+                previous_key: Some(wrap(&previous_master_key)),
+                previous_key_version: Some(1),
+            },
+            MerchantKeyStore {
+                merchant_id: "merchant_new".to_string(),
+                key: wrap(&master_key),
+                created_at: now,
+                key_version: 1,
+                previous_key: None,
+                previous_key_version: None,
+            },
+        ]);
+        db.user_key_store.lock().await.push(UserKeyStore {
+            user_id: "user_old".to_string(),
+            key: wrap(&previous_master_key),
+            created_at: now,
+        });
+
+        let summary = rotate_master_key(&db, &previous_master_key, 1)
+            .await
+            .expect("Failed to rotate master key");
+
+        assert_eq!(summary.merchant_key_stores_re_wrapped, 1);
+        assert_eq!(summary.user_key_stores_re_wrapped, 1);
+        assert_eq!(summary.key_stores_skipped, 0);
+
+        let unwrap = |key: &Encryption| {
+            GcmAes256
+                .decode_message(&master_key, key.get_inner().clone())
+                .expect("Key store was not re-wrapped with the current master key")
+        };
+        for key_store in db.merchant_key_store.lock().await.iter() {
+            assert_eq!(unwrap(&key_store.key), merchant_key);
+            if let Some(previous_key) = &key_store.previous_key {
+                assert_eq!(unwrap(previous_key), merchant_key);
+            }
+        }
+        for key_store in db.user_key_store.lock().await.iter() {
+            assert_eq!(unwrap(&key_store.key), merchant_key);
+        }
+
+        // Key stores already wrapped with the current master key are left untouched
+        let summary = rotate_master_key(&db, &previous_master_key, 1)
+            .await
+            .expect("Failed to rotate master key");
+        assert_eq!(summary.merchant_key_stores_re_wrapped, 0);
+        assert_eq!(summary.user_key_stores_re_wrapped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_key_store_wrapped_with_previous_master_key_is_read() {
+        use masking::PeekInterface;
+
+        use crate::types::domain::{self, behaviour::ReverseConversion};
+
+        let previous_master_key = [7_u8; 32];
+        let master_key = [9_u8; 32];
+        let merchant_key = [42_u8; 32];
+        let key_store = MerchantKeyStore {
+            merchant_id: "merchant_old".to_string(),
+            key: Encryption::new(
+                GcmAes256
+                    .encode_message(&previous_master_key, &merchant_key)
+                    .unwrap()
+                    .into(),
+            ),
+            created_at: common_utils::date_time::now(),
+            key_version: 1,
+            previous_key: None,
+            previous_key_version: None,
+        };
+
+        let result: Result<domain::MerchantKeyStore, _> =
+            key_store.clone().convert(&master_key.to_vec().into()).await;
+        assert!(result.is_err());
+
+        let key_store: domain::MerchantKeyStore = key_store
+            .convert_with_previous_key(
+                &master_key.to_vec().into(),
+                Some(&previous_master_key.to_vec().into()),
+            )
+            .await
+            .expect("Failed to read key store with the previous master key");
+        assert_eq!(key_store.key.get_inner().peek(), &merchant_key.to_vec());
+    }
+}
@@ -0,0 +1,840 @@
+use api_models::admin as admin_types;
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, Encryptable, GcmAes256},
+    date_time,
+    fp_utils::when,
+};
+use diesel_models::encryption::Encryption;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, AppState},
+    services::{self, ApplicationResponse},
+    types::{
+        domain::{self, types as domain_types},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const KEY_ROTATION_TAG: &str = "MERCHANT_KEY";
+const KEY_ROTATION_NAME: &str = "MERCHANT_KEY_ROTATION";
+const KEY_ROTATION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::MerchantKeyRotationWorkflow;
+
+/// Number of records of an entity fetched and re-encrypted together
+const KEY_ROTATION_BATCH_SIZE: i64 = 100;
+/// Number of batches processed in a single run of the key rotation workflow, after which the
+/// workflow is rescheduled so that other tasks get a chance to run
+const KEY_ROTATION_BATCHES_PER_RUN: usize = 10;
+/// Number of consecutive failed runs after which the key rotation is marked as failed
+pub const KEY_ROTATION_MAX_RETRIES: i32 = 5;
+const KEY_ROTATION_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+/// Generates a new data encryption key for the merchant and schedules the re-encryption of all
+/// data encrypted with the current key. Data written after this point is encrypted with the new
+/// key, while data not yet re-encrypted continues to be decrypted with the previous key.
+#[instrument(skip_all)]
+pub async fn rotate_merchant_key(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<admin_types::MerchantKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let master_key = db.get_master_key();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &master_key.to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let latest_rotation_status = db
+        .find_latest_merchant_key_rotation_by_merchant_id(&merchant_id)
+        .await
+        .map(|rotation| Some(rotation.status))
+        .or_else(|error| {
+            if error.current_context().is_db_not_found() {
+                Ok(None)
+            } else {
+                Err(error)
+            }
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch latest merchant key rotation")?;
+
+    when(
+        key_store.previous_key.is_some()
+            || latest_rotation_status == Some(enums::KeyRotationStatus::InProgress),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A key rotation is already in progress for the merchant".to_string(),
+            })
+        },
+    )?;
+
+    let key = services::generate_aes256_key()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to generate aes 256 key")?;
+    let key = domain_types::encrypt(key.to_vec().into(), master_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt merchant key")?;
+
+    let from_key_version = key_store.key_version;
+    let to_key_version = from_key_version + 1;
+
+    // The rotation and its task are created before the key is switched, so that data is never
+    // left encrypted with a key that no rotation is going to retire
+    let now = date_time::now();
+    let rotation = db
+        .insert_merchant_key_rotation(storage::MerchantKeyRotationNew {
+            rotation_id: utils::generate_id(consts::ID_LENGTH, "key_rotation"),
+            merchant_id: merchant_id.clone(),
+            from_key_version,
+            to_key_version,
+            status: enums::KeyRotationStatus::InProgress,
+            current_entity: Some(enums::KeyRotationEntity::MerchantAccount),
+            last_processed_id: None,
+            records_re_encrypted: 0,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert merchant key rotation")?;
+
+    add_key_rotation_task(db, &rotation).await?;
+
+    let key_store_update_result = db
+        .update_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            storage::MerchantKeyStoreUpdate::KeyRotated {
+                key: key.into(),
+                key_version: to_key_version,
+                previous_key: key_store.key.into(),
+                previous_key_version: from_key_version,
+            },
+            &master_key.to_vec().into(),
+        )
+        .await;
+
+    if let Err(error) = key_store_update_result {
+        // The task finishes without re-encrypting anything once the rotation has failed
+        fail_key_rotation(db, &rotation, error.to_string()).await?;
+        return Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update merchant key store with the new key");
+    }
+
+    Ok(ApplicationResponse::Json(rotation.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_key_rotation(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<admin_types::MerchantKeyRotationResponse> {
+    let rotation = state
+        .store
+        .find_latest_merchant_key_rotation_by_merchant_id(&merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No key rotation found for the merchant".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(rotation.foreign_into()))
+}
+
+/// Resumes a failed key rotation from the last record that was successfully re-encrypted
+#[instrument(skip_all)]
+pub async fn resume_merchant_key_rotation(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<admin_types::MerchantKeyRotationResponse> {
+    let db = state.store.as_ref();
+    let rotation = db
+        .find_latest_merchant_key_rotation_by_merchant_id(&merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No key rotation found for the merchant".to_string(),
+        })?;
+
+    when(rotation.status != enums::KeyRotationStatus::Failed, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Only failed key rotations can be resumed, the latest key rotation is {}",
+                rotation.status
+            ),
+        })
+    })?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // A rotation that failed before the key was switched has no data to re-encrypt
+    when(key_store.previous_key.is_none(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The merchant key was not rotated, a new key rotation must be started"
+                .to_string(),
+        })
+    })?;
+
+    let rotation = db
+        .update_merchant_key_rotation_by_merchant_id_rotation_id(
+            &rotation.merchant_id,
+            &rotation.rotation_id,
+            storage::MerchantKeyRotationUpdate::StatusUpdate {
+                status: enums::KeyRotationStatus::InProgress,
+                error_message: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update merchant key rotation status")?;
+
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(date_time::now()),
+        tracking_data: None,
+        business_status: Some("PENDING".to_string()),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(date_time::now()),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![generate_task_id_for_key_rotation_workflow(
+            &rotation.rotation_id,
+        )],
+        updated_process_tracker_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to reschedule merchant key rotation task")?;
+
+    Ok(ApplicationResponse::Json(rotation.foreign_into()))
+}
+
+fn generate_task_id_for_key_rotation_workflow(rotation_id: &str) -> String {
+    format!("{KEY_ROTATION_RUNNER}_{KEY_ROTATION_NAME}_{rotation_id}")
+}
+
+#[instrument(skip_all)]
+async fn add_key_rotation_task(
+    db: &dyn StorageInterface,
+    rotation: &storage::MerchantKeyRotation,
+) -> RouterResult<()> {
+    let tracking_data = storage::MerchantKeyRotationTrackingData {
+        rotation_id: rotation.rotation_id.clone(),
+        merchant_id: rotation.merchant_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_key_rotation_workflow(&rotation.rotation_id),
+        KEY_ROTATION_NAME,
+        KEY_ROTATION_RUNNER,
+        [KEY_ROTATION_TAG],
+        tracking_data,
+        date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct merchant key rotation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting merchant key rotation task to process_tracker: \
+                 rotation_id: {}",
+                rotation.rotation_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "flow",
+            "MerchantKeyRotation",
+        )],
+    );
+
+    Ok(())
+}
+
+/// Schedules the next run of the key rotation workflow. Failed runs are retried after a delay,
+/// while runs that made progress are continued immediately.
+#[instrument(skip_all)]
+pub async fn reschedule_key_rotation_task(
+    db: &dyn StorageInterface,
+    process: &storage::ProcessTracker,
+    is_retry: bool,
+) -> RouterResult<()> {
+    let (retry_count, schedule_time) = if is_retry {
+        (
+            process.retry_count + 1,
+            date_time::now()
+                .saturating_add(time::Duration::seconds(KEY_ROTATION_RETRY_DELAY_IN_SECONDS)),
+        )
+    } else {
+        (0, date_time::now())
+    };
+
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(retry_count),
+        schedule_time: Some(schedule_time),
+        tracking_data: None,
+        business_status: Some("PENDING".to_string()),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(date_time::now()),
+    };
+
+    db.process_tracker_update_process_status_by_ids(
+        vec![process.id.clone()],
+        updated_process_tracker_data,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to reschedule merchant key rotation task")?;
+
+    Ok(())
+}
+
+/// Marks the key rotation as failed, after which it can only be continued by resuming it
+#[instrument(skip_all)]
+pub async fn fail_key_rotation(
+    db: &dyn StorageInterface,
+    rotation: &storage::MerchantKeyRotation,
+    error_message: String,
+) -> RouterResult<()> {
+    db.update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &rotation.merchant_id,
+        &rotation.rotation_id,
+        storage::MerchantKeyRotationUpdate::StatusUpdate {
+            status: enums::KeyRotationStatus::Failed,
+            error_message: Some(error_message),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update merchant key rotation status")?;
+
+    Ok(())
+}
+
+/// Re-encrypts the next batches of records with the merchant's current key, persisting the
+/// progress after every batch. Once all entities have been processed, the previous key is
+/// retired and the key rotation is marked as completed.
+#[instrument(skip_all)]
+pub async fn re_encrypt_next_batches(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    rotation: storage::MerchantKeyRotation,
+) -> RouterResult<storage::MerchantKeyRotation> {
+    let previous_key = key_store
+        .previous_key
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Previous key not found in merchant key store during key rotation")?;
+
+    let mut rotation = rotation;
+    for _ in 0..KEY_ROTATION_BATCHES_PER_RUN {
+        let entity = match rotation.current_entity {
+            Some(entity) => entity,
+            None => break,
+        };
+
+        let records = db
+            .find_encrypted_records_by_merchant_id(
+                entity,
+                &rotation.merchant_id,
+                rotation.last_processed_id.clone(),
+                KEY_ROTATION_BATCH_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to fetch {entity} records to re-encrypt"))?;
+
+        let is_last_batch = i64::try_from(records.len())
+            .map(|records_count| records_count < KEY_ROTATION_BATCH_SIZE)
+            .unwrap_or(false);
+        let last_processed_id = records
+            .last()
+            .map(|record| record.record_id().to_owned())
+            .or(rotation.last_processed_id.clone());
+
+        let mut records_re_encrypted = rotation.records_re_encrypted;
+        for mut record in records {
+            let record_id = record.record_id().to_owned();
+            let mut is_modified = false;
+            for column in record.columns_mut() {
+                column.updated = re_encrypt(
+                    &column.original,
+                    previous_key.get_inner().peek(),
+                    key_store.key.get_inner().peek(),
+                )
+                .attach_printable_lazy(|| format!("Failed to re-encrypt {entity} {record_id}"))?;
+                is_modified |= column.updated != column.original;
+            }
+
+            if !is_modified {
+                continue;
+            }
+
+            let is_updated = update_re_encrypted_record(
+                db,
+                key_store,
+                &rotation.merchant_id,
+                record,
+                storage_scheme,
+            )
+            .await
+            .attach_printable_lazy(|| format!("Failed to update {entity} {record_id}"))?;
+
+            // A record that was modified after it was read has been written with the new key
+            if is_updated {
+                records_re_encrypted += 1;
+            } else {
+                logger::debug!(%record_id, "Skipped {entity} modified during key rotation");
+            }
+        }
+
+        let (current_entity, last_processed_id) = if is_last_batch {
+            (entity.next(), None)
+        } else {
+            (Some(entity), last_processed_id)
+        };
+
+        rotation = db
+            .update_merchant_key_rotation_by_merchant_id_rotation_id(
+                &rotation.merchant_id,
+                &rotation.rotation_id,
+                storage::MerchantKeyRotationUpdate::ProgressUpdate {
+                    current_entity,
+                    last_processed_id,
+                    records_re_encrypted,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update merchant key rotation progress")?;
+    }
+
+    if rotation.current_entity.is_some() {
+        return Ok(rotation);
+    }
+
+    db.update_merchant_key_store_by_merchant_id(
+        &rotation.merchant_id,
+        storage::MerchantKeyStoreUpdate::PreviousKeyRetired,
+        &db.get_master_key().to_vec().into(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to retire previous merchant key")?;
+
+    db.update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &rotation.merchant_id,
+        &rotation.rotation_id,
+        storage::MerchantKeyRotationUpdate::StatusUpdate {
+            status: enums::KeyRotationStatus::Completed,
+            error_message: None,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update merchant key rotation status")
+}
+
+/// Persists a re-encrypted record. Records that are also stored in Redis for merchants using the
+/// KV storage scheme are re-encrypted through their KV-aware update, so that the Redis copy and
+/// the updates queued for the drainer are encrypted with the new key as well.
+async fn update_re_encrypted_record(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &str,
+    record: storage::EncryptedRecord,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<bool> {
+    let key = key_store.key.get_inner().peek();
+    match (storage_scheme, record) {
+        (
+            enums::MerchantStorageScheme::RedisKv,
+            storage::EncryptedRecord::Customer { customer_id, .. },
+        ) => {
+            let customer = db
+                .find_customer_by_customer_id_merchant_id(
+                    &customer_id,
+                    merchant_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            let customer_update = storage::CustomerUpdate::Update {
+                name: re_encrypt_optional(customer.name.clone(), key).await?,
+                email: re_encrypt_optional(customer.email.clone(), key).await?,
+                phone: Box::new(re_encrypt_optional(customer.phone.clone(), key).await?),
+                description: customer.description.clone(),
+                phone_country_code: customer.phone_country_code.clone(),
+                metadata: customer.metadata.clone(),
+                connector_customer: customer.connector_customer.clone(),
+                address_id: customer.address_id.clone(),
+            };
+
+            db.update_customer_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id.to_owned(),
+                customer,
+                customer_update,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            Ok(true)
+        }
+        (
+            enums::MerchantStorageScheme::RedisKv,
+            storage::EncryptedRecord::Address {
+                address_id,
+                payment_id: Some(payment_id),
+                ..
+            },
+        ) => {
+            let payment_address = db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    merchant_id,
+                    &payment_id,
+                    &address_id,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            let address = &payment_address.address;
+            let address_update = storage::AddressUpdate::Update {
+                city: address.city.clone(),
+                country: address.country,
+                line1: re_encrypt_optional(address.line1.clone(), key).await?,
+                line2: re_encrypt_optional(address.line2.clone(), key).await?,
+                line3: re_encrypt_optional(address.line3.clone(), key).await?,
+                state: re_encrypt_optional(address.state.clone(), key).await?,
+                zip: re_encrypt_optional(address.zip.clone(), key).await?,
+                first_name: re_encrypt_optional(address.first_name.clone(), key).await?,
+                last_name: re_encrypt_optional(address.last_name.clone(), key).await?,
+                phone_number: re_encrypt_optional(address.phone_number.clone(), key).await?,
+                country_code: address.country_code.clone(),
+                updated_by: storage_scheme.to_string(),
+                email: re_encrypt_optional(address.email.clone(), key).await?,
+            };
+
+            db.update_address_for_payments(
+                payment_address,
+                address_update,
+                payment_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            Ok(true)
+        }
+        (
+            enums::MerchantStorageScheme::RedisKv,
+            storage::EncryptedRecord::PaymentMethod {
+                payment_method_id, ..
+            },
+        ) => {
+            let previous_key = key_store
+                .previous_key
+                .as_ref()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Previous key not found in merchant key store")?
+                .get_inner()
+                .peek();
+            let payment_method = db
+                .find_payment_method(&payment_method_id, storage_scheme)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            let payment_method_update = storage::PaymentMethodUpdate::EncryptedDataUpdate {
+                payment_method_data: payment_method
+                    .payment_method_data
+                    .as_ref()
+                    .map(|data| re_encrypt(data, previous_key, key))
+                    .transpose()?,
+                payment_method_billing_address: payment_method
+                    .payment_method_billing_address
+                    .as_ref()
+                    .map(|address| re_encrypt(address, previous_key, key))
+                    .transpose()?,
+            };
+
+            db.update_payment_method(payment_method, payment_method_update, storage_scheme)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            Ok(true)
+        }
+        (_, record) => db
+            .update_encrypted_record(merchant_id, record)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+    }
+}
+
+/// Encrypts decrypted data with the merchant's current key
+async fn re_encrypt_optional<T, S>(
+    value: Option<Encryptable<Secret<T, S>>>,
+    key: &[u8],
+) -> RouterResult<Option<Encryptable<Secret<T, S>>>>
+where
+    T: Clone,
+    Secret<T, S>: Send,
+    S: masking::Strategy<T>,
+    Encryptable<Secret<T, S>>: domain_types::TypeEncryption<T, GcmAes256, S>,
+{
+    domain_types::encrypt_optional(value.map(Encryptable::into_inner), key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt data with the new key")
+}
+
+/// Re-encrypts data encrypted with the previous key using the current key. Data that cannot be
+/// decrypted with the previous key must have been written after the key was rotated, and is
+/// returned as is after verifying that it can be decrypted with the current key.
+pub(crate) fn re_encrypt(
+    encrypted_data: &Encryption,
+    previous_key: &[u8],
+    key: &[u8],
+) -> RouterResult<Encryption> {
+    match GcmAes256.decode_message(previous_key, encrypted_data.get_inner().clone()) {
+        Ok(data) => GcmAes256
+            .encode_message(key, &data)
+            .map(|encrypted| Encryption::new(encrypted.into()))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt data with the new key"),
+        Err(_) => GcmAes256
+            .decode_message(key, encrypted_data.get_inner().clone())
+            .map(|_| encrypted_data.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decrypt data with either the previous or the current key"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::{
+        events::EventInterface, merchant_key_rotation::MerchantKeyRotationInterface,
+        merchant_key_store::MerchantKeyStoreInterface, MasterKeyInterface,
+    };
+
+    const MERCHANT_ID: &str = "merchant_key_rotation";
+    const EVENT_ID: &str = "event_key_rotation";
+    const PREVIOUS_KEY: [u8; 32] = [7; 32];
+    const KEY: [u8; 32] = [42; 32];
+
+    /// Sets up a merchant whose key was rotated, with an event encrypted with the previous key
+    async fn setup() -> (MockDb, domain::MerchantKeyStore) {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let master_key = db.get_master_key().to_vec();
+
+        db.insert_merchant_key_store(
+            domain::MerchantKeyStore {
+                merchant_id: MERCHANT_ID.to_string(),
+                key: domain_types::encrypt(KEY.to_vec().into(), &master_key)
+                    .await
+                    .unwrap(),
+                created_at: date_time::now(),
+                key_version: 2,
+                previous_key: Some(
+                    domain_types::encrypt(PREVIOUS_KEY.to_vec().into(), &master_key)
+                        .await
+                        .unwrap(),
+                ),
+                previous_key_version: Some(1),
+            },
+            &master_key.clone().into(),
+        )
+        .await
+        .unwrap();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(MERCHANT_ID, &master_key.into())
+            .await
+            .unwrap();
+
+        db.insert_event(
+            domain::Event {
+                event_id: EVENT_ID.to_string(),
+                event_type: enums::EventType::PaymentSucceeded,
+                event_class: enums::EventClass::Payments,
+                is_webhook_notified: false,
+                primary_object_id: "pay_key_rotation".to_string(),
+                primary_object_type: enums::EventObjectType::PaymentDetails,
+                created_at: date_time::now(),
+                merchant_id: Some(MERCHANT_ID.to_string()),
+                business_profile_id: None,
+                primary_object_created_at: None,
+                idempotent_event_id: Some(EVENT_ID.to_string()),
+                initial_attempt_id: Some(EVENT_ID.to_string()),
+                request: Some(
+                    domain_types::encrypt(Secret::new("request".to_string()), &PREVIOUS_KEY)
+                        .await
+                        .unwrap(),
+                ),
+                response: None,
+                delivery_attempt: None,
+            },
+            &key_store,
+        )
+        .await
+        .unwrap();
+
+        (db, key_store)
+    }
+
+    async fn insert_rotation(db: &MockDb) -> storage::MerchantKeyRotation {
+        let now = date_time::now();
+        db.insert_merchant_key_rotation(storage::MerchantKeyRotationNew {
+            rotation_id: "key_rotation_test".to_string(),
+            merchant_id: MERCHANT_ID.to_string(),
+            from_key_version: 1,
+            to_key_version: 2,
+            status: enums::KeyRotationStatus::InProgress,
+            current_entity: Some(enums::KeyRotationEntity::MerchantAccount),
+            last_processed_id: None,
+            records_re_encrypted: 0,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_data_encrypted_with_previous_key_is_decrypted_during_rotation() {
+        let (db, key_store) = setup().await;
+
+        let event = db
+            .find_event_by_merchant_id_event_id(MERCHANT_ID, EVENT_ID, &key_store)
+            .await
+            .expect("Failed to decrypt event encrypted with the previous key");
+
+        assert_eq!(
+            event.request.unwrap().into_inner().peek(),
+            &"request".to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_re_encrypt_next_batches_completes_rotation() {
+        let (db, key_store) = setup().await;
+        let rotation = insert_rotation(&db).await;
+
+        let rotation = re_encrypt_next_batches(
+            &db,
+            &key_store,
+            enums::MerchantStorageScheme::PostgresOnly,
+            rotation,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rotation.status, enums::KeyRotationStatus::Completed);
+        assert_eq!(rotation.current_entity, None);
+        assert_eq!(rotation.records_re_encrypted, 1);
+
+        let event = db.events.lock().await.first().cloned().unwrap();
+        let request = event.request.unwrap();
+        assert!(GcmAes256
+            .decode_message(&KEY, request.get_inner().clone())
+            .is_ok());
+        assert!(GcmAes256
+            .decode_message(&PREVIOUS_KEY, request.get_inner().clone())
+            .is_err());
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                MERCHANT_ID,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .unwrap();
+        assert!(key_store.previous_key.is_none());
+        assert_eq!(
+            db.find_latest_merchant_key_rotation_by_merchant_id(MERCHANT_ID)
+                .await
+                .unwrap()
+                .status,
+            enums::KeyRotationStatus::Completed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_encrypted_record_skips_records_modified_after_read() {
+        let (db, _) = setup().await;
+        let event = db.events.lock().await.first().cloned().unwrap();
+        let mut record = storage::EncryptedRecord::from(event);
+        for column in record.columns_mut() {
+            column.updated = re_encrypt(&column.original, &PREVIOUS_KEY, &KEY).unwrap();
+        }
+
+        // The event is written concurrently after the record was read
+        let concurrent_request = Encryption::new(
+            GcmAes256
+                .encode_message(&KEY, b"concurrent request")
+                .unwrap()
+                .into(),
+        );
+        if let Some(stored) = db.events.lock().await.first_mut() {
+            stored.request = Some(concurrent_request.clone());
+        }
+
+        let is_updated = db
+            .update_encrypted_record(MERCHANT_ID, record)
+            .await
+            .unwrap();
+
+        assert!(!is_updated);
+        assert_eq!(
+            db.events.lock().await.first().cloned().unwrap().request,
+            Some(concurrent_request)
+        );
+    }
+
+    #[test]
+    fn test_re_encrypt_leaves_data_encrypted_with_current_key() {
+        let encrypted = Encryption::new(
+            GcmAes256
+                .encode_message(&KEY, b"already rotated")
+                .unwrap()
+                .into(),
+        );
+
+        assert_eq!(
+            re_encrypt(&encrypted, &PREVIOUS_KEY, &KEY).unwrap(),
+            encrypted
+        );
+        assert!(re_encrypt(&encrypted, &PREVIOUS_KEY, &[1; 32]).is_err());
+    }
+}
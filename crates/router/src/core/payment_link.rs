@@ -4,24 +4,47 @@ use common_utils::{
         DEFAULT_BACKGROUND_COLOR, DEFAULT_DISPLAY_SDK_ONLY, DEFAULT_ENABLE_SAVED_PAYMENT_METHOD,
         DEFAULT_MERCHANT_LOGO, DEFAULT_PRODUCT_IMG, DEFAULT_SDK_LAYOUT, DEFAULT_SESSION_EXPIRY,
     },
-    ext_traits::{OptionExt, ValueExt},
+    ext_traits::{Encode, OptionExt, ValueExt},
+    types::MinorUnit,
 };
 use error_stack::ResultExt;
 use futures::future;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
-use super::errors::{self, RouterResult, StorageErrorExt};
+use super::{
+    errors::{self, RouterResult, StorageErrorExt},
+    payments,
+};
 use crate::{
+    consts,
     errors::RouterResponse,
-    routes::AppState,
+    routes::{app::ReqState, AppState},
     services,
     types::{
-        api::payment_link::PaymentLinkResponseExt, domain, storage::enums as storage_enums,
+        api::{self as api_types, payment_link::PaymentLinkResponseExt},
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
     },
+    utils,
 };
 
+const PAYMENT_LINK_QR_CODE_DEFAULT_SIZE: u32 = 256;
+const PAYMENT_LINK_QR_CODE_MAX_SIZE: u32 = 2048;
+const REUSABLE_PAYMENT_LINK_LIST_DEFAULT_LIMIT: i64 = 10;
+const REUSABLE_PAYMENT_LINK_LIST_MAX_LIMIT: i64 = 100;
+
+/// Statuses of payments made through a reusable payment link that count towards its usage limit.
+/// Payments that are still being processed are included so that the limit cannot be exceeded by
+/// customers paying concurrently.
+const REUSABLE_PAYMENT_LINK_USAGE_STATUSES: [storage_enums::IntentStatus; 4] = [
+    storage_enums::IntentStatus::Succeeded,
+    storage_enums::IntentStatus::PartiallyCaptured,
+    storage_enums::IntentStatus::RequiresCapture,
+    storage_enums::IntentStatus::Processing,
+];
+
 pub async fn retrieve_payment_link(
     state: AppState,
     payment_link_id: String,
@@ -51,6 +74,7 @@ pub async fn initiate_payment_link_flow(
     merchant_account: domain::MerchantAccount,
     merchant_id: String,
     payment_id: String,
+    request_host: Option<String>,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let db = &*state.store;
     let payment_intent = db
@@ -103,6 +127,11 @@ pub async fn initiate_payment_link_flow(
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
             id: profile_id.to_string(),
         })?;
+    validate_payment_link_host(
+        &state,
+        business_profile.payment_link_config.clone(),
+        request_host.as_deref(),
+    )?;
 
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
@@ -480,6 +509,58 @@ pub fn get_payment_link_config_based_on_priority(
     Ok((payment_link_config, domain_name))
 }
 
+/// Ensures that a payment link is served either on the router's base URL or on the custom domain
+/// registered on the business profile of the payment link
+fn validate_payment_link_host(
+    state: &AppState,
+    business_link_config: Option<serde_json::Value>,
+    request_host: Option<&str>,
+) -> RouterResult<()> {
+    if !state.conf.payment_link.validate_host {
+        return Ok(());
+    }
+
+    let get_host_name = |url: &str| {
+        url::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(ToOwned::to_owned))
+    };
+    let custom_domain_name = business_link_config
+        .map(|business_config| {
+            business_config
+                .parse_value::<api_models::admin::BusinessPaymentLinkConfig>(
+                    "BusinessPaymentLinkConfig",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid payment_link_config in business profile")
+        })
+        .transpose()?
+        .and_then(|business_config| business_config.domain_name);
+    let request_host_name = request_host
+        .and_then(|request_host| get_host_name(&format!("https://{request_host}")))
+        .ok_or(errors::ApiErrorResponse::AccessForbidden {
+            resource: "payment_link".to_string(),
+        })?;
+
+    let is_allowed_host = [
+        get_host_name(&state.conf.server.base_url),
+        custom_domain_name,
+    ]
+    .into_iter()
+    .flatten()
+    .any(|allowed_host_name| allowed_host_name.eq_ignore_ascii_case(&request_host_name));
+    if is_allowed_host {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: "payment_link".to_string(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Payment link requested on unregistered host {request_host_name}")
+        })
+    }
+}
+
 fn capitalize_first_char(s: &str) -> String {
     if let Some(first_char) = s.chars().next() {
         let capitalized = first_char.to_uppercase();
@@ -505,6 +586,7 @@ pub async fn get_payment_link_status(
     merchant_account: domain::MerchantAccount,
     merchant_id: String,
     payment_id: String,
+    request_host: Option<String>,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let db = &*state.store;
     let payment_intent = db
@@ -583,6 +665,11 @@ pub async fn get_payment_link_status(
         .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
             id: profile_id.to_string(),
         })?;
+    validate_payment_link_host(
+        &state,
+        business_profile.payment_link_config.clone(),
+        request_host.as_deref(),
+    )?;
 
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
@@ -619,3 +706,750 @@ pub async fn get_payment_link_status(
         services::api::PaymentLinkAction::PaymentLinkStatus(payment_link_status_data),
     )))
 }
+
+fn get_payment_link_qr_code(
+    link_to_pay: &str,
+    request: api_models::payments::PaymentLinkQrCodeRequest,
+) -> RouterResponse<serde_json::Value> {
+    let size = request.size.unwrap_or(PAYMENT_LINK_QR_CODE_DEFAULT_SIZE);
+    if !(1..=PAYMENT_LINK_QR_CODE_MAX_SIZE).contains(&size) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("size should be between 1 and {PAYMENT_LINK_QR_CODE_MAX_SIZE}"),
+        }
+        .into());
+    }
+
+    let qr_code = match request.format.unwrap_or_default() {
+        api_models::payments::QrCodeImageFormat::Png => {
+            utils::QrImage::render_png(link_to_pay, size).map(|png| (png, mime::IMAGE_PNG))
+        }
+        api_models::payments::QrCodeImageFormat::Svg => {
+            utils::QrImage::render_svg(link_to_pay, size)
+                .map(|svg| (svg.into_bytes(), mime::IMAGE_SVG))
+        }
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to render QR code of payment link")?;
+
+    Ok(services::ApplicationResponse::FileData(qr_code))
+}
+
+pub async fn get_payment_link_qr_code_by_payment_link_id(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+    request: api_models::payments::PaymentLinkQrCodeRequest,
+) -> RouterResponse<serde_json::Value> {
+    let payment_link = state
+        .store
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if payment_link.merchant_id != merchant_account.merchant_id {
+        return Err(errors::ApiErrorResponse::PaymentLinkNotFound.into());
+    }
+
+    get_payment_link_qr_code(&payment_link.link_to_pay, request)
+}
+
+pub async fn get_reusable_payment_link_qr_code(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    reusable_payment_link_id: String,
+    request: api_models::payments::PaymentLinkQrCodeRequest,
+) -> RouterResponse<serde_json::Value> {
+    let reusable_payment_link = state
+        .store
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &merchant_account.merchant_id,
+            &reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    get_payment_link_qr_code(&reusable_payment_link.link_to_pay, request)
+}
+
+fn validate_reusable_payment_link_amounts(
+    request: &api_models::payments::ReusablePaymentLinkCreateRequest,
+) -> RouterResult<()> {
+    let is_positive =
+        |amount: Option<MinorUnit>| amount.map_or(true, |amount| amount > MinorUnit::new(0));
+    if !is_positive(request.amount)
+        || !is_positive(request.min_amount)
+        || !is_positive(request.max_amount)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount, min_amount and max_amount must be greater than zero".to_string(),
+        }
+        .into());
+    }
+
+    match (request.amount, request.min_amount, request.max_amount) {
+        (Some(_), Some(_), _) | (Some(_), _, Some(_)) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "min_amount and max_amount cannot be specified along with amount"
+                    .to_string(),
+            }
+            .into())
+        }
+        (None, Some(min_amount), Some(max_amount)) if min_amount > max_amount => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "min_amount cannot be greater than max_amount".to_string(),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn get_reusable_payment_link_usage_limit(usage_limit: Option<u32>) -> RouterResult<Option<i32>> {
+    usage_limit
+        .map(|usage_limit| {
+            i32::try_from(usage_limit)
+                .ok()
+                .filter(|usage_limit| *usage_limit > 0)
+                .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "usage_limit",
+                })
+        })
+        .transpose()
+        .map_err(Into::into)
+}
+
+fn validate_reusable_payment_link_expiry(
+    expires_at: Option<PrimitiveDateTime>,
+) -> RouterResult<()> {
+    match expires_at {
+        Some(expires_at) if expires_at <= common_utils::date_time::now() => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at must be in the future".to_string(),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub async fn create_reusable_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: api_models::payments::ReusablePaymentLinkCreateRequest,
+) -> RouterResponse<api_models::payments::ReusablePaymentLinkResponse> {
+    let db = &*state.store;
+    validate_reusable_payment_link_amounts(&request)?;
+    validate_reusable_payment_link_expiry(request.expires_at)?;
+    let usage_limit = get_reusable_payment_link_usage_limit(request.usage_limit)?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&request.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: request.profile_id.clone(),
+        })?;
+    if business_profile.merchant_id != merchant_account.merchant_id {
+        return Err(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: request.profile_id,
+        }
+        .into());
+    }
+
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+    let (_, domain_name) = get_payment_link_config_based_on_priority(
+        request.payment_link_config.clone(),
+        business_profile.payment_link_config,
+        merchant_name,
+        state.conf.server.base_url.clone(),
+    )?;
+
+    let reusable_payment_link_id = utils::generate_id(consts::ID_LENGTH, "rplink");
+    let link_to_pay = format!(
+        "{domain_name}/payment_link/pay/{}/{reusable_payment_link_id}",
+        merchant_account.merchant_id
+    );
+    let payment_link_config = request
+        .payment_link_config
+        .map(|payment_link_config| payment_link_config.encode_to_value())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_link_config",
+        })?;
+
+    let created_at @ modified_at = common_utils::date_time::now();
+    let reusable_payment_link = db
+        .insert_reusable_payment_link(storage::ReusablePaymentLinkNew {
+            reusable_payment_link_id,
+            merchant_id: merchant_account.merchant_id,
+            profile_id: request.profile_id,
+            link_to_pay,
+            currency: request.currency,
+            amount: request.amount,
+            min_amount: request.min_amount,
+            max_amount: request.max_amount,
+            usage_limit,
+            expires_at: request.expires_at,
+            is_active: true,
+            description: request.description,
+            return_url: request.return_url.map(|return_url| return_url.to_string()),
+            payment_link_config,
+            created_at,
+            modified_at,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "reusable payment link already exists!".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::ReusablePaymentLinkResponse::foreign_from(reusable_payment_link),
+    ))
+}
+
+pub async fn retrieve_reusable_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    reusable_payment_link_id: String,
+) -> RouterResponse<api_models::payments::ReusablePaymentLinkResponse> {
+    let reusable_payment_link = state
+        .store
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &merchant_account.merchant_id,
+            &reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::ReusablePaymentLinkResponse::foreign_from(reusable_payment_link),
+    ))
+}
+
+fn get_reusable_payment_link_list_limit(limit: Option<i64>) -> RouterResult<i64> {
+    match limit {
+        None => Ok(REUSABLE_PAYMENT_LINK_LIST_DEFAULT_LIMIT),
+        Some(limit) if (1..=REUSABLE_PAYMENT_LINK_LIST_MAX_LIMIT).contains(&limit) => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be between 1 and {REUSABLE_PAYMENT_LINK_LIST_MAX_LIMIT}"
+            ),
+        }
+        .into()),
+    }
+}
+
+pub async fn list_reusable_payment_links(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: api_models::payments::ReusablePaymentLinkListConstraints,
+) -> RouterResponse<api_models::payments::ReusablePaymentLinkListResponse> {
+    let limit = get_reusable_payment_link_list_limit(constraints.limit)?;
+    let reusable_payment_links = state
+        .store
+        .list_reusable_payment_links_by_merchant_id(
+            &merchant_account.merchant_id,
+            limit,
+            constraints.offset.unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve reusable payment links")?;
+
+    let data: Vec<_> = reusable_payment_links
+        .into_iter()
+        .map(api_models::payments::ReusablePaymentLinkResponse::foreign_from)
+        .collect();
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::ReusablePaymentLinkListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+pub async fn update_reusable_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    reusable_payment_link_id: String,
+    request: api_models::payments::ReusablePaymentLinkUpdateRequest,
+) -> RouterResponse<api_models::payments::ReusablePaymentLinkResponse> {
+    validate_reusable_payment_link_expiry(request.expires_at)?;
+    let usage_limit = get_reusable_payment_link_usage_limit(request.usage_limit)?;
+
+    let reusable_payment_link = state
+        .store
+        .update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &merchant_account.merchant_id,
+            &reusable_payment_link_id,
+            storage::ReusablePaymentLinkUpdate::Update {
+                usage_limit,
+                expires_at: request.expires_at,
+                is_active: request.is_active,
+                description: request.description,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::ReusablePaymentLinkResponse::foreign_from(reusable_payment_link),
+    ))
+}
+
+fn summarize_reusable_payment_link_payments(
+    payment_amounts: &[storage::ReusablePaymentLinkPaymentAmount],
+) -> api_models::payments::ReusablePaymentLinkPaymentsSummary {
+    payment_amounts.iter().fold(
+        api_models::payments::ReusablePaymentLinkPaymentsSummary::default(),
+        |mut summary, payment| {
+            summary.payments_initiated += 1;
+            if payment.status == storage_enums::IntentStatus::Succeeded {
+                summary.payments_succeeded += 1;
+            }
+            if let Some(amount_captured) = payment.amount_captured {
+                summary.amount_collected = summary.amount_collected + amount_captured;
+            }
+            summary
+        },
+    )
+}
+
+pub async fn list_reusable_payment_link_payments(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    reusable_payment_link_id: String,
+    constraints: api_models::payments::ReusablePaymentLinkListConstraints,
+) -> RouterResponse<api_models::payments::ReusablePaymentLinkPaymentsResponse> {
+    let db = &*state.store;
+    let limit = get_reusable_payment_link_list_limit(constraints.limit)?;
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &merchant_account.merchant_id,
+            &reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let payment_amounts = db
+        .get_reusable_payment_link_payment_amounts(
+            &reusable_payment_link.merchant_id,
+            &reusable_payment_link.reusable_payment_link_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve payments of reusable payment link")?;
+    let summary = summarize_reusable_payment_link_payments(&payment_amounts);
+
+    let payment_links = db
+        .list_payment_links_by_merchant_id_reusable_payment_link_id(
+            &reusable_payment_link.merchant_id,
+            &reusable_payment_link.reusable_payment_link_id,
+            limit,
+            constraints.offset.unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve payment links of reusable payment link")?;
+
+    let data = future::try_join_all(payment_links.into_iter().map(|payment_link| async {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &payment_link.payment_id,
+                &payment_link.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(
+            api_models::payments::ReusablePaymentLinkPayment {
+                payment_id: payment_link.payment_id,
+                payment_link_id: payment_link.payment_link_id,
+                amount: payment_link.amount,
+                currency: payment_link.currency,
+                status: payment_intent.status,
+                created_at: payment_link.created_at,
+            },
+        )
+    }))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::ReusablePaymentLinkPaymentsResponse {
+            reusable_payment_link_id: reusable_payment_link.reusable_payment_link_id,
+            summary,
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Returns the amount of the payment to be created through a reusable payment link, or `None`
+/// if the customer is yet to enter the amount.
+fn get_reusable_payment_link_payment_amount(
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    amount: Option<MinorUnit>,
+) -> RouterResult<Option<MinorUnit>> {
+    match (reusable_payment_link.amount, amount) {
+        (Some(fixed_amount), _) => Ok(Some(fixed_amount)),
+        (None, None) => Ok(None),
+        (None, Some(amount)) => {
+            let is_below_minimum = reusable_payment_link
+                .min_amount
+                .map_or(amount <= MinorUnit::new(0), |min_amount| {
+                    amount < min_amount
+                });
+            let is_above_maximum = reusable_payment_link
+                .max_amount
+                .is_some_and(|max_amount| amount > max_amount);
+            if is_below_minimum || is_above_maximum {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "amount is outside the range allowed by the payment link".to_string(),
+                }
+                .into())
+            } else {
+                Ok(Some(amount))
+            }
+        }
+    }
+}
+
+async fn validate_reusable_payment_link_usage(
+    db: &dyn crate::db::StorageInterface,
+    reusable_payment_link: &storage::ReusablePaymentLink,
+) -> RouterResult<()> {
+    let is_expired = reusable_payment_link
+        .expires_at
+        .is_some_and(|expires_at| expires_at <= common_utils::date_time::now());
+    if !reusable_payment_link.is_active || is_expired {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment link is no longer accepting payments".to_string(),
+        }
+        .into());
+    }
+
+    if let Some(usage_limit) = reusable_payment_link.usage_limit {
+        // Counting stops at the usage limit, so that the number of payments read is bounded
+        let payments_count = db
+            .count_reusable_payment_link_payments_by_statuses(
+                &reusable_payment_link.merchant_id,
+                &reusable_payment_link.reusable_payment_link_id,
+                REUSABLE_PAYMENT_LINK_USAGE_STATUSES.to_vec(),
+                i64::from(usage_limit),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to count payments of reusable payment link")?;
+        if is_reusable_payment_link_usage_limit_reached(payments_count, usage_limit) {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment link has reached its usage limit".to_string(),
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+fn is_reusable_payment_link_usage_limit_reached(payments_count: usize, usage_limit: i32) -> bool {
+    usize::try_from(usage_limit).map_or(true, |usage_limit| payments_count >= usage_limit)
+}
+
+/// Validates that the reusable payment link through which a payment was created still accepts
+/// payments. This is checked again when the payment is confirmed, as several customers may have
+/// opened the link before its usage limit was reached.
+pub async fn validate_reusable_payment_link_usage_on_confirm(
+    db: &dyn crate::db::StorageInterface,
+    payment_link_id: &str,
+) -> RouterResult<()> {
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let Some(reusable_payment_link_id) = payment_link.reusable_payment_link_id else {
+        return Ok(());
+    };
+
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &payment_link.merchant_id,
+            &reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    validate_reusable_payment_link_usage(db, &reusable_payment_link).await
+}
+
+pub async fn initiate_reusable_payment_link_flow(
+    state: AppState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: api_models::payments::ReusablePaymentLinkInitiateRequest,
+    request_host: Option<String>,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let db = &*state.store;
+    let reusable_payment_link = db
+        .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+            &request.merchant_id,
+            &request.reusable_payment_link_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(&reusable_payment_link.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: reusable_payment_link.profile_id.clone(),
+        })?;
+    validate_payment_link_host(
+        &state,
+        business_profile.payment_link_config.clone(),
+        request_host.as_deref(),
+    )?;
+    validate_reusable_payment_link_usage(db, &reusable_payment_link).await?;
+
+    let payment_link_config = reusable_payment_link
+        .payment_link_config
+        .clone()
+        .map(|payment_link_config| {
+            payment_link_config.parse_value::<api_models::payments::PaymentCreatePaymentLinkConfig>(
+                "PaymentCreatePaymentLinkConfig",
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment link config of reusable payment link")?;
+
+    let amount =
+        match get_reusable_payment_link_payment_amount(&reusable_payment_link, request.amount)? {
+            Some(amount) => amount,
+            None => {
+                return get_reusable_payment_link_amount_form(
+                    &state,
+                    &merchant_account,
+                    business_profile,
+                    &reusable_payment_link,
+                    payment_link_config,
+                );
+            }
+        };
+
+    let payments_request = api_types::PaymentsRequest {
+        payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            utils::generate_id(consts::ID_LENGTH, "pay"),
+        )),
+        amount: Some(amount.into()),
+        currency: Some(reusable_payment_link.currency),
+        profile_id: Some(reusable_payment_link.profile_id.clone()),
+        description: reusable_payment_link.description.clone(),
+        return_url: reusable_payment_link
+            .return_url
+            .as_deref()
+            .and_then(|return_url| url::Url::parse(return_url).ok()),
+        payment_link: Some(true),
+        payment_link_config,
+        confirm: Some(false),
+        ..Default::default()
+    };
+    let payments_response = Box::pin(payments::payments_core::<
+        api_types::Authorize,
+        api_types::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        key_store,
+        payments::PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api_types::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let payments_response = match payments_response {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | services::ApplicationResponse::Json(payments_response) => payments_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments core")?,
+    };
+    let payment_id = payments_response
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("payment id not received from payments core")?;
+    let payment_link_id = payments_response
+        .payment_link
+        .map(|payment_link| payment_link.payment_link_id)
+        .get_required_value("payment_link")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("payment link not created for payment of reusable payment link")?;
+
+    db.update_payment_link_by_payment_link_id(
+        &payment_link_id,
+        storage::PaymentLinkUpdate::ReusablePaymentLinkUpdate {
+            reusable_payment_link_id: reusable_payment_link.reusable_payment_link_id,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to associate payment link with reusable payment link")?;
+
+    initiate_payment_link_flow(
+        state,
+        merchant_account,
+        request.merchant_id,
+        payment_id,
+        request_host,
+    )
+    .await
+}
+
+fn get_reusable_payment_link_amount_form(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: storage::business_profile::BusinessProfile,
+    reusable_payment_link: &storage::ReusablePaymentLink,
+    payment_link_config: Option<api_models::payments::PaymentCreatePaymentLinkConfig>,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let merchant_name = merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        .unwrap_or_default();
+    let (payment_link_config, _) = get_payment_link_config_based_on_priority(
+        payment_link_config,
+        business_profile.payment_link_config,
+        merchant_name,
+        state.conf.server.base_url.clone(),
+    )?;
+
+    let currency = reusable_payment_link.currency;
+    let to_base_unit = |amount: Option<MinorUnit>| {
+        amount
+            .map(|amount| currency.to_currency_base_unit(amount.get_amount_as_i64()))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)
+    };
+    let amount_details = api_models::payments::ReusablePaymentLinkAmountDetails {
+        currency,
+        currency_exponent: currency.number_of_digits_after_decimal_point(),
+        min_amount: to_base_unit(reusable_payment_link.min_amount)?,
+        max_amount: to_base_unit(reusable_payment_link.max_amount)?,
+        merchant_name: capitalize_first_char(&payment_link_config.seller_name),
+        merchant_logo: payment_link_config.logo.clone(),
+        merchant_description: reusable_payment_link.description.clone(),
+        theme: payment_link_config.theme.clone(),
+    };
+
+    let js_script = get_js_script(
+        &api_models::payments::PaymentLinkData::ReusablePaymentLinkAmountDetails(amount_details),
+    )?;
+    let css_script = get_color_scheme_css(payment_link_config);
+    Ok(services::ApplicationResponse::PaymentLinkForm(Box::new(
+        services::api::PaymentLinkAction::PaymentLinkAmountForm(services::PaymentLinkStatusData {
+            js_script,
+            css_script,
+        }),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn reusable_payment_link(
+        amount: Option<i64>,
+        min_amount: Option<i64>,
+        max_amount: Option<i64>,
+    ) -> storage::ReusablePaymentLink {
+        let now = common_utils::date_time::now();
+        storage::ReusablePaymentLink {
+            reusable_payment_link_id: "rplink_test".to_string(),
+            merchant_id: "merchant_test".to_string(),
+            profile_id: "pro_test".to_string(),
+            link_to_pay: "https://example.com".to_string(),
+            currency: storage_enums::Currency::USD,
+            amount: amount.map(MinorUnit::new),
+            min_amount: min_amount.map(MinorUnit::new),
+            max_amount: max_amount.map(MinorUnit::new),
+            usage_limit: None,
+            expires_at: None,
+            is_active: true,
+            description: None,
+            return_url: None,
+            payment_link_config: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_reusable_payment_link_fixed_amount() {
+        let link = reusable_payment_link(Some(500), None, None);
+        assert_eq!(
+            get_reusable_payment_link_payment_amount(&link, Some(MinorUnit::new(100))).unwrap(),
+            Some(MinorUnit::new(500))
+        );
+    }
+
+    #[test]
+    fn test_reusable_payment_link_customer_entered_amount() {
+        let link = reusable_payment_link(None, Some(100), Some(1000));
+        assert_eq!(
+            get_reusable_payment_link_payment_amount(&link, None).unwrap(),
+            None
+        );
+        assert_eq!(
+            get_reusable_payment_link_payment_amount(&link, Some(MinorUnit::new(100))).unwrap(),
+            Some(MinorUnit::new(100))
+        );
+        assert!(get_reusable_payment_link_payment_amount(&link, Some(MinorUnit::new(99))).is_err());
+        assert!(
+            get_reusable_payment_link_payment_amount(&link, Some(MinorUnit::new(1001))).is_err()
+        );
+
+        let unbounded_link = reusable_payment_link(None, None, None);
+        assert!(
+            get_reusable_payment_link_payment_amount(&unbounded_link, Some(MinorUnit::new(0)))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_summarize_reusable_payment_link_payments() {
+        let payment =
+            |status, amount_captured: Option<i64>| storage::ReusablePaymentLinkPaymentAmount {
+                status,
+                amount: MinorUnit::new(500),
+                amount_captured: amount_captured.map(MinorUnit::new),
+            };
+        let summary = summarize_reusable_payment_link_payments(&[
+            payment(storage_enums::IntentStatus::Succeeded, Some(500)),
+            payment(storage_enums::IntentStatus::PartiallyCaptured, Some(200)),
+            payment(storage_enums::IntentStatus::Failed, None),
+            payment(storage_enums::IntentStatus::RequiresPaymentMethod, None),
+        ]);
+        assert_eq!(summary.payments_initiated, 4);
+        assert_eq!(summary.payments_succeeded, 1);
+        assert_eq!(summary.amount_collected, MinorUnit::new(700));
+    }
+
+    #[test]
+    fn test_reusable_payment_link_usage_limit_reached() {
+        assert!(!is_reusable_payment_link_usage_limit_reached(0, 1));
+        assert!(!is_reusable_payment_link_usage_limit_reached(2, 3));
+        assert!(is_reusable_payment_link_usage_limit_reached(3, 3));
+        assert!(is_reusable_payment_link_usage_limit_reached(1, -1));
+    }
+}
@@ -0,0 +1,96 @@
+// @ts-check
+
+{{ payment_details_js_script }}
+
+/**
+ * Trigger - on boot
+ * Uses
+ *    - Render merchant details and the allowed amount range
+ */
+function boot() {
+  // @ts-ignore
+  var paymentDetails = window.__PAYMENT_DETAILS;
+
+  document.title = "Payment request from " + paymentDetails.merchant_name;
+
+  var merchantNameNode = document.getElementById(
+    "hyper-checkout-amount-merchant-name"
+  );
+  if (merchantNameNode instanceof HTMLDivElement) {
+    merchantNameNode.innerText = paymentDetails.merchant_name;
+  }
+
+  var merchantLogoNode = document.getElementById(
+    "hyper-checkout-amount-merchant-logo"
+  );
+  if (merchantLogoNode instanceof HTMLImageElement) {
+    merchantLogoNode.src = paymentDetails.merchant_logo;
+  }
+
+  var descriptionNode = document.getElementById(
+    "hyper-checkout-amount-description"
+  );
+  if (descriptionNode instanceof HTMLDivElement) {
+    descriptionNode.innerText = paymentDetails.merchant_description || "";
+  }
+
+  var currencyNode = document.getElementById("hyper-checkout-amount-currency");
+  if (currencyNode instanceof HTMLSpanElement) {
+    currencyNode.innerText = paymentDetails.currency;
+  }
+
+  var amountInput = document.getElementById("hyper-checkout-amount-input");
+  if (amountInput instanceof HTMLInputElement) {
+    amountInput.step = Math.pow(10, -paymentDetails.currency_exponent).toString();
+    if (paymentDetails.min_amount) {
+      amountInput.min = paymentDetails.min_amount;
+    }
+    if (paymentDetails.max_amount) {
+      amountInput.max = paymentDetails.max_amount;
+    }
+  }
+
+  var limitsNode = document.getElementById("hyper-checkout-amount-limits");
+  if (limitsNode instanceof HTMLDivElement) {
+    var limits = [];
+    if (paymentDetails.min_amount) {
+      limits.push("Minimum " + paymentDetails.currency + " " + paymentDetails.min_amount);
+    }
+    if (paymentDetails.max_amount) {
+      limits.push("Maximum " + paymentDetails.currency + " " + paymentDetails.max_amount);
+    }
+    limitsNode.innerText = limits.join(" · ");
+  }
+}
+
+/**
+ * Trigger - on submitting the amount
+ * Uses
+ *    - Convert the entered amount to the lowest denomination of the currency
+ *    - Reload the payment link with the amount, which creates the payment
+ */
+function submitAmount(event) {
+  event.preventDefault();
+  // @ts-ignore
+  var paymentDetails = window.__PAYMENT_DETAILS;
+  var amountInput = document.getElementById("hyper-checkout-amount-input");
+  var errorNode = document.getElementById("hyper-checkout-amount-error");
+  if (!(amountInput instanceof HTMLInputElement)) {
+    return;
+  }
+
+  var amount = Math.round(
+    parseFloat(amountInput.value) *
+      Math.pow(10, paymentDetails.currency_exponent)
+  );
+  if (!isFinite(amount) || amount <= 0) {
+    if (errorNode instanceof HTMLDivElement) {
+      errorNode.innerText = "Please enter a valid amount";
+    }
+    return;
+  }
+
+  var url = new URL(window.location.href);
+  url.searchParams.set("amount", amount.toString());
+  window.location.href = url.toString();
+}
@@ -1,7 +1,10 @@
+pub mod account_updater;
 pub mod cards;
+pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 pub mod transformers;
 pub mod vault;
+pub mod webhooks;
 
 pub use api_models::enums::Connector;
 use api_models::payments::CardToken;
@@ -0,0 +1,697 @@
+use std::collections::HashMap;
+
+use ::cards::CardNumber;
+use api_models::payment_methods::{
+    self as api_payment_methods, CardDetailsPaymentMethod, PaymentMethodsData,
+};
+use common_utils::{date_time, ext_traits::ByteSliceExt};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing, Instrument};
+use serde::{Deserialize, Serialize};
+
+use super::{cards, webhooks as pm_webhooks};
+use crate::{
+    configs::settings,
+    core::errors::{self, CardAccountUpdaterError, CustomResult, RouterResult},
+    routes::{metrics, AppState},
+    types::{
+        api,
+        domain::{self, types::decrypt_with_key_store},
+        storage::{self, enums},
+    },
+};
+
+const CARD_ACCOUNT_UPDATER_TAG: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_NAME: &str = "CARD_ACCOUNT_UPDATER";
+const CARD_ACCOUNT_UPDATER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::CardAccountUpdaterWorkflow;
+
+/// A saved card submitted to the account updater provider
+#[derive(Debug, Clone)]
+pub struct AccountUpdateRequest {
+    pub payment_method_id: String,
+    pub card_number: CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+}
+
+/// The outcome reported by the account updater provider for a saved card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AccountUpdateResult {
+    NoChange,
+    NewExpiry {
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    NewAccountNumber {
+        new_card_number: CardNumber,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    AccountClosed,
+    ContactCardholder,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdateResponse {
+    pub payment_method_id: String,
+    pub result: AccountUpdateResult,
+}
+
+#[async_trait::async_trait]
+pub trait CardAccountUpdaterProvider: Send + Sync {
+    async fn get_account_updates(
+        &self,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<Vec<AccountUpdateResponse>, CardAccountUpdaterError>;
+}
+
+pub fn get_card_account_updater_provider(
+    config: &settings::CardAccountUpdaterSettings,
+) -> Box<dyn CardAccountUpdaterProvider> {
+    match config.provider {
+        settings::CardAccountUpdaterProviderKind::Mock => {
+            Box::new(MockCardAccountUpdaterProvider {
+                updates_file: config.mock_updates_file.clone(),
+            })
+        }
+    }
+}
+
+/// Reads card account updates from a local JSON file. Meant for local development and tests.
+pub struct MockCardAccountUpdaterProvider {
+    pub updates_file: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MockCardAccountUpdate {
+    card_number: CardNumber,
+    #[serde(flatten)]
+    result: AccountUpdateResult,
+}
+
+#[async_trait::async_trait]
+impl CardAccountUpdaterProvider for MockCardAccountUpdaterProvider {
+    async fn get_account_updates(
+        &self,
+        requests: &[AccountUpdateRequest],
+    ) -> CustomResult<Vec<AccountUpdateResponse>, CardAccountUpdaterError> {
+        let file_contents = tokio::fs::read(&self.updates_file)
+            .await
+            .change_context(CardAccountUpdaterError::FetchUpdatesFailed)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to read mock card account updates file: {}",
+                    self.updates_file
+                )
+            })?;
+        let updates: Vec<MockCardAccountUpdate> = file_contents
+            .parse_struct("MockCardAccountUpdates")
+            .change_context(CardAccountUpdaterError::ResponseDeserializationFailed)?;
+
+        Ok(requests
+            .iter()
+            .map(|request| AccountUpdateResponse {
+                payment_method_id: request.payment_method_id.clone(),
+                result: updates
+                    .iter()
+                    .find(|update| update.card_number == request.card_number)
+                    .map(|update| update.result.clone())
+                    .unwrap_or(AccountUpdateResult::NoChange),
+            })
+            .collect())
+    }
+}
+
+fn generate_task_id_for_account_updater_workflow(merchant_id: &str) -> String {
+    format!("{CARD_ACCOUNT_UPDATER_RUNNER}_{CARD_ACCOUNT_UPDATER_NAME}_{merchant_id}")
+}
+
+pub fn get_next_run_time(config: &settings::CardAccountUpdaterSettings) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::hours(i64::from(
+        config.run_interval_in_hours,
+    )))
+}
+
+/// Makes sure the saved cards of the merchant are periodically checked for updates, without
+/// blocking the caller.
+pub fn schedule_account_updater_task_in_background(state: &AppState, merchant_id: &str) {
+    if !state.conf.card_account_updater.enabled {
+        return;
+    }
+
+    let state = state.clone();
+    let merchant_id = merchant_id.to_owned();
+    tokio::spawn(
+        async move {
+            if let Err(error) = add_account_updater_task(&state, &merchant_id).await {
+                logger::error!(card_account_updater_error=?error);
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+async fn add_account_updater_task(state: &AppState, merchant_id: &str) -> RouterResult<()> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_account_updater_workflow(merchant_id);
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card account updater task")?;
+
+    match existing_process {
+        // The task was finished while the updater was disabled, start a fresh pass
+        Some(process) if process.status == enums::ProcessTrackerStatus::Finish => {
+            reschedule_account_updater_task(
+                state,
+                merchant_id,
+                0,
+                get_next_run_time(&state.conf.card_account_updater),
+            )
+            .await
+        }
+        Some(_) => Ok(()),
+        None => {
+            let tracking_data = storage::CardAccountUpdaterTrackingData {
+                merchant_id: merchant_id.to_owned(),
+                offset: 0,
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                CARD_ACCOUNT_UPDATER_NAME,
+                CARD_ACCOUNT_UPDATER_RUNNER,
+                [CARD_ACCOUNT_UPDATER_TAG],
+                tracking_data,
+                get_next_run_time(&state.conf.card_account_updater),
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct card account updater process tracker task")?;
+
+            match db.insert_process(process_tracker_entry).await {
+                Ok(_) => {
+                    metrics::TASKS_ADDED_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[metrics::request::add_attributes(
+                            "flow",
+                            "CardAccountUpdater",
+                        )],
+                    );
+                    Ok(())
+                }
+                // Another card of the merchant was saved concurrently and already added the task
+                Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                Err(error) => Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Failed while inserting card account updater task to process_tracker: \
+                             merchant_id: {merchant_id}"
+                        )
+                    })),
+            }
+        }
+    }
+}
+
+/// Schedules the next run of the workflow, continuing from `offset` in the merchant's saved cards.
+#[instrument(skip_all)]
+pub async fn reschedule_account_updater_task(
+    state: &AppState,
+    merchant_id: &str,
+    offset: i64,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = storage::CardAccountUpdaterTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        offset,
+    };
+    let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: None,
+        schedule_time: Some(schedule_time),
+        tracking_data: Some(
+            serde_json::to_value(tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize card account updater tracking data")?,
+        ),
+        business_status: Some("PENDING".to_string()),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(date_time::now()),
+    };
+
+    state
+        .store
+        .process_tracker_update_process_status_by_ids(
+            vec![generate_task_id_for_account_updater_workflow(merchant_id)],
+            updated_process_tracker_data,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule card account updater task")?;
+
+    Ok(())
+}
+
+/// Checks one batch of the merchant's saved cards, starting at `offset`, and applies the updates
+/// reported by the provider. Returns the number of payment methods in the batch.
+#[instrument(skip_all)]
+pub async fn run_account_updater_batch(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    offset: i64,
+) -> RouterResult<usize> {
+    let config = &state.conf.card_account_updater;
+    let db = &*state.store;
+
+    let payment_methods = db
+        .find_payment_method_by_merchant_id_payment_method(
+            &merchant_account.merchant_id,
+            enums::PaymentMethod::Card,
+            config.batch_size,
+            offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch saved cards of the merchant")?;
+    let batch_length = payment_methods.len();
+
+    let expiry_threshold = date_time::now().saturating_add(time::Duration::days(i64::from(
+        config.expiry_window_in_days,
+    )));
+
+    let mut stale_cards = HashMap::new();
+    let mut requests = Vec::new();
+    for pm in payment_methods {
+        if pm.status != enums::PaymentMethodStatus::Active {
+            continue;
+        }
+        let Some(saved_card) = get_saved_card_details(&pm, key_store).await else {
+            continue;
+        };
+        if !is_card_expiring(&saved_card, expiry_threshold) {
+            continue;
+        }
+
+        let locker_card = match cards::get_card_from_locker(
+            state,
+            &pm.customer_id,
+            &pm.merchant_id,
+            pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
+        )
+        .await
+        {
+            Ok(locker_card) => locker_card,
+            Err(error) => {
+                logger::error!(
+                    payment_method_id = %pm.payment_method_id,
+                    ?error,
+                    "Failed to fetch saved card from locker for account update"
+                );
+                continue;
+            }
+        };
+
+        requests.push(AccountUpdateRequest {
+            payment_method_id: pm.payment_method_id.clone(),
+            card_number: locker_card.card_number.clone(),
+            card_exp_month: locker_card.card_exp_month.clone(),
+            card_exp_year: locker_card.card_exp_year.clone(),
+        });
+        stale_cards.insert(pm.payment_method_id.clone(), (pm, saved_card, locker_card));
+    }
+
+    if requests.is_empty() {
+        return Ok(batch_length);
+    }
+
+    let provider = get_card_account_updater_provider(config);
+    let responses = provider
+        .get_account_updates(&requests)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card account updates from provider")?;
+
+    for response in responses {
+        let Some((pm, saved_card, locker_card)) = stale_cards.remove(&response.payment_method_id)
+        else {
+            continue;
+        };
+        let payment_method_id = pm.payment_method_id.clone();
+        let update_result = apply_account_update(
+            state,
+            merchant_account,
+            key_store,
+            pm,
+            saved_card,
+            locker_card,
+            response.result,
+        )
+        .await;
+
+        match update_result {
+            Ok(Some(updated_pm)) => {
+                logger::info!(%payment_method_id, "Applied card account update");
+                pm_webhooks::trigger_payment_method_webhook(
+                    state,
+                    merchant_account,
+                    key_store,
+                    &updated_pm,
+                    enums::EventType::PaymentMethodUpdated,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        %payment_method_id,
+                        ?error,
+                        "Failed to trigger payment method updated webhook"
+                    )
+                })
+                .ok();
+            }
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(
+                    %payment_method_id,
+                    ?error,
+                    "Failed to apply card account update"
+                );
+            }
+        }
+    }
+
+    Ok(batch_length)
+}
+
+/// Returns the updated payment method, or `None` if nothing was changed.
+#[instrument(skip_all)]
+async fn apply_account_update(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    pm: storage::PaymentMethod,
+    saved_card: CardDetailsPaymentMethod,
+    locker_card: api_payment_methods::Card,
+    result: AccountUpdateResult,
+) -> RouterResult<Option<storage::PaymentMethod>> {
+    let db = &*state.store;
+
+    let (card_number, card_exp_month, card_exp_year) = match result {
+        AccountUpdateResult::NoChange => return Ok(None),
+        AccountUpdateResult::ContactCardholder => {
+            logger::info!(
+                payment_method_id = %pm.payment_method_id,
+                "Card issuer asked to contact the cardholder; saved card left unchanged"
+            );
+            return Ok(None);
+        }
+        AccountUpdateResult::AccountClosed => {
+            let updated_pm = db
+                .update_payment_method(
+                    pm,
+                    storage::PaymentMethodUpdate::StatusUpdate {
+                        status: Some(enums::PaymentMethodStatus::Inactive),
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deactivate payment method of closed card account")?;
+            return Ok(Some(updated_pm));
+        }
+        AccountUpdateResult::NewExpiry {
+            card_exp_month,
+            card_exp_year,
+        } => (locker_card.card_number, card_exp_month, card_exp_year),
+        AccountUpdateResult::NewAccountNumber {
+            new_card_number,
+            card_exp_month,
+            card_exp_year,
+        } => (new_card_number, card_exp_month, card_exp_year),
+    };
+
+    let old_card_reference = pm
+        .locker_id
+        .clone()
+        .unwrap_or_else(|| pm.payment_method_id.clone());
+    let updated_card = api::CardDetail {
+        card_number,
+        card_exp_month,
+        card_exp_year,
+        card_holder_name: locker_card.name_on_card,
+        nick_name: locker_card.nick_name.map(Secret::new),
+        card_issuing_country: None,
+        card_network: None,
+        card_issuer: None,
+        card_type: None,
+    };
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: pm.payment_method,
+        payment_method_type: pm.payment_method_type,
+        payment_method_issuer: pm.payment_method_issuer.clone(),
+        payment_method_issuer_code: pm.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(pm.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+    };
+
+    // The updated card is stored as a new locker entry, and the old entry is deleted only once the
+    // payment method points to the new one, so that the payment method never refers to a missing
+    // card if any of the steps fail
+    let (locker_response, _) = cards::add_card_to_locker(
+        state,
+        new_pm,
+        &updated_card,
+        &pm.customer_id,
+        merchant_account,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add updated card to locker")?;
+    let new_card_reference = locker_response.payment_method_id;
+
+    let updated_pmd = get_updated_payment_method_data(saved_card, &updated_card);
+    let pm_data_encrypted = cards::create_encrypted_data(key_store, Some(updated_pmd)).await;
+
+    let customer_id = pm.customer_id.clone();
+    let merchant_id = pm.merchant_id.clone();
+    let updated_pm = match db
+        .update_payment_method(
+            pm,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: pm_data_encrypted,
+                status: None,
+                locker_id: Some(new_card_reference.clone()),
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(updated_pm) => updated_pm,
+        Err(error) => {
+            cards::delete_card_from_locker(state, &customer_id, &merchant_id, &new_card_reference)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to delete unused updated card from locker")
+                })
+                .ok();
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update payment method in db");
+        }
+    };
+
+    // The payment method already refers to the updated card, a leftover old card only takes up
+    // space in the locker
+    cards::delete_card_from_locker(state, &customer_id, &merchant_id, &old_card_reference)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete replaced card from locker"))
+        .ok();
+
+    Ok(Some(updated_pm))
+}
+
+fn get_updated_payment_method_data(
+    saved_card: CardDetailsPaymentMethod,
+    updated_card: &api::CardDetail,
+) -> PaymentMethodsData {
+    PaymentMethodsData::Card(CardDetailsPaymentMethod {
+        last4_digits: Some(updated_card.card_number.get_last4()),
+        expiry_month: Some(updated_card.card_exp_month.clone()),
+        expiry_year: Some(updated_card.card_exp_year.clone()),
+        card_isin: Some(updated_card.card_number.get_card_isin()),
+        ..saved_card
+    })
+}
+
+async fn get_saved_card_details(
+    pm: &storage::PaymentMethod,
+    key_store: &domain::MerchantKeyStore,
+) -> Option<CardDetailsPaymentMethod> {
+    decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store,
+    )
+    .await
+    .ok()
+    .flatten()
+    .map(|x| x.into_inner().expose())
+    .and_then(|v| serde_json::from_value::<PaymentMethodsData>(v).ok())
+    .and_then(|pmd| match pmd {
+        PaymentMethodsData::Card(card) => Some(card),
+        PaymentMethodsData::BankDetails(_) => None,
+    })
+}
+
+/// Whether the card has expired or expires before the month of `threshold`, inclusive
+fn is_card_expiring(card: &CardDetailsPaymentMethod, threshold: time::PrimitiveDateTime) -> bool {
+    let expiry_month = card
+        .expiry_month
+        .as_ref()
+        .and_then(|month| month.peek().trim().parse::<u8>().ok());
+    let expiry_year = card
+        .expiry_year
+        .as_ref()
+        .and_then(|year| year.peek().trim().parse::<i32>().ok())
+        // Two digit years are stored as entered by the customer
+        .map(|year| {
+            if year < 100 {
+                year.saturating_add(2000)
+            } else {
+                year
+            }
+        });
+
+    match (expiry_year, expiry_month) {
+        (Some(year), Some(month)) => {
+            (year, month) <= (threshold.year(), u8::from(threshold.month()))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_saved_card(expiry_month: &str, expiry_year: &str) -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            last4_digits: Some("4444".to_string()),
+            issuer_country: Some("US".to_string()),
+            expiry_month: Some(Secret::new(expiry_month.to_string())),
+            expiry_year: Some(Secret::new(expiry_year.to_string())),
+            nick_name: Some(Secret::new("Business card".to_string())),
+            card_holder_name: Some(Secret::new("John Doe".to_string())),
+            card_isin: Some("555555".to_string()),
+            card_issuer: None,
+            card_network: Some(api_models::enums::CardNetwork::Mastercard),
+            card_type: None,
+            saved_to_locker: true,
+        }
+    }
+
+    #[test]
+    fn test_is_card_expiring() {
+        let threshold = datetime!(2024-06-15 0:00);
+
+        assert!(is_card_expiring(&get_saved_card("05", "2024"), threshold));
+        assert!(is_card_expiring(&get_saved_card("06", "24"), threshold));
+        assert!(!is_card_expiring(&get_saved_card("07", "2024"), threshold));
+        assert!(!is_card_expiring(&get_saved_card("01", "2025"), threshold));
+        assert!(!is_card_expiring(&get_saved_card("", "2024"), threshold));
+    }
+
+    #[test]
+    fn test_get_updated_payment_method_data() {
+        let updated_card = api::CardDetail {
+            card_number: CardNumber::from_str("5105105105105100").unwrap(),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2031".to_string()),
+            card_holder_name: None,
+            nick_name: None,
+            card_issuing_country: None,
+            card_network: None,
+            card_issuer: None,
+            card_type: None,
+        };
+
+        let card =
+            match get_updated_payment_method_data(get_saved_card("05", "2024"), &updated_card) {
+                PaymentMethodsData::Card(card) => Some(card),
+                PaymentMethodsData::BankDetails(_) => None,
+            }
+            .unwrap();
+
+        assert_eq!(card.last4_digits.as_deref(), Some("5100"));
+        assert_eq!(card.card_isin.as_deref(), Some("510510"));
+        assert_eq!(card.expiry_month.unwrap().expose(), "10");
+        assert_eq!(card.expiry_year.unwrap().expose(), "2031");
+        // Details not reported by the account updater are kept
+        assert_eq!(card.nick_name.unwrap().expose(), "Business card");
+        assert_eq!(
+            card.card_network,
+            Some(api_models::enums::CardNetwork::Mastercard)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mock_card_account_updater_provider() {
+        let provider = MockCardAccountUpdaterProvider {
+            updates_file: "../../config/mock-card-account-updates.json".to_string(),
+        };
+        let get_request = |card_number: &str| AccountUpdateRequest {
+            payment_method_id: format!("pm_{card_number}"),
+            card_number: CardNumber::from_str(card_number).unwrap(),
+            card_exp_month: Secret::new("01".to_string()),
+            card_exp_year: Secret::new("2024".to_string()),
+        };
+
+        let responses = provider
+            .get_account_updates(&[
+                get_request("5555555555554444"),
+                get_request("378282246310005"),
+                get_request("4111111111111111"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(responses.len(), 3);
+        assert!(matches!(
+            responses.first().map(|response| &response.result),
+            Some(AccountUpdateResult::NewAccountNumber { new_card_number, .. })
+                if new_card_number.get_last4() == "5100"
+        ));
+        assert!(matches!(
+            responses.get(1).map(|response| &response.result),
+            Some(AccountUpdateResult::AccountClosed)
+        ));
+        // Cards unknown to the provider have not changed
+        assert!(matches!(
+            responses.get(2).map(|response| &response.result),
+            Some(AccountUpdateResult::NoChange)
+        ));
+    }
+}
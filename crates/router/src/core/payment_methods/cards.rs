@@ -45,7 +45,10 @@ use crate::{
     configs::settings,
     core::{
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{
+            account_updater, network_tokenization, transformers as payment_methods, vault,
+            webhooks as pm_webhooks,
+        },
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
@@ -64,7 +67,7 @@ use crate::{
         api::{self, routing as routing_types, PaymentMethodCreateExt},
         domain::{
             self,
-            types::{decrypt, encrypt_optional, AsyncLift},
+            types::{decrypt_with_key_store, encrypt_optional, AsyncLift},
         },
         storage::{self, enums, PaymentMethodListContext, PaymentTokenData},
         transformers::ForeignFrom,
@@ -75,12 +78,12 @@ use crate::{
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn create_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     req: &api::PaymentMethodCreate,
     customer_id: &str,
     payment_method_id: &str,
     locker_id: Option<String>,
-    merchant_id: &str,
+    merchant_account: &domain::MerchantAccount,
     pm_metadata: Option<serde_json::Value>,
     customer_acceptance: Option<serde_json::Value>,
     payment_method_data: Option<Encryption>,
@@ -88,9 +91,11 @@ pub async fn create_payment_method(
     connector_mandate_details: Option<serde_json::Value>,
     status: Option<enums::PaymentMethodStatus>,
     network_transaction_id: Option<String>,
-    storage_scheme: MerchantStorageScheme,
     payment_method_billing_address: Option<Encryption>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id.as_str();
+    let storage_scheme = merchant_account.storage_scheme;
     let customer = db
         .find_customer_by_customer_id_merchant_id(
             customer_id,
@@ -141,6 +146,10 @@ pub async fn create_payment_method(
                 last_used_at: current_time,
                 payment_method_billing_address,
                 updated_by: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
             },
             storage_scheme,
         )
@@ -148,14 +157,33 @@ pub async fn create_payment_method(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to add payment method in db")?;
 
+    // Payment methods awaiting data are announced once their data is saved
+    if response.status == enums::PaymentMethodStatus::Active {
+        pm_webhooks::trigger_payment_method_webhook(
+            state,
+            merchant_account,
+            key_store,
+            &response,
+            enums::EventType::PaymentMethodCreated,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                payment_method_id = %response.payment_method_id,
+                ?error,
+                "Failed to trigger payment method created webhook"
+            )
+        })
+        .ok();
+    }
+
     if customer.default_payment_method_id.is_none() && req.payment_method.is_some() {
         let _ = set_default_payment_method(
-            db,
-            merchant_id.to_string(),
+            state.clone(),
+            merchant_account.clone(),
             key_store.clone(),
             customer_id,
             payment_method_id.to_owned(),
-            storage_scheme,
         )
         .await
         .map_err(|err| logger::error!(error=?err,"Failed to set the payment method as default"));
@@ -194,13 +222,14 @@ pub fn store_default_payment_method(
 }
 #[instrument(skip_all)]
 pub async fn get_or_insert_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     req: api::PaymentMethodCreate,
     resp: &mut api::PaymentMethodResponse,
     merchant_account: &domain::MerchantAccount,
     customer_id: &str,
     key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<diesel_models::PaymentMethod> {
+    let db = &*state.store;
     let mut payment_method_id = resp.payment_method_id.clone();
     let mut locker_id = None;
     let payment_method = {
@@ -237,18 +266,17 @@ pub async fn get_or_insert_payment_method(
         Err(err) => {
             if err.current_context().is_db_not_found() {
                 insert_payment_method(
-                    db,
+                    state,
                     resp,
                     req,
                     key_store,
-                    &merchant_account.merchant_id,
+                    merchant_account,
                     customer_id,
                     resp.metadata.clone().map(|val| val.expose()),
                     None,
                     locker_id,
                     None,
                     None,
-                    merchant_account.storage_scheme,
                     None,
                 )
                 .await
@@ -268,8 +296,6 @@ pub async fn get_client_secret_or_add_payment_method(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
-    let db = &*state.store;
-    let merchant_id = &merchant_account.merchant_id;
     let customer_id = req.customer_id.clone().get_required_value("customer_id")?;
 
     #[cfg(not(feature = "payouts"))]
@@ -283,12 +309,12 @@ pub async fn get_client_secret_or_add_payment_method(
         let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
 
         let res = create_payment_method(
-            db,
+            &state,
             &req,
             customer_id.as_str(),
             payment_method_id.as_str(),
             None,
-            merchant_id.as_str(),
+            merchant_account,
             None,
             None,
             None,
@@ -296,7 +322,6 @@ pub async fn get_client_secret_or_add_payment_method(
             None,
             Some(enums::PaymentMethodStatus::AwaitingData),
             None,
-            merchant_account.storage_scheme,
             None,
         )
         .await?;
@@ -413,7 +438,7 @@ pub async fn add_payment_method_data(
                         .attach_printable("Failed to add payment method in db")?;
 
                         get_or_insert_payment_method(
-                            db,
+                            &state,
                             req.clone(),
                             &mut pm_resp,
                             &merchant_account,
@@ -465,23 +490,40 @@ pub async fn add_payment_method_data(
                             payment_method_type: req.payment_method_type,
                         };
 
-                        db.update_payment_method(
-                            payment_method,
-                            pm_update,
-                            merchant_account.storage_scheme,
+                        let updated_pm = db
+                            .update_payment_method(
+                                payment_method,
+                                pm_update,
+                                merchant_account.storage_scheme,
+                            )
+                            .await
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Failed to add payment method in db")?;
+
+                        pm_webhooks::trigger_payment_method_webhook(
+                            &state,
+                            &merchant_account,
+                            &key_store,
+                            &updated_pm,
+                            enums::EventType::PaymentMethodCreated,
                         )
                         .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Failed to add payment method in db")?;
+                        .map_err(|error| {
+                            logger::error!(
+                                payment_method_id = %updated_pm.payment_method_id,
+                                ?error,
+                                "Failed to trigger payment method created webhook"
+                            )
+                        })
+                        .ok();
 
                         if customer.default_payment_method_id.is_none() {
                             let _ = set_default_payment_method(
-                                db,
-                                merchant_account.merchant_id.clone(),
+                                state.clone(),
+                                merchant_account.clone(),
                                 key_store.clone(),
                                 customer_id.as_str(),
-                               pm_id,
-                               merchant_account.storage_scheme,
+                                pm_id,
                             )
                             .await
                             .map_err(|err| logger::error!(error=?err,"Failed to set the payment method as default"));
@@ -578,7 +620,7 @@ pub async fn add_payment_method(
         Some(duplication_check) => match duplication_check {
             payment_methods::DataDuplicationCheck::Duplicated => {
                 let existing_pm = get_or_insert_payment_method(
-                    db,
+                    &state,
                     req.clone(),
                     &mut resp,
                     merchant_account,
@@ -592,7 +634,7 @@ pub async fn add_payment_method(
             payment_methods::DataDuplicationCheck::MetaDataChanged => {
                 if let Some(card) = req.card.clone() {
                     let existing_pm = get_or_insert_payment_method(
-                        db,
+                        &state,
                         req.clone(),
                         &mut resp,
                         merchant_account,
@@ -670,14 +712,32 @@ pub async fn add_payment_method(
                         payment_method_data: pm_data_encrypted,
                     };
 
-                    db.update_payment_method(
-                        existing_pm,
-                        pm_update,
-                        merchant_account.storage_scheme,
+                    let updated_pm = db
+                        .update_payment_method(
+                            existing_pm,
+                            pm_update,
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to add payment method in db")?;
+
+                    pm_webhooks::trigger_payment_method_webhook(
+                        &state,
+                        merchant_account,
+                        key_store,
+                        &updated_pm,
+                        enums::EventType::PaymentMethodUpdated,
                     )
                     .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to add payment method in db")?;
+                    .map_err(|error| {
+                        logger::error!(
+                            payment_method_id = %updated_pm.payment_method_id,
+                            ?error,
+                            "Failed to trigger payment method updated webhook"
+                        )
+                    })
+                    .ok();
 
                     resp.client_secret = client_secret;
                 }
@@ -694,24 +754,37 @@ pub async fn add_payment_method(
                 None
             };
             resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+            let card = req.card.clone();
             let pm = insert_payment_method(
-                db,
+                &state,
                 &resp,
                 req,
                 key_store,
-                merchant_id,
+                merchant_account,
                 &customer_id,
                 pm_metadata.cloned(),
                 None,
                 locker_id,
                 None,
                 None,
-                merchant_account.storage_scheme,
                 None,
             )
             .await?;
 
-            resp.client_secret = pm.client_secret;
+            resp.client_secret = pm.client_secret.clone();
+
+            if let Some(card) = card {
+                account_updater::schedule_account_updater_task_in_background(
+                    &state,
+                    &pm.merchant_id,
+                );
+                network_tokenization::provision_network_token_in_background(
+                    &state,
+                    card,
+                    pm,
+                    merchant_account.storage_scheme,
+                );
+            }
         }
     }
 
@@ -720,18 +793,17 @@ pub async fn add_payment_method(
 
 #[allow(clippy::too_many_arguments)]
 pub async fn insert_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     resp: &api::PaymentMethodResponse,
     req: api::PaymentMethodCreate,
     key_store: &domain::MerchantKeyStore,
-    merchant_id: &str,
+    merchant_account: &domain::MerchantAccount,
     customer_id: &str,
     pm_metadata: Option<serde_json::Value>,
     customer_acceptance: Option<serde_json::Value>,
     locker_id: Option<String>,
     connector_mandate_details: Option<serde_json::Value>,
     network_transaction_id: Option<String>,
-    storage_scheme: MerchantStorageScheme,
     payment_method_billing_address: Option<Encryption>,
 ) -> errors::RouterResult<diesel_models::PaymentMethod> {
     let pm_card_details = resp
@@ -740,12 +812,12 @@ pub async fn insert_payment_method(
         .map(|card| PaymentMethodsData::Card(CardDetailsPaymentMethod::from(card.clone())));
     let pm_data_encrypted = create_encrypted_data(key_store, pm_card_details).await;
     create_payment_method(
-        db,
+        state,
         &req,
         customer_id,
         &resp.payment_method_id,
         locker_id,
-        merchant_id,
+        merchant_account,
         pm_metadata,
         customer_acceptance,
         pm_data_encrypted,
@@ -753,7 +825,6 @@ pub async fn insert_payment_method(
         connector_mandate_details,
         None,
         network_transaction_id,
-        storage_scheme,
         payment_method_billing_address,
     )
     .await
@@ -797,9 +868,9 @@ pub async fn update_customer_payment_method(
         }
 
         // Fetch the existing payment method data from db
-        let existing_card_data = decrypt::<serde_json::Value, masking::WithType>(
+        let existing_card_data = decrypt_with_key_store::<serde_json::Value, masking::WithType>(
             pm.payment_method_data.clone(),
-            key_store.key.get_inner().peek(),
+            &key_store,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -928,11 +999,29 @@ pub async fn update_customer_payment_method(
                 .payment_method_id
                 .clone_from(&pm.payment_method_id);
 
-            db.update_payment_method(pm, pm_update, merchant_account.storage_scheme)
+            let updated_pm = db
+                .update_payment_method(pm, pm_update, merchant_account.storage_scheme)
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed to update payment method in db")?;
 
+            pm_webhooks::trigger_payment_method_webhook(
+                &state,
+                &merchant_account,
+                &key_store,
+                &updated_pm,
+                enums::EventType::PaymentMethodUpdated,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    payment_method_id = %updated_pm.payment_method_id,
+                    ?error,
+                    "Failed to trigger payment method updated webhook"
+                )
+            })
+            .ok();
+
             add_card_resp
         } else {
             // Return existing payment method data as response without any changes
@@ -1240,14 +1329,12 @@ pub async fn decode_and_decrypt_locker_data(
     key_store: &domain::MerchantKeyStore,
     enc_card_data: String,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    // Fetch key
-    let key = key_store.key.get_inner().peek();
     // Decode
     let decoded_bytes = hex::decode(&enc_card_data)
         .change_context(errors::VaultError::ResponseDeserializationFailed)
         .attach_printable("Failed to decode hex string into bytes")?;
     // Decrypt
-    decrypt(Some(Encryption::new(decoded_bytes.into())), key)
+    decrypt_with_key_store(Some(Encryption::new(decoded_bytes.into())), key_store)
         .await
         .change_context(errors::VaultError::FetchPaymentMethodFailed)?
         .map_or(
@@ -3396,8 +3483,6 @@ pub async fn list_customer_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let key = key_store.key.get_inner().peek();
-
     let is_requires_cvv = db
         .find_config_by_key_unwrap_or(
             format!("{}_requires_cvv", merchant_account.merchant_id).as_str(),
@@ -3428,7 +3513,8 @@ pub async fn list_customer_payment_method(
 
         let payment_method_retrieval_context = match payment_method {
             enums::PaymentMethod::Card => {
-                let card_details = get_card_details_with_locker_fallback(&pm, key, state).await?;
+                let card_details =
+                    get_card_details_with_locker_fallback(&pm, &key_store, state).await?;
 
                 if card_details.is_some() {
                     PaymentMethodListContext {
@@ -3448,7 +3534,7 @@ pub async fn list_customer_payment_method(
 
             enums::PaymentMethod::BankDebit => {
                 // Retrieve the pm_auth connector details so that it can be tokenized
-                let bank_account_token_data = get_bank_account_connector_details(&pm, key)
+                let bank_account_token_data = get_bank_account_connector_details(&pm, &key_store)
                     .await
                     .unwrap_or_else(|err| {
                         logger::error!(error=?err);
@@ -3509,7 +3595,7 @@ pub async fn list_customer_payment_method(
 
         // Retrieve the masked bank details to be sent as a response
         let bank_details = if payment_method == enums::PaymentMethod::BankDebit {
-            get_masked_bank_details(&pm, key)
+            get_masked_bank_details(&pm, &key_store)
                 .await
                 .unwrap_or_else(|err| {
                     logger::error!(error=?err);
@@ -3521,7 +3607,7 @@ pub async fn list_customer_payment_method(
 
         let payment_method_billing = decrypt_generic_data::<api_models::payments::Address>(
             pm.payment_method_billing_address,
-            key,
+            &key_store,
         )
         .await
         .attach_printable("unable to decrypt payment method billing address details")?;
@@ -3670,16 +3756,17 @@ pub async fn list_customer_payment_method(
 
 pub async fn decrypt_generic_data<T>(
     data: Option<Encryption>,
-    key: &[u8],
+    key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<Option<T>>
 where
     T: serde::de::DeserializeOwned,
 {
-    let decrypted_data = decrypt::<serde_json::Value, masking::WithType>(data, key)
-        .await
-        .change_context(errors::StorageError::DecryptionError)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("unable to decrypt data")?;
+    let decrypted_data =
+        decrypt_with_key_store::<serde_json::Value, masking::WithType>(data, key_store)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to decrypt data")?;
 
     decrypted_data
         .map(|decrypted_data| decrypted_data.into_inner().expose())
@@ -3691,22 +3778,24 @@ where
 
 pub async fn get_card_details_with_locker_fallback(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key_store: &domain::MerchantKeyStore,
     state: &routes::AppState,
 ) -> errors::RouterResult<Option<api::CardDetailFromLocker>> {
-    let card_decrypted =
-        decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-            .attach_printable("unable to decrypt card details")
-            .ok()
-            .flatten()
-            .map(|x| x.into_inner().expose())
-            .and_then(|v| serde_json::from_value::<PaymentMethodsData>(v).ok())
-            .and_then(|pmd| match pmd {
-                PaymentMethodsData::Card(crd) => Some(api::CardDetailFromLocker::from(crd)),
-                _ => None,
-            });
+    let card_decrypted = decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store,
+    )
+    .await
+    .change_context(errors::StorageError::DecryptionError)
+    .attach_printable("unable to decrypt card details")
+    .ok()
+    .flatten()
+    .map(|x| x.into_inner().expose())
+    .and_then(|v| serde_json::from_value::<PaymentMethodsData>(v).ok())
+    .and_then(|pmd| match pmd {
+        PaymentMethodsData::Card(crd) => Some(api::CardDetailFromLocker::from(crd)),
+        _ => None,
+    });
 
     Ok(if let Some(mut crd) = card_decrypted {
         if crd.saved_to_locker {
@@ -3722,22 +3811,24 @@ pub async fn get_card_details_with_locker_fallback(
 
 pub async fn get_card_details_without_locker_fallback(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key_store: &domain::MerchantKeyStore,
     state: &routes::AppState,
 ) -> errors::RouterResult<api::CardDetailFromLocker> {
-    let card_decrypted =
-        decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-            .attach_printable("unable to decrypt card details")
-            .ok()
-            .flatten()
-            .map(|x| x.into_inner().expose())
-            .and_then(|v| serde_json::from_value::<PaymentMethodsData>(v).ok())
-            .and_then(|pmd| match pmd {
-                PaymentMethodsData::Card(crd) => Some(api::CardDetailFromLocker::from(crd)),
-                _ => None,
-            });
+    let card_decrypted = decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store,
+    )
+    .await
+    .change_context(errors::StorageError::DecryptionError)
+    .attach_printable("unable to decrypt card details")
+    .ok()
+    .flatten()
+    .map(|x| x.into_inner().expose())
+    .and_then(|v| serde_json::from_value::<PaymentMethodsData>(v).ok())
+    .and_then(|pmd| match pmd {
+        PaymentMethodsData::Card(crd) => Some(api::CardDetailFromLocker::from(crd)),
+        _ => None,
+    });
 
     Ok(if let Some(mut crd) = card_decrypted {
         crd.scheme.clone_from(&pm.scheme);
@@ -3788,24 +3879,26 @@ pub async fn get_lookup_key_from_locker(
 
 async fn get_masked_bank_details(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<Option<MaskedBankDetails>> {
-    let payment_method_data =
-        decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to decrypt bank details")?
-            .map(|x| x.into_inner().expose())
-            .map(
-                |v| -> Result<PaymentMethodsData, error_stack::Report<errors::ApiErrorResponse>> {
-                    v.parse_value::<PaymentMethodsData>("PaymentMethodsData")
-                        .change_context(errors::StorageError::DeserializationFailed)
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Failed to deserialize Payment Method Auth config")
-                },
-            )
-            .transpose()?;
+    let payment_method_data = decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store,
+    )
+    .await
+    .change_context(errors::StorageError::DecryptionError)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to decrypt bank details")?
+    .map(|x| x.into_inner().expose())
+    .map(
+        |v| -> Result<PaymentMethodsData, error_stack::Report<errors::ApiErrorResponse>> {
+            v.parse_value::<PaymentMethodsData>("PaymentMethodsData")
+                .change_context(errors::StorageError::DeserializationFailed)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize Payment Method Auth config")
+        },
+    )
+    .transpose()?;
 
     match payment_method_data {
         Some(pmd) => match pmd {
@@ -3821,24 +3914,26 @@ async fn get_masked_bank_details(
 
 async fn get_bank_account_connector_details(
     pm: &payment_method::PaymentMethod,
-    key: &[u8],
+    key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<Option<BankAccountTokenData>> {
-    let payment_method_data =
-        decrypt::<serde_json::Value, masking::WithType>(pm.payment_method_data.clone(), key)
-            .await
-            .change_context(errors::StorageError::DecryptionError)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to decrypt bank details")?
-            .map(|x| x.into_inner().expose())
-            .map(
-                |v| -> Result<PaymentMethodsData, error_stack::Report<errors::ApiErrorResponse>> {
-                    v.parse_value::<PaymentMethodsData>("PaymentMethodsData")
-                        .change_context(errors::StorageError::DeserializationFailed)
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Failed to deserialize Payment Method Auth config")
-                },
-            )
-            .transpose()?;
+    let payment_method_data = decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+        pm.payment_method_data.clone(),
+        key_store,
+    )
+    .await
+    .change_context(errors::StorageError::DecryptionError)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("unable to decrypt bank details")?
+    .map(|x| x.into_inner().expose())
+    .map(
+        |v| -> Result<PaymentMethodsData, error_stack::Report<errors::ApiErrorResponse>> {
+            v.parse_value::<PaymentMethodsData>("PaymentMethodsData")
+                .change_context(errors::StorageError::DeserializationFailed)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize Payment Method Auth config")
+        },
+    )
+    .transpose()?;
 
     match payment_method_data {
         Some(pmd) => match pmd {
@@ -3875,13 +3970,15 @@ async fn get_bank_account_connector_details(
     }
 }
 pub async fn set_default_payment_method(
-    db: &dyn db::StorageInterface,
-    merchant_id: String,
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     customer_id: &str,
     payment_method_id: String,
-    storage_scheme: MerchantStorageScheme,
 ) -> errors::RouterResponse<CustomerDefaultPaymentMethodResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id.clone();
+    let storage_scheme = merchant_account.storage_scheme;
     //check for the customer
     let customer = db
         .find_customer_by_customer_id_merchant_id(
@@ -3939,6 +4036,23 @@ pub async fn set_default_payment_method(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update the default payment method id for the customer")?;
 
+    pm_webhooks::trigger_payment_method_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_method,
+        enums::EventType::DefaultPaymentMethodChanged,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            payment_method_id = %payment_method.payment_method_id,
+            ?error,
+            "Failed to trigger default payment method changed webhook"
+        )
+    })
+    .ok();
+
     let resp = CustomerDefaultPaymentMethodResponse {
         default_payment_method_id: updated_customer_details.default_payment_method_id,
         customer_id,
@@ -4119,7 +4233,6 @@ pub async fn retrieve_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
-    let key = key_store.key.peek();
     let card = if pm.payment_method == Some(enums::PaymentMethod::Card) {
         let card_detail = if state.conf.locker.locker_enabled {
             let card = get_card_from_locker(
@@ -4135,7 +4248,7 @@ pub async fn retrieve_payment_method(
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Failed while getting card details from locker")?
         } else {
-            get_card_details_without_locker_fallback(&pm, key, &state).await?
+            get_card_details_without_locker_fallback(&pm, &key_store, &state).await?
         };
         Some(card_detail)
     } else {
@@ -4205,6 +4318,18 @@ pub async fn delete_payment_method(
         || Err(errors::ApiErrorResponse::PaymentMethodDeleteFailed),
     )?;
 
+    // The card details are read before the card is removed from locker
+    let webhook_content = pm_webhooks::get_payment_method_webhook_content(&state, &key_store, &key)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                payment_method_id = %key.payment_method_id,
+                ?error,
+                "Failed to construct payment method webhook"
+            )
+        })
+        .ok();
+
     if key.payment_method == Some(enums::PaymentMethod::Card) {
         let response = delete_card_from_locker(
             &state,
@@ -4220,6 +4345,13 @@ pub async fn delete_payment_method(
             logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
             Err(errors::ApiErrorResponse::InternalServerError)?
         }
+
+        if key.network_token_reference_id.is_some() {
+            network_tokenization::delete_network_token(&state, &key)
+                .await
+                .map_err(|error| logger::error!(network_token_deletion_error=?error))
+                .ok();
+        }
     }
 
     db.delete_payment_method_by_merchant_id_payment_method_id(
@@ -4247,6 +4379,25 @@ pub async fn delete_payment_method(
         .attach_printable("Failed to update the default payment method id for the customer")?;
     };
 
+    if let Some(content) = webhook_content {
+        pm_webhooks::send_payment_method_webhook(
+            &state,
+            &merchant_account,
+            &key_store,
+            content,
+            enums::EventType::PaymentMethodDeleted,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(
+                payment_method_id = %pm_id.payment_method_id,
+                ?error,
+                "Failed to trigger payment method deleted webhook"
+            )
+        })
+        .ok();
+    }
+
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodDeleteResponse {
             payment_method_id: key.payment_method_id,
@@ -0,0 +1,816 @@
+use api_models::{enums as api_enums, payment_methods as api_payment_methods};
+use base64::Engine;
+use common_utils::{
+    crypto::{self, VerifySignature},
+    ext_traits::{ByteSliceExt, Encode, ValueExt},
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use rand::Rng;
+use router_env::{instrument, tracing, Instrument};
+use serde::{Deserialize, Serialize};
+
+use super::transformers as payment_methods;
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult, NetworkTokenizationError, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::storage,
+    utils::ConnectorResponseExt,
+};
+
+/// Header carrying the hex encoded HMAC-SHA256 signature of a network token lifecycle webhook
+pub const NETWORK_TOKEN_WEBHOOK_SIGNATURE_HEADER: &str = "x-network-token-signature";
+
+/// Time (in seconds) for which a network token lifecycle webhook is accepted after it was sent.
+/// Older webhooks are rejected as replays.
+const NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS: i64 = 300;
+
+/// Non sensitive details of a network token, stored along with the payment method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkTokenDetails {
+    pub card_network: api_enums::CardNetwork,
+    pub token_last4: String,
+    pub token_exp_month: String,
+    pub token_exp_year: String,
+    pub card_art_url: Option<String>,
+    pub cryptogram_supported: bool,
+}
+
+/// A network token (DPAN) issued by the card network for a funding card
+#[derive(Debug, Clone)]
+pub struct ProvisionedNetworkToken {
+    pub token_reference_id: String,
+    pub token_number: cards::CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub card_art_url: Option<String>,
+    pub cryptogram_supported: bool,
+}
+
+/// The network token to be sent to the connector in place of the funding card
+#[derive(Debug, Clone)]
+pub struct NetworkTokenForPayment {
+    pub token_number: cards::CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub cryptogram: Option<Secret<String>>,
+}
+
+#[async_trait::async_trait]
+pub trait NetworkTokenProvider: Send + Sync {
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api_payment_methods::CardDetail,
+        card_network: api_enums::CardNetwork,
+    ) -> CustomResult<ProvisionedNetworkToken, NetworkTokenizationError>;
+
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, NetworkTokenizationError>;
+
+    async fn delete_token(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<(), NetworkTokenizationError>;
+}
+
+pub fn get_network_token_provider(
+    config: &settings::NetworkTokenization,
+) -> Box<dyn NetworkTokenProvider> {
+    match config.provider {
+        settings::NetworkTokenProviderKind::Mock => Box::new(MockNetworkTokenProvider),
+        settings::NetworkTokenProviderKind::TokenRequestor => Box::new(TokenRequestorProvider {
+            base_url: config.token_requestor_base_url.clone(),
+            api_key: config.token_requestor_api_key.clone(),
+        }),
+    }
+}
+
+/// Issues tokens locally, without calling any card network. Meant for local development and tests.
+pub struct MockNetworkTokenProvider;
+
+impl MockNetworkTokenProvider {
+    fn generate_token_number(card_network: &api_enums::CardNetwork) -> String {
+        let prefix = match card_network {
+            api_enums::CardNetwork::Mastercard => "5",
+            api_enums::CardNetwork::AmericanExpress => "37",
+            api_enums::CardNetwork::Discover => "6011",
+            _ => "4",
+        };
+        let mut rng = rand::thread_rng();
+        let mut digits: Vec<u32> = prefix.chars().filter_map(|c| c.to_digit(10)).collect();
+        while digits.len() < 15 {
+            digits.push(rng.gen_range(0..10));
+        }
+        digits.push(luhn_check_digit(&digits));
+        digits
+            .iter()
+            .filter_map(|digit| char::from_digit(*digit, 10))
+            .collect()
+    }
+}
+
+fn luhn_check_digit(digits: &[u32]) -> u32 {
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 0 {
+                let doubled = digit.saturating_mul(2);
+                if doubled > 9 {
+                    doubled.saturating_sub(9)
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .fold(0u32, |acc, digit| acc.saturating_add(digit));
+    (10u32.saturating_sub(sum % 10)) % 10
+}
+
+#[async_trait::async_trait]
+impl NetworkTokenProvider for MockNetworkTokenProvider {
+    async fn provision_token(
+        &self,
+        _state: &AppState,
+        card: &api_payment_methods::CardDetail,
+        card_network: api_enums::CardNetwork,
+    ) -> CustomResult<ProvisionedNetworkToken, NetworkTokenizationError> {
+        let token_number = cards::CardNumber::try_from(Self::generate_token_number(&card_network))
+            .change_context(NetworkTokenizationError::ApiError)
+            .attach_printable("Failed to generate mock network token")?;
+
+        Ok(ProvisionedNetworkToken {
+            token_reference_id: common_utils::generate_id(consts::ID_LENGTH, "ntref"),
+            token_number,
+            token_exp_month: card.card_exp_month.clone(),
+            token_exp_year: card.card_exp_year.clone(),
+            card_art_url: None,
+            cryptogram_supported: true,
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        _state: &AppState,
+        _token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, NetworkTokenizationError> {
+        let cryptogram: [u8; 20] = rand::thread_rng().gen();
+        Ok(Secret::new(consts::BASE64_ENGINE.encode(cryptogram)))
+    }
+
+    async fn delete_token(
+        &self,
+        _state: &AppState,
+        _token_reference_id: &str,
+    ) -> CustomResult<(), NetworkTokenizationError> {
+        Ok(())
+    }
+}
+
+/// Provisions tokens with Visa and Mastercard through a token requestor service
+pub struct TokenRequestorProvider {
+    base_url: String,
+    api_key: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct TokenRequestorProvisionRequest {
+    card_number: cards::CardNumber,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    card_holder_name: Option<Secret<String>>,
+    card_network: api_enums::CardNetwork,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenRequestorProvisionResponse {
+    token_reference_id: String,
+    token_number: cards::CardNumber,
+    token_exp_month: Secret<String>,
+    token_exp_year: Secret<String>,
+    card_art_url: Option<String>,
+    #[serde(default)]
+    cryptogram_supported: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenRequestorCryptogramResponse {
+    cryptogram: Secret<String>,
+}
+
+impl TokenRequestorProvider {
+    fn build_request(
+        &self,
+        method: services::Method,
+        endpoint: &str,
+        body: Option<RequestContent>,
+    ) -> services::Request {
+        let mut request_builder = services::RequestBuilder::new()
+            .method(method)
+            .url(&format!("{}{}", self.base_url, endpoint))
+            .attach_default_headers()
+            .headers(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    "application/json".to_string().into(),
+                ),
+                (
+                    headers::API_KEY.to_string(),
+                    self.api_key.clone().expose().into_masked(),
+                ),
+            ]);
+        if let Some(body) = body {
+            request_builder = request_builder.set_body(body);
+        }
+        request_builder.build()
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkTokenProvider for TokenRequestorProvider {
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api_payment_methods::CardDetail,
+        card_network: api_enums::CardNetwork,
+    ) -> CustomResult<ProvisionedNetworkToken, NetworkTokenizationError> {
+        let body = TokenRequestorProvisionRequest {
+            card_number: card.card_number.clone(),
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year: card.card_exp_year.clone(),
+            card_holder_name: card.card_holder_name.clone(),
+            card_network,
+        };
+        let request = self.build_request(
+            services::Method::Post,
+            "/tokens",
+            Some(RequestContent::Json(Box::new(body))),
+        );
+
+        let response: TokenRequestorProvisionResponse =
+            services::call_connector_api(state, request, "provision_network_token")
+                .await
+                .change_context(NetworkTokenizationError::ApiError)
+                .get_response_inner("TokenRequestorProvisionResponse")
+                .change_context(NetworkTokenizationError::ResponseDeserializationFailed)?;
+
+        Ok(ProvisionedNetworkToken {
+            token_reference_id: response.token_reference_id,
+            token_number: response.token_number,
+            token_exp_month: response.token_exp_month,
+            token_exp_year: response.token_exp_year,
+            card_art_url: response.card_art_url,
+            cryptogram_supported: response.cryptogram_supported,
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<Secret<String>, NetworkTokenizationError> {
+        let request = self.build_request(
+            services::Method::Post,
+            &format!("/tokens/{token_reference_id}/cryptograms"),
+            None,
+        );
+
+        let response: TokenRequestorCryptogramResponse =
+            services::call_connector_api(state, request, "get_network_token_cryptogram")
+                .await
+                .change_context(NetworkTokenizationError::ApiError)
+                .get_response_inner("TokenRequestorCryptogramResponse")
+                .change_context(NetworkTokenizationError::ResponseDeserializationFailed)?;
+
+        Ok(response.cryptogram)
+    }
+
+    async fn delete_token(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+    ) -> CustomResult<(), NetworkTokenizationError> {
+        let request = self.build_request(
+            services::Method::Delete,
+            &format!("/tokens/{token_reference_id}"),
+            None,
+        );
+
+        services::call_connector_api(state, request, "delete_network_token")
+            .await
+            .change_context(NetworkTokenizationError::ApiError)?
+            .map_err(|error_response| {
+                report!(NetworkTokenizationError::ApiError).attach_printable(format!(
+                    "Token requestor responded with status code {}",
+                    error_response.status_code
+                ))
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Returns the card network of the card if network tokenization is enabled for it
+async fn get_card_network_for_tokenization(
+    state: &AppState,
+    card: &api_payment_methods::CardDetail,
+) -> Option<api_enums::CardNetwork> {
+    let config = state.conf.network_tokenization.get_inner();
+    if !config.enabled {
+        return None;
+    }
+
+    let card_network = match card.card_network.clone() {
+        Some(card_network) => Some(card_network),
+        None => state
+            .store
+            .get_card_info(&card.card_number.get_card_isin())
+            .await
+            .map_err(|error| logger::error!(card_info_error=?error))
+            .ok()
+            .flatten()
+            .and_then(|card_info| card_info.card_network),
+    };
+
+    card_network.filter(|card_network| config.card_networks.contains(card_network))
+}
+
+/// Provisions a network token for a newly saved card and stores it along with the payment method.
+///
+/// Provisioning happens in the background and failures are only logged, the payment method keeps
+/// working with the funding card in that case.
+pub fn provision_network_token_in_background(
+    state: &AppState,
+    card: api_payment_methods::CardDetail,
+    payment_method: storage::PaymentMethod,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) {
+    let state = state.clone();
+    tokio::spawn(
+        async move {
+            if let Err(error) =
+                provision_network_token(&state, &card, payment_method, storage_scheme).await
+            {
+                logger::error!(network_tokenization_error=?error);
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+#[instrument(skip_all)]
+async fn provision_network_token(
+    state: &AppState,
+    card: &api_payment_methods::CardDetail,
+    payment_method: storage::PaymentMethod,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> CustomResult<(), NetworkTokenizationError> {
+    let Some(card_network) = get_card_network_for_tokenization(state, card).await else {
+        return Ok(());
+    };
+
+    let provider = get_network_token_provider(state.conf.network_tokenization.get_inner());
+    let network_token = provider
+        .provision_token(state, card, card_network.clone())
+        .await?;
+
+    let store_token_request =
+        payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+            merchant_id: &payment_method.merchant_id,
+            merchant_customer_id: payment_method.customer_id.clone(),
+            requestor_card_reference: None,
+            card: api_payment_methods::Card {
+                card_number: network_token.token_number.clone(),
+                name_on_card: card.card_holder_name.clone(),
+                card_exp_month: network_token.token_exp_month.clone(),
+                card_exp_year: network_token.token_exp_year.clone(),
+                card_brand: Some(card_network.to_string()),
+                card_isin: None,
+                nick_name: None,
+            },
+        });
+    let stored_token = super::cards::call_to_locker_hs(
+        state,
+        &store_token_request,
+        &payment_method.customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(NetworkTokenizationError::SaveNetworkTokenFailed)?;
+
+    let network_token_details = NetworkTokenDetails {
+        card_network,
+        token_last4: network_token.token_number.get_last4(),
+        token_exp_month: network_token.token_exp_month.expose(),
+        token_exp_year: network_token.token_exp_year.expose(),
+        card_art_url: network_token.card_art_url,
+        cryptogram_supported: network_token.cryptogram_supported,
+    }
+    .encode_to_value()
+    .change_context(NetworkTokenizationError::UpdatePaymentMethodFailed)?;
+
+    let payment_method_update = storage::PaymentMethodUpdate::NetworkTokenUpdate {
+        network_token_reference_id: Some(network_token.token_reference_id),
+        network_token_locker_id: Some(stored_token.card_reference),
+        network_token_status: Some(api_enums::NetworkTokenStatus::Active),
+        network_token_details: Some(network_token_details),
+    };
+    state
+        .store
+        .update_payment_method(payment_method, payment_method_update, storage_scheme)
+        .await
+        .change_context(NetworkTokenizationError::UpdatePaymentMethodFailed)?;
+
+    Ok(())
+}
+
+fn get_network_token_details(
+    payment_method: &storage::PaymentMethod,
+) -> Option<NetworkTokenDetails> {
+    payment_method
+        .network_token_details
+        .clone()
+        .map(|details| details.parse_value("NetworkTokenDetails"))
+        .transpose()
+        .map_err(|error| logger::error!(network_token_details_parsing_error=?error))
+        .ok()
+        .flatten()
+}
+
+/// Fetches the active network token of a saved card to be used for a payment.
+///
+/// A cryptogram is requested only for customer initiated transactions, merchant initiated
+/// transactions are authorized with the token alone.
+#[instrument(skip_all)]
+pub async fn get_network_token_for_payment(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    is_customer_initiated: bool,
+) -> CustomResult<Option<NetworkTokenForPayment>, NetworkTokenizationError> {
+    let config = state.conf.network_tokenization.get_inner();
+    let (true, Some(api_enums::NetworkTokenStatus::Active), Some(locker_id), Some(reference_id)) = (
+        config.enabled,
+        payment_method.network_token_status,
+        payment_method.network_token_locker_id.as_ref(),
+        payment_method.network_token_reference_id.as_ref(),
+    ) else {
+        return Ok(None);
+    };
+
+    let token_card = super::cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        locker_id,
+    )
+    .await
+    .change_context(NetworkTokenizationError::FetchNetworkTokenFailed)?;
+
+    let cryptogram_supported = get_network_token_details(payment_method)
+        .map(|details| details.cryptogram_supported)
+        .unwrap_or(false);
+    let cryptogram = if is_customer_initiated && cryptogram_supported {
+        Some(
+            get_network_token_provider(config)
+                .get_cryptogram(state, reference_id)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    Ok(Some(NetworkTokenForPayment {
+        token_number: token_card.card_number,
+        token_exp_month: token_card.card_exp_month,
+        token_exp_year: token_card.card_exp_year,
+        cryptogram,
+    }))
+}
+
+/// Deletes the network token of a payment method at the provider and in the card vault
+#[instrument(skip_all)]
+pub async fn delete_network_token(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+) -> CustomResult<(), NetworkTokenizationError> {
+    if let Some(reference_id) = payment_method.network_token_reference_id.as_ref() {
+        get_network_token_provider(state.conf.network_tokenization.get_inner())
+            .delete_token(state, reference_id)
+            .await?;
+    }
+
+    if let Some(locker_id) = payment_method.network_token_locker_id.as_ref() {
+        super::cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            locker_id,
+        )
+        .await
+        .change_context(NetworkTokenizationError::FetchNetworkTokenFailed)?;
+    }
+
+    Ok(())
+}
+
+fn verify_network_token_webhook_signature(
+    config: &settings::NetworkTokenization,
+    request_headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> RouterResult<()> {
+    if config.webhook_secret.peek().is_empty() {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token webhook secret is not configured");
+    }
+
+    let signature = request_headers
+        .get(NETWORK_TOKEN_WEBHOOK_SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Missing network token webhook signature")?;
+    let signature = hex::decode(signature)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Network token webhook signature is not hex encoded")?;
+
+    let is_verified = crypto::HmacSha256
+        .verify_signature(config.webhook_secret.peek().as_bytes(), &signature, body)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    if is_verified {
+        Ok(())
+    } else {
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token webhook signature verification failed")
+    }
+}
+
+/// The timestamp is covered by the signature, so a captured webhook cannot be replayed once it is
+/// older than the tolerance
+fn validate_network_token_webhook_timestamp(timestamp: i64, now: i64) -> RouterResult<()> {
+    if now.saturating_sub(timestamp).abs() > NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS {
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network token webhook timestamp is outside the tolerance")
+    } else {
+        Ok(())
+    }
+}
+
+fn get_network_token_webhook_event_key(event_id: &str) -> String {
+    format!("network_token_webhook_{event_id}")
+}
+
+/// Applies a token lifecycle event sent by the card network to the payment method holding the token
+#[instrument(skip_all)]
+pub async fn handle_network_token_webhook(
+    state: AppState,
+    request_headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> errors::RouterResponse<()> {
+    let config = state.conf.network_tokenization.get_inner();
+    if !config.enabled {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("Network tokenization is disabled");
+    }
+    verify_network_token_webhook_signature(config, request_headers, body)?;
+
+    let webhook: api_payment_methods::NetworkTokenWebhookRequest = body
+        .parse_struct("NetworkTokenWebhookRequest")
+        .change_context(errors::ApiErrorResponse::WebhookUnprocessableEntity)?;
+    validate_network_token_webhook_timestamp(
+        webhook.timestamp,
+        common_utils::date_time::now_unix_timestamp(),
+    )?;
+
+    // Webhooks older than the tolerance are rejected above, so the event ids only need to be
+    // remembered for as long as a webhook is accepted
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let event_key = get_network_token_webhook_event_key(&webhook.event_id);
+    let setnx_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &event_key,
+            webhook.timestamp,
+            Some(NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS.saturating_mul(2)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the network token webhook event")?;
+    if let redis_interface::SetnxReply::KeyNotSet = setnx_reply {
+        logger::info!(
+            event_id = %webhook.event_id,
+            "Discarding network token webhook that was already received"
+        );
+        return Ok(services::ApplicationResponse::StatusOk);
+    }
+
+    match apply_network_token_webhook(&state, &webhook).await {
+        Ok(()) => Ok(services::ApplicationResponse::StatusOk),
+        Err(error) => {
+            // Lets the card network retry the webhook
+            redis_conn
+                .delete_key(&event_key)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to forget the network token webhook event")
+                })
+                .ok();
+            Err(error)
+        }
+    }
+}
+
+async fn apply_network_token_webhook(
+    state: &AppState,
+    webhook: &api_payment_methods::NetworkTokenWebhookRequest,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method_by_network_token_reference_id(&webhook.token_reference_id)
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &payment_method.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key store")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&payment_method.merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant account")?;
+
+    let payment_method_update = match webhook.event_type {
+        api_payment_methods::NetworkTokenEventType::TokenSuspended => {
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: Some(api_enums::NetworkTokenStatus::Suspended),
+                network_token_details: None,
+            }
+        }
+        api_payment_methods::NetworkTokenEventType::TokenResumed => {
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: Some(api_enums::NetworkTokenStatus::Active),
+                network_token_details: None,
+            }
+        }
+        api_payment_methods::NetworkTokenEventType::TokenDeleted => {
+            if let Some(locker_id) = payment_method.network_token_locker_id.as_ref() {
+                super::cards::delete_card_from_locker(
+                    state,
+                    &payment_method.customer_id,
+                    &payment_method.merchant_id,
+                    locker_id,
+                )
+                .await
+                .map_err(|error| logger::error!(network_token_locker_deletion_error=?error))
+                .ok();
+            }
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: Some(api_enums::NetworkTokenStatus::Deleted),
+                network_token_details: None,
+            }
+        }
+        api_payment_methods::NetworkTokenEventType::CardArtUpdated => {
+            let network_token_details = get_network_token_details(&payment_method)
+                .map(|details| NetworkTokenDetails {
+                    card_art_url: webhook.card_art_url.clone(),
+                    ..details
+                })
+                .map(|details| details.encode_to_value())
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode network token details")?;
+            storage::PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details,
+            }
+        }
+    };
+
+    db.update_payment_method(
+        payment_method,
+        payment_method_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the network token of the payment method")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use common_utils::crypto::SignMessage;
+
+    use super::*;
+
+    fn get_signed_headers(secret: &str, body: &[u8]) -> actix_web::http::header::HeaderMap {
+        let signature = crypto::HmacSha256
+            .sign_message(secret.as_bytes(), body)
+            .unwrap();
+        let mut headers = actix_web::http::header::HeaderMap::new();
+        headers.insert(
+            actix_web::http::header::HeaderName::from_static(
+                NETWORK_TOKEN_WEBHOOK_SIGNATURE_HEADER,
+            ),
+            actix_web::http::header::HeaderValue::from_str(&hex::encode(signature)).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_verify_network_token_webhook_signature() {
+        let body = br#"{"event_id":"evt_1","timestamp":0,"event_type":"token_suspended","token_reference_id":"ntr_1"}"#;
+        let config = settings::NetworkTokenization {
+            webhook_secret: Secret::new("network_token_webhook_secret".to_string()),
+            ..Default::default()
+        };
+
+        let headers = get_signed_headers("network_token_webhook_secret", body);
+        assert!(verify_network_token_webhook_signature(&config, &headers, body).is_ok());
+
+        let tampered_body = br#"{"event_id":"evt_1","timestamp":0,"event_type":"token_deleted","token_reference_id":"ntr_1"}"#;
+        assert!(verify_network_token_webhook_signature(&config, &headers, tampered_body).is_err());
+
+        let other_secret_headers = get_signed_headers("other_secret", body);
+        assert!(
+            verify_network_token_webhook_signature(&config, &other_secret_headers, body).is_err()
+        );
+
+        assert!(verify_network_token_webhook_signature(
+            &config,
+            &actix_web::http::header::HeaderMap::new(),
+            body
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_network_token_webhook_signature_with_empty_secret() {
+        let body = br#"{"event_id":"evt_1"}"#;
+        let config = settings::NetworkTokenization::default();
+
+        // A webhook signed with the empty secret must not be accepted
+        let headers = get_signed_headers("", body);
+        assert!(verify_network_token_webhook_signature(&config, &headers, body).is_err());
+    }
+
+    #[test]
+    fn test_validate_network_token_webhook_timestamp() {
+        let now = 1_700_000_000;
+
+        assert!(validate_network_token_webhook_timestamp(now, now).is_ok());
+        assert!(validate_network_token_webhook_timestamp(
+            now - NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS,
+            now
+        )
+        .is_ok());
+        assert!(validate_network_token_webhook_timestamp(
+            now - NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS - 1,
+            now
+        )
+        .is_err());
+        assert!(validate_network_token_webhook_timestamp(
+            now + NETWORK_TOKEN_WEBHOOK_TOLERANCE_IN_SECS + 1,
+            now
+        )
+        .is_err());
+    }
+}
@@ -762,8 +762,7 @@ impl Vault {
         lookup_key: &str,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<api::PaymentMethodData>, SupplementaryVaultData)> {
-        let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+        let de_tokenize = get_tokenized_data(state, lookup_key, true, merchant_key_store).await?;
         let (payment_method, customer_id) =
             api::PaymentMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -813,8 +812,7 @@ impl Vault {
         lookup_key: &str,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> RouterResult<(Option<api::PayoutMethodData>, SupplementaryVaultData)> {
-        let de_tokenize =
-            get_tokenized_data(state, lookup_key, true, merchant_key_store.key.get_inner()).await?;
+        let de_tokenize = get_tokenized_data(state, lookup_key, true, merchant_key_store).await?;
         let (payout_method, supp_data) =
             api::PayoutMethodData::from_values(de_tokenize.value1, de_tokenize.value2)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -945,12 +943,12 @@ pub async fn create_tokenize(
     }
 }
 
-#[instrument(skip(state))]
+#[instrument(skip(state, merchant_key_store))]
 pub async fn get_tokenized_data(
     state: &routes::AppState,
     lookup_key: &str,
     _should_get_value2: bool,
-    encryption_key: &masking::Secret<Vec<u8>>,
+    merchant_key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<api::TokenizePayloadRequest> {
     let redis_key = get_redis_locker_key(lookup_key);
     let func = || async {
@@ -966,11 +964,19 @@ pub async fn get_tokenized_data(
 
         match response {
             Ok(resp) => {
+                let encrypted_payload = masking::Secret::new(resp.into());
+                // Data stored before a merchant key rotation is encrypted with the previous key
                 let decrypted_payload = GcmAes256
                     .decode_message(
-                        encryption_key.peek().as_ref(),
-                        masking::Secret::new(resp.into()),
+                        merchant_key_store.key.get_inner().peek(),
+                        encrypted_payload.clone(),
                     )
+                    .or_else(|error| match merchant_key_store.get_previous_key() {
+                        Some(previous_key) => {
+                            GcmAes256.decode_message(previous_key.peek(), encrypted_payload)
+                        }
+                        None => Err(error),
+                    })
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Failed to decode redis temp locker data")?;
 
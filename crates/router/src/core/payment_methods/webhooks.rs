@@ -0,0 +1,114 @@
+use error_stack::ResultExt;
+use futures::future::join_all;
+use router_env::{instrument, tracing};
+
+use super::cards;
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        webhooks as webhooks_core,
+    },
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// Builds the payment method object sent in outgoing webhooks. The card number and the client
+/// secret are never included.
+#[instrument(skip_all)]
+pub async fn get_payment_method_webhook_content(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    pm: &storage::PaymentMethod,
+) -> RouterResult<api::PaymentMethodResponse> {
+    let card = if pm.payment_method == Some(enums::PaymentMethod::Card) {
+        let mut card_detail =
+            cards::get_card_details_without_locker_fallback(pm, key_store, state).await?;
+        card_detail.card_number = None;
+        Some(card_detail)
+    } else {
+        None
+    };
+
+    Ok(api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id.clone(),
+        customer_id: Some(pm.customer_id.clone()),
+        payment_method_id: pm.payment_method_id.clone(),
+        payment_method: pm.payment_method,
+        payment_method_type: pm.payment_method_type,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: pm.metadata.clone(),
+        created: Some(pm.created_at),
+        recurring_enabled: is_recurring_enabled(pm),
+        installment_payment_enabled: false,
+        // The payment method does not record a payment experience, so none is reported instead of
+        // a made up one
+        payment_experience: None,
+        last_used_at: Some(pm.last_used_at),
+        client_secret: None,
+    })
+}
+
+/// A saved payment method can be charged for recurring payments once a connector mandate or the
+/// network transaction id of its first payment is stored with it
+fn is_recurring_enabled(pm: &storage::PaymentMethod) -> bool {
+    pm.connector_mandate_details.is_some() || pm.network_transaction_id.is_some()
+}
+
+/// Sends an outgoing webhook for the payment method to every business profile of the merchant, as
+/// saved payment methods are shared by all the profiles of the merchant.
+#[instrument(skip_all)]
+pub async fn trigger_payment_method_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    pm: &storage::PaymentMethod,
+    event_type: enums::EventType,
+) -> RouterResult<()> {
+    let content = get_payment_method_webhook_content(state, key_store, pm).await?;
+    send_payment_method_webhook(state, merchant_account, key_store, content, event_type).await
+}
+
+/// Same as [`trigger_payment_method_webhook`], for payment methods which may no longer exist,
+/// for example after deletion.
+#[instrument(skip_all)]
+pub async fn send_payment_method_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    content: api::PaymentMethodResponse,
+    event_type: enums::EventType,
+) -> RouterResult<()> {
+    let business_profiles = state
+        .store
+        .list_business_profile_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the business profiles of the merchant")?;
+
+    let payment_method_id = content.payment_method_id.clone();
+    let created_at = content.created;
+
+    join_all(business_profiles.into_iter().map(|business_profile| {
+        webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            event_type,
+            enums::EventClass::PaymentMethods,
+            payment_method_id.clone(),
+            enums::EventObjectType::PaymentMethodDetails,
+            api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(content.clone())),
+            created_at,
+        )
+    }))
+    .await
+    .into_iter()
+    .collect::<RouterResult<Vec<_>>>()
+    .map(|_| ())
+}
@@ -1,4 +1,5 @@
 pub mod access_token;
+pub mod authorization_expiry;
 pub mod conditional_configs;
 pub mod customers;
 pub mod flows;
@@ -7,6 +8,7 @@ pub mod operations;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod scheduled_capture;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -71,6 +73,7 @@ use crate::{
     connector::utils::missing_field_err,
     core::{
         authentication as authentication_core,
+        blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         utils,
     },
@@ -171,6 +174,8 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
+    payment_data.card_info = self_routing::get_card_info_for_routing(state, &payment_data).await;
+
     call_decision_manager(state, &merchant_account, &mut payment_data).await?;
 
     let connector = get_connector_choice(
@@ -546,6 +551,21 @@ where
         )
         .await?;
 
+    scheduled_capture::sync_scheduled_capture_task(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await;
+
+    authorization_expiry::sync_authorization_expiry_task(
+        state,
+        &business_profile,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await;
+
     crate::utils::trigger_payments_webhook(
         merchant_account,
         business_profile,
@@ -1618,24 +1638,11 @@ async fn blocklist_guard<F, ApiRequest>(
 where
     F: Send + Clone + Sync,
 {
-    let merchant_id = &payment_data.payment_attempt.merchant_id;
-    let blocklist_enabled_key = format!("guard_blocklist_for_{merchant_id}");
-    let blocklist_guard_enabled = state
-        .store
-        .find_config_by_key_unwrap_or(&blocklist_enabled_key, Some("false".to_string()))
-        .await;
-
-    let blocklist_guard_enabled: bool = match blocklist_guard_enabled {
-        Ok(config) => serde_json::from_str(&config.config).unwrap_or(false),
-
-        // If it is not present in db we are defaulting it to false
-        Err(inner) => {
-            if !inner.current_context().is_db_not_found() {
-                logger::error!("Error fetching guard blocklist enabled config {:?}", inner);
-            }
-            false
-        }
-    };
+    let blocklist_guard_enabled = blocklist_utils::is_blocklist_guard_enabled(
+        state,
+        &payment_data.payment_attempt.merchant_id,
+    )
+    .await;
 
     if blocklist_guard_enabled {
         Ok(operation
@@ -2452,6 +2459,7 @@ where
     pub authentication: Option<storage::Authentication>,
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
+    pub card_info: Option<storage::CardInfo>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -0,0 +1,495 @@
+use api_models::{admin::AuthorizationExpiryPolicy, payments as payment_types};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
+
+use super::{payments_core, scheduled_capture, CallConnectorAction, PaymentStatus};
+use crate::{
+    configs::settings::AuthorizationExpirySettings,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        webhooks as webhooks_core,
+    },
+    routes::{metrics, AppState},
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const AUTHORIZATION_EXPIRY_TAG: &str = "PAYMENT";
+pub const AUTHORIZATION_EXPIRY_NAME: &str = "AUTHORIZATION_EXPIRY";
+pub const AUTHORIZATION_EXPIRY_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow;
+
+/// Maximum window (in hours) that can be looked ahead when listing authorizations expiring soon
+const MAX_EXPIRING_SOON_WINDOW_HOURS: u32 = 30 * 24;
+
+/// Computes when the authorization of a payment is expected to be released by the issuer.
+///
+/// The validity of the authorization is the one configured for the card network of the payment,
+/// shortened to the validity configured for the connector when the connector releases
+/// authorizations earlier.
+pub fn get_authorization_expiry(
+    settings: &AuthorizationExpirySettings,
+    connector: Option<&str>,
+    payment_method_data: Option<&serde_json::Value>,
+    authorized_at: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let card_network = payment_method_data
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .clone()
+                .parse_value::<payment_types::AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            payment_types::AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        });
+
+    let network_validity = card_network
+        .and_then(|card_network| {
+            settings
+                .card_networks
+                .get(&card_network.to_string().to_lowercase())
+                .copied()
+        })
+        .unwrap_or(settings.default_validity_in_days);
+    let validity_in_days = connector
+        .and_then(|connector| settings.connectors.get(connector).copied())
+        .map_or(network_validity, |connector_validity| {
+            connector_validity.min(network_validity)
+        });
+
+    authorized_at.saturating_add(time::Duration::days(i64::from(validity_in_days)))
+}
+
+pub fn get_authorization_expiry_policy(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<Option<AuthorizationExpiryPolicy>> {
+    business_profile
+        .authorization_expiry_policy
+        .clone()
+        .map(|policy| policy.parse_value("AuthorizationExpiryPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the authorization expiry policy of the business profile")
+}
+
+/// Adds, moves or finishes the authorization expiry task of a payment after its status changes.
+///
+/// The task runs the lead time of the profile's authorization expiry policy ahead of the expected
+/// expiry, for as long as some of the authorized amount can still be captured. It is finished once
+/// nothing remains capturable.
+///
+/// Failures are logged and do not affect the payment.
+#[instrument(skip_all)]
+pub async fn sync_authorization_expiry_task(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let result = match payment_intent.status {
+        status if has_capturable_authorization(status) => {
+            match (
+                payment_attempt.authorization_expires_at,
+                get_authorization_expiry_policy(business_profile),
+            ) {
+                (Some(authorization_expires_at), Ok(Some(policy))) => {
+                    schedule_authorization_expiry(
+                        state,
+                        payment_attempt,
+                        authorization_expires_at,
+                        &policy,
+                    )
+                    .await
+                }
+                (_, Err(error)) => Err(error),
+                _ => Ok(()),
+            }
+        }
+        // Only payments whose authorization expiry was tracked can have a task to finish
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::PartiallyCaptured
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::Failed
+            if payment_attempt.authorization_expires_at.is_some() =>
+        {
+            finish_authorization_expiry(state, payment_intent).await
+        }
+        _ => Ok(()),
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            payment_id = %payment_intent.payment_id,
+            ?error,
+            "Failed to update the authorization expiry task of the payment"
+        );
+    }
+}
+
+async fn schedule_authorization_expiry(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+    authorization_expires_at: time::PrimitiveDateTime,
+    policy: &AuthorizationExpiryPolicy,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_authorization_expiry_workflow(
+        &payment_attempt.payment_id,
+        &payment_attempt.merchant_id,
+    );
+    let tracking_data = storage::payment_attempt::AuthorizationExpiryTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        authorization_expires_at,
+    };
+    // Tasks scheduled in the past would not be picked up by the producer
+    let schedule_time = authorization_expires_at
+        .saturating_sub(time::Duration::seconds(i64::from(policy.lead_time_in_secs)))
+        .max(date_time::now());
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the authorization expiry task")?;
+
+    match existing_process {
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                AUTHORIZATION_EXPIRY_NAME,
+                AUTHORIZATION_EXPIRY_RUNNER,
+                [AUTHORIZATION_EXPIRY_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct authorization expiry process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to insert authorization expiry task to process_tracker",
+                )?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "AuthorizationExpiry",
+                )],
+            );
+        }
+        Some(process) => {
+            let authorization_expiry = process
+                .tracking_data
+                .clone()
+                .parse_value::<storage::payment_attempt::AuthorizationExpiryTrackingData>(
+                    "AuthorizationExpiryTrackingData",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            if authorization_expiry.attempt_id == payment_attempt.attempt_id
+                && authorization_expiry.authorization_expires_at == authorization_expires_at
+            {
+                return Ok(());
+            }
+
+            // The payment was authorized again
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize authorization expiry tracking data")?;
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id],
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: Some("Pending".to_string()),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the authorization expiry task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes(
+                    "flow",
+                    "AuthorizationExpiry",
+                )],
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn finish_authorization_expiry(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let process = db
+        .find_process_by_id(&generate_task_id_for_authorization_expiry_workflow(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the authorization expiry task")?;
+
+    if let Some(process) = process.filter(|process| {
+        !matches!(
+            process.status,
+            storage_enums::ProcessTrackerStatus::Finish
+                | storage_enums::ProcessTrackerStatus::ProcessStarted
+        )
+    }) {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                scheduled_capture::get_finished_business_status(payment_intent.status).to_string(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to finish the authorization expiry task")?;
+    }
+
+    Ok(())
+}
+
+/// Sends a `payment_authorization_expiring` webhook for the authorized payment
+pub async fn notify_authorization_expiring(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    business_profile: storage::business_profile::BusinessProfile,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let request = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(
+            payment_intent.payment_id.clone(),
+        ),
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    let payments_response = match Box::pin(payments_core::<
+        api::PSync,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        key_store.clone(),
+        PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => payments_response,
+        ApplicationResponse::StatusOk
+        | ApplicationResponse::TextPlain(_)
+        | ApplicationResponse::JsonForRedirection(_)
+        | ApplicationResponse::Form(_)
+        | ApplicationResponse::PaymentLinkForm(_)
+        | ApplicationResponse::FileData(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payment retrieve")?,
+    };
+
+    webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        storage_enums::EventType::PaymentAuthorizationExpiring,
+        storage_enums::EventClass::Payments,
+        payment_intent.payment_id.clone(),
+        storage_enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+        Some(payment_intent.created_at),
+    )
+    .await
+}
+
+/// Lists the authorized payments of the merchant whose authorization is expected to expire
+/// within the requested window, earliest expiry first.
+#[instrument(skip(state))]
+pub async fn list_authorizations_expiring_soon(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payment_types::PaymentAuthorizationExpiringSoonConstraints,
+) -> RouterResponse<Vec<payment_types::PaymentAuthorizationExpiryResponse>> {
+    common_utils::fp_utils::when(
+        constraints.within_hours == 0 || constraints.within_hours > MAX_EXPIRING_SOON_WINDOW_HOURS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "within_hours must be between 1 and {MAX_EXPIRING_SOON_WINDOW_HOURS}"
+                ),
+            })
+        },
+    )?;
+
+    let now = date_time::now();
+    let expires_before =
+        now.saturating_add(time::Duration::hours(i64::from(constraints.within_hours)));
+    let payment_attempts = state
+        .store
+        .find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+            &merchant_account.merchant_id,
+            now,
+            expires_before,
+            constraints.limit,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve authorizations expiring soon")?;
+
+    let response = payment_attempts
+        .into_iter()
+        .filter_map(|payment_attempt| {
+            payment_attempt
+                .authorization_expires_at
+                .map(
+                    |authorization_expires_at| payment_types::PaymentAuthorizationExpiryResponse {
+                        payment_id: payment_attempt.payment_id,
+                        attempt_id: payment_attempt.attempt_id,
+                        amount_capturable: payment_attempt.amount_capturable,
+                        currency: payment_attempt.currency,
+                        connector: payment_attempt.connector,
+                        authorization_expires_at,
+                    },
+                )
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Whether some of the authorized amount of a payment in this status can still be captured
+pub fn has_capturable_authorization(status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::IntentStatus::RequiresCapture
+            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+#[inline]
+fn generate_task_id_for_authorization_expiry_workflow(
+    payment_id: &str,
+    merchant_id: &str,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        AUTHORIZATION_EXPIRY_RUNNER,
+        AUTHORIZATION_EXPIRY_NAME,
+        payment_id,
+        merchant_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn settings() -> AuthorizationExpirySettings {
+        AuthorizationExpirySettings {
+            default_validity_in_days: 7,
+            card_networks: HashMap::from([("discover".to_string(), 10)]),
+            connectors: HashMap::from([("adyen".to_string(), 5)]),
+        }
+    }
+
+    fn card_payment_method_data(card_network: &str) -> serde_json::Value {
+        serde_json::json!({ "card": { "card_network": card_network } })
+    }
+
+    #[test]
+    fn test_authorization_expiry_uses_card_network_validity() {
+        let authorized_at = date_time::now();
+        let payment_method_data = card_payment_method_data("Discover");
+        assert_eq!(
+            get_authorization_expiry(
+                &settings(),
+                Some("stripe"),
+                Some(&payment_method_data),
+                authorized_at
+            ),
+            authorized_at.saturating_add(time::Duration::days(10))
+        );
+        assert_eq!(
+            get_authorization_expiry(&settings(), Some("stripe"), None, authorized_at),
+            authorized_at.saturating_add(time::Duration::days(7))
+        );
+    }
+
+    #[test]
+    fn test_has_capturable_authorization() {
+        assert!(has_capturable_authorization(
+            storage_enums::IntentStatus::RequiresCapture
+        ));
+        assert!(has_capturable_authorization(
+            storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        ));
+        assert!(!has_capturable_authorization(
+            storage_enums::IntentStatus::PartiallyCaptured
+        ));
+        assert!(!has_capturable_authorization(
+            storage_enums::IntentStatus::Succeeded
+        ));
+    }
+
+    #[test]
+    fn test_task_id_is_unique_per_merchant() {
+        assert_ne!(
+            generate_task_id_for_authorization_expiry_workflow("pay_abc", "merchant_1"),
+            generate_task_id_for_authorization_expiry_workflow("pay_abc", "merchant_2")
+        );
+    }
+
+    #[test]
+    fn test_authorization_expiry_is_shortened_by_connector_validity() {
+        let authorized_at = date_time::now();
+        let payment_method_data = card_payment_method_data("Discover");
+        assert_eq!(
+            get_authorization_expiry(
+                &settings(),
+                Some("adyen"),
+                Some(&payment_method_data),
+                authorized_at
+            ),
+            authorized_at.saturating_add(time::Duration::days(5))
+        );
+    }
+}
@@ -1434,6 +1434,62 @@ pub fn validate_customer_details_in_request(
     Ok(())
 }
 
+const L2_L3_CUSTOMER_REFERENCE_MAX_LENGTH: usize = 25;
+
+/// Validates the level 2 / level 3 data of the request: the customer reference must fit the length
+/// accepted by connectors and none of the amounts may be negative
+pub fn validate_l2_l3_data(
+    l2_l3_data: Option<&api_models::payments::L2L3Data>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(l2_l3_data) = l2_l3_data else {
+        return Ok(());
+    };
+
+    if let Some(customer_reference) = l2_l3_data.customer_reference.as_ref() {
+        utils::when(
+            customer_reference.chars().count() > L2_L3_CUSTOMER_REFERENCE_MAX_LENGTH,
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "l2_l3_data.customer_reference must be at most {L2_L3_CUSTOMER_REFERENCE_MAX_LENGTH} characters long"
+                    )
+                }))
+            },
+        )?;
+    }
+
+    let order_amounts = [
+        ("tax_amount", l2_l3_data.tax_amount),
+        ("discount_amount", l2_l3_data.discount_amount),
+        ("duty_amount", l2_l3_data.duty_amount),
+        ("freight_amount", l2_l3_data.freight_amount),
+    ];
+    let line_item_amounts = l2_l3_data
+        .line_items
+        .iter()
+        .flatten()
+        .flat_map(|line_item| {
+            [
+                ("line_items.unit_amount", Some(line_item.unit_amount)),
+                ("line_items.discount_amount", line_item.discount_amount),
+                ("line_items.tax_amount", line_item.tax_amount),
+            ]
+        });
+
+    for (field_name, amount) in order_amounts.into_iter().chain(line_item_amounts) {
+        utils::when(
+            amount.is_some_and(|amount| amount.get_amount_as_i64() < 0),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("l2_l3_data.{field_name} must not be negative")
+                }))
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Get the customer details from customer field if present
 /// or from the individual fields in `PaymentsRequest`
 #[instrument(skip_all)]
@@ -2920,6 +2976,90 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_validate_webhook_retry_policy() {
+        let retry_policy = api_models::admin::WebhookRetryPolicy {
+            max_retries: 5,
+            initial_backoff_in_secs: 60,
+            backoff_multiplier: 2,
+            max_backoff_in_secs: 3600,
+            max_event_age_in_secs: 86400,
+            disable_endpoint_after_failing_for_secs: None,
+        };
+        assert!(validate_webhook_retry_policy(&retry_policy).is_ok());
+
+        // A fixed retry interval, with the maximum backoff below the initial backoff
+        let fixed_interval_policy = api_models::admin::WebhookRetryPolicy {
+            backoff_multiplier: 1,
+            max_backoff_in_secs: 1,
+            ..retry_policy.clone()
+        };
+        assert!(validate_webhook_retry_policy(&fixed_interval_policy).is_ok());
+
+        let invalid_policies = [
+            api_models::admin::WebhookRetryPolicy {
+                max_retries: 0,
+                ..retry_policy.clone()
+            },
+            api_models::admin::WebhookRetryPolicy {
+                initial_backoff_in_secs: 0,
+                ..retry_policy.clone()
+            },
+            api_models::admin::WebhookRetryPolicy {
+                backoff_multiplier: 0,
+                ..retry_policy.clone()
+            },
+            api_models::admin::WebhookRetryPolicy {
+                max_backoff_in_secs: 0,
+                ..retry_policy
+            },
+        ];
+        for invalid_policy in invalid_policies {
+            assert!(validate_webhook_retry_policy(&invalid_policy).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_l2_l3_data() {
+        let line_item = api_models::payments::L2L3LineItem {
+            product_name: "Printer paper".to_string(),
+            quantity: 10,
+            unit_amount: MinorUnit::new(1500),
+            tax_amount: Some(MinorUnit::new(825)),
+            ..Default::default()
+        };
+        let l2_l3_data = api_models::payments::L2L3Data {
+            customer_reference: Some("PO-2024-0042".to_string()),
+            tax_amount: Some(MinorUnit::new(825)),
+            discount_amount: Some(MinorUnit::new(0)),
+            line_items: Some(vec![line_item.clone()]),
+            ..Default::default()
+        };
+        assert!(validate_l2_l3_data(None).is_ok());
+        assert!(validate_l2_l3_data(Some(&l2_l3_data)).is_ok());
+
+        let long_customer_reference = api_models::payments::L2L3Data {
+            customer_reference: Some("P".repeat(26)),
+            ..l2_l3_data.clone()
+        };
+        assert!(validate_l2_l3_data(Some(&long_customer_reference)).is_err());
+
+        let negative_freight_amount = api_models::payments::L2L3Data {
+            freight_amount: Some(MinorUnit::new(-1)),
+            ..l2_l3_data.clone()
+        };
+        assert!(validate_l2_l3_data(Some(&negative_freight_amount)).is_err());
+
+        let negative_line_item_discount = api_models::payments::L2L3Data {
+            line_items: Some(vec![api_models::payments::L2L3LineItem {
+                discount_amount: Some(MinorUnit::new(-100)),
+                ..line_item
+            }]),
+            ..l2_l3_data
+        };
+        assert!(validate_l2_l3_data(Some(&negative_line_item_discount)).is_err());
+    }
+
     #[test]
     fn test_authenticate_client_secret_fulfillment_time_not_expired() {
         let payment_intent = PaymentIntent {
@@ -3094,6 +3234,17 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_validate_payment_link_domain_name() {
+        assert!(validate_payment_link_domain_name("pay.example.com").is_ok());
+        assert!(validate_payment_link_domain_name("Pay.Example.com").is_ok());
+        assert!(validate_payment_link_domain_name("https://pay.example.com").is_err());
+        assert!(validate_payment_link_domain_name("pay.example.com/checkout").is_err());
+        assert!(validate_payment_link_domain_name("pay.example.com:8080").is_err());
+        assert!(validate_payment_link_domain_name("localhost").is_err());
+        assert!(validate_payment_link_domain_name("127.0.0.1").is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3563,6 +3714,12 @@ impl AttemptType {
             fingerprint_id: None,
             client_source: None,
             client_version: None,
+            authorization_expires_at: None,
+            fx_quote_id: None,
+            presentment_amount: None,
+            presentment_currency: None,
+            fx_rate: None,
+            fx_markup_bps: None,
         }
     }
 
@@ -4226,6 +4383,52 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+pub fn validate_webhook_retry_policy(
+    webhook_retry_policy: &api_models::admin::WebhookRetryPolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    if webhook_retry_policy.max_retries == 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_retry_policy.max_retries should be at least 1.".to_string(),
+        })
+    } else if webhook_retry_policy.backoff_multiplier == 0
+        || webhook_retry_policy.initial_backoff_in_secs == 0
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_retry_policy.backoff_multiplier and initial_backoff_in_secs should \
+                      be at least 1."
+                .to_string(),
+        })
+    } else if webhook_retry_policy.max_backoff_in_secs == 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "webhook_retry_policy.max_backoff_in_secs should be at least 1.".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_payment_link_domain_name(
+    domain_name: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    // The domain name must be a bare host name, without a scheme, port or path
+    let is_valid_domain_name = domain_name.contains('.')
+        && url::Url::parse(&format!("https://{domain_name}"))
+            .ok()
+            .and_then(|url| {
+                url.domain()
+                    .map(|domain| domain.eq_ignore_ascii_case(domain_name))
+            })
+            .unwrap_or(false);
+
+    if is_valid_domain_name {
+        Ok(())
+    } else {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "domain_name should be a host name such as pay.example.com".to_string(),
+        })
+    }
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: AdditionalPaymentMethodConnectorResponse,
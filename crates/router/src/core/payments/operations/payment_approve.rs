@@ -175,6 +175,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            card_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -233,6 +233,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             authentication: None,
             recurring_details: None,
             poll_config: None,
+            card_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -313,6 +313,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             authentication: None,
             recurring_details,
             poll_config: None,
+            card_info: None,
         };
 
         let customer_details = Some(CustomerDetails {
@@ -19,8 +19,10 @@ use crate::{
     core::{
         authentication,
         blocklist::utils as blocklist_utils,
+        currency as currency_core,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
+        payment_link,
         payment_methods::cards,
         payments::{
             self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
@@ -122,6 +124,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
 
+        if let Some(payment_link_id) = payment_intent.payment_link_id.as_deref() {
+            payment_link::validate_reusable_payment_link_usage_on_confirm(store, payment_link_id)
+                .await?;
+        }
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         // Stage 2
@@ -358,6 +365,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
+        // The quote is validated against the net amount of the payment once its surcharge is known
+        if let Some(quote_id) = request.quote_id.clone() {
+            payment_attempt.fx_quote_id = Some(quote_id);
+        }
+
         helpers::validate_customer_id_mandatory_cases(
             request.setup_future_usage.is_some(),
             &payment_intent
@@ -399,6 +411,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
+        payment_intent.l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error converting l2_l3_data to Value")?
+            .or(payment_intent.l2_l3_data);
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
             .map(|request_incremental_authorization| {
@@ -641,6 +658,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            card_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -763,9 +781,39 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         &'a self,
         state: &AppState,
         payment_data: &mut PaymentData<F>,
-        _merchant_account: &domain::MerchantAccount,
+        merchant_account: &domain::MerchantAccount,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
-        populate_surcharge_details(state, payment_data).await
+        populate_surcharge_details(state, payment_data).await?;
+
+        // The quote is only recorded with the payment attempt, the connector is still sent the
+        // amount and currency of the payment. A retried attempt keeps the quote already recorded.
+        if let Some(quote_id) = payment_data
+            .payment_attempt
+            .fx_quote_id
+            .clone()
+            .filter(|_| payment_data.payment_attempt.presentment_amount.is_none())
+        {
+            let net_amount = payment_data
+                .surcharge_details
+                .as_ref()
+                .map(|surcharge_details| surcharge_details.final_amount)
+                .unwrap_or(payment_data.payment_attempt.amount);
+            let fx_quote = currency_core::consume_fx_quote_for_payment(
+                state,
+                &merchant_account.merchant_id,
+                &quote_id,
+                &payment_data.payment_intent.payment_id,
+                net_amount,
+                payment_data.currency,
+            )
+            .await?;
+            payment_data.payment_attempt.presentment_amount = Some(fx_quote.presentment_amount);
+            payment_data.payment_attempt.presentment_currency = Some(fx_quote.presentment_currency);
+            payment_data.payment_attempt.fx_rate = Some(fx_quote.rate);
+            payment_data.payment_attempt.fx_markup_bps = Some(i32::from(fx_quote.markup_bps));
+        }
+
+        Ok(())
     }
 
     async fn call_external_three_ds_authentication_if_eligible<'a>(
@@ -1040,13 +1088,11 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
-        let key = key_store.key.get_inner().peek();
-
         let card_detail_from_locker = payment_data
             .payment_method_info
             .as_ref()
             .async_map(|pm| async move {
-                cards::get_card_details_without_locker_fallback(pm, key, state).await
+                cards::get_card_details_without_locker_fallback(pm, key_store, state).await
             })
             .await
             .transpose()?;
@@ -1088,6 +1134,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let order_details = payment_data.payment_intent.order_details.clone();
         let metadata = payment_data.payment_intent.metadata.clone();
         let frm_metadata = payment_data.payment_intent.frm_metadata.clone();
+        let l2_l3_data = payment_data.payment_intent.l2_l3_data.clone();
         let authorized_amount = payment_data
             .surcharge_details
             .as_ref()
@@ -1123,6 +1170,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_error_code = error_code.clone();
         let m_error_message = error_message.clone();
         let m_fingerprint_id = payment_data.payment_attempt.fingerprint_id.clone();
+        let m_fx_quote_id = payment_data.payment_attempt.fx_quote_id.clone();
+        let m_fx_rate = payment_data.payment_attempt.fx_rate.clone();
         let m_db = state.clone().store;
         let surcharge_amount = payment_data
             .surcharge_details
@@ -1180,6 +1229,11 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         payment_method_id: m_payment_method_id,
                         client_source,
                         client_version,
+                        fx_quote_id: m_fx_quote_id,
+                        presentment_amount: payment_data.payment_attempt.presentment_amount,
+                        presentment_currency: payment_data.payment_attempt.presentment_currency,
+                        fx_rate: m_fx_rate,
+                        fx_markup_bps: payment_data.payment_attempt.fx_markup_bps,
                     },
                     storage_scheme,
                 )
@@ -1201,6 +1255,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_order_details = order_details.clone();
         let m_metadata = metadata.clone();
         let m_frm_metadata = frm_metadata.clone();
+        let m_l2_l3_data = l2_l3_data.clone();
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
@@ -1231,6 +1286,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         session_expiry,
                         request_external_three_ds_authentication: None,
                         frm_metadata: m_frm_metadata,
+                        l2_l3_data: m_l2_l3_data,
                     },
                     storage_scheme,
                 )
@@ -1296,6 +1352,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_l2_l3_data(request.l2_l3_data.as_ref())?;
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
@@ -23,6 +23,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     consts,
     core::{
+        currency as currency_core,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_link,
@@ -451,6 +452,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             authentication: None,
             recurring_details,
             poll_config: None,
+            card_info: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -663,6 +665,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_l2_l3_data(request.l2_l3_data.as_ref())?;
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
@@ -803,9 +806,9 @@ impl PaymentCreate {
             additional_pm_data = payment_method_info
                 .as_ref()
                 .async_map(|pm_info| async {
-                    domain::types::decrypt::<serde_json::Value, masking::WithType>(
+                    domain::types::decrypt_with_key_store::<serde_json::Value, masking::WithType>(
                         pm_info.payment_method_data.clone(),
-                        key_store.key.get_inner().peek(),
+                        key_store,
                     )
                     .await
                     .map_err(|err| logger::error!("Failed to decrypt card details: {:?}", err))
@@ -855,6 +858,28 @@ impl PaymentCreate {
             .surcharge_details
             .and_then(|surcharge_details| surcharge_details.tax_amount);
 
+        let fx_quote = match request.quote_id.as_deref() {
+            Some(quote_id) if request.confirm == Some(true) => Some(
+                currency_core::consume_fx_quote_for_payment(
+                    state,
+                    merchant_id,
+                    quote_id,
+                    payment_id,
+                    MinorUnit::from(amount)
+                        + request
+                            .surcharge_details
+                            .map(|surcharge_details| surcharge_details.get_total_surcharge_amount())
+                            .unwrap_or_default(),
+                    money.1,
+                )
+                .await?,
+            ),
+            Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "quote_id can only be passed when confirming the payment".to_string(),
+            })?,
+            None => None,
+        };
+
         if request.mandate_data.as_ref().map_or(false, |mandate_data| {
             mandate_data.update_mandate_id.is_some() && mandate_data.mandate_type.is_some()
         }) {
@@ -935,6 +960,12 @@ impl PaymentCreate {
                 authentication_id: None,
                 client_source: None,
                 client_version: None,
+                authorization_expires_at: None,
+                presentment_amount: fx_quote.as_ref().map(|quote| quote.presentment_amount),
+                presentment_currency: fx_quote.as_ref().map(|quote| quote.presentment_currency),
+                fx_markup_bps: fx_quote.as_ref().map(|quote| i32::from(quote.markup_bps)),
+                fx_rate: fx_quote.as_ref().map(|quote| quote.rate.clone()),
+                fx_quote_id: fx_quote.map(|quote| quote.quote_id),
             },
             additional_pm_data,
         ))
@@ -989,6 +1020,11 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting feature_metadata to Value")?;
 
+        let l2_l3_data = request
+            .get_l2_l3_data_as_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error converting l2_l3_data to Value")?;
+
         let payment_link_id = payment_link_data.map(|pl_data| pl_data.payment_link_id);
 
         let request_incremental_authorization =
@@ -1043,6 +1079,7 @@ impl PaymentCreate {
             request_external_three_ds_authentication: request
                 .request_external_three_ds_authentication,
             frm_metadata: request.frm_metadata.clone(),
+            l2_l3_data,
         })
     }
 
@@ -1123,6 +1160,7 @@ async fn create_payment_link(
         description,
         payment_link_config: Some(payment_link_config_encoded_value),
         profile_id: Some(profile_id),
+        reusable_payment_link_id: None,
     };
     let payment_link_db = db
         .insert_payment_link(payment_link_req)
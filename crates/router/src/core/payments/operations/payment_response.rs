@@ -18,6 +18,7 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate, payment_methods,
         payments::{
+            authorization_expiry,
             helpers::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
@@ -958,6 +959,20 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 updated_by: storage_scheme.to_string(),
                                 authentication_data,
                                 encoded_data,
+                                authorization_expires_at: (updated_attempt_status
+                                    == enums::AttemptStatus::Authorized
+                                    && payment_data
+                                        .payment_attempt
+                                        .authorization_expires_at
+                                        .is_none())
+                                .then(|| {
+                                    authorization_expiry::get_authorization_expiry(
+                                        &state.conf.authorization_expiry,
+                                        payment_data.payment_attempt.connector.as_deref(),
+                                        additional_payment_method_data.as_ref(),
+                                        common_utils::date_time::now(),
+                                    )
+                                }),
                                 payment_method_data: additional_payment_method_data,
                             }),
                         ),
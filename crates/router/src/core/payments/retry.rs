@@ -407,6 +407,7 @@ where
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
+                    authorization_expires_at: None,
                 },
                 storage_scheme,
             )
@@ -509,6 +510,12 @@ pub fn make_new_payment_attempt(
         browser_info: old_payment_attempt.browser_info,
         payment_token: old_payment_attempt.payment_token,
 
+        fx_quote_id: old_payment_attempt.fx_quote_id,
+        presentment_amount: old_payment_attempt.presentment_amount,
+        presentment_currency: old_payment_attempt.presentment_currency,
+        fx_rate: old_payment_attempt.fx_rate,
+        fx_markup_bps: old_payment_attempt.fx_markup_bps,
+
         created_at,
         modified_at,
         last_synced,
@@ -38,7 +38,7 @@ use crate::core::payouts;
 use crate::utils::StringExt;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        cards_info, errors, errors as oss_errors, payments as payments_oss,
         routing::{self, helpers as routing_helpers},
     },
     logger,
@@ -149,6 +149,9 @@ pub fn make_dsl_input_for_payouts(
             .clone()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        card_issuer: None,
+        card_issuing_country: None,
+        card_subtype: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
@@ -199,30 +202,34 @@ where
             |_| euclid_enums::PaymentType::SetupMandate,
         )),
     };
+    let card = payment_data
+        .payment_method_data
+        .as_ref()
+        .and_then(|pm_data| match pm_data {
+            api::PaymentMethodData::Card(card) => Some(card),
+            _ => None,
+        });
+    let card_bin: Option<String> =
+        card.map(|card| card.card_number.peek().chars().take(6).collect());
+    let card_info = payment_data.card_info.as_ref();
+
     let payment_method_input = dsl_inputs::PaymentMethodInput {
         payment_method: payment_data.payment_attempt.payment_method,
         payment_method_type: payment_data.payment_attempt.payment_method_type,
-        card_network: payment_data
-            .payment_method_data
-            .as_ref()
-            .and_then(|pm_data| match pm_data {
-                api::PaymentMethodData::Card(card) => card.card_network.clone(),
-
-                _ => None,
-            }),
+        card_network: card.and_then(|card| card.card_network.clone()),
+        card_issuer: card
+            .and_then(|card| card.card_issuer.clone())
+            .or_else(|| card_info.and_then(|card_info| card_info.card_issuer.clone())),
+        card_issuing_country: card
+            .and_then(|card| card.card_issuing_country.clone())
+            .or_else(|| card_info.and_then(|card_info| card_info.card_issuing_country.clone()))
+            .and_then(|country| cards_info::parse_card_issuing_country(&country)),
+        card_subtype: card_info.and_then(|card_info| card_info.card_subtype.clone()),
     };
 
     let payment_input = dsl_inputs::PaymentInput {
         amount: payment_data.payment_intent.amount.get_amount_as_i64(),
-        card_bin: payment_data
-            .payment_method_data
-            .as_ref()
-            .and_then(|pm_data| match pm_data {
-                api::PaymentMethodData::Card(card) => {
-                    Some(card.card_number.peek().chars().take(6).collect())
-                }
-                _ => None,
-            }),
+        card_bin,
         currency: payment_data.currency,
         authentication_type: payment_data.payment_attempt.authentication_type,
         capture_method: payment_data
@@ -264,6 +271,30 @@ where
     })
 }
 
+/// Looks up the BIN database entry of the card being paid with, so that the card issuer keys are
+/// available to the routing, surcharge and fraud rules. It is looked up once per payment and kept in
+/// the payment data. A failed lookup only means that those keys are unavailable to the rules, so it
+/// is logged and ignored.
+pub async fn get_card_info_for_routing<F>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+) -> Option<oss_storage::CardInfo>
+where
+    F: Clone,
+{
+    let card_bin: String = match payment_data.payment_method_data.as_ref()? {
+        api::PaymentMethodData::Card(card) => card.card_number.peek().chars().take(6).collect(),
+        _ => return None,
+    };
+    state
+        .store
+        .get_card_info(&card_bin)
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten()
+}
+
 pub async fn perform_static_routing_v1<F: Clone>(
     state: &AppState,
     merchant_id: &str,
@@ -894,6 +925,9 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_issuer: None,
+        card_issuing_country: None,
+        card_subtype: None,
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -1178,6 +1212,9 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_issuer: None,
+        card_issuing_country: None,
+        card_subtype: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
@@ -0,0 +1,238 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, instrument},
+};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::{metrics, AppState},
+    types::storage::{self, enums as storage_enums},
+};
+
+pub const SCHEDULED_CAPTURE_TAG: &str = "PAYMENT";
+pub const SCHEDULED_CAPTURE_NAME: &str = "SCHEDULED_CAPTURE";
+pub const SCHEDULED_CAPTURE_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+
+/// Keeps the scheduled capture task of a payment in sync with the payment.
+///
+/// The task is added once a manual capture payment with `capture_on` is authorized and moved
+/// when the authorized attempt or its `capture_on` changes. It is finished once the payment is
+/// captured, voided or fails before the scheduled time.
+///
+/// Failures are logged and do not affect the payment.
+#[instrument(skip_all)]
+pub async fn sync_scheduled_capture_task(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    let Some(capture_on) = payment_attempt.capture_on else {
+        return;
+    };
+
+    let result = match payment_intent.status {
+        storage_enums::IntentStatus::RequiresCapture
+            if payment_attempt.capture_method == Some(storage_enums::CaptureMethod::Manual) =>
+        {
+            schedule_capture(state, payment_attempt, capture_on).await
+        }
+        storage_enums::IntentStatus::Succeeded
+        | storage_enums::IntentStatus::PartiallyCaptured
+        | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        | storage_enums::IntentStatus::Cancelled
+        | storage_enums::IntentStatus::Failed => {
+            finish_scheduled_capture(state, payment_intent).await
+        }
+        _ => Ok(()),
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            payment_id = %payment_intent.payment_id,
+            ?error,
+            "Failed to update the scheduled capture of the payment"
+        );
+    }
+}
+
+async fn schedule_capture(
+    state: &AppState,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_on: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let process_tracker_id = generate_task_id_for_scheduled_capture_workflow(
+        &payment_attempt.payment_id,
+        &payment_attempt.merchant_id,
+    );
+    let tracking_data = storage::payment_attempt::ScheduledCaptureTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        capture_on,
+    };
+    // Tasks scheduled in the past would not be picked up by the producer
+    let schedule_time = capture_on.max(common_utils::date_time::now());
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the scheduled capture task")?;
+
+    match existing_process {
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                SCHEDULED_CAPTURE_NAME,
+                SCHEDULED_CAPTURE_RUNNER,
+                [SCHEDULED_CAPTURE_TAG],
+                tracking_data,
+                schedule_time,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct scheduled capture process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert scheduled capture task to process_tracker")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "ScheduledCapture")],
+            );
+        }
+        Some(process) => {
+            let scheduled_capture = process
+                .tracking_data
+                .clone()
+                .parse_value::<storage::payment_attempt::ScheduledCaptureTrackingData>(
+                    "ScheduledCaptureTrackingData",
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            if scheduled_capture.attempt_id == payment_attempt.attempt_id
+                && scheduled_capture.capture_on == capture_on
+            {
+                return Ok(());
+            }
+
+            // The payment was authorized again, or its capture was moved
+            let tracking_data = tracking_data
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize scheduled capture tracking data")?;
+            db.process_tracker_update_process_status_by_ids(
+                vec![process.id],
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: Some("Pending".to_string()),
+                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the scheduled capture task")?;
+            metrics::TASKS_RESET_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "ScheduledCapture")],
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn finish_scheduled_capture(
+    state: &AppState,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let process = db
+        .find_process_by_id(&generate_task_id_for_scheduled_capture_workflow(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the scheduled capture task")?;
+
+    if let Some(process) = process.filter(|process| {
+        !matches!(
+            process.status,
+            storage_enums::ProcessTrackerStatus::Finish
+                | storage_enums::ProcessTrackerStatus::ProcessStarted
+        )
+    }) {
+        db.as_scheduler()
+            .finish_process_with_business_status(
+                process,
+                get_finished_business_status(payment_intent.status).to_string(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to finish the scheduled capture task")?;
+    }
+
+    Ok(())
+}
+
+/// Business status of a payment task finished because the payment reached the specified status
+pub(crate) fn get_finished_business_status(status: storage_enums::IntentStatus) -> &'static str {
+    match status {
+        storage_enums::IntentStatus::Cancelled => "PAYMENT_CANCELLED",
+        storage_enums::IntentStatus::Failed => "PAYMENT_FAILED",
+        _ => "PAYMENT_CAPTURED",
+    }
+}
+
+#[inline]
+fn generate_task_id_for_scheduled_capture_workflow(payment_id: &str, merchant_id: &str) -> String {
+    scheduler::utils::get_process_tracker_id(
+        SCHEDULED_CAPTURE_RUNNER,
+        SCHEDULED_CAPTURE_NAME,
+        payment_id,
+        merchant_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_id_is_unique_per_merchant() {
+        assert_eq!(
+            generate_task_id_for_scheduled_capture_workflow("pay_abc", "merchant_1"),
+            "SCHEDULED_CAPTURE_WORKFLOW_SCHEDULED_CAPTURE_pay_abc_merchant_1"
+        );
+        assert_ne!(
+            generate_task_id_for_scheduled_capture_workflow("pay_abc", "merchant_1"),
+            generate_task_id_for_scheduled_capture_workflow("pay_abc", "merchant_2")
+        );
+    }
+
+    #[test]
+    fn test_get_finished_business_status() {
+        assert_eq!(
+            get_finished_business_status(storage_enums::IntentStatus::Cancelled),
+            "PAYMENT_CANCELLED"
+        );
+        assert_eq!(
+            get_finished_business_status(storage_enums::IntentStatus::Failed),
+            "PAYMENT_FAILED"
+        );
+        assert_eq!(
+            get_finished_business_status(storage_enums::IntentStatus::PartiallyCaptured),
+            "PAYMENT_CAPTURED"
+        );
+    }
+}
@@ -305,12 +305,12 @@ where
                                         let pm_metadata =
                                             create_payment_method_metadata(None, connector_token)?;
                                         payment_methods::cards::create_payment_method(
-                                            db,
+                                            state,
                                             &payment_method_create_request,
                                             customer_id.as_str(),
                                             &resp.payment_method_id,
                                             locker_id,
-                                            merchant_id,
+                                            merchant_account,
                                             pm_metadata,
                                             customer_acceptance,
                                             pm_data_encrypted,
@@ -318,7 +318,6 @@ where
                                             connector_mandate_details,
                                             None,
                                             network_transaction_id,
-                                            merchant_account.storage_scheme,
                                             encrypted_payment_method_billing_address,
                                         )
                                         .await
@@ -397,18 +396,17 @@ where
                                     Err(err) => {
                                         if err.current_context().is_db_not_found() {
                                             payment_methods::cards::insert_payment_method(
-                                                db,
+                                                state,
                                                 &resp,
                                                 payment_method_create_request.clone(),
                                                 key_store,
-                                                &merchant_account.merchant_id,
+                                                merchant_account,
                                                 customer_id.as_str(),
                                                 resp.metadata.clone().map(|val| val.expose()),
                                                 customer_acceptance,
                                                 locker_id,
                                                 connector_mandate_details,
                                                 network_transaction_id,
-                                                merchant_account.storage_scheme,
                                                 encrypted_payment_method_billing_address,
                                             )
                                             .await
@@ -526,13 +524,13 @@ where
                         });
 
                         resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-                        payment_methods::cards::create_payment_method(
-                            db,
+                        let pm = payment_methods::cards::create_payment_method(
+                            state,
                             &payment_method_create_request,
                             customer_id.as_str(),
                             &resp.payment_method_id,
                             locker_id,
-                            merchant_id,
+                            merchant_account,
                             pm_metadata,
                             customer_acceptance,
                             pm_data_encrypted,
@@ -540,10 +538,22 @@ where
                             connector_mandate_details,
                             None,
                             network_transaction_id,
-                            merchant_account.storage_scheme,
                             encrypted_payment_method_billing_address,
                         )
                         .await?;
+
+                        if let Some(card) = payment_method_create_request.card.clone() {
+                            payment_methods::account_updater::schedule_account_updater_task_in_background(
+                                state,
+                                &pm.merchant_id,
+                            );
+                            payment_methods::network_tokenization::provision_network_token_in_background(
+                                state,
+                                card,
+                                pm,
+                                merchant_account.storage_scheme,
+                            );
+                        }
                     }
                 }
 
@@ -7,8 +7,8 @@ use common_enums::RequestIncrementalAuthorization;
 use common_utils::{consts::X_HS_LATENCY, fp_utils, types::MinorUnit};
 use diesel_models::ephemeral_key;
 use error_stack::{report, ResultExt};
-use masking::{Maskable, Secret};
-use router_env::{instrument, tracing};
+use masking::{ExposeInterface, Maskable, Secret};
+use router_env::{instrument, logger, tracing};
 
 use super::{flows::Feature, types::AuthenticationData, PaymentData};
 use crate::{
@@ -16,6 +16,7 @@ use crate::{
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
+        payment_methods::network_tokenization,
         payments::{self, helpers},
         utils as core_utils,
     },
@@ -88,14 +89,6 @@ where
         incremental_authorization_allowed: None,
     });
 
-    let additional_data = PaymentAdditionalData {
-        router_base_url: state.conf.server.base_url.clone(),
-        connector_name: connector_id.to_string(),
-        payment_data: payment_data.clone(),
-        state,
-        customer_data: customer,
-    };
-
     let customer_id = customer.to_owned().map(|customer| customer.customer_id);
 
     let supported_connector = &state
@@ -109,6 +102,27 @@ where
         })
         .attach_printable_lazy(|| format!("unable to parse connector name {connector_id:?}"))?;
 
+    let (payment_data, network_token_data) =
+        match get_network_token_payment_method_data(state, &payment_data, &connector_enum).await {
+            Some((payment_method_data, network_token_data)) => (
+                PaymentData {
+                    payment_method_data: Some(payment_method_data),
+                    ..payment_data
+                },
+                Some(network_token_data),
+            ),
+            None => (payment_data, None),
+        };
+
+    let additional_data = PaymentAdditionalData {
+        router_base_url: state.conf.server.base_url.clone(),
+        connector_name: connector_id.to_string(),
+        payment_data: payment_data.clone(),
+        state,
+        customer_data: customer,
+        network_token_data,
+    };
+
     let connector_api_version = if supported_connector.contains(&connector_enum) {
         state
             .store
@@ -465,6 +479,8 @@ where
             });
     let merchant_decision = payment_intent.merchant_decision.to_owned();
     let frm_message = payment_data.frm_message.map(FrmMessage::foreign_from);
+    let l2_l3_data = get_l2_l3_data(&payment_intent)?;
+    let fx_quote = get_fx_quote_details(&payment_attempt);
 
     let payment_method_data =
         additional_payment_method_data.map(api::PaymentMethodDataResponse::from);
@@ -786,6 +802,9 @@ where
                 .set_browser_info(payment_attempt.browser_info)
                 .set_updated(Some(payment_intent.modified_at))
                 .set_frm_metadata(payment_intent.frm_metadata)
+                .set_authorization_expires_at(payment_attempt.authorization_expires_at)
+                .set_l2_l3_data(l2_l3_data)
+                .set_fx_quote(fx_quote)
                 .to_owned(),
             headers,
         ))
@@ -864,6 +883,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
     fn foreign_from(item: (storage::PaymentIntent, storage::PaymentAttempt)) -> Self {
         let pi = item.0;
         let pa = item.1;
+        let fx_quote = get_fx_quote_details(&pa);
         Self {
             payment_id: Some(pi.payment_id),
             merchant_id: Some(pi.merchant_id),
@@ -890,6 +910,8 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             attempt_count: pi.attempt_count,
             profile_id: pi.profile_id,
             merchant_connector_id: pa.merchant_connector_id,
+            authorization_expires_at: pa.authorization_expires_at,
+            fx_quote,
             ..Default::default()
         }
     }
@@ -1072,6 +1094,58 @@ where
     payment_data: PaymentData<F>,
     state: &'a AppState,
     customer_data: &'a Option<domain::Customer>,
+    network_token_data: Option<types::NetworkTokenPaymentData>,
+}
+
+/// Replaces the saved card with its network token when the connector accepts network tokens.
+/// Falls back to the funding card if the token could not be fetched.
+async fn get_network_token_payment_method_data<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+    connector: &api_models::enums::Connector,
+) -> Option<(
+    api_models::payments::PaymentMethodData,
+    types::NetworkTokenPaymentData,
+)> {
+    if !state
+        .conf
+        .network_tokenization_supported_connectors
+        .connector_list
+        .contains(connector)
+    {
+        return None;
+    }
+
+    let payment_method_info = payment_data.payment_method_info.as_ref()?;
+    let Some(api_models::payments::PaymentMethodData::Card(card)) =
+        payment_data.payment_method_data.as_ref()
+    else {
+        return None;
+    };
+
+    let is_customer_initiated =
+        payment_data.payment_intent.off_session != Some(true) && payment_data.mandate_id.is_none();
+    let network_token = network_tokenization::get_network_token_for_payment(
+        state,
+        payment_method_info,
+        is_customer_initiated,
+    )
+    .await
+    .map_err(|error| logger::error!(network_token_error=?error))
+    .ok()
+    .flatten()?;
+
+    Some((
+        api_models::payments::PaymentMethodData::Card(api_models::payments::Card {
+            card_number: network_token.token_number,
+            card_exp_month: network_token.token_exp_month,
+            card_exp_year: network_token.token_exp_year,
+            ..card.clone()
+        }),
+        types::NetworkTokenPaymentData {
+            cryptogram: network_token.cryptogram,
+        },
+    ))
 }
 impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthorizeData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -1163,6 +1237,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                     .map(|customer| customer.clone().into_inner())
             });
 
+        let l2_l3_data = get_l2_l3_data(&payment_data.payment_intent)?;
+
         Ok(Self {
             payment_method_data: From::from(
                 payment_method_data.get_required_value("payment_method_data")?,
@@ -1199,6 +1275,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 Some(RequestIncrementalAuthorization::True)
                     | Some(RequestIncrementalAuthorization::Default)
             ),
+            l2_l3_data,
+            network_token_data: additional_data.network_token_data,
             metadata: additional_data.payment_data.payment_intent.metadata,
             authentication_data: payment_data
                 .authentication
@@ -1332,6 +1410,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
                 field_name: "browser_info",
             })?;
         let amount = MinorUnit::from(payment_data.amount);
+        let l2_l3_data = get_l2_l3_data(&payment_data.payment_intent)?;
         Ok(Self {
             amount_to_capture: amount_to_capture.get_amount_as_i64(), // This should be removed once we start moving to connector module
             currency: payment_data.currency,
@@ -1353,6 +1432,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             },
             browser_info,
             metadata: payment_data.payment_intent.metadata,
+            l2_l3_data,
         })
     }
 }
@@ -1719,3 +1799,29 @@ impl ForeignFrom<payments::FraudCheck> for FrmMessage {
         }
     }
 }
+
+fn get_fx_quote_details(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<api_models::payments::PaymentFxQuoteDetails> {
+    payment_attempt.fx_quote_id.clone().map(|quote_id| {
+        api_models::payments::PaymentFxQuoteDetails {
+            quote_id,
+            presentment_amount: payment_attempt.presentment_amount,
+            presentment_currency: payment_attempt.presentment_currency,
+            rate: payment_attempt.fx_rate.clone(),
+            markup_bps: payment_attempt.fx_markup_bps,
+        }
+    })
+}
+
+fn get_l2_l3_data(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<api_models::payments::L2L3Data>> {
+    payment_intent
+        .l2_l3_data
+        .clone()
+        .map(|l2_l3_data| l2_l3_data.expose().parse_value("L2L3Data"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the l2_l3_data of the payment")
+}
@@ -475,12 +475,12 @@ pub async fn save_payout_data_to_locker(
     if should_insert_in_pm_table {
         let payment_method_id = common_utils::generate_id(crate::consts::ID_LENGTH, "pm");
         cards::create_payment_method(
-            db,
+            state,
             &new_payment_method,
             &payout_attempt.customer_id,
             &payment_method_id,
             Some(stored_resp.card_reference.clone()),
-            &merchant_account.merchant_id,
+            merchant_account,
             None,
             None,
             card_details_encrypted.clone(),
@@ -488,7 +488,6 @@ pub async fn save_payout_data_to_locker(
             None,
             None,
             None,
-            merchant_account.storage_scheme,
             None,
         )
         .await?;
@@ -47,7 +47,7 @@ use crate::{
     },
     types::{
         self,
-        domain::{self, types::decrypt},
+        domain::{self, types::decrypt_with_key_store},
         storage,
         transformers::ForeignTryFrom,
     },
@@ -275,7 +275,6 @@ async fn store_bank_details_in_payment_methods(
     connector_details: (&str, Secret<String>),
     mca_id: String,
 ) -> RouterResult<()> {
-    let key = key_store.key.get_inner().peek();
     let db = &*state.clone().store;
     let (connector_name, access_token) = connector_details;
 
@@ -311,29 +310,32 @@ async fn store_bank_details_in_payment_methods(
 
     for pm in payment_methods {
         if pm.payment_method == Some(enums::PaymentMethod::BankDebit) {
-            let bank_details_pm_data = decrypt::<serde_json::Value, masking::WithType>(
-                pm.payment_method_data.clone(),
-                key,
-            )
-            .await
-            .change_context(ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to decrypt bank account details")?
-            .map(|x| x.into_inner().expose())
-            .map(|v| {
-                serde_json::from_value::<payment_methods::PaymentMethodsData>(v)
-                    .change_context(errors::StorageError::DeserializationFailed)
-                    .attach_printable("Failed to deserialize Payment Method Auth config")
-            })
-            .transpose()
-            .unwrap_or_else(|err| {
-                logger::error!(error=?err);
-                None
-            })
-            .and_then(|pmd| match pmd {
-                payment_methods::PaymentMethodsData::BankDetails(bank_creds) => Some(bank_creds),
-                _ => None,
-            })
-            .ok_or(ApiErrorResponse::InternalServerError)?;
+            let bank_details_pm_data =
+                decrypt_with_key_store::<serde_json::Value, masking::WithType>(
+                    pm.payment_method_data.clone(),
+                    &key_store,
+                )
+                .await
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("unable to decrypt bank account details")?
+                .map(|x| x.into_inner().expose())
+                .map(|v| {
+                    serde_json::from_value::<payment_methods::PaymentMethodsData>(v)
+                        .change_context(errors::StorageError::DeserializationFailed)
+                        .attach_printable("Failed to deserialize Payment Method Auth config")
+                })
+                .transpose()
+                .unwrap_or_else(|err| {
+                    logger::error!(error=?err);
+                    None
+                })
+                .and_then(|pmd| match pmd {
+                    payment_methods::PaymentMethodsData::BankDetails(bank_creds) => {
+                        Some(bank_creds)
+                    }
+                    _ => None,
+                })
+                .ok_or(ApiErrorResponse::InternalServerError)?;
 
             hash_to_payment_method.insert(
                 bank_details_pm_data.hash.clone(),
@@ -468,6 +470,10 @@ async fn store_bank_details_in_payment_methods(
                 client_secret: None,
                 payment_method_billing_address: None,
                 updated_by: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_status: None,
+                network_token_details: None,
             };
 
             new_entries.push(pm_new);
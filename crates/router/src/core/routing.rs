@@ -24,11 +24,12 @@ use crate::types::transformers::{ForeignInto, ForeignTryFrom};
 use crate::{
     consts,
     core::{
+        config_audit,
         errors::{RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
-    routes::AppState,
-    types::domain,
+    routes::{app::ReqState, AppState},
+    types::{domain, storage::enums as storage_enums},
     utils::{self, OptionExt, ValueExt},
 };
 #[cfg(not(feature = "business_profile_routing"))]
@@ -98,9 +99,12 @@ pub async fn create_routing_config(
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
+    let merchant_id = merchant_account.merchant_id.clone();
+    let audit_request = request.clone();
 
     let name = request
         .name
@@ -175,6 +179,20 @@ pub async fn create_routing_config(
 
         let new_record = record.foreign_into();
 
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_id),
+                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                resource_id: &algorithm_id,
+                action: storage_enums::AuditAction::Create,
+                before: None,
+                after: Some(&audit_request),
+            },
+        )
+        .await;
+
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
@@ -231,7 +249,7 @@ pub async fn create_routing_config(
 
         if records_are_empty {
             merchant_dictionary.active_id = Some(algorithm_id.clone());
-            algorithm_ref.update_algorithm_id(algorithm_id);
+            algorithm_ref.update_algorithm_id(algorithm_id.clone());
             helpers::update_merchant_active_algorithm_ref(db, &key_store, algorithm_ref).await?;
         }
 
@@ -242,6 +260,20 @@ pub async fn create_routing_config(
         )
         .await?;
 
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_id),
+                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                resource_id: &algorithm_id,
+                action: storage_enums::AuditAction::Create,
+                before: None,
+                after: Some(&audit_request),
+            },
+        )
+        .await;
+
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
@@ -253,6 +285,7 @@ pub async fn link_routing_config(
     #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
     algorithm_id: String,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -306,15 +339,30 @@ pub async fn link_routing_config(
             },
         )?;
 
-        routing_ref.update_algorithm_id(algorithm_id);
+        let routing_ref_before = routing_ref.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_business_profile_active_algorithm_ref(
             db,
             business_profile,
-            routing_ref,
+            routing_ref.clone(),
             transaction_type,
         )
         .await?;
 
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_account.merchant_id),
+                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                resource_id: &algorithm_id,
+                action: storage_enums::AuditAction::Update,
+                before: Some(&routing_ref_before),
+                after: Some(&routing_ref),
+            },
+        )
+        .await;
+
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(
             routing_algorithm.foreign_into(),
@@ -356,14 +404,29 @@ pub async fn link_routing_config(
         record.modified_at = modified_at;
         merchant_dictionary.active_id = Some(record.id.clone());
         let response = record.clone();
-        routing_ref.update_algorithm_id(algorithm_id);
+        let routing_ref_before = routing_ref.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_merchant_routing_dictionary(
             db,
             &merchant_account.merchant_id,
             merchant_dictionary,
         )
         .await?;
-        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
+        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref.clone()).await?;
+
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_account.merchant_id),
+                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                resource_id: &algorithm_id,
+                action: storage_enums::AuditAction::Update,
+                before: Some(&routing_ref_before),
+                after: Some(&routing_ref),
+            },
+        )
+        .await;
 
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
@@ -450,6 +513,7 @@ pub async fn unlink_routing_config(
     #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
     #[cfg(feature = "business_profile_routing")] request: routing_types::RoutingConfigRequest,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -487,14 +551,16 @@ pub async fn unlink_routing_config(
 
                 let timestamp = common_utils::date_time::now_unix_timestamp();
 
-                match routing_algo_ref.algorithm_id {
+                match routing_algo_ref.algorithm_id.clone() {
                     Some(algorithm_id) => {
                         let routing_algorithm: routing_types::RoutingAlgorithmRef =
                             routing_types::RoutingAlgorithmRef {
                                 algorithm_id: None,
                                 timestamp,
                                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                                surcharge_config_algo_id: routing_algo_ref
+                                    .surcharge_config_algo_id
+                                    .clone(),
                             };
 
                         let record = db
@@ -508,11 +574,25 @@ pub async fn unlink_routing_config(
                         helpers::update_business_profile_active_algorithm_ref(
                             db,
                             business_profile,
-                            routing_algorithm,
+                            routing_algorithm.clone(),
                             transaction_type,
                         )
                         .await?;
 
+                        config_audit::record_config_change(
+                            &state,
+                            &req_state,
+                            config_audit::ConfigChange {
+                                merchant_id: Some(&merchant_account.merchant_id),
+                                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                                resource_id: &algorithm_id,
+                                action: storage_enums::AuditAction::Update,
+                                before: Some(&routing_algo_ref),
+                                after: Some(&routing_algorithm),
+                            },
+                        )
+                        .await;
+
                         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
                             &metrics::CONTEXT,
                             1,
@@ -559,7 +639,7 @@ pub async fn unlink_routing_config(
                 algorithm_id: None,
                 timestamp,
                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id.clone(),
             };
 
         let active_algorithm_id = merchant_dictionary
@@ -624,6 +704,20 @@ pub async fn unlink_routing_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
 
+        config_audit::record_config_change(
+            &state,
+            &req_state,
+            config_audit::ConfigChange {
+                merchant_id: Some(&merchant_account.merchant_id),
+                resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+                resource_id: &response.id,
+                action: storage_enums::AuditAction::Update,
+                before: Some(&routing_algo_ref),
+                after: Some(&routing_algorithm),
+            },
+        )
+        .await;
+
         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
     }
@@ -634,6 +728,7 @@ pub async fn update_default_routing_config(
     merchant_account: domain::MerchantAccount,
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
     metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -674,6 +769,20 @@ pub async fn update_default_routing_config(
     )
     .await?;
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+            resource_id: &merchant_account.merchant_id,
+            action: storage_enums::AuditAction::Update,
+            before: Some(&default_config),
+            after: Some(&updated_config),
+        },
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(updated_config))
 }
@@ -852,6 +961,7 @@ pub async fn update_default_routing_config_for_profile(
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
     profile_id: String,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -919,6 +1029,20 @@ pub async fn update_default_routing_config_for_profile(
     )
     .await?;
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&merchant_account.merchant_id),
+            resource_type: storage_enums::AuditResourceType::RoutingAlgorithm,
+            resource_id: &business_profile.profile_id,
+            action: storage_enums::AuditAction::Update,
+            before: Some(&default_config),
+            after: Some(&updated_config),
+        },
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
         routing_types::ProfileDefaultRoutingConfig {
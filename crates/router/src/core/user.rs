@@ -29,6 +29,7 @@ use crate::{
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod sso;
 
 #[cfg(feature = "email")]
 pub async fn signup_with_merchant_id(
@@ -179,6 +180,7 @@ pub async fn signin(
         .into();
 
     user_from_db.compare_password(request.password)?;
+    sso::check_sso_not_enforced(&state, &user_from_db).await?;
 
     let signin_strategy =
         if let Some(preferred_merchant_id) = user_from_db.get_preferred_merchant_id() {
@@ -217,6 +219,7 @@ pub async fn signin_token_only_flow(
         .into();
 
     user_from_db.compare_password(request.password)?;
+    sso::check_sso_not_enforced(&state, &user_from_db).await?;
 
     let next_flow =
         domain::NextFlow::from_origin(domain::Origin::SignIn, user_from_db.clone(), &state).await?;
@@ -239,6 +242,7 @@ pub async fn connect_account(
 
     if let Ok(found_user) = find_user {
         let user_from_db: domain::UserFromStorage = found_user.into();
+        sso::check_sso_not_enforced(&state, &user_from_db).await?;
         let user_role = user_from_db.get_role_from_db(state.clone()).await?;
 
         let email_contents = email_types::MagicLink {
@@ -402,6 +406,7 @@ pub async fn forgot_password(
             }
         })
         .map(domain::UserFromStorage::from)?;
+    sso::check_sso_not_enforced(&state, &user_from_db).await?;
 
     let email_contents = email_types::ResetPassword {
         recipient_email: domain::UserEmail::from_pii_email(user_from_db.get_email())?,
@@ -0,0 +1,680 @@
+use std::collections::HashSet;
+
+use api_models::user as user_api;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{
+    enums::{AuditAction, AuditResourceType, UserStatus},
+    user_role::{UserRole, UserRoleNew, UserRoleUpdate},
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::env;
+
+use crate::{
+    consts,
+    core::{
+        config_audit,
+        errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    },
+    routes::{app::ReqState, AppState},
+    services::{authentication as auth, authorization::roles, ApplicationResponse},
+    types::{domain, storage},
+    utils::user::oidc,
+};
+
+pub async fn get_oidc_sso_config(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_api::OidcSsoConfigResponse> {
+    let config = state
+        .store
+        .find_oidc_sso_config_by_org_id(&user_from_token.org_id)
+        .await
+        .to_not_found_response(UserErrors::SsoNotConfigured)?;
+
+    Ok(ApplicationResponse::Json(get_oidc_sso_config_response(
+        config,
+    )?))
+}
+
+pub async fn upsert_oidc_sso_config(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    request: user_api::OidcSsoConfigRequest,
+    req_state: ReqState,
+) -> UserResponse<user_api::OidcSsoConfigResponse> {
+    check_org_admin(&user_from_token)?;
+
+    let provisioning_merchant_id = request
+        .provisioning_merchant_id
+        .clone()
+        .unwrap_or_else(|| user_from_token.merchant_id.clone());
+    validate_oidc_sso_config_request(
+        &state,
+        &user_from_token,
+        &request,
+        &provisioning_merchant_id,
+    )
+    .await?;
+
+    let client_secret = match request.client_secret {
+        Some(client_secret) => Some(
+            domain::types::encrypt::<String, masking::WithType>(
+                client_secret,
+                state.store.get_master_key(),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to encrypt client secret")?
+            .into(),
+        ),
+        None => None,
+    };
+    let scopes = request.scopes.unwrap_or_else(|| {
+        consts::user::OIDC_DEFAULT_SCOPES
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    });
+    let groups_claim = request
+        .groups_claim
+        .unwrap_or_else(|| consts::user::OIDC_DEFAULT_GROUPS_CLAIM.to_string());
+    let role_mappings = serde_json::to_value(&request.role_mappings)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize role mappings")?;
+
+    let existing_config = state
+        .store
+        .find_oidc_sso_config_by_org_id(&user_from_token.org_id)
+        .await;
+
+    let (before, config) = match existing_config {
+        Ok(existing_config) => {
+            let before = get_oidc_sso_config_response(existing_config.clone())?;
+            let config = state
+                .store
+                .update_oidc_sso_config_by_org_id(
+                    &user_from_token.org_id,
+                    storage::OidcSsoConfigUpdate::Update {
+                        issuer_url: request.issuer_url,
+                        client_id: request.client_id,
+                        client_secret,
+                        redirect_uri: request.redirect_uri,
+                        scopes,
+                        groups_claim,
+                        role_mappings,
+                        default_role_id: request.default_role_id,
+                        provisioning_merchant_id,
+                        is_sso_enforced: request
+                            .is_sso_enforced
+                            .unwrap_or(existing_config.is_sso_enforced),
+                    },
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            (Some(before), config)
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            let client_secret = client_secret.ok_or(UserErrors::InvalidSsoConfig(
+                "client_secret is required to configure SSO".to_string(),
+            ))?;
+            let now = common_utils::date_time::now();
+            let config = state
+                .store
+                .insert_oidc_sso_config(storage::OidcSsoConfigNew {
+                    org_id: user_from_token.org_id.clone(),
+                    issuer_url: request.issuer_url,
+                    client_id: request.client_id,
+                    client_secret,
+                    redirect_uri: request.redirect_uri,
+                    scopes,
+                    groups_claim,
+                    role_mappings,
+                    default_role_id: request.default_role_id,
+                    provisioning_merchant_id,
+                    is_sso_enforced: request.is_sso_enforced.unwrap_or(false),
+                    created_at: now,
+                    last_modified_at: now,
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            (None, config)
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    let response = get_oidc_sso_config_response(config)?;
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&user_from_token.merchant_id),
+            resource_type: AuditResourceType::OidcSsoConfig,
+            resource_id: &user_from_token.org_id,
+            action: if before.is_some() {
+                AuditAction::Update
+            } else {
+                AuditAction::Create
+            },
+            before: before.as_ref(),
+            after: Some(&response),
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn delete_oidc_sso_config(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req_state: ReqState,
+) -> UserResponse<()> {
+    check_org_admin(&user_from_token)?;
+
+    let config = state
+        .store
+        .delete_oidc_sso_config_by_org_id(&user_from_token.org_id)
+        .await
+        .to_not_found_response(UserErrors::SsoNotConfigured)?;
+
+    let before = get_oidc_sso_config_response(config)?;
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&user_from_token.merchant_id),
+            resource_type: AuditResourceType::OidcSsoConfig,
+            resource_id: &user_from_token.org_id,
+            action: AuditAction::Delete,
+            before: Some(&before),
+            after: None,
+        },
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn authorize_oidc_sso(
+    state: AppState,
+    request: user_api::OidcSsoAuthorizeRequest,
+) -> UserResponse<user_api::OidcSsoAuthorizeResponse> {
+    let config = state
+        .store
+        .find_oidc_sso_config_by_org_id(&request.org_id)
+        .await
+        .to_not_found_response(UserErrors::SsoNotConfigured)?;
+
+    let metadata = oidc::get_provider_metadata(&state, &config.issuer_url).await?;
+
+    let state_param = oidc::generate_random_token();
+    let browser_binding = oidc::generate_random_token();
+    let authorization_state = oidc::AuthorizationState {
+        org_id: config.org_id.clone(),
+        code_verifier: oidc::generate_random_token(),
+        nonce: oidc::generate_random_token(),
+        browser_binding_hash: oidc::hash_browser_binding(&browser_binding)?,
+    };
+    let authorization_url = oidc::build_authorization_url(
+        &metadata,
+        &config,
+        state_param.peek(),
+        &authorization_state,
+    )?;
+
+    get_redis_connection(&state)?
+        .serialize_and_set_key_with_expiry(
+            &get_authorization_state_key(state_param.peek()),
+            authorization_state,
+            consts::user::OIDC_SSO_STATE_EXPIRY_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to store OIDC authorization state")?;
+
+    auth::cookies::set_oidc_sso_state_cookie_response(
+        user_api::OidcSsoAuthorizeResponse { authorization_url },
+        browser_binding,
+    )
+}
+
+pub async fn oidc_sso_callback(
+    state: AppState,
+    request: user_api::OidcSsoCallbackRequest,
+    browser_binding: Option<masking::Secret<String>>,
+) -> UserResponse<user_api::TokenResponse> {
+    let authorization_state = consume_authorization_state(&state, &request.state).await?;
+
+    if !authorization_state.is_bound_to_browser(browser_binding.as_ref())? {
+        return Err(report!(UserErrors::SsoAuthenticationFailed))
+            .attach_printable("Authorization was not started by this browser");
+    }
+
+    let config = state
+        .store
+        .find_oidc_sso_config_by_org_id(&authorization_state.org_id)
+        .await
+        .to_not_found_response(UserErrors::SsoNotConfigured)?;
+
+    let metadata = oidc::get_provider_metadata(&state, &config.issuer_url).await?;
+    let client_secret = domain::types::decrypt::<String, masking::WithType>(
+        Some(config.client_secret.clone()),
+        state.store.get_master_key(),
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to decrypt client secret")?
+    .map(|client_secret| client_secret.into_inner())
+    .ok_or(UserErrors::InternalServerError)?;
+
+    let id_token = oidc::exchange_authorization_code(
+        &state,
+        &metadata,
+        &config,
+        client_secret,
+        request.code,
+        authorization_state.code_verifier,
+    )
+    .await?;
+    let claims = oidc::validate_id_token(
+        &state,
+        &metadata,
+        &config.client_id,
+        id_token,
+        &authorization_state.nonce,
+    )
+    .await?;
+
+    if !claims.is_email_verified() {
+        return Err(report!(UserErrors::SsoAuthenticationFailed))
+            .attach_printable("Email of the user is not verified by the identity provider");
+    }
+
+    let groups = claims.get_groups(&config.groups_claim);
+    let role_id = oidc::get_role_id_from_groups(
+        &get_role_mappings(&config)?,
+        config.default_role_id.as_ref(),
+        &groups,
+    )
+    .ok_or(UserErrors::SsoRoleMappingNotFound)
+    .attach_printable_lazy(|| format!("No role mapped to groups {groups:?}"))?;
+
+    let user_details = domain::SsoUserDetails {
+        email: claims.get_email()?,
+        name: claims.get_name(),
+        merchant_id: config.provisioning_merchant_id.clone(),
+        org_id: config.org_id.clone(),
+    };
+    let (user_from_db, user_role) = provision_user(&state, user_details, role_id).await?;
+
+    let next_flow =
+        domain::NextFlow::from_origin(domain::Origin::SignInWithSso, user_from_db, &state).await?;
+    let token = next_flow
+        .get_token_with_user_role(&state, &user_role)
+        .await?;
+
+    auth::cookies::set_cookie_response(
+        user_api::TokenResponse {
+            token: token.clone(),
+            token_type: next_flow.get_flow().into(),
+        },
+        token,
+    )
+}
+
+/// Password and magic link sign in are not allowed for users belonging to an organization which
+/// enforces SSO
+pub async fn check_sso_not_enforced(
+    state: &AppState,
+    user: &domain::UserFromStorage,
+) -> UserResult<()> {
+    let org_ids = user
+        .get_roles_from_db(state)
+        .await?
+        .into_iter()
+        .map(|user_role| user_role.org_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if org_ids.is_empty() {
+        return Ok(());
+    }
+
+    let sso_enforced_configs = state
+        .store
+        .list_sso_enforced_oidc_sso_configs_by_org_ids(org_ids)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if let Some(config) = sso_enforced_configs.first() {
+        return Err(report!(UserErrors::SsoEnforced))
+            .attach_printable(format!("SSO is enforced for org_id = {}", config.org_id));
+    }
+
+    Ok(())
+}
+
+/// Whether any of the roles of the user belongs to the organization
+fn is_member_of_org(user_roles: &[UserRole], org_id: &str) -> bool {
+    user_roles
+        .iter()
+        .any(|user_role| user_role.org_id == org_id)
+}
+
+/// Creates the user if they don't exist and assigns them the role mapped from their groups in the
+/// provisioning merchant account. The role of the organization admin is never changed.
+async fn provision_user(
+    state: &AppState,
+    user_details: domain::SsoUserDetails,
+    role_id: String,
+) -> UserResult<(domain::UserFromStorage, UserRole)> {
+    let role_info = roles::RoleInfo::from_role_id(
+        state,
+        &role_id,
+        &user_details.merchant_id,
+        &user_details.org_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)
+    .attach_printable_lazy(|| format!("Mapped role_id = {role_id} not found"))?;
+
+    let user_from_db = match state.store.find_user_by_email(&user_details.email).await {
+        Ok(user) => domain::UserFromStorage::from(user),
+        Err(error) if error.current_context().is_db_not_found() => {
+            let new_user = domain::NewUser::try_from(user_details.clone())?;
+            let mut store_user: storage::UserNew = new_user.clone().try_into()?;
+            store_user.set_is_verified(true);
+
+            let user_from_db = state
+                .store
+                .insert_user(store_user)
+                .await
+                .map_err(|e| {
+                    if e.current_context().is_db_unique_violation() {
+                        e.change_context(UserErrors::UserExists)
+                    } else {
+                        e.change_context(UserErrors::InternalServerError)
+                    }
+                })
+                .map(domain::UserFromStorage::from)?;
+
+            let user_role = new_user
+                .insert_user_role_in_db(
+                    state.clone(),
+                    role_info.get_role_id().to_string(),
+                    UserStatus::Active,
+                )
+                .await?;
+
+            return Ok((user_from_db, user_role));
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    // An identity provider configured by one organization must not be able to sign in to an
+    // account it doesn't own, so existing users are only signed in if they already belong to the
+    // organization
+    if !is_member_of_org(
+        &user_from_db.get_roles_from_db(state).await?,
+        &user_details.org_id,
+    ) {
+        return Err(report!(UserErrors::SsoAuthenticationFailed))
+            .attach_printable("Existing user is not a member of the organization");
+    }
+
+    let user_id = user_from_db.get_user_id().to_string();
+    let existing_user_role = state
+        .store
+        .find_user_role_by_user_id_merchant_id(&user_id, &user_details.merchant_id)
+        .await;
+
+    let mut user_role = match existing_user_role {
+        Ok(user_role) => user_role,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            let user_role = state
+                .store
+                .insert_user_role(UserRoleNew {
+                    user_id: user_id.clone(),
+                    merchant_id: user_details.merchant_id,
+                    role_id: role_info.get_role_id().to_string(),
+                    org_id: user_details.org_id,
+                    status: UserStatus::Active,
+                    created_by: user_id.clone(),
+                    last_modified_by: user_id,
+                    created_at: now,
+                    last_modified: now,
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+
+            return Ok((user_from_db, user_role));
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    if user_role.status != UserStatus::Active {
+        user_role = state
+            .store
+            .update_user_role_by_user_id_merchant_id(
+                &user_id,
+                &user_role.merchant_id,
+                UserRoleUpdate::UpdateStatus {
+                    status: UserStatus::Active,
+                    modified_by: user_id.clone(),
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    if user_role.role_id != role_info.get_role_id()
+        && user_role.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+    {
+        user_role = state
+            .store
+            .update_user_role_by_user_id_merchant_id(
+                &user_id,
+                &user_role.merchant_id,
+                UserRoleUpdate::UpdateRole {
+                    role_id: role_info.get_role_id().to_string(),
+                    modified_by: user_id.clone(),
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    Ok((user_from_db, user_role))
+}
+
+async fn validate_oidc_sso_config_request(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    request: &user_api::OidcSsoConfigRequest,
+    provisioning_merchant_id: &str,
+) -> UserResult<()> {
+    let issuer_url = url::Url::parse(&request.issuer_url).change_context(
+        UserErrors::InvalidSsoConfig("issuer_url is not a valid URL".to_string()),
+    )?;
+    if matches!(env::which(), env::Env::Production) && issuer_url.scheme() != "https" {
+        return Err(report!(UserErrors::InvalidSsoConfig(
+            "issuer_url must use https".to_string()
+        )));
+    }
+    url::Url::parse(&request.redirect_uri).change_context(UserErrors::InvalidSsoConfig(
+        "redirect_uri is not a valid URL".to_string(),
+    ))?;
+
+    if request
+        .scopes
+        .as_ref()
+        .is_some_and(|scopes| !scopes.iter().any(|scope| scope == "openid"))
+    {
+        return Err(report!(UserErrors::InvalidSsoConfig(
+            "scopes must include openid".to_string()
+        )));
+    }
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            provisioning_merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(UserErrors::MerchantIdNotFound)?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(provisioning_merchant_id, &key_store)
+        .await
+        .to_not_found_response(UserErrors::MerchantIdNotFound)?;
+    if merchant_account.organization_id != user_from_token.org_id {
+        return Err(report!(UserErrors::MerchantIdNotFound)).attach_printable(format!(
+            "merchant_id = {provisioning_merchant_id} does not belong to org_id = {}",
+            user_from_token.org_id
+        ));
+    }
+
+    let role_ids = request
+        .role_mappings
+        .iter()
+        .map(|mapping| &mapping.role_id)
+        .chain(request.default_role_id.as_ref());
+    for role_id in role_ids {
+        let role_info = roles::RoleInfo::from_role_id(
+            state,
+            role_id,
+            provisioning_merchant_id,
+            &user_from_token.org_id,
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+        if !role_info.is_invitable() {
+            return Err(report!(UserErrors::InvalidRoleId)).attach_printable(format!(
+                "role_id = {role_id} cannot be assigned through SSO"
+            ));
+        }
+    }
+
+    oidc::get_provider_metadata(state, &request.issuer_url)
+        .await
+        .change_context(UserErrors::InvalidSsoConfig(
+            "Unable to discover the OpenID provider from issuer_url".to_string(),
+        ))?;
+
+    Ok(())
+}
+
+fn check_org_admin(user_from_token: &auth::UserFromToken) -> UserResult<()> {
+    if user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN {
+        return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+            "role_id = {} is not org_admin",
+            user_from_token.role_id
+        ));
+    }
+    Ok(())
+}
+
+fn get_role_mappings(
+    config: &storage::OidcSsoConfig,
+) -> UserResult<Vec<user_api::OidcRoleMapping>> {
+    config
+        .role_mappings
+        .clone()
+        .parse_value("OidcRoleMappings")
+        .change_context(UserErrors::InternalServerError)
+}
+
+fn get_oidc_sso_config_response(
+    config: storage::OidcSsoConfig,
+) -> UserResult<user_api::OidcSsoConfigResponse> {
+    Ok(user_api::OidcSsoConfigResponse {
+        role_mappings: get_role_mappings(&config)?,
+        org_id: config.org_id,
+        issuer_url: config.issuer_url,
+        client_id: config.client_id,
+        redirect_uri: config.redirect_uri,
+        scopes: config.scopes,
+        groups_claim: config.groups_claim,
+        default_role_id: config.default_role_id,
+        provisioning_merchant_id: config.provisioning_merchant_id,
+        is_sso_enforced: config.is_sso_enforced,
+    })
+}
+
+fn get_authorization_state_key(state_param: &str) -> String {
+    format!("{}{}", consts::user::OIDC_SSO_STATE_PREFIX, state_param)
+}
+
+/// Fetches and deletes the authorization state, so that it can only be used once
+async fn consume_authorization_state(
+    state: &AppState,
+    state_param: &str,
+) -> UserResult<oidc::AuthorizationState> {
+    let redis_conn = get_redis_connection(state)?;
+    let key = get_authorization_state_key(state_param);
+
+    let authorization_state = redis_conn
+        .get_and_deserialize_key::<oidc::AuthorizationState>(&key, "AuthorizationState")
+        .await
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Invalid or expired state")?;
+
+    // The state is consumed only if this request deleted it, guarding against replays
+    match redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to delete OIDC authorization state")?
+    {
+        redis_interface::DelReply::KeyDeleted => Ok(authorization_state),
+        redis_interface::DelReply::KeyNotDeleted => {
+            Err(report!(UserErrors::SsoAuthenticationFailed))
+                .attach_printable("State has already been used")
+        }
+    }
+}
+
+fn get_redis_connection(
+    state: &AppState,
+) -> UserResult<std::sync::Arc<redis_interface::RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_role_in_org(org_id: &str) -> UserRole {
+        let now = common_utils::date_time::now();
+        UserRole {
+            id: 1,
+            user_id: "user_abc".to_string(),
+            merchant_id: "merchant_abc".to_string(),
+            role_id: "merchant_admin".to_string(),
+            org_id: org_id.to_string(),
+            status: UserStatus::Active,
+            created_by: "user_abc".to_string(),
+            last_modified_by: "user_abc".to_string(),
+            created_at: now,
+            last_modified: now,
+        }
+    }
+
+    #[test]
+    fn test_is_member_of_org() {
+        let user_roles = vec![user_role_in_org("org_abc"), user_role_in_org("org_def")];
+
+        assert!(is_member_of_org(&user_roles, "org_def"));
+        assert!(!is_member_of_org(&user_roles, "org_xyz"));
+        assert!(!is_member_of_org(&[], "org_abc"));
+    }
+}
@@ -1,11 +1,17 @@
 use api_models::{user as user_api, user_role as user_role_api};
-use diesel_models::{enums::UserStatus, user_role::UserRoleUpdate};
+use diesel_models::{
+    enums::UserStatus,
+    user_role::{UserRole, UserRoleUpdate},
+};
 use error_stack::{report, ResultExt};
 use router_env::logger;
 
 use crate::{
     consts,
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    core::{
+        config_audit,
+        errors::{StorageErrorExt, UserErrors, UserResponse},
+    },
     routes::{app::ReqState, AppState},
     services::{
         authentication as auth,
@@ -49,7 +55,7 @@ pub async fn update_user_role(
     state: AppState,
     user_from_token: auth::UserFromToken,
     req: user_role_api::UpdateUserRoleRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> UserResponse<()> {
     let role_info = roles::RoleInfo::from_role_id(
         &state,
@@ -97,7 +103,7 @@ pub async fn update_user_role(
         ));
     }
 
-    state
+    let updated_user_role = state
         .store
         .update_user_role_by_user_id_merchant_id(
             user_to_be_updated.get_user_id(),
@@ -111,6 +117,20 @@ pub async fn update_user_role(
         .to_not_found_response(UserErrors::InvalidRoleOperation)
         .attach_printable("User with given email is not found in the organization")?;
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&user_role_to_be_updated.merchant_id),
+            resource_type: common_enums::AuditResourceType::UserRole,
+            resource_id: user_to_be_updated.get_user_id(),
+            action: common_enums::AuditAction::Update,
+            before: Some(&get_user_role_audit_snapshot(&user_role_to_be_updated)),
+            after: Some(&get_user_role_audit_snapshot(&updated_user_role)),
+        },
+    )
+    .await;
+
     auth::blacklist::insert_user_in_blacklist(&state, user_to_be_updated.get_user_id()).await?;
 
     Ok(ApplicationResponse::StatusOk)
@@ -306,7 +326,7 @@ pub async fn delete_user_role(
     state: AppState,
     user_from_token: auth::UserFromToken,
     request: user_role_api::DeleteUserRoleRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> UserResponse<()> {
     let user_from_db: domain::UserFromStorage = state
         .store
@@ -386,6 +406,28 @@ pub async fn delete_user_role(
             .attach_printable("Error while deleting user role")?
     };
 
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&deleted_user_role.merchant_id),
+            resource_type: common_enums::AuditResourceType::UserRole,
+            resource_id: &deleted_user_role.user_id,
+            action: common_enums::AuditAction::Delete,
+            before: Some(&get_user_role_audit_snapshot(&deleted_user_role)),
+            after: None,
+        },
+    )
+    .await;
+
     auth::blacklist::insert_user_in_blacklist(&state, &deleted_user_role.user_id).await?;
     Ok(ApplicationResponse::StatusOk)
 }
+
+fn get_user_role_audit_snapshot(user_role: &UserRole) -> serde_json::Value {
+    serde_json::json!({
+        "user_id": user_role.user_id,
+        "role_id": user_role.role_id,
+        "status": user_role.status,
+    })
+}
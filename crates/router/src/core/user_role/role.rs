@@ -6,7 +6,10 @@ use error_stack::{report, ResultExt};
 
 use crate::{
     consts,
-    core::errors::{StorageErrorExt, UserErrors, UserResponse},
+    core::{
+        config_audit,
+        errors::{StorageErrorExt, UserErrors, UserResponse},
+    },
     routes::{app::ReqState, AppState},
     services::{
         authentication::{blacklist, UserFromToken},
@@ -57,7 +60,7 @@ pub async fn create_role(
     state: AppState,
     user_from_token: UserFromToken,
     req: role_api::CreateRoleRequest,
-    _req_state: ReqState,
+    req_state: ReqState,
 ) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
     let now = common_utils::date_time::now();
     let role_name = RoleName::new(req.role_name)?;
@@ -95,14 +98,28 @@ pub async fn create_role(
         .await
         .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
 
-    Ok(ApplicationResponse::Json(
-        role_api::RoleInfoWithGroupsResponse {
-            groups: role.groups,
-            role_id: role.role_id,
-            role_name: role.role_name,
-            role_scope: role.scope,
+    let response = role_api::RoleInfoWithGroupsResponse {
+        groups: role.groups,
+        role_id: role.role_id,
+        role_name: role.role_name,
+        role_scope: role.scope,
+    };
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&role.merchant_id),
+            resource_type: common_enums::AuditResourceType::Role,
+            resource_id: &response.role_id,
+            action: common_enums::AuditAction::Create,
+            before: None,
+            after: Some(&response),
         },
-    ))
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
 
 // TODO: To be deprecated once groups are stable
@@ -264,6 +281,7 @@ pub async fn update_role(
     user_from_token: UserFromToken,
     req: role_api::UpdateRoleRequest,
     role_id: &str,
+    req_state: ReqState,
 ) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
     let role_name = req.role_name.map(RoleName::new).transpose()?;
 
@@ -297,6 +315,13 @@ pub async fn update_role(
             .attach_printable("Non org admin user changing org level role");
     }
 
+    let role_before = role_api::RoleInfoWithGroupsResponse {
+        groups: role_info.get_permission_groups().clone(),
+        role_id: role_info.get_role_id().to_string(),
+        role_name: role_info.get_role_name().to_string(),
+        role_scope: role_info.get_scope(),
+    };
+
     let updated_role = state
         .store
         .update_role_by_role_id(
@@ -313,12 +338,26 @@ pub async fn update_role(
 
     blacklist::insert_role_in_blacklist(&state, role_id).await?;
 
-    Ok(ApplicationResponse::Json(
-        role_api::RoleInfoWithGroupsResponse {
-            groups: updated_role.groups,
-            role_id: updated_role.role_id,
-            role_name: updated_role.role_name,
-            role_scope: updated_role.scope,
+    let response = role_api::RoleInfoWithGroupsResponse {
+        groups: updated_role.groups,
+        role_id: updated_role.role_id,
+        role_name: updated_role.role_name,
+        role_scope: updated_role.scope,
+    };
+
+    config_audit::record_config_change(
+        &state,
+        &req_state,
+        config_audit::ConfigChange {
+            merchant_id: Some(&updated_role.merchant_id),
+            resource_type: common_enums::AuditResourceType::Role,
+            resource_id: role_id,
+            action: common_enums::AuditAction::Update,
+            before: Some(&role_before),
+            after: Some(&response),
         },
-    ))
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
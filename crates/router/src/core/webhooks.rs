@@ -1,3 +1,4 @@
+pub mod endpoint_health;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -28,7 +29,7 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payments, refunds,
     },
@@ -719,6 +720,7 @@ pub async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        disputes::schedule_deadline_reminders(&state, &dispute_object).await;
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -849,10 +851,47 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        &primary_object_id,
+        event_type,
+        enums::WebhookDeliveryAttempt::InitialAttempt,
+    );
+
+    create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        merchant_key_store,
+        event_type,
+        event_class,
+        primary_object_id,
+        primary_object_type,
+        content,
+        primary_object_created_at,
+        idempotent_event_id,
+    )
+    .await
+}
+
+/// Same as `create_event_and_trigger_outgoing_webhook`, for events which are deduplicated on an
+/// idempotent event ID other than the one derived from the primary object and event type.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_event_with_idempotent_event_id_and_trigger_outgoing_webhook(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    idempotent_event_id: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
     let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
 
     if !state.conf.webhooks.outgoing_enabled
@@ -1019,10 +1058,16 @@ async fn trigger_webhook_to_merchant(
     delivery_attempt: enums::WebhookDeliveryAttempt,
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
-        process_tracker.clone(),
-    ) {
+    // Webhooks are not sent to disabled endpoints unless retried manually
+    let webhook_url_result = if business_profile.webhook_endpoint_disabled_at.is_some()
+        && delivery_attempt != enums::WebhookDeliveryAttempt::ManualRetry
+    {
+        Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+    } else {
+        get_webhook_url_from_business_profile(&business_profile)
+    };
+
+    let webhook_url = match (webhook_url_result, process_tracker.clone()) {
         (Ok(webhook_url), _) => Ok(webhook_url),
         (Err(error), Some(process_tracker)) => {
             if !error
@@ -1259,6 +1304,15 @@ async fn trigger_webhook_to_merchant(
         logger::warn!(?error, ?delivery_attempt, ?status_code, %log_message);
     };
 
+    let is_delivered = matches!(&response, Ok(response) if response.status().is_success());
+    endpoint_health::record_delivery_outcome(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        is_delivered,
+    )
+    .await;
+
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
             Err(client_error) => {
@@ -1320,7 +1374,7 @@ async fn trigger_webhook_to_merchant(
                     // Schedule a retry attempt for webhook delivery
                     outgoing_webhook_retry::retry_webhook_delivery_task(
                         &*state.store,
-                        &business_profile.merchant_id,
+                        &business_profile,
                         process_tracker,
                     )
                     .await
@@ -1357,7 +1411,7 @@ async fn trigger_webhook_to_merchant(
                         // Schedule a retry attempt for webhook delivery
                         outgoing_webhook_retry::retry_webhook_delivery_task(
                             &*state.store,
-                            &business_profile.merchant_id,
+                            &business_profile,
                             process_tracker,
                         )
                         .await
@@ -1977,16 +2031,13 @@ pub async fn add_outgoing_webhook_retry_task_to_process_tracker(
     business_profile: &diesel_models::business_profile::BusinessProfile,
     event: &domain::Event,
 ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-    let schedule_time = outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
-        db,
-        &business_profile.merchant_id,
-        0,
-    )
-    .await
-    .ok_or(errors::StorageError::ValueNotFound(
-        "Process tracker schedule time".into(), // Can raise a better error here
-    ))
-    .attach_printable("Failed to obtain initial process tracker schedule time")?;
+    let schedule_time =
+        outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(db, business_profile, 0)
+            .await
+            .ok_or(errors::StorageError::ValueNotFound(
+                "Process tracker schedule time".into(), // Can raise a better error here
+            ))
+            .attach_printable("Failed to obtain initial process tracker schedule time")?;
 
     let tracking_data = types::OutgoingWebhookTrackingData {
         merchant_id: business_profile.merchant_id.clone(),
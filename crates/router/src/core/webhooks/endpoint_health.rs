@@ -0,0 +1,389 @@
+use diesel_models::business_profile::{BusinessProfile, BusinessProfileUpdate};
+use router_env::{instrument, logger, tracing};
+
+use super::utils;
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::AppState,
+    types::domain,
+};
+
+/// Records the outcome of a webhook delivery attempt against the webhook endpoint of the business
+/// profile.
+///
+/// The endpoint is marked as failing on the first failed delivery, and is marked healthy again on
+/// the next successful delivery. If no webhook could be delivered to the endpoint for longer than
+/// allowed by the retry policy of the business profile, the endpoint is disabled and the merchant
+/// is notified by email.
+///
+/// Failures are logged and do not affect the delivery of the webhook.
+#[instrument(skip_all)]
+pub(crate) async fn record_delivery_outcome(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    is_delivered: bool,
+) {
+    let result = update_endpoint_health(state, business_profile, is_delivered).await;
+
+    match result {
+        Ok(Some(updated_business_profile)) => {
+            logger::warn!(
+                profile_id = %updated_business_profile.profile_id,
+                failing_since = ?updated_business_profile.webhook_endpoint_failing_since,
+                "Disabled webhook endpoint due to sustained delivery failures"
+            );
+
+            #[cfg(feature = "email")]
+            send_endpoint_disabled_email(state, &updated_business_profile, merchant_key_store)
+                .await;
+            #[cfg(not(feature = "email"))]
+            let _ = merchant_key_store;
+        }
+        Ok(None) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                profile_id = %business_profile.profile_id,
+                "Failed to update webhook endpoint health"
+            );
+        }
+    }
+}
+
+/// Updates the health of the webhook endpoint, returning the updated business profile if the
+/// endpoint was disabled by this update.
+async fn update_endpoint_health(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    is_delivered: bool,
+) -> CustomResult<Option<BusinessProfile>, errors::StorageError> {
+    let now = common_utils::date_time::now();
+    let disabled_at = business_profile.webhook_endpoint_disabled_at;
+
+    let (business_profile_update, is_disabled_now) = match (
+        is_delivered,
+        business_profile.webhook_endpoint_failing_since,
+    ) {
+        // Nothing to update for healthy endpoints
+        (true, None) => return Ok(None),
+        (true, Some(_)) => (
+            BusinessProfileUpdate::WebhookEndpointHealthUpdate {
+                webhook_endpoint_failing_since: None,
+                webhook_endpoint_disabled_at: disabled_at,
+            },
+            false,
+        ),
+        (false, None) => (
+            BusinessProfileUpdate::WebhookEndpointHealthUpdate {
+                webhook_endpoint_failing_since: Some(now),
+                webhook_endpoint_disabled_at: disabled_at,
+            },
+            false,
+        ),
+        (false, Some(failing_since)) => {
+            let disable_after = utils::get_webhook_retry_policy(business_profile)
+                .and_then(|retry_policy| retry_policy.disable_endpoint_after_failing_for_secs);
+            let should_disable = disabled_at.is_none()
+                && disable_after.is_some_and(|disable_after| {
+                    failing_since.saturating_add(time::Duration::seconds(i64::from(disable_after)))
+                        <= now
+                });
+
+            if !should_disable {
+                return Ok(None);
+            }
+
+            (
+                BusinessProfileUpdate::WebhookEndpointHealthUpdate {
+                    webhook_endpoint_failing_since: Some(failing_since),
+                    webhook_endpoint_disabled_at: Some(now),
+                },
+                true,
+            )
+        }
+    };
+
+    let updated_business_profile = state
+        .store
+        .update_business_profile_by_profile_id(business_profile.clone(), business_profile_update)
+        .await?;
+
+    Ok(is_disabled_now.then_some(updated_business_profile))
+}
+
+/// Emails the merchant about the disabled webhook endpoint. Failures are logged.
+#[cfg(feature = "email")]
+async fn send_endpoint_disabled_email(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    merchant_key_store: &domain::MerchantKeyStore,
+) {
+    use common_utils::ext_traits::ValueExt;
+    use error_stack::ResultExt;
+    use masking::ExposeInterface;
+
+    use crate::{services::email::types::WebhookEndpointDisabled, types::api};
+
+    let result: CustomResult<(), errors::ApiErrorResponse> = async {
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(&business_profile.merchant_id, merchant_key_store)
+            .await
+            .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+        let Some(email_id) = merchant_account
+            .merchant_details
+            .clone()
+            .parse_value::<api::MerchantDetails>("MerchantDetails")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .primary_email
+        else {
+            logger::debug!(
+                merchant_id = %business_profile.merchant_id,
+                "Merchant does not have a primary email configured; skipping endpoint disabled email"
+            );
+            return Ok(());
+        };
+
+        let webhook_url = business_profile
+            .webhook_details
+            .clone()
+            .map(|webhook_details| webhook_details.parse_value::<api::WebhookDetails>("WebhookDetails"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .and_then(|webhook_details| webhook_details.webhook_url)
+            .map(ExposeInterface::expose)
+            .unwrap_or_default();
+
+        let email_contents = WebhookEndpointDisabled {
+            recipient_email: domain::UserEmail::from_pii_email(email_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to convert recipient's email to UserEmail from pii::Email",
+                )?,
+            subject: "Webhook Endpoint Disabled",
+            profile_id: business_profile.profile_id.clone(),
+            profile_name: business_profile.profile_name.clone(),
+            webhook_url,
+            failing_since: business_profile
+                .webhook_endpoint_failing_since
+                .unwrap_or_else(common_utils::date_time::now),
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send webhook endpoint disabled email")?;
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            profile_id = %business_profile.profile_id,
+            ?error,
+            "Failed to send webhook endpoint disabled email"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use diesel_models::business_profile::BusinessProfileNew;
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, services};
+
+    async fn get_mock_state() -> AppState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        Box::pin(AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            api_client,
+        ))
+        .await
+    }
+
+    async fn insert_business_profile(
+        state: &AppState,
+        profile_id: &str,
+        disable_endpoint_after_failing_for_secs: Option<u32>,
+        webhook_endpoint_failing_since: Option<time::PrimitiveDateTime>,
+        webhook_endpoint_disabled_at: Option<time::PrimitiveDateTime>,
+    ) -> BusinessProfile {
+        let now = common_utils::date_time::now();
+        let webhook_retry_policy = serde_json::json!({
+            "disable_endpoint_after_failing_for_secs": disable_endpoint_after_failing_for_secs,
+        });
+
+        state
+            .store
+            .insert_business_profile(BusinessProfileNew {
+                profile_id: profile_id.to_string(),
+                merchant_id: "merchant_1".to_string(),
+                profile_name: "default".to_string(),
+                created_at: now,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: true,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: false,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: false,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                authorization_expiry_policy: None,
+                webhook_retry_policy: Some(webhook_retry_policy),
+                webhook_endpoint_failing_since,
+                webhook_endpoint_disabled_at,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn test_update_endpoint_health_of_healthy_endpoint() {
+        let state = get_mock_state().await;
+        let business_profile =
+            insert_business_profile(&state, "pro_healthy", Some(3600), None, None).await;
+
+        // A successful delivery to a healthy endpoint does not change anything
+        let disabled = update_endpoint_health(&state, &business_profile, true)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+        let stored = state
+            .store
+            .find_business_profile_by_profile_id("pro_healthy")
+            .await
+            .unwrap();
+        assert_eq!(stored.webhook_endpoint_failing_since, None);
+
+        // The first failed delivery marks the endpoint as failing, without disabling it
+        let disabled = update_endpoint_health(&state, &business_profile, false)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+        let stored = state
+            .store
+            .find_business_profile_by_profile_id("pro_healthy")
+            .await
+            .unwrap();
+        assert!(stored.webhook_endpoint_failing_since.is_some());
+        assert_eq!(stored.webhook_endpoint_disabled_at, None);
+    }
+
+    #[actix_rt::test]
+    async fn test_update_endpoint_health_of_recovered_endpoint() {
+        let state = get_mock_state().await;
+        let failing_since = common_utils::date_time::now().saturating_sub(time::Duration::hours(2));
+        let business_profile = insert_business_profile(
+            &state,
+            "pro_recovered",
+            Some(3600),
+            Some(failing_since),
+            None,
+        )
+        .await;
+
+        let disabled = update_endpoint_health(&state, &business_profile, true)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+        let stored = state
+            .store
+            .find_business_profile_by_profile_id("pro_recovered")
+            .await
+            .unwrap();
+        assert_eq!(stored.webhook_endpoint_failing_since, None);
+        assert_eq!(stored.webhook_endpoint_disabled_at, None);
+    }
+
+    #[actix_rt::test]
+    async fn test_update_endpoint_health_disables_endpoint() {
+        let state = get_mock_state().await;
+        let now = common_utils::date_time::now();
+
+        // Failing for less time than allowed by the retry policy
+        let business_profile = insert_business_profile(
+            &state,
+            "pro_failing",
+            Some(3600),
+            Some(now.saturating_sub(time::Duration::minutes(30))),
+            None,
+        )
+        .await;
+        let disabled = update_endpoint_health(&state, &business_profile, false)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+
+        // Failing for longer than allowed by the retry policy
+        let failing_since = now.saturating_sub(time::Duration::hours(2));
+        let business_profile =
+            insert_business_profile(&state, "pro_failed", Some(3600), Some(failing_since), None)
+                .await;
+        let disabled = update_endpoint_health(&state, &business_profile, false)
+            .await
+            .unwrap()
+            .expect("endpoint should be disabled");
+        assert_eq!(disabled.webhook_endpoint_failing_since, Some(failing_since));
+        assert!(disabled.webhook_endpoint_disabled_at.is_some());
+
+        // Endpoints which are already disabled are not disabled again
+        let disabled = update_endpoint_health(&state, &disabled, false)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_update_endpoint_health_without_disable_policy() {
+        let state = get_mock_state().await;
+        let failing_since = common_utils::date_time::now().saturating_sub(time::Duration::days(30));
+        let business_profile = insert_business_profile(
+            &state,
+            "pro_never_disabled",
+            None,
+            Some(failing_since),
+            None,
+        )
+        .await;
+
+        let disabled = update_endpoint_health(&state, &business_profile, false)
+            .await
+            .unwrap();
+        assert!(disabled.is_none());
+        let stored = state
+            .store
+            .find_business_profile_by_profile_id("pro_never_disabled")
+            .await
+            .unwrap();
+        assert_eq!(stored.webhook_endpoint_disabled_at, None);
+    }
+}
@@ -3,6 +3,7 @@ use common_utils::{crypto::SignMessage, ext_traits::Encode};
 use error_stack::ResultExt;
 use masking::Secret;
 use serde::Serialize;
+use time::PrimitiveDateTime;
 
 use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
 
@@ -66,3 +67,16 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
 }
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WebhookBulkRetryTrackingData {
+    pub(crate) merchant_id: String,
+    // Only events of this business profile are redelivered, if specified
+    pub(crate) business_profile_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) created_after: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub(crate) created_before: PrimitiveDateTime,
+    // Number of initial events in the time range already processed by the job
+    pub(crate) offset: i64,
+}
@@ -133,14 +133,89 @@ pub(crate) fn get_idempotent_event_id(
 
     let common_prefix = format!("{primary_object_id}_{event_type}");
     match delivery_attempt {
-        WebhookDeliveryAttempt::InitialAttempt => common_prefix,
-        WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
+        WebhookDeliveryAttempt::InitialAttempt if !is_recurring_event_type(event_type) => {
+            common_prefix
+        }
+        // Events which may be raised more than once for the same object are never deduplicated
+        WebhookDeliveryAttempt::InitialAttempt
+        | WebhookDeliveryAttempt::AutomaticRetry
+        | WebhookDeliveryAttempt::ManualRetry => {
             common_utils::generate_id(EVENT_ID_SUFFIX_LENGTH, &common_prefix)
         }
     }
 }
 
+/// Whether the event can be raised multiple times for the same object, unlike status changes of
+/// payments or refunds which happen only once. Payment method events are also sent once for every
+/// business profile of the merchant.
+fn is_recurring_event_type(event_type: types::storage::enums::EventType) -> bool {
+    matches!(
+        event_type,
+        types::storage::enums::EventType::PaymentMethodCreated
+            | types::storage::enums::EventType::PaymentMethodUpdated
+            | types::storage::enums::EventType::PaymentMethodDeleted
+            | types::storage::enums::EventType::DefaultPaymentMethodChanged
+    )
+}
+
 #[inline]
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+/// Get the outgoing webhook retry policy configured on the business profile. A policy which
+/// cannot be parsed is logged and ignored, so that the global retry schedule is used instead.
+pub(crate) fn get_webhook_retry_policy(
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> Option<api_models::admin::WebhookRetryPolicy> {
+    business_profile
+        .webhook_retry_policy
+        .clone()
+        .map(|policy| policy.parse_value("WebhookRetryPolicy"))
+        .transpose()
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                profile_id = %business_profile.profile_id,
+                "Failed to parse the webhook retry policy of the business profile"
+            );
+        })
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::storage::enums::{EventType, WebhookDeliveryAttempt};
+
+    #[test]
+    fn test_get_idempotent_event_id() {
+        assert_eq!(
+            get_idempotent_event_id(
+                "pay_1",
+                EventType::PaymentSucceeded,
+                WebhookDeliveryAttempt::InitialAttempt
+            ),
+            get_idempotent_event_id(
+                "pay_1",
+                EventType::PaymentSucceeded,
+                WebhookDeliveryAttempt::InitialAttempt
+            )
+        );
+
+        // Payment method events are sent to every business profile of the merchant, so each of
+        // them needs its own event
+        for event_type in [
+            EventType::PaymentMethodCreated,
+            EventType::PaymentMethodUpdated,
+            EventType::PaymentMethodDeleted,
+            EventType::DefaultPaymentMethodChanged,
+        ] {
+            assert_ne!(
+                get_idempotent_event_id("pm_1", event_type, WebhookDeliveryAttempt::InitialAttempt),
+                get_idempotent_event_id("pm_1", event_type, WebhookDeliveryAttempt::InitialAttempt)
+            );
+        }
+    }
+}
@@ -1,16 +1,20 @@
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+use futures::StreamExt;
 use masking::PeekInterface;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
+use super::types;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    routes::AppState,
+    core::errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    routes::{metrics, AppState},
     services::ApplicationResponse,
     types::{api, domain, storage, transformers::ForeignTryFrom},
     utils::{OptionExt, StringExt},
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
+const BULK_RETRY_MAX_CONCURRENT_DELIVERIES: usize = 10;
 
 #[derive(Debug)]
 enum MerchantAccountOrBusinessProfile {
@@ -187,6 +191,336 @@ pub async fn retry_delivery_attempt(
         MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => Ok(business_profile),
     }?;
 
+    let updated_event =
+        redeliver_event(&state, business_profile, &key_store, event_to_retry).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn bulk_retry_delivery_attempts(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    request: api::webhook_events::WebhookDeliveryBulkRetryRequest,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryBulkRetryResponse> {
+    if request.created_after > request.created_before {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`created_after` must be earlier than `created_before`".to_string(),
+        }));
+    }
+
+    let (account, _key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let (merchant_id, business_profile_id) = match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(merchant_account) => {
+            (merchant_account.merchant_id, None)
+        }
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => (
+            business_profile.merchant_id,
+            Some(business_profile.profile_id),
+        ),
+    };
+
+    let job_id = common_utils::generate_time_ordered_id("whbr");
+    let tracking_data = types::WebhookBulkRetryTrackingData {
+        merchant_id,
+        business_profile_id,
+        created_after: request.created_after,
+        created_before: request.created_before,
+        offset: 0,
+    };
+    add_bulk_retry_task_to_process_tracker(state.store.as_ref(), &job_id, tracking_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule bulk redelivery of events")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookDeliveryBulkRetryResponse { job_id },
+    ))
+}
+
+async fn add_bulk_retry_task_to_process_tracker(
+    db: &dyn StorageInterface,
+    job_id: &str,
+    tracking_data: types::WebhookBulkRetryTrackingData,
+) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::WebhookBulkRetryWorkflow;
+    let task = "WEBHOOK_BULK_RETRY";
+    let tag = ["OUTGOING_WEBHOOKS"];
+    let process_tracker_id =
+        scheduler::utils::get_process_tracker_id(runner, task, job_id, &tracking_data.merchant_id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(process_tracker) => {
+            metrics::TASKS_ADDED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "WebhookBulkRetry")],
+            );
+            Ok(process_tracker)
+        }
+        Err(error) => {
+            metrics::TASK_ADDITION_FAILURES_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::request::add_attributes("flow", "WebhookBulkRetry")],
+            );
+            Err(error)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BulkRedeliveryOutcome {
+    AlreadyDelivered,
+    Delivered,
+    Failed,
+}
+
+/// Redelivers one batch of the undelivered events of a bulk retry job, starting at the offset of
+/// the job. The events in the batch are redelivered concurrently, with at most
+/// `BULK_RETRY_MAX_CONCURRENT_DELIVERIES` deliveries in flight. Returns the offset of the next
+/// batch, if there may be more events in the time range.
+#[instrument(skip_all)]
+pub(crate) async fn run_bulk_retry_batch(
+    state: &AppState,
+    tracking_data: &types::WebhookBulkRetryTrackingData,
+) -> errors::RouterResult<Option<i64>> {
+    let store = state.store.as_ref();
+
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &tracking_data.merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let offset = Some(tracking_data.offset).filter(|offset| *offset > 0);
+
+    let (events, business_profiles) = match &tracking_data.business_profile_id {
+        None => {
+            let events = store
+                .list_initial_events_by_merchant_id_constraints(
+                    &tracking_data.merchant_id,
+                    Some(tracking_data.created_after),
+                    Some(tracking_data.created_before),
+                    Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+                    offset,
+                    &key_store,
+                )
+                .await;
+            let business_profiles = store
+                .list_business_profile_by_merchant_id(&tracking_data.merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list business profiles of merchant")?;
+            (events, business_profiles)
+        }
+        Some(profile_id) => {
+            let events = store
+                .list_initial_events_by_profile_id_constraints(
+                    profile_id,
+                    Some(tracking_data.created_after),
+                    Some(tracking_data.created_before),
+                    Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+                    offset,
+                    &key_store,
+                )
+                .await;
+            let business_profile = store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_owned(),
+                })?;
+            (events, vec![business_profile])
+        }
+    };
+    let events = events
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events with specified constraints")?;
+
+    let batch_length = i64::try_from(events.len()).unwrap_or(i64::MAX);
+    let next_offset = (batch_length >= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT)
+        .then(|| tracking_data.offset.saturating_add(batch_length));
+
+    let outcomes = futures::stream::iter(
+        events
+            .into_iter()
+            .filter(|event| !event.is_webhook_notified),
+    )
+    .map(|event| redeliver_undelivered_event(state, &key_store, &business_profiles, event))
+    .buffer_unordered(BULK_RETRY_MAX_CONCURRENT_DELIVERIES)
+    .collect::<Vec<_>>()
+    .await;
+
+    let count_outcomes = |expected: BulkRedeliveryOutcome| {
+        outcomes
+            .iter()
+            .filter(|outcome| **outcome == expected)
+            .count()
+    };
+    logger::info!(
+        merchant_id = %tracking_data.merchant_id,
+        business_profile_id = ?tracking_data.business_profile_id,
+        offset = tracking_data.offset,
+        batch_length,
+        delivered_count = count_outcomes(BulkRedeliveryOutcome::Delivered),
+        failed_count = count_outcomes(BulkRedeliveryOutcome::Failed),
+        "Redelivered batch of undelivered events"
+    );
+
+    Ok(next_offset)
+}
+
+async fn redeliver_undelivered_event(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    business_profiles: &[storage::BusinessProfile],
+    event: domain::Event,
+) -> BulkRedeliveryOutcome {
+    let event_id = event.event_id.clone();
+
+    // Skip events which were delivered by a later delivery attempt
+    let delivery_attempts = match state
+        .store
+        .list_events_by_merchant_id_initial_attempt_id(
+            &key_store.merchant_id,
+            &event.event_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(delivery_attempts) => delivery_attempts,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                %event_id,
+                "Failed to list delivery attempts for initial event"
+            );
+            return BulkRedeliveryOutcome::Failed;
+        }
+    };
+    if delivery_attempts
+        .iter()
+        .any(|delivery_attempt| delivery_attempt.is_webhook_notified)
+    {
+        return BulkRedeliveryOutcome::AlreadyDelivered;
+    }
+
+    // Events of business profiles with a disabled webhook endpoint are not redelivered
+    let Some(business_profile) = event
+        .business_profile_id
+        .as_ref()
+        .and_then(|profile_id| {
+            business_profiles
+                .iter()
+                .find(|business_profile| &business_profile.profile_id == profile_id)
+        })
+        .filter(|business_profile| business_profile.webhook_endpoint_disabled_at.is_none())
+    else {
+        logger::debug!(%event_id, "Webhook endpoint is disabled; skipping redelivery of event");
+        return BulkRedeliveryOutcome::Failed;
+    };
+
+    match redeliver_event(state, business_profile.clone(), key_store, event).await {
+        Ok(updated_event) if updated_event.is_webhook_notified => BulkRedeliveryOutcome::Delivered,
+        Ok(_) => BulkRedeliveryOutcome::Failed,
+        Err(error) => {
+            logger::error!(?error, %event_id, "Failed to redeliver event");
+            BulkRedeliveryOutcome::Failed
+        }
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn get_delivery_stats(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    constraints: api::webhook_events::WebhookDeliveryStatsConstraints,
+) -> RouterResponse<Vec<api::webhook_events::WebhookEndpointDeliveryStats>> {
+    let store = state.store.as_ref();
+
+    let (account, _key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let created_before = constraints
+        .created_before
+        .unwrap_or_else(common_utils::date_time::now);
+    let created_after = constraints
+        .created_after
+        .unwrap_or_else(|| created_before.saturating_sub(time::Duration::days(1)));
+
+    let business_profiles = match account {
+        MerchantAccountOrBusinessProfile::MerchantAccount(merchant_account) => store
+            .list_business_profile_by_merchant_id(&merchant_account.merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list business profiles of merchant")?,
+        MerchantAccountOrBusinessProfile::BusinessProfile(business_profile) => {
+            vec![business_profile]
+        }
+    };
+
+    let mut delivery_stats = Vec::with_capacity(business_profiles.len());
+    for business_profile in business_profiles {
+        let total_attempts = store
+            .get_delivery_attempt_count_by_profile_id_constraints(
+                &business_profile.profile_id,
+                created_after,
+                created_before,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get count of delivery attempts")?;
+        let successful_attempts = store
+            .get_delivery_attempt_count_by_profile_id_constraints(
+                &business_profile.profile_id,
+                created_after,
+                created_before,
+                Some(true),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get count of successful delivery attempts")?;
+
+        delivery_stats.push(api::webhook_events::WebhookEndpointDeliveryStats {
+            profile_id: business_profile.profile_id,
+            total_attempts,
+            successful_attempts,
+            failed_attempts: total_attempts.saturating_sub(successful_attempts),
+            failing_since: business_profile.webhook_endpoint_failing_since,
+            disabled_at: business_profile.webhook_endpoint_disabled_at,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(delivery_stats))
+}
+
+/// Sends a manual retry of the event to the webhook endpoint of the business profile, returning
+/// the new delivery attempt.
+async fn redeliver_event(
+    state: &AppState,
+    business_profile: storage::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    event_to_retry: domain::Event,
+) -> errors::RouterResult<domain::Event> {
+    let store = state.store.as_ref();
+
     let delivery_attempt = storage::enums::WebhookDeliveryAttempt::ManualRetry;
     let new_event_id = super::utils::generate_event_id();
     let idempotent_event_id = super::utils::get_idempotent_event_id(
@@ -215,7 +549,7 @@ pub async fn retry_delivery_attempt(
     };
 
     let event = store
-        .insert_event(new_event, &key_store)
+        .insert_event(new_event, key_store)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert event")?;
@@ -234,7 +568,7 @@ pub async fn retry_delivery_attempt(
     super::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
-        &key_store,
+        key_store,
         event,
         request_content,
         delivery_attempt,
@@ -243,14 +577,10 @@ pub async fn retry_delivery_attempt(
     )
     .await;
 
-    let updated_event = store
-        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &new_event_id, &key_store)
+    store
+        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &new_event_id, key_store)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
-
-    Ok(ApplicationResponse::Json(
-        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
-    ))
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
 }
 
 async fn determine_identifier_and_get_key_store(
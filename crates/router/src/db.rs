@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_alert;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -9,6 +10,7 @@ pub mod business_profile;
 pub mod cache;
 pub mod capture;
 pub mod cards_info;
+pub mod config_audit_log;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
@@ -22,13 +24,17 @@ pub mod health_check;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
+pub mod master_key_rotation;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
+pub mod oidc_sso_config;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
 pub mod refund;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
@@ -77,8 +83,10 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + analytics_alert::AnalyticsAlertInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
+    + config_audit_log::ConfigAuditLogInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
@@ -104,13 +112,17 @@ pub trait StorageInterface:
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
+    + merchant_key_rotation::MerchantKeyRotationInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
+    + master_key_rotation::MasterKeyRotationInterface
     + payment_link::PaymentLinkInterface
+    + reusable_payment_link::ReusablePaymentLinkInterface
     + RedisConnInterface
     + RequestIdStore
     + business_profile::BusinessProfileInterface
     + OrganizationInterface
+    + oidc_sso_config::OidcSsoConfigInterface
     + routing_algorithm::RoutingAlgorithmInterface
     + gsm::GsmInterface
     + user::UserInterface
@@ -128,12 +140,21 @@ pub trait StorageInterface:
 
 pub trait MasterKeyInterface {
     fn get_master_key(&self) -> &[u8];
+
+    /// The master key replaced by a master key rotation, used to read key stores that have not
+    /// been re-wrapped with the current master key yet
+    fn get_previous_master_key(&self) -> Option<&[u8]>;
 }
 
 impl MasterKeyInterface for Store {
     fn get_master_key(&self) -> &[u8] {
         self.master_key().peek()
     }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        self.previous_master_key()
+            .map(|previous_master_key| previous_master_key.peek().as_slice())
+    }
 }
 
 /// Default dummy key for MockDb
@@ -144,6 +165,10 @@ impl MasterKeyInterface for MockDb {
             25, 26, 27, 28, 29, 30, 31, 32,
         ]
     }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 #[async_trait::async_trait]
@@ -238,6 +263,17 @@ impl FraudCheckInterface for KafkaStore {
             .find_fraud_check_by_payment_id_if_present(payment_id, merchant_id)
             .await
     }
+    async fn list_pending_fraud_check_reviews(
+        &self,
+        merchant_id: &str,
+        assigned_to: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, StorageError> {
+        self.diesel_store
+            .list_pending_fraud_check_reviews(merchant_id, assigned_to, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -106,7 +106,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -133,7 +133,7 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
             .async_and_then(|address| async {
                 address
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -153,7 +153,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -179,7 +179,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -204,7 +204,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -227,7 +227,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -256,7 +256,7 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -307,7 +307,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -361,7 +361,7 @@ mod storage {
                 }
             }?;
             address
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -379,7 +379,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -419,7 +419,7 @@ mod storage {
                         .map_err(|error| report!(errors::StorageError::from(error)))
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert_with_key_store(key_store)
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -456,7 +456,7 @@ mod storage {
                     .change_context(errors::StorageError::KVError)?;
 
                     updated_address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }
@@ -492,7 +492,7 @@ mod storage {
                         .map_err(|error| report!(errors::StorageError::from(error)))
                         .async_and_then(|address| async {
                             address
-                                .convert(key_store.key.get_inner())
+                                .convert_with_key_store(key_store)
                                 .await
                                 .change_context(errors::StorageError::DecryptionError)
                         })
@@ -552,7 +552,7 @@ mod storage {
                         }
                         .into()),
                         Ok(HsetnxReply::KeySet) => Ok(created_address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?),
                         Err(er) => Err(er).change_context(errors::StorageError::KVError),
@@ -577,7 +577,7 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|address| async {
                     address
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -606,7 +606,7 @@ mod storage {
                 for address in addresses.into_iter() {
                     output.push(
                         address
-                            .convert(key_store.key.get_inner())
+                            .convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -634,7 +634,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -662,7 +662,7 @@ impl AddressInterface for MockDb {
         {
             Some(address) => address
                 .clone()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => {
@@ -693,7 +693,7 @@ impl AddressInterface for MockDb {
             });
         match updated_addr {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -725,7 +725,7 @@ impl AddressInterface for MockDb {
             });
         match updated_addr {
             Some(address_updated) => address_updated
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -751,7 +751,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -770,7 +770,7 @@ impl AddressInterface for MockDb {
         addresses.push(address.clone());
 
         address
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -800,7 +800,7 @@ impl AddressInterface for MockDb {
         match updated_addr {
             Some(address) => {
                 let address: domain::Address = address
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?;
                 Ok(vec![address])
@@ -0,0 +1,391 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsAlertInterface {
+    async fn insert_analytics_alert_rule(
+        &self,
+        rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError>;
+
+    async fn update_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+        rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn insert_analytics_alert(
+        &self,
+        alert: storage::AnalyticsAlertNew,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError>;
+
+    async fn find_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError>;
+
+    async fn update_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        alert_update: storage::AnalyticsAlertUpdate,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_analytics_alerts_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        rule_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AnalyticsAlert>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_rule(
+        &self,
+        rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        rule.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::find_by_merchant_id_rule_id(&conn, merchant_id, rule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+        rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertRule::update_by_merchant_id_rule_id(
+            &conn,
+            merchant_id,
+            rule_id,
+            rule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertRule::delete_by_merchant_id_rule_id(&conn, merchant_id, rule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert(
+        &self,
+        alert: storage::AnalyticsAlertNew,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        alert
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlert::find_by_merchant_id_alert_id(&conn, merchant_id, alert_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        alert_update: storage::AnalyticsAlertUpdate,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlert::update_by_merchant_id_alert_id(
+            &conn,
+            merchant_id,
+            alert_id,
+            alert_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alerts_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        rule_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AnalyticsAlert>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlert::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            rule_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for MockDb {
+    async fn insert_analytics_alert_rule(
+        &self,
+        _rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        _merchant_id: &str,
+        _rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        _merchant_id: &str,
+        _rule_id: &str,
+        _rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        _merchant_id: &str,
+        _rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_analytics_alert(
+        &self,
+        _alert: storage::AnalyticsAlertNew,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        _merchant_id: &str,
+        _alert_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        _merchant_id: &str,
+        _alert_id: &str,
+        _alert_update: storage::AnalyticsAlertUpdate,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_alerts_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &str,
+        _rule_id: Option<String>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AnalyticsAlert>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_rule(
+        &self,
+        rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store.insert_analytics_alert_rule(rule).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_alert_rules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+        rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id, rule_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &str,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert(
+        &self,
+        alert: storage::AnalyticsAlertNew,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        self.diesel_store.insert_analytics_alert(alert).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_alert_by_merchant_id_alert_id(merchant_id, alert_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        alert_update: storage::AnalyticsAlertUpdate,
+    ) -> CustomResult<storage::AnalyticsAlert, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_alert_by_merchant_id_alert_id(merchant_id, alert_id, alert_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alerts_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        rule_id: Option<String>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AnalyticsAlert>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_alerts_by_merchant_id_constraints(
+                merchant_id,
+                rule_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
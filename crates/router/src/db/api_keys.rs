@@ -233,6 +233,8 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            permission_groups: api_key.permission_groups,
+            allowed_ips: api_key.allowed_ips,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +260,8 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
+                allowed_ips,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +276,12 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(permission_groups) = permission_groups {
+                    key_to_update.permission_groups = permission_groups;
+                }
+                if let Some(allowed_ips) = allowed_ips {
+                    key_to_update.allowed_ips = allowed_ips;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -405,6 +415,8 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                permission_groups: None,
+                allowed_ips: None,
             })
             .await
             .unwrap();
@@ -420,6 +432,8 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                permission_groups: None,
+                allowed_ips: None,
             })
             .await
             .unwrap();
@@ -497,6 +511,8 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            permission_groups: None,
+            allowed_ips: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -6,7 +6,7 @@ use crate::{
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::storage::cards_info::CardInfo,
+    types::storage::cards_info::{CardInfo, UpdateCardInfo},
 };
 
 #[async_trait::async_trait]
@@ -15,6 +15,19 @@ pub trait CardsInfoInterface {
         &self,
         _card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError>;
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError>;
+
+    async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, errors::StorageError>;
+
+    async fn update_card_info(
+        &self,
+        card_iin: String,
+        data: UpdateCardInfo,
+    ) -> CustomResult<CardInfo, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -29,6 +42,37 @@ impl CardsInfoInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        CardInfo::find_by_iins(&conn, card_iins)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_card_info(
+        &self,
+        card_iin: String,
+        data: UpdateCardInfo,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        CardInfo::update_by_iin(&conn, card_iin, data)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -46,4 +90,71 @@ impl CardsInfoInterface for MockDb {
             .find(|ci| ci.card_iin == card_iin)
             .cloned())
     }
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<CardInfo>, errors::StorageError> {
+        Ok(self
+            .cards_info
+            .lock()
+            .await
+            .iter()
+            .filter(|ci| card_iins.contains(&ci.card_iin))
+            .cloned()
+            .collect())
+    }
+
+    async fn add_card_info(&self, data: CardInfo) -> CustomResult<CardInfo, errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        if cards_info.iter().any(|ci| ci.card_iin == data.card_iin) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "card_iin",
+                key: Some(data.card_iin.clone()),
+            })?
+        }
+        cards_info.push(data.clone());
+        Ok(data)
+    }
+
+    async fn update_card_info(
+        &self,
+        card_iin: String,
+        data: UpdateCardInfo,
+    ) -> CustomResult<CardInfo, errors::StorageError> {
+        let mut cards_info = self.cards_info.lock().await;
+        let card_info = cards_info
+            .iter_mut()
+            .find(|ci| ci.card_iin == card_iin)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "Card info not found for card_iin = {card_iin}"
+            )))?;
+        let UpdateCardInfo {
+            card_issuer,
+            card_network,
+            card_type,
+            card_subtype,
+            card_issuing_country,
+            bank_code_id,
+            bank_code,
+            country_code,
+            last_updated,
+            last_updated_provider,
+        } = data;
+        *card_info = CardInfo {
+            card_issuer: card_issuer.or(card_info.card_issuer.take()),
+            card_network: card_network.or(card_info.card_network.take()),
+            card_type: card_type.or(card_info.card_type.take()),
+            card_subtype: card_subtype.or(card_info.card_subtype.take()),
+            card_issuing_country: card_issuing_country.or(card_info.card_issuing_country.take()),
+            bank_code_id: bank_code_id.or(card_info.bank_code_id.take()),
+            bank_code: bank_code.or(card_info.bank_code.take()),
+            country_code: country_code.or(card_info.country_code.take()),
+            last_updated: last_updated.or(card_info.last_updated),
+            last_updated_provider: last_updated_provider.or(card_info.last_updated_provider.take()),
+            card_iin: card_info.card_iin.clone(),
+            date_created: card_info.date_created,
+        };
+        Ok(card_info.clone())
+    }
 }
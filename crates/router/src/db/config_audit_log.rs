@@ -0,0 +1,124 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ConfigAuditLogInterface {
+    async fn insert_config_audit_log(
+        &self,
+        config_audit_log: storage::ConfigAuditLogNew,
+    ) -> CustomResult<storage::ConfigAuditLog, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_config_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: Option<&str>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConfigAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConfigAuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_config_audit_log(
+        &self,
+        config_audit_log: storage::ConfigAuditLogNew,
+    ) -> CustomResult<storage::ConfigAuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        config_audit_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_config_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: Option<&str>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConfigAuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConfigAuditLog::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            created_after,
+            created_before,
+            resource_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConfigAuditLogInterface for MockDb {
+    async fn insert_config_audit_log(
+        &self,
+        _config_audit_log: storage::ConfigAuditLogNew,
+    ) -> CustomResult<storage::ConfigAuditLog, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_config_audit_logs_by_merchant_id_constraints(
+        &self,
+        _merchant_id: Option<&str>,
+        _created_after: Option<time::PrimitiveDateTime>,
+        _created_before: Option<time::PrimitiveDateTime>,
+        _resource_type: Option<enums::AuditResourceType>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConfigAuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ConfigAuditLogInterface for KafkaStore {
+    async fn insert_config_audit_log(
+        &self,
+        config_audit_log: storage::ConfigAuditLogNew,
+    ) -> CustomResult<storage::ConfigAuditLog, errors::StorageError> {
+        self.diesel_store
+            .insert_config_audit_log(config_audit_log)
+            .await
+    }
+
+    async fn list_config_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: Option<&str>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        resource_type: Option<enums::AuditResourceType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ConfigAuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_config_audit_logs_by_merchant_id_constraints(
+                merchant_id,
+                created_after,
+                created_before,
+                resource_type,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
@@ -149,7 +149,7 @@ mod storage {
 
             let maybe_result = maybe_customer
                 .async_map(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -236,7 +236,7 @@ mod storage {
             };
 
             updated_object?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -287,7 +287,7 @@ mod storage {
             }?;
 
             let result: domain::Customer = customer
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             //.await
@@ -316,7 +316,7 @@ mod storage {
             let customers = try_join_all(encrypted_customers.into_iter().map(
                 |encrypted_customer| async {
                     encrypted_customer
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 },
@@ -394,7 +394,7 @@ mod storage {
             }?;
 
             create_customer
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -462,7 +462,7 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))?
                 .async_map(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -500,7 +500,7 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
             .async_and_then(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -525,7 +525,7 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -554,7 +554,7 @@ mod storage {
             let customers = try_join_all(encrypted_customers.into_iter().map(
                 |encrypted_customer| async {
                     encrypted_customer
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 },
@@ -580,7 +580,7 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|c| async {
-                    c.convert(key_store.key.get_inner())
+                    c.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -624,7 +624,7 @@ impl CustomerInterface for MockDb {
             .cloned();
         customer
             .async_map(|c| async {
-                c.convert(key_store.key.get_inner())
+                c.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -646,7 +646,7 @@ impl CustomerInterface for MockDb {
                 .map(|customer| async {
                     customer
                         .to_owned()
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }),
@@ -697,7 +697,7 @@ impl CustomerInterface for MockDb {
         customers.push(customer.clone());
 
         customer
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
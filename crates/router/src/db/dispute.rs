@@ -45,6 +45,14 @@ pub trait DisputeInterface {
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
     ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_open_disputes_by_merchant_id_challenge_deadline(
+        &self,
+        merchant_id: &str,
+        deadline_after: time::PrimitiveDateTime,
+        deadline_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -126,6 +134,26 @@ impl DisputeInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_open_disputes_by_merchant_id_challenge_deadline(
+        &self,
+        merchant_id: &str,
+        deadline_after: time::PrimitiveDateTime,
+        deadline_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::filter_open_by_challenge_deadline(
+            &conn,
+            merchant_id,
+            deadline_after,
+            deadline_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -359,6 +387,39 @@ impl DisputeInterface for MockDb {
 
         Ok(dispute_to_update.clone())
     }
+
+    async fn find_open_disputes_by_merchant_id_challenge_deadline(
+        &self,
+        merchant_id: &str,
+        deadline_after: time::PrimitiveDateTime,
+        deadline_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        let mut disputes: Vec<storage::Dispute> = locked_disputes
+            .iter()
+            .filter(|d| {
+                d.merchant_id == merchant_id
+                    && d.dispute_stage == storage::enums::DisputeStage::Dispute
+                    && d.dispute_status == storage::enums::DisputeStatus::DisputeOpened
+                    && d.challenge_required_by.is_some_and(|deadline| {
+                        deadline > deadline_after && deadline <= deadline_before
+                    })
+            })
+            .cloned()
+            .collect();
+        disputes.sort_by_key(|d| d.challenge_required_by);
+
+        Ok(disputes
+            .into_iter()
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -614,6 +675,82 @@ mod tests {
             assert_eq!(created_dispute, found_disputes.first().unwrap().clone());
         }
 
+        #[tokio::test]
+        async fn test_find_open_disputes_by_merchant_id_challenge_deadline() {
+            #[allow(clippy::expect_used)]
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create Mock store");
+
+            let later_dispute = mockdb
+                .insert_dispute(DisputeNew {
+                    challenge_required_by: Some(datetime!(2019-01-05 0:00)),
+                    ..create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_1".into(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    })
+                })
+                .await
+                .unwrap();
+
+            let earlier_dispute = mockdb
+                .insert_dispute(DisputeNew {
+                    challenge_required_by: Some(datetime!(2019-01-03 0:00)),
+                    ..create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_2".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_2".into(),
+                        connector_dispute_id: "connector_dispute_2".into(),
+                    })
+                })
+                .await
+                .unwrap();
+
+            // Deadline already passed
+            let _ = mockdb
+                .insert_dispute(create_dispute_new(DisputeNewIds {
+                    dispute_id: "dispute_3".into(),
+                    attempt_id: "attempt_3".into(),
+                    merchant_id: "merchant_1".into(),
+                    payment_id: "payment_3".into(),
+                    connector_dispute_id: "connector_dispute_3".into(),
+                }))
+                .await
+                .unwrap();
+
+            // Dispute no longer open
+            let _ = mockdb
+                .insert_dispute(DisputeNew {
+                    dispute_status: DisputeStatus::DisputeChallenged,
+                    challenge_required_by: Some(datetime!(2019-01-04 0:00)),
+                    ..create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_4".into(),
+                        attempt_id: "attempt_4".into(),
+                        merchant_id: "merchant_1".into(),
+                        payment_id: "payment_4".into(),
+                        connector_dispute_id: "connector_dispute_4".into(),
+                    })
+                })
+                .await
+                .unwrap();
+
+            let found_disputes = mockdb
+                .find_open_disputes_by_merchant_id_challenge_deadline(
+                    "merchant_1",
+                    datetime!(2019-01-02 0:00),
+                    datetime!(2019-01-09 0:00),
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(vec![earlier_dispute, later_dispute], found_disputes);
+        }
+
         mod update_dispute {
             use diesel_models::{
                 dispute::DisputeUpdate,
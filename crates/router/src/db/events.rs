@@ -82,6 +82,14 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    async fn get_delivery_attempt_count_by_profile_id_constraints(
+        &self,
+        profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        is_webhook_notified: Option<bool>,
+    ) -> CustomResult<i64, errors::StorageError>;
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -107,7 +115,7 @@ impl EventInterface for Store {
             .insert(&conn)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -123,7 +131,7 @@ impl EventInterface for Store {
         storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -148,7 +156,7 @@ impl EventInterface for Store {
             for event in events.into_iter() {
                 domain_events.push(
                     event
-                        .convert(merchant_key_store.key.get_inner())
+                        .convert_with_key_store(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 );
@@ -184,7 +192,7 @@ impl EventInterface for Store {
             for event in events.into_iter() {
                 domain_events.push(
                     event
-                        .convert(merchant_key_store.key.get_inner())
+                        .convert_with_key_store(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 );
@@ -214,7 +222,7 @@ impl EventInterface for Store {
             for event in events.into_iter() {
                 domain_events.push(
                     event
-                        .convert(merchant_key_store.key.get_inner())
+                        .convert_with_key_store(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 );
@@ -244,7 +252,7 @@ impl EventInterface for Store {
             for event in events.into_iter() {
                 domain_events.push(
                     event
-                        .convert(merchant_key_store.key.get_inner())
+                        .convert_with_key_store(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 );
@@ -280,7 +288,7 @@ impl EventInterface for Store {
             for event in events.into_iter() {
                 domain_events.push(
                     event
-                        .convert(merchant_key_store.key.get_inner())
+                        .convert_with_key_store(merchant_key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 );
@@ -306,7 +314,7 @@ impl EventInterface for Store {
                 for event in events.into_iter() {
                     domain_events.push(
                         event
-                            .convert(merchant_key_store.key.get_inner())
+                            .convert_with_key_store(merchant_key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     );
@@ -316,6 +324,26 @@ impl EventInterface for Store {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn get_delivery_attempt_count_by_profile_id_constraints(
+        &self,
+        profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        is_webhook_notified: Option<bool>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::get_delivery_attempt_count_by_profile_id_constraints(
+            &conn,
+            profile_id,
+            created_after,
+            created_before,
+            is_webhook_notified,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_event_by_merchant_id_event_id(
         &self,
@@ -328,7 +356,7 @@ impl EventInterface for Store {
         storage::Event::update_by_merchant_id_event_id(&conn, merchant_id, event_id, event.into())
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -350,7 +378,7 @@ impl EventInterface for MockDb {
         locked_events.push(stored_event.clone());
 
         stored_event
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -370,7 +398,7 @@ impl EventInterface for MockDb {
             .cloned()
             .async_map(|event| async {
                 event
-                    .convert(merchant_key_store.key.get_inner())
+                    .convert_with_key_store(merchant_key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -405,7 +433,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -470,7 +498,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -498,7 +526,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -528,7 +556,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -593,7 +621,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -621,7 +649,7 @@ impl EventInterface for MockDb {
 
         for event in events {
             let domain_event = event
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?;
             domain_events.push(domain_event);
@@ -630,6 +658,29 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    async fn get_delivery_attempt_count_by_profile_id_constraints(
+        &self,
+        profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        is_webhook_notified: Option<bool>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let count = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id.as_deref() == Some(profile_id)
+                    && event.created_at >= created_after
+                    && event.created_at <= created_before
+                    && is_webhook_notified.map_or(true, |is_webhook_notified| {
+                        event.is_webhook_notified == is_webhook_notified
+                    })
+            })
+            .count();
+
+        i64::try_from(count).map_err(|_| report!(errors::StorageError::MockDbError))
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -657,7 +708,7 @@ impl EventInterface for MockDb {
 
         event_to_update
             .clone()
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -700,6 +751,9 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
+                    previous_key_version: None,
                 },
                 &master_key.to_vec().into(),
             )
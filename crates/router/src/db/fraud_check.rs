@@ -33,6 +33,14 @@ pub trait FraudCheckInterface {
         payment_id: String,
         merchant_id: String,
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError>;
+
+    async fn list_pending_fraud_check_reviews(
+        &self,
+        merchant_id: &str,
+        assigned_to: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -83,6 +91,20 @@ impl FraudCheckInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn list_pending_fraud_check_reviews(
+        &self,
+        merchant_id: &str,
+        assigned_to: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        FraudCheck::filter_pending_reviews(&conn, merchant_id, assigned_to, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,6 +137,16 @@ impl FraudCheckInterface for MockDb {
     ) -> CustomResult<Option<FraudCheck>, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_pending_fraud_check_reviews(
+        &self,
+        _merchant_id: &str,
+        _assigned_to: Option<String>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<FraudCheck>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[cfg(feature = "kafka_events")]
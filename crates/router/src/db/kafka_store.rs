@@ -253,6 +253,28 @@ impl CardsInfoInterface for KafkaStore {
     ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
         self.diesel_store.get_card_info(card_iin).await
     }
+
+    async fn find_card_info_by_iins(
+        &self,
+        card_iins: Vec<String>,
+    ) -> CustomResult<Vec<storage::CardInfo>, errors::StorageError> {
+        self.diesel_store.find_card_info_by_iins(card_iins).await
+    }
+
+    async fn add_card_info(
+        &self,
+        data: storage::CardInfo,
+    ) -> CustomResult<storage::CardInfo, errors::StorageError> {
+        self.diesel_store.add_card_info(data).await
+    }
+
+    async fn update_card_info(
+        &self,
+        card_iin: String,
+        data: storage::UpdateCardInfo,
+    ) -> CustomResult<storage::CardInfo, errors::StorageError> {
+        self.diesel_store.update_card_info(card_iin, data).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -488,6 +510,23 @@ impl DisputeInterface for KafkaStore {
             .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)
             .await
     }
+
+    async fn find_open_disputes_by_merchant_id_challenge_deadline(
+        &self,
+        merchant_id: &str,
+        deadline_after: time::PrimitiveDateTime,
+        deadline_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_open_disputes_by_merchant_id_challenge_deadline(
+                merchant_id,
+                deadline_after,
+                deadline_before,
+                limit,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -638,6 +677,23 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn get_delivery_attempt_count_by_profile_id_constraints(
+        &self,
+        profile_id: &str,
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        is_webhook_notified: Option<bool>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_delivery_attempt_count_by_profile_id_constraints(
+                profile_id,
+                created_after,
+                created_before,
+                is_webhook_notified,
+            )
+            .await
+    }
+
     async fn update_event_by_merchant_id_event_id(
         &self,
         merchant_id: &str,
@@ -782,6 +838,33 @@ impl PaymentLinkInterface for KafkaStore {
             .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
             .await
     }
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_payment_link_by_payment_link_id(payment_link_id, payment_link_update)
+            .await
+    }
+
+    async fn list_payment_links_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_links_by_merchant_id_reusable_payment_link_id(
+                merchant_id,
+                reusable_payment_link_id,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1297,6 +1380,25 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    async fn find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+        &self,
+        merchant_id: &str,
+        expires_after: time::PrimitiveDateTime,
+        expires_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+                merchant_id,
+                expires_after,
+                expires_before,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
         merchant_id: &str,
@@ -1506,6 +1608,32 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_network_token_reference_id(network_token_reference_id)
+            .await
+    }
+
+    async fn find_payment_method_by_merchant_id_payment_method(
+        &self,
+        merchant_id: &str,
+        payment_method: common_enums::PaymentMethod,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_merchant_id_payment_method(
+                merchant_id,
+                payment_method,
+                limit,
+                offset,
+            )
+            .await
+    }
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -2032,6 +2160,17 @@ impl MerchantKeyStoreInterface for KafkaStore {
             .await
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store_by_merchant_id(merchant_id, key_store_update, key)
+            .await
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -2294,6 +2433,10 @@ impl MasterKeyInterface for KafkaStore {
     fn get_master_key(&self) -> &[u8] {
         self.diesel_store.get_master_key()
     }
+
+    fn get_previous_master_key(&self) -> Option<&[u8]> {
+        self.diesel_store.get_previous_master_key()
+    }
 }
 #[async_trait::async_trait]
 impl UserInterface for KafkaStore {
@@ -0,0 +1,247 @@
+use diesel_models::user_key_store::UserKeyStore;
+use error_stack::report;
+use router_env::{instrument, tracing};
+#[cfg(feature = "accounts_cache")]
+use storage_impl::redis::cache::CacheKind;
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MasterKeyRotationInterface {
+    async fn list_merchant_key_stores_after_merchant_id(
+        &self,
+        last_merchant_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError>;
+
+    async fn update_merchant_key_store_master_key_wrapping(
+        &self,
+        merchant_id: &str,
+        key: storage::EncryptedColumn,
+        previous_key: Option<storage::EncryptedColumn>,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_user_key_stores_after_user_id(
+        &self,
+        last_user_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<UserKeyStore>, errors::StorageError>;
+
+    async fn update_user_key_store_master_key_wrapping(
+        &self,
+        user_id: &str,
+        key: storage::EncryptedColumn,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MasterKeyRotationInterface for Store {
+    #[instrument(skip_all)]
+    async fn list_merchant_key_stores_after_merchant_id(
+        &self,
+        last_merchant_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantKeyStore::list_key_stores_after_merchant_id(&conn, last_merchant_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store_master_key_wrapping(
+        &self,
+        merchant_id: &str,
+        key: storage::EncryptedColumn,
+        previous_key: Option<storage::EncryptedColumn>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let is_updated = storage::MerchantKeyStore::update_master_key_wrapping_by_merchant_id(
+            &conn,
+            merchant_id,
+            key,
+            previous_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        // Cached key stores hold the keys wrapped with the previous master key, which are not
+        // readable once the previous master key is removed from the configuration
+        #[cfg(feature = "accounts_cache")]
+        if is_updated {
+            super::cache::publish_into_redact_channel(
+                self,
+                [CacheKind::Accounts(
+                    format!("merchant_key_store_{}", merchant_id).into(),
+                )],
+            )
+            .await?;
+        }
+
+        Ok(is_updated)
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_key_stores_after_user_id(
+        &self,
+        last_user_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<UserKeyStore>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        UserKeyStore::list_key_stores_after_user_id(&conn, last_user_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_key_store_master_key_wrapping(
+        &self,
+        user_id: &str,
+        key: storage::EncryptedColumn,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        UserKeyStore::update_master_key_wrapping_by_user_id(&conn, user_id, key)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl MasterKeyRotationInterface for MockDb {
+    async fn list_merchant_key_stores_after_merchant_id(
+        &self,
+        last_merchant_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError> {
+        let last_merchant_id = last_merchant_id.unwrap_or_default();
+        let mut merchant_key_stores: Vec<_> = self
+            .merchant_key_store
+            .lock()
+            .await
+            .iter()
+            .filter(|key_store| key_store.merchant_id > last_merchant_id)
+            .cloned()
+            .collect();
+        merchant_key_stores.sort_by(|a, b| a.merchant_id.cmp(&b.merchant_id));
+        merchant_key_stores.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(merchant_key_stores)
+    }
+
+    async fn update_merchant_key_store_master_key_wrapping(
+        &self,
+        merchant_id: &str,
+        key: storage::EncryptedColumn,
+        previous_key: Option<storage::EncryptedColumn>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let merchant_key_store = merchant_key_stores.iter_mut().find(|key_store| {
+            key_store.merchant_id == merchant_id
+                && key_store.key == key.original
+                && key_store.previous_key
+                    == previous_key
+                        .as_ref()
+                        .map(|previous_key| previous_key.original.clone())
+        });
+
+        Ok(match merchant_key_store {
+            Some(merchant_key_store) => {
+                merchant_key_store.key = key.updated;
+                merchant_key_store.previous_key =
+                    previous_key.map(|previous_key| previous_key.updated);
+                true
+            }
+            None => false,
+        })
+    }
+
+    async fn list_user_key_stores_after_user_id(
+        &self,
+        last_user_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<UserKeyStore>, errors::StorageError> {
+        let last_user_id = last_user_id.unwrap_or_default();
+        let mut user_key_stores: Vec<_> = self
+            .user_key_store
+            .lock()
+            .await
+            .iter()
+            .filter(|key_store| key_store.user_id > last_user_id)
+            .cloned()
+            .collect();
+        user_key_stores.sort_by(|a, b| a.user_id.cmp(&b.user_id));
+        user_key_stores.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(user_key_stores)
+    }
+
+    async fn update_user_key_store_master_key_wrapping(
+        &self,
+        user_id: &str,
+        key: storage::EncryptedColumn,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_key_stores = self.user_key_store.lock().await;
+        let user_key_store = user_key_stores
+            .iter_mut()
+            .find(|key_store| key_store.user_id == user_id && key_store.key == key.original);
+
+        Ok(match user_key_store {
+            Some(user_key_store) => {
+                user_key_store.key = key.updated;
+                true
+            }
+            None => false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl MasterKeyRotationInterface for KafkaStore {
+    async fn list_merchant_key_stores_after_merchant_id(
+        &self,
+        last_merchant_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::MerchantKeyStore>, errors::StorageError> {
+        self.diesel_store
+            .list_merchant_key_stores_after_merchant_id(last_merchant_id, limit)
+            .await
+    }
+
+    async fn update_merchant_key_store_master_key_wrapping(
+        &self,
+        merchant_id: &str,
+        key: storage::EncryptedColumn,
+        previous_key: Option<storage::EncryptedColumn>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_store_master_key_wrapping(merchant_id, key, previous_key)
+            .await
+    }
+
+    async fn list_user_key_stores_after_user_id(
+        &self,
+        last_user_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<UserKeyStore>, errors::StorageError> {
+        self.diesel_store
+            .list_user_key_stores_after_user_id(last_user_id, limit)
+            .await
+    }
+
+    async fn update_user_key_store_master_key_wrapping(
+        &self,
+        user_id: &str,
+        key: storage::EncryptedColumn,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_user_key_store_master_key_wrapping(user_id, key)
+            .await
+    }
+}
@@ -99,7 +99,7 @@ impl MerchantAccountInterface for Store {
             .insert(&conn)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -121,7 +121,7 @@ impl MerchantAccountInterface for Store {
         {
             fetch_func()
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -130,7 +130,7 @@ impl MerchantAccountInterface for Store {
         {
             super::cache::get_or_populate_in_memory(self, merchant_id, fetch_func, &ACCOUNTS_CACHE)
                 .await?
-                .convert(merchant_key_store.key.get_inner())
+                .convert_with_key_store(merchant_key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -157,7 +157,7 @@ impl MerchantAccountInterface for Store {
             publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
         }
         updated_merchant_account
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -183,7 +183,7 @@ impl MerchantAccountInterface for Store {
             publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
         }
         updated_merchant_account
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -226,7 +226,7 @@ impl MerchantAccountInterface for Store {
 
         Ok(authentication::AuthenticationData {
             merchant_account: merchant_account
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)?,
 
@@ -265,7 +265,7 @@ impl MerchantAccountInterface for Store {
                 .zip(merchant_key_stores.iter())
                 .map(|(merchant_account, key_store)| async {
                     merchant_account
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 }),
@@ -351,7 +351,7 @@ impl MerchantAccountInterface for Store {
                         )),
                     )?;
                     merchant_account
-                        .convert(key_store.key.get_inner())
+                        .convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 },
@@ -412,7 +412,7 @@ impl MerchantAccountInterface for MockDb {
         accounts.push(account.clone());
 
         account
-            .convert(merchant_key_store.key.get_inner())
+            .convert_with_key_store(merchant_key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -429,7 +429,7 @@ impl MerchantAccountInterface for MockDb {
             .find(|account| account.merchant_id == merchant_id)
             .cloned()
             .async_map(|a| async {
-                a.convert(merchant_key_store.key.get_inner())
+                a.convert_with_key_store(merchant_key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -504,7 +504,7 @@ impl MerchantAccountInterface for MockDb {
 }
 
 #[cfg(feature = "accounts_cache")]
-async fn publish_and_redact_merchant_account_cache(
+pub(super) async fn publish_and_redact_merchant_account_cache(
     store: &dyn super::StorageInterface,
     merchant_account: &storage::MerchantAccount,
 ) -> CustomResult<(), errors::StorageError> {
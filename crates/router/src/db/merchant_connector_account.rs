@@ -196,7 +196,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -211,7 +211,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -241,7 +241,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DeserializationFailed)
         }
@@ -256,7 +256,7 @@ impl MerchantConnectorAccountInterface for Store {
             )
             .await
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -283,7 +283,7 @@ impl MerchantConnectorAccountInterface for Store {
             let mut output = Vec::with_capacity(items.len());
             for item in items.into_iter() {
                 output.push(
-                    item.convert(key_store.key.get_inner())
+                    item.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)?,
                 )
@@ -315,7 +315,7 @@ impl MerchantConnectorAccountInterface for Store {
         {
             find_call()
                 .await?
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -329,7 +329,7 @@ impl MerchantConnectorAccountInterface for Store {
                 &cache::ACCOUNTS_CACHE,
             )
             .await?
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
         }
@@ -349,7 +349,7 @@ impl MerchantConnectorAccountInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
             .async_and_then(|item| async {
-                item.convert(key_store.key.get_inner())
+                item.convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -371,7 +371,7 @@ impl MerchantConnectorAccountInterface for Store {
                 let mut output = Vec::with_capacity(items.len());
                 for item in items.into_iter() {
                     output.push(
-                        item.convert(key_store.key.get_inner())
+                        item.convert_with_key_store(key_store)
                             .await
                             .change_context(errors::StorageError::DecryptionError)?,
                     )
@@ -408,7 +408,7 @@ impl MerchantConnectorAccountInterface for Store {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
                 .async_and_then(|item| async {
-                    item.convert(key_store.key.get_inner())
+                    item.convert_with_key_store(key_store)
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
@@ -510,7 +510,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -546,7 +546,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -574,7 +574,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         match maybe_mca {
             Some(mca) => mca
                 .to_owned()
-                .convert(key_store.key.get_inner())
+                .convert_with_key_store(key_store)
                 .await
                 .change_context(errors::StorageError::DecryptionError),
             None => Err(errors::StorageError::ValueNotFound(
@@ -602,7 +602,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             .cloned()
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -651,7 +651,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         };
         accounts.push(account.clone());
         account
-            .convert(key_store.key.get_inner())
+            .convert_with_key_store(key_store)
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -681,7 +681,7 @@ impl MerchantConnectorAccountInterface for MockDb {
         for account in accounts.into_iter() {
             output.push(
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)?,
             )
@@ -709,7 +709,7 @@ impl MerchantConnectorAccountInterface for MockDb {
             })
             .async_map(|account| async {
                 account
-                    .convert(key_store.key.get_inner())
+                    .convert_with_key_store(key_store)
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
@@ -807,6 +807,9 @@ mod merchant_connector_account_cache_tests {
                 .await
                 .unwrap(),
                 created_at: datetime!(2023-02-01 0:00),
+                key_version: 1,
+                previous_key: None,
+                previous_key_version: None,
             },
             &master_key.to_vec().into(),
         )
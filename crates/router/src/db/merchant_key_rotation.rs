@@ -0,0 +1,586 @@
+use diesel_models::{
+    encryption::Encryption, merchant_key_rotation::MerchantKeyRotationUpdateInternal,
+};
+use error_stack::report;
+use router_env::{instrument, tracing};
+#[cfg(feature = "accounts_cache")]
+use storage_impl::redis::cache::CacheKind;
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait MerchantKeyRotationInterface {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn find_latest_merchant_key_rotation_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError>;
+
+    async fn find_encrypted_records_by_merchant_id(
+        &self,
+        entity: enums::KeyRotationEntity,
+        merchant_id: &str,
+        last_processed_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRecord>, errors::StorageError>;
+
+    async fn update_encrypted_record(
+        &self,
+        merchant_id: &str,
+        record: storage::EncryptedRecord,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        rotation
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantKeyRotation::find_by_merchant_id_rotation_id(
+            &conn,
+            merchant_id,
+            rotation_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_merchant_key_rotation_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantKeyRotation::find_latest_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::MerchantKeyRotation::update_by_merchant_id_rotation_id(
+            &conn,
+            merchant_id,
+            rotation_id,
+            rotation_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_encrypted_records_by_merchant_id(
+        &self,
+        entity: enums::KeyRotationEntity,
+        merchant_id: &str,
+        last_processed_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EncryptedRecord::find_by_merchant_id(
+            &conn,
+            entity,
+            merchant_id,
+            last_processed_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_encrypted_record(
+        &self,
+        merchant_id: &str,
+        record: storage::EncryptedRecord,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        #[cfg(feature = "accounts_cache")]
+        let is_merchant_account =
+            matches!(record, storage::EncryptedRecord::MerchantAccount { .. });
+        #[cfg(feature = "accounts_cache")]
+        let merchant_connector_id = match &record {
+            storage::EncryptedRecord::MerchantConnectorAccount {
+                merchant_connector_id,
+                ..
+            } => Some(merchant_connector_id.clone()),
+            _ => None,
+        };
+
+        let is_updated = record
+            .update(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        // Merchant accounts and merchant connector accounts are cached along with their
+        // encrypted columns, which must not outlive the key they were encrypted with
+        #[cfg(feature = "accounts_cache")]
+        if is_updated && is_merchant_account {
+            let merchant_account =
+                storage::MerchantAccount::find_by_merchant_id(&conn, merchant_id)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))?;
+            super::merchant_account::publish_and_redact_merchant_account_cache(
+                self,
+                &merchant_account,
+            )
+            .await?;
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        if let Some(merchant_connector_id) = merchant_connector_id.filter(|_| is_updated) {
+            let merchant_connector_account =
+                storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
+                    &conn,
+                    merchant_id,
+                    &merchant_connector_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let cache_keys = [
+                Some(format!("{}_{}", merchant_id, merchant_connector_id)),
+                merchant_connector_account
+                    .profile_id
+                    .as_ref()
+                    .map(|profile_id| {
+                        format!(
+                            "{}_{}",
+                            profile_id, merchant_connector_account.connector_name
+                        )
+                    }),
+                merchant_connector_account
+                    .connector_label
+                    .as_ref()
+                    .map(|connector_label| format!("{}_{}", merchant_id, connector_label)),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|cache_key| CacheKind::Accounts(cache_key.into()));
+
+            super::cache::publish_into_redact_channel(self, cache_keys).await?;
+        }
+
+        Ok(is_updated)
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for MockDb {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let rotation = storage::MerchantKeyRotation::from(rotation);
+        self.merchant_key_rotations
+            .lock()
+            .await
+            .push(rotation.clone());
+        Ok(rotation)
+    }
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.merchant_key_rotations
+            .lock()
+            .await
+            .iter()
+            .find(|rotation| {
+                rotation.merchant_id == merchant_id && rotation.rotation_id == rotation_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No key rotation found for merchant_id = {merchant_id} and rotation_id = {rotation_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_latest_merchant_key_rotation_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.merchant_key_rotations
+            .lock()
+            .await
+            .iter()
+            .filter(|rotation| rotation.merchant_id == merchant_id)
+            .max_by_key(|rotation| rotation.created_at)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No key rotation found for merchant_id = {merchant_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        let mut rotations = self.merchant_key_rotations.lock().await;
+        let rotation = rotations
+            .iter_mut()
+            .find(|rotation| {
+                rotation.merchant_id == merchant_id && rotation.rotation_id == rotation_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No key rotation found for merchant_id = {merchant_id} and rotation_id = {rotation_id}"
+            )))?;
+
+        *rotation = MerchantKeyRotationUpdateInternal::from(rotation_update)
+            .apply_changeset(rotation.clone());
+        Ok(rotation.clone())
+    }
+
+    async fn find_encrypted_records_by_merchant_id(
+        &self,
+        entity: enums::KeyRotationEntity,
+        merchant_id: &str,
+        last_processed_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRecord>, errors::StorageError> {
+        let mut records: Vec<storage::EncryptedRecord> = match entity {
+            enums::KeyRotationEntity::MerchantAccount => self
+                .merchant_accounts
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id == merchant_id)
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            enums::KeyRotationEntity::MerchantConnectorAccount => self
+                .merchant_connector_accounts
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id == merchant_id)
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            enums::KeyRotationEntity::Customer => self
+                .customers
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id == merchant_id)
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            enums::KeyRotationEntity::Address => self
+                .addresses
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id == merchant_id)
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            enums::KeyRotationEntity::PaymentMethod => self
+                .payment_methods
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id == merchant_id)
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            enums::KeyRotationEntity::Event => self
+                .events
+                .lock()
+                .await
+                .iter()
+                .filter(|record| record.merchant_id.as_deref() == Some(merchant_id))
+                .cloned()
+                .map(Into::into)
+                .collect(),
+        };
+
+        let last_processed_id = last_processed_id.unwrap_or_default();
+        records.retain(|record| record.record_id() > last_processed_id.as_str());
+        records.sort_by(|first, second| first.record_id().cmp(second.record_id()));
+        records.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(records)
+    }
+
+    async fn update_encrypted_record(
+        &self,
+        merchant_id: &str,
+        record: storage::EncryptedRecord,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let is_updated = match record {
+            storage::EncryptedRecord::MerchantAccount {
+                merchant_id: _,
+                merchant_name,
+                merchant_details,
+            } => self
+                .merchant_accounts
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| stored.merchant_id == merchant_id)
+                .is_some_and(|stored| {
+                    update_mock_columns(vec![
+                        (&mut stored.merchant_name, merchant_name),
+                        (&mut stored.merchant_details, merchant_details),
+                    ])
+                }),
+            storage::EncryptedRecord::MerchantConnectorAccount {
+                merchant_connector_id,
+                connector_account_details,
+            } => self
+                .merchant_connector_accounts
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| {
+                    stored.merchant_id == merchant_id
+                        && stored.merchant_connector_id == merchant_connector_id
+                })
+                .is_some_and(|stored| {
+                    let is_unmodified =
+                        stored.connector_account_details == connector_account_details.original;
+                    if is_unmodified {
+                        stored.connector_account_details = connector_account_details.updated;
+                    }
+                    is_unmodified
+                }),
+            storage::EncryptedRecord::Customer {
+                customer_id,
+                name,
+                email,
+                phone,
+            } => self
+                .customers
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| {
+                    stored.merchant_id == merchant_id && stored.customer_id == customer_id
+                })
+                .is_some_and(|stored| {
+                    update_mock_columns(vec![
+                        (&mut stored.name, name),
+                        (&mut stored.email, email),
+                        (&mut stored.phone, phone),
+                    ])
+                }),
+            storage::EncryptedRecord::Address {
+                address_id,
+                payment_id: _,
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                email,
+            } => self
+                .addresses
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| stored.merchant_id == merchant_id && stored.address_id == address_id)
+                .is_some_and(|stored| {
+                    update_mock_columns(vec![
+                        (&mut stored.line1, line1),
+                        (&mut stored.line2, line2),
+                        (&mut stored.line3, line3),
+                        (&mut stored.state, state),
+                        (&mut stored.zip, zip),
+                        (&mut stored.first_name, first_name),
+                        (&mut stored.last_name, last_name),
+                        (&mut stored.phone_number, phone_number),
+                        (&mut stored.email, email),
+                    ])
+                }),
+            storage::EncryptedRecord::PaymentMethod {
+                payment_method_id,
+                payment_method_data,
+                payment_method_billing_address,
+            } => self
+                .payment_methods
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| {
+                    stored.merchant_id == merchant_id
+                        && stored.payment_method_id == payment_method_id
+                })
+                .is_some_and(|stored| {
+                    update_mock_columns(vec![
+                        (&mut stored.payment_method_data, payment_method_data),
+                        (
+                            &mut stored.payment_method_billing_address,
+                            payment_method_billing_address,
+                        ),
+                    ])
+                }),
+            storage::EncryptedRecord::Event {
+                event_id,
+                request,
+                response,
+            } => self
+                .events
+                .lock()
+                .await
+                .iter_mut()
+                .find(|stored| {
+                    stored.merchant_id.as_deref() == Some(merchant_id)
+                        && stored.event_id == event_id
+                })
+                .is_some_and(|stored| {
+                    update_mock_columns(vec![
+                        (&mut stored.request, request),
+                        (&mut stored.response, response),
+                    ])
+                }),
+        };
+
+        Ok(is_updated)
+    }
+}
+
+/// Replaces the encrypted columns of a record held by the mock database, provided that none of
+/// them has been modified since the record was read
+fn update_mock_columns(
+    columns: Vec<(&mut Option<Encryption>, Option<storage::EncryptedColumn>)>,
+) -> bool {
+    let is_unmodified = columns
+        .iter()
+        .all(|(stored, column)| stored.as_ref() == column.as_ref().map(|column| &column.original));
+
+    if is_unmodified {
+        for (stored, column) in columns {
+            *stored = column.map(|column| column.updated);
+        }
+    }
+
+    is_unmodified
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyRotationInterface for KafkaStore {
+    async fn insert_merchant_key_rotation(
+        &self,
+        rotation: storage::MerchantKeyRotationNew,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .insert_merchant_key_rotation(rotation)
+            .await
+    }
+
+    async fn find_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(merchant_id, rotation_id)
+            .await
+    }
+
+    async fn find_latest_merchant_key_rotation_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .find_latest_merchant_key_rotation_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_merchant_key_rotation_by_merchant_id_rotation_id(
+        &self,
+        merchant_id: &str,
+        rotation_id: &str,
+        rotation_update: storage::MerchantKeyRotationUpdate,
+    ) -> CustomResult<storage::MerchantKeyRotation, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_key_rotation_by_merchant_id_rotation_id(
+                merchant_id,
+                rotation_id,
+                rotation_update,
+            )
+            .await
+    }
+
+    async fn find_encrypted_records_by_merchant_id(
+        &self,
+        entity: enums::KeyRotationEntity,
+        merchant_id: &str,
+        last_processed_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EncryptedRecord>, errors::StorageError> {
+        self.diesel_store
+            .find_encrypted_records_by_merchant_id(entity, merchant_id, last_processed_id, limit)
+            .await
+    }
+
+    async fn update_encrypted_record(
+        &self,
+        merchant_id: &str,
+        record: storage::EncryptedRecord,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_encrypted_record(merchant_id, record)
+            .await
+    }
+}
@@ -7,11 +7,14 @@ use storage_impl::redis::cache::{CacheKind, ACCOUNTS_CACHE};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    db::MockDb,
+    db::{MasterKeyInterface, MockDb},
     services::Store,
-    types::domain::{
-        self,
-        behaviour::{Conversion, ReverseConversion},
+    types::{
+        domain::{
+            self,
+            behaviour::{Conversion, ReverseConversion},
+        },
+        storage,
     },
 };
 
@@ -29,6 +32,13 @@ pub trait MerchantKeyStoreInterface {
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError>;
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -50,6 +60,9 @@ impl MerchantKeyStoreInterface for Store {
         merchant_key_store: domain::MerchantKeyStore,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
         let conn = connection::pg_connection_write(self).await?;
         merchant_key_store
             .construct_new()
@@ -58,7 +71,7 @@ impl MerchantKeyStoreInterface for Store {
             .insert(&conn)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(key)
+            .convert_with_previous_key(key, previous_master_key.as_ref())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -69,6 +82,9 @@ impl MerchantKeyStoreInterface for Store {
         merchant_id: &str,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
         let fetch_func = || async {
             let conn = connection::pg_connection_read(self).await?;
 
@@ -84,7 +100,7 @@ impl MerchantKeyStoreInterface for Store {
         {
             fetch_func()
                 .await?
-                .convert(key)
+                .convert_with_previous_key(key, previous_master_key.as_ref())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }
@@ -99,7 +115,52 @@ impl MerchantKeyStoreInterface for Store {
                 &ACCOUNTS_CACHE,
             )
             .await?
-            .convert(key)
+            .convert_with_previous_key(key, previous_master_key.as_ref())
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            diesel_models::merchant_key_store::MerchantKeyStore::update_by_merchant_id(
+                &conn,
+                merchant_id,
+                key_store_update,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_func()
+                .await?
+                .convert_with_previous_key(key, previous_master_key.as_ref())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            let key_store_cache_key = format!("merchant_key_store_{}", merchant_id);
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(key_store_cache_key.into()),
+                update_func,
+            )
+            .await?
+            .convert_with_previous_key(key, previous_master_key.as_ref())
             .await
             .change_context(errors::StorageError::DecryptionError)
         }
@@ -144,6 +205,9 @@ impl MerchantKeyStoreInterface for Store {
         merchant_ids: Vec<String>,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<Vec<domain::MerchantKeyStore>, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
         let fetch_func = || async {
             let conn = connection::pg_connection_read(self).await?;
 
@@ -157,7 +221,7 @@ impl MerchantKeyStoreInterface for Store {
 
         futures::future::try_join_all(fetch_func().await?.into_iter().map(|key_store| async {
             key_store
-                .convert(key)
+                .convert_with_previous_key(key, previous_master_key.as_ref())
                 .await
                 .change_context(errors::StorageError::DecryptionError)
         }))
@@ -214,6 +278,46 @@ impl MerchantKeyStoreInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn update_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        key_store_update: storage::MerchantKeyStoreUpdate,
+        key: &Secret<Vec<u8>>,
+    ) -> CustomResult<domain::MerchantKeyStore, errors::StorageError> {
+        let mut merchant_key_stores = self.merchant_key_store.lock().await;
+        let merchant_key_store = merchant_key_stores
+            .iter_mut()
+            .find(|merchant_key| merchant_key.merchant_id == merchant_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No merchant key store found for merchant_id = {}",
+                merchant_id
+            )))?;
+
+        match key_store_update {
+            storage::MerchantKeyStoreUpdate::KeyRotated {
+                key,
+                key_version,
+                previous_key,
+                previous_key_version,
+            } => {
+                merchant_key_store.key = key;
+                merchant_key_store.key_version = key_version;
+                merchant_key_store.previous_key = Some(previous_key);
+                merchant_key_store.previous_key_version = Some(previous_key_version);
+            }
+            storage::MerchantKeyStoreUpdate::PreviousKeyRetired => {
+                merchant_key_store.previous_key = None;
+                merchant_key_store.previous_key_version = None;
+            }
+        }
+
+        merchant_key_store
+            .clone()
+            .convert(key)
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     async fn delete_merchant_key_store_by_merchant_id(
         &self,
         merchant_id: &str,
@@ -284,6 +388,9 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
+                    previous_key_version: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -309,6 +416,9 @@ mod tests {
                     .await
                     .unwrap(),
                     created_at: datetime!(2023-02-01 0:00),
+                    key_version: 1,
+                    previous_key: None,
+                    previous_key_version: None,
                 },
                 &master_key.to_vec().into(),
             )
@@ -0,0 +1,283 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait OidcSsoConfigInterface {
+    async fn insert_oidc_sso_config(
+        &self,
+        oidc_sso_config: storage::OidcSsoConfigNew,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError>;
+
+    async fn find_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError>;
+
+    async fn list_sso_enforced_oidc_sso_configs_by_org_ids(
+        &self,
+        org_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::OidcSsoConfig>, errors::StorageError>;
+
+    async fn update_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+        oidc_sso_config_update: storage::OidcSsoConfigUpdate,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError>;
+
+    async fn delete_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl OidcSsoConfigInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_oidc_sso_config(
+        &self,
+        oidc_sso_config: storage::OidcSsoConfigNew,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        oidc_sso_config
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::OidcSsoConfig::find_by_org_id(&conn, org_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_sso_enforced_oidc_sso_configs_by_org_ids(
+        &self,
+        org_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::OidcSsoConfig>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::OidcSsoConfig::list_sso_enforced_by_org_ids(&conn, org_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+        oidc_sso_config_update: storage::OidcSsoConfigUpdate,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::OidcSsoConfig::update_by_org_id(&conn, org_id, oidc_sso_config_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::OidcSsoConfig::delete_by_org_id(&conn, org_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcSsoConfigInterface for MockDb {
+    async fn insert_oidc_sso_config(
+        &self,
+        oidc_sso_config: storage::OidcSsoConfigNew,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let mut oidc_sso_configs = self.oidc_sso_configs.lock().await;
+
+        if oidc_sso_configs
+            .iter()
+            .any(|config| config.org_id == oidc_sso_config.org_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "org_id",
+                key: Some(oidc_sso_config.org_id.clone()),
+            })?
+        }
+
+        let config = storage::OidcSsoConfig {
+            org_id: oidc_sso_config.org_id,
+            issuer_url: oidc_sso_config.issuer_url,
+            client_id: oidc_sso_config.client_id,
+            client_secret: oidc_sso_config.client_secret,
+            redirect_uri: oidc_sso_config.redirect_uri,
+            scopes: oidc_sso_config.scopes,
+            groups_claim: oidc_sso_config.groups_claim,
+            role_mappings: oidc_sso_config.role_mappings,
+            default_role_id: oidc_sso_config.default_role_id,
+            provisioning_merchant_id: oidc_sso_config.provisioning_merchant_id,
+            is_sso_enforced: oidc_sso_config.is_sso_enforced,
+            created_at: oidc_sso_config.created_at,
+            last_modified_at: oidc_sso_config.last_modified_at,
+        };
+        oidc_sso_configs.push(config.clone());
+        Ok(config)
+    }
+
+    async fn find_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        self.oidc_sso_configs
+            .lock()
+            .await
+            .iter()
+            .find(|config| config.org_id == org_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No OIDC SSO config available for org_id = {org_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_sso_enforced_oidc_sso_configs_by_org_ids(
+        &self,
+        org_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::OidcSsoConfig>, errors::StorageError> {
+        Ok(self
+            .oidc_sso_configs
+            .lock()
+            .await
+            .iter()
+            .filter(|config| config.is_sso_enforced && org_ids.contains(&config.org_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+        oidc_sso_config_update: storage::OidcSsoConfigUpdate,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let mut oidc_sso_configs = self.oidc_sso_configs.lock().await;
+
+        oidc_sso_configs
+            .iter_mut()
+            .find(|config| config.org_id == org_id)
+            .map(|config| {
+                match oidc_sso_config_update {
+                    storage::OidcSsoConfigUpdate::Update {
+                        issuer_url,
+                        client_id,
+                        client_secret,
+                        redirect_uri,
+                        scopes,
+                        groups_claim,
+                        role_mappings,
+                        default_role_id,
+                        provisioning_merchant_id,
+                        is_sso_enforced,
+                    } => {
+                        config.issuer_url = issuer_url;
+                        config.client_id = client_id;
+                        if let Some(client_secret) = client_secret {
+                            config.client_secret = client_secret;
+                        }
+                        config.redirect_uri = redirect_uri;
+                        config.scopes = scopes;
+                        config.groups_claim = groups_claim;
+                        config.role_mappings = role_mappings;
+                        config.default_role_id = default_role_id;
+                        config.provisioning_merchant_id = provisioning_merchant_id;
+                        config.is_sso_enforced = is_sso_enforced;
+                        config.last_modified_at = common_utils::date_time::now();
+                    }
+                }
+                config.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No OIDC SSO config available for org_id = {org_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        let mut oidc_sso_configs = self.oidc_sso_configs.lock().await;
+        let index = oidc_sso_configs
+            .iter()
+            .position(|config| config.org_id == org_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No OIDC SSO config available for org_id = {org_id}"
+            )))?;
+
+        Ok(oidc_sso_configs.remove(index))
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcSsoConfigInterface for KafkaStore {
+    async fn insert_oidc_sso_config(
+        &self,
+        oidc_sso_config: storage::OidcSsoConfigNew,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        self.diesel_store
+            .insert_oidc_sso_config(oidc_sso_config)
+            .await
+    }
+
+    async fn find_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        self.diesel_store
+            .find_oidc_sso_config_by_org_id(org_id)
+            .await
+    }
+
+    async fn list_sso_enforced_oidc_sso_configs_by_org_ids(
+        &self,
+        org_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::OidcSsoConfig>, errors::StorageError> {
+        self.diesel_store
+            .list_sso_enforced_oidc_sso_configs_by_org_ids(org_ids)
+            .await
+    }
+
+    async fn update_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+        oidc_sso_config_update: storage::OidcSsoConfigUpdate,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        self.diesel_store
+            .update_oidc_sso_config_by_org_id(org_id, oidc_sso_config_update)
+            .await
+    }
+
+    async fn delete_oidc_sso_config_by_org_id(
+        &self,
+        org_id: &str,
+    ) -> CustomResult<storage::OidcSsoConfig, errors::StorageError> {
+        self.diesel_store
+            .delete_oidc_sso_config_by_org_id(org_id)
+            .await
+    }
+}
@@ -26,6 +26,20 @@ pub trait PaymentLinkInterface {
         merchant_id: &str,
         payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
     ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn list_payment_links_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -64,6 +78,38 @@ impl PaymentLinkInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentLink::update_by_payment_link_id(&conn, payment_link_id, payment_link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payment_links_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentLink::list_by_merchant_id_reusable_payment_link_id(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,4 +138,24 @@ impl PaymentLinkInterface for MockDb {
         // TODO: Implement function for `MockDb`x
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        _payment_link_id: &str,
+        _payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payment_links_by_merchant_id_reusable_payment_link_id(
+        &self,
+        _merchant_id: &str,
+        _reusable_payment_link_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
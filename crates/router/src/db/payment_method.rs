@@ -28,6 +28,19 @@ pub trait PaymentMethodInterface {
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
+    async fn find_payment_method_by_merchant_id_payment_method(
+        &self,
+        merchant_id: &str,
+        payment_method: common_enums::PaymentMethod,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -379,6 +392,40 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_network_token_reference_id(
+            &self,
+            network_token_reference_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_network_token_reference_id(
+                &conn,
+                network_token_reference_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_merchant_id_payment_method(
+            &self,
+            merchant_id: &str,
+            payment_method: common_enums::PaymentMethod,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_merchant_id_payment_method(
+                &conn,
+                merchant_id,
+                payment_method,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -555,6 +602,40 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_network_token_reference_id(
+            &self,
+            network_token_reference_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_network_token_reference_id(
+                &conn,
+                network_token_reference_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_merchant_id_payment_method(
+            &self,
+            merchant_id: &str,
+            payment_method: common_enums::PaymentMethod,
+            limit: i64,
+            offset: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::PaymentMethod::find_by_merchant_id_payment_method(
+                &conn,
+                merchant_id,
+                payment_method,
+                limit,
+                offset,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -693,6 +774,10 @@ impl PaymentMethodInterface for MockDb {
             network_transaction_id: payment_method_new.network_transaction_id,
             updated_by: payment_method_new.updated_by,
             payment_method_billing_address: payment_method_new.payment_method_billing_address,
+            network_token_reference_id: payment_method_new.network_token_reference_id,
+            network_token_locker_id: payment_method_new.network_token_locker_id,
+            network_token_status: payment_method_new.network_token_status,
+            network_token_details: payment_method_new.network_token_details,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -721,6 +806,47 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_merchant_id_payment_method(
+        &self,
+        merchant_id: &str,
+        payment_method: common_enums::PaymentMethod,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| pm.merchant_id == merchant_id && pm.payment_method == Some(payment_method))
+            .cloned()
+            .collect();
+        payment_methods.sort_by_key(|pm| pm.created_at);
+
+        Ok(payment_methods
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(0))
+            .collect())
+    }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let payment_method = payment_methods
+            .iter()
+            .find(|pm| pm.network_token_reference_id.as_deref() == Some(network_token_reference_id))
+            .cloned();
+
+        match payment_method {
+            Some(pm) => Ok(pm),
+            None => Err(errors::StorageError::ValueNotFound(
+                "cannot find payment method".to_string(),
+            )
+            .into()),
+        }
+    }
+
     async fn find_payment_method_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -0,0 +1,283 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReusablePaymentLinkInterface {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError>;
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError>;
+
+    async fn get_reusable_payment_link_payment_amounts(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPaymentAmount>, errors::StorageError>;
+
+    async fn count_reusable_payment_link_payments_by_statuses(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        statuses: Vec<storage::enums::IntentStatus>,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        reusable_payment_link
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::find_by_merchant_id_reusable_payment_link_id(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReusablePaymentLink::update_by_merchant_id_reusable_payment_link_id(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+            reusable_payment_link_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_reusable_payment_link_payment_amounts(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPaymentAmount>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::get_payment_amounts(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_reusable_payment_link_payments_by_statuses(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        statuses: Vec<storage::enums::IntentStatus>,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReusablePaymentLink::count_payments_by_statuses(
+            &conn,
+            merchant_id,
+            reusable_payment_link_id,
+            statuses,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for MockDb {
+    async fn insert_reusable_payment_link(
+        &self,
+        _reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        _merchant_id: &str,
+        _reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        _merchant_id: &str,
+        _reusable_payment_link_id: &str,
+        _reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_reusable_payment_link_payment_amounts(
+        &self,
+        _merchant_id: &str,
+        _reusable_payment_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPaymentAmount>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_reusable_payment_link_payments_by_statuses(
+        &self,
+        _merchant_id: &str,
+        _reusable_payment_link_id: &str,
+        _statuses: Vec<storage::enums::IntentStatus>,
+        _limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ReusablePaymentLinkInterface for KafkaStore {
+    async fn insert_reusable_payment_link(
+        &self,
+        reusable_payment_link: storage::ReusablePaymentLinkNew,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .insert_reusable_payment_link(reusable_payment_link)
+            .await
+    }
+
+    async fn find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .find_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+                merchant_id,
+                reusable_payment_link_id,
+            )
+            .await
+    }
+
+    async fn list_reusable_payment_links_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .list_reusable_payment_links_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        reusable_payment_link_update: storage::ReusablePaymentLinkUpdate,
+    ) -> CustomResult<storage::ReusablePaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_reusable_payment_link_by_merchant_id_reusable_payment_link_id(
+                merchant_id,
+                reusable_payment_link_id,
+                reusable_payment_link_update,
+            )
+            .await
+    }
+
+    async fn get_reusable_payment_link_payment_amounts(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+    ) -> CustomResult<Vec<storage::ReusablePaymentLinkPaymentAmount>, errors::StorageError> {
+        self.diesel_store
+            .get_reusable_payment_link_payment_amounts(merchant_id, reusable_payment_link_id)
+            .await
+    }
+
+    async fn count_reusable_payment_link_payments_by_statuses(
+        &self,
+        merchant_id: &str,
+        reusable_payment_link_id: &str,
+        statuses: Vec<storage::enums::IntentStatus>,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .count_reusable_payment_link_payments_by_statuses(
+                merchant_id,
+                reusable_payment_link_id,
+                statuses,
+                limit,
+            )
+            .await
+    }
+}
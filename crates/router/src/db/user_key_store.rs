@@ -7,6 +7,7 @@ use storage_impl::MockDb;
 use crate::{
     connection,
     core::errors,
+    db::MasterKeyInterface,
     services::Store,
     types::domain::{
         self,
@@ -37,6 +38,9 @@ impl UserKeyStoreInterface for Store {
         user_key_store: domain::UserKeyStore,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::UserKeyStore, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
         let conn = connection::pg_connection_write(self).await?;
         user_key_store
             .construct_new()
@@ -45,7 +49,7 @@ impl UserKeyStoreInterface for Store {
             .insert(&conn)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(key)
+            .convert_with_previous_key(key, previous_master_key.as_ref())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -56,12 +60,15 @@ impl UserKeyStoreInterface for Store {
         user_id: &str,
         key: &Secret<Vec<u8>>,
     ) -> CustomResult<domain::UserKeyStore, errors::StorageError> {
+        let previous_master_key = self
+            .get_previous_master_key()
+            .map(|previous_master_key| Secret::new(previous_master_key.to_vec()));
         let conn = connection::pg_connection_read(self).await?;
 
         diesel_models::user_key_store::UserKeyStore::find_by_user_id(&conn, user_id)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))?
-            .convert(key)
+            .convert_with_previous_key(key, previous_master_key.as_ref())
             .await
             .change_context(errors::StorageError::DecryptionError)
     }
@@ -20,7 +20,8 @@ use crate::{
         PaymentLinkFormData,
     },
     types::api::{
-        AttachEvidenceRequest, Config, ConfigUpdate, CreateFileRequest, DisputeId, FileId, PollId,
+        AttachEvidenceRequest, CardInfoImportRequest, Config, ConfigUpdate, CreateFileRequest,
+        DisputeId, FileId, PollId,
     },
 };
 
@@ -113,6 +114,7 @@ impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
 impl_misc_api_event_type!(
     Config,
     CreateFileRequest,
+    CardInfoImportRequest,
     FileId,
     AttachEvidenceRequest,
     PaymentLinkFormData,
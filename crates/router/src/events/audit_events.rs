@@ -5,14 +5,27 @@ use time::PrimitiveDateTime;
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "event_type")]
 pub enum AuditEventType {
-    Error { error_message: String },
+    Error {
+        error_message: String,
+    },
     PaymentCreated,
     ConnectorDecided,
     ConnectorCalled,
     RefundCreated,
     RefundSuccess,
     RefundFail,
-    PaymentCancelled { cancellation_reason: Option<String> },
+    PaymentCancelled {
+        cancellation_reason: Option<String>,
+    },
+    ConfigChanged {
+        audit_id: String,
+        resource_type: common_enums::AuditResourceType,
+        resource_id: String,
+        action: common_enums::AuditAction,
+        actor_type: common_enums::AuditActorType,
+        actor_id: Option<String>,
+        changes: serde_json::Value,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -49,6 +62,7 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::ConfigChanged { .. } => "config_changed",
         };
         format!(
             "{event_type}-{}",
@@ -43,6 +43,20 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    AnalyticsAlert {
+        alert_id: String,
+        rule_id: String,
+        content: Value,
+    },
+    FraudCheck {
+        payment_id: String,
+        content: Value,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+        customer_id: Option<String>,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -74,6 +88,29 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::AnalyticsAlertDetails(alert_payload) => {
+                Some(OutgoingWebhookEventContent::AnalyticsAlert {
+                    alert_id: alert_payload.alert_id.clone(),
+                    rule_id: alert_payload.rule_id.clone(),
+                    content: masking::masked_serialize(&alert_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::FraudCheckDetails(fraud_check_payload) => {
+                Some(OutgoingWebhookEventContent::FraudCheck {
+                    payment_id: fraud_check_payload.payment_id.clone(),
+                    content: masking::masked_serialize(&fraud_check_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::PaymentMethodDetails(payment_method_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    payment_method_id: payment_method_payload.payment_method_id.clone(),
+                    customer_id: payment_method_payload.customer_id.clone(),
+                    content: masking::masked_serialize(&payment_method_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
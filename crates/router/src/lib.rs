@@ -148,7 +148,8 @@ pub fn mk_app(
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
             .service(routes::Verify::server(state.clone()))
-            .service(routes::WebhookEvents::server(state.clone()));
+            .service(routes::WebhookEvents::server(state.clone()))
+            .service(routes::ConfigAuditLogs::server(state.clone()));
     }
 
     #[cfg(feature = "payouts")]
@@ -166,6 +167,11 @@ pub fn mk_app(
         server_app = server_app.service(routes::Recon::server(state.clone()));
     }
 
+    #[cfg(all(feature = "olap", feature = "frm"))]
+    {
+        server_app = server_app.service(routes::FrmReviewQueue::server(state.clone()));
+    }
+
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
@@ -5,6 +5,8 @@ pub mod app;
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
+#[cfg(feature = "olap")]
+pub mod config_audit;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -53,6 +55,8 @@ pub mod webhooks;
 pub use self::app::DummyConnector;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub use self::app::Forex;
+#[cfg(all(feature = "olap", feature = "frm"))]
+pub use self::app::FrmReviewQueue;
 #[cfg(feature = "payouts")]
 pub use self::app::Payouts;
 #[cfg(all(feature = "olap", feature = "recon"))]
@@ -63,7 +67,7 @@ pub use self::app::{
     MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Refunds, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{Blocklist, ConfigAuditLogs, Routing, Verify, WebhookEvents};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
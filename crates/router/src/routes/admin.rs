@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{admin::*, api_locking, merchant_key_rotation},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -35,7 +35,7 @@ pub async fn merchant_account_create(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| create_merchant_account(state, req),
+        |state, _, req, req_state| create_merchant_account(state, req, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -139,7 +139,7 @@ pub async fn update_merchant_account(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| merchant_account_update(state, &merchant_id, req),
+        |state, _, req, req_state| merchant_account_update(state, &merchant_id, req, req_state),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -184,7 +184,7 @@ pub async fn delete_merchant_account(
         state,
         &req,
         payload,
-        |state, _, req, _| merchant_account_delete(state, req.merchant_id),
+        |state, _, req, req_state| merchant_account_delete(state, req.merchant_id, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -219,7 +219,7 @@ pub async fn payment_connector_create(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| create_payment_connector(state, req, &merchant_id),
+        |state, _, req, req_state| create_payment_connector(state, req, &merchant_id, req_state),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -365,8 +365,8 @@ pub async fn payment_connector_update(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| {
-            update_payment_connector(state, &merchant_id, &merchant_connector_id, req)
+        |state, _, req, req_state| {
+            update_payment_connector(state, &merchant_id, &merchant_connector_id, req, req_state)
         },
         auth::auth_type(
             &auth::AdminApiAuth,
@@ -418,8 +418,8 @@ pub async fn payment_connector_delete(
         state,
         &req,
         payload,
-        |state, _, req, _| {
-            delete_payment_connector(state, req.merchant_id, req.merchant_connector_id)
+        |state, _, req, req_state| {
+            delete_payment_connector(state, req.merchant_id, req.merchant_connector_id, req_state)
         },
         auth::auth_type(
             &auth::AdminApiAuth,
@@ -499,7 +499,7 @@ pub async fn business_profile_create(
         state,
         &req,
         payload,
-        |state, _, req, _| create_business_profile(state, req, &merchant_id),
+        |state, _, req, req_state| create_business_profile(state, req, &merchant_id, req_state),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -554,7 +554,9 @@ pub async fn business_profile_update(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| update_business_profile(state, &profile_id, &merchant_id, req),
+        |state, _, req, req_state| {
+            update_business_profile(state, &profile_id, &merchant_id, req, req_state)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -581,7 +583,9 @@ pub async fn business_profile_delete(
         state,
         &req,
         profile_id,
-        |state, _, profile_id, _| delete_business_profile(state, profile_id, &merchant_id),
+        |state, _, profile_id, req_state| {
+            delete_business_profile(state, profile_id, &merchant_id, req_state)
+        },
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -632,7 +636,7 @@ pub async fn toggle_connector_agnostic_mit(
         json_payload.into_inner(),
         |state, _, req, _| connector_agnostic_mit_toggle(state, &merchant_id, &profile_id, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -664,6 +668,82 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
+/// Merchant Account - Rotate Key
+///
+/// Rotate the data encryption key of the Merchant Account and re-encrypt its data in the background
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotate))]
+pub async fn merchant_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotate;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| merchant_key_rotation::rotate_merchant_key(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Key Rotation Status
+///
+/// Retrieve the progress of the latest data encryption key rotation of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationRetrieve))]
+pub async fn merchant_key_rotation_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationRetrieve;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| {
+            merchant_key_rotation::retrieve_merchant_key_rotation(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Resume Key Rotation
+///
+/// Resume a failed data encryption key rotation of the Merchant Account
+#[instrument(skip_all, fields(flow = ?Flow::MerchantKeyRotationResume))]
+pub async fn merchant_key_rotation_resume(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantKeyRotationResume;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| {
+            merchant_key_rotation::resume_merchant_key_rotation(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::ToggleExtendedCardInfo))]
 pub async fn toggle_extended_card_info(
     state: web::Data<AppState>,
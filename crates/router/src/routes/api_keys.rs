@@ -41,8 +41,8 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, _, payload, _| async {
-            api_keys::create_api_key(state, payload, merchant_id.clone()).await
+        |state, _, payload, req_state| async {
+            api_keys::create_api_key(state, payload, merchant_id.clone(), req_state).await
         },
         auth::auth_type(
             &auth::AdminApiAuth,
@@ -138,7 +138,7 @@ pub async fn api_key_update(
         state,
         &req,
         payload,
-        |state, _, payload, _| api_keys::update_api_key(state, payload),
+        |state, _, payload, req_state| api_keys::update_api_key(state, payload, req_state),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -184,7 +184,9 @@ pub async fn api_key_revoke(
         state,
         &req,
         (&merchant_id, &key_id),
-        |state, _, (merchant_id, key_id), _| api_keys::revoke_api_key(state, merchant_id, key_id),
+        |state, _, (merchant_id, key_id), req_state| {
+            api_keys::revoke_api_key(state, merchant_id, key_id, req_state)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -29,8 +29,8 @@ use super::routing as cloud_routing;
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{
-    admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*, payment_link::*,
-    user::*, user_role::*, webhook_events::*,
+    admin::*, api_keys::*, config_audit::*, connector_onboarding::*, disputes::*, files::*, gsm::*,
+    payment_link::*, user::*, user_role::*, webhook_events::*,
 };
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -44,7 +44,9 @@ use super::{pm_auth, poll::retrieve_poll_status};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 use crate::configs::secrets_transformers;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(feature = "olap")]
+use crate::routes::cards_info::{cards_info_import, cards_info_import_diff};
+#[cfg(all(feature = "frm", any(feature = "olap", feature = "oltp")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
@@ -62,6 +64,9 @@ pub use crate::{
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// The credential the request was authenticated with, available once the request has been
+    /// authenticated
+    pub auth_type: Option<crate::services::authentication::AuthenticationType>,
 }
 
 #[derive(Clone)]
@@ -263,6 +268,7 @@ impl AppState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
         }
     }
 }
@@ -334,6 +340,10 @@ impl Payments {
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_payment_filters)))
+                .service(
+                    web::resource("/authorizations/expiring_soon")
+                        .route(web::get().to(payments_authorizations_expiring_soon)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -423,6 +433,11 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(web::resource("/quotes").route(web::post().to(currency::create_fx_quote)))
+            .service(
+                web::resource("/quotes/{quote_id}")
+                    .route(web::get().to(currency::retrieve_fx_quote)),
+            )
     }
 }
 
@@ -537,6 +552,16 @@ impl Routing {
                 })),
             );
 
+        #[cfg(feature = "frm")]
+        {
+            route = route.service(
+                web::resource("/decision/frm")
+                    .route(web::put().to(cloud_routing::upsert_frm_rules))
+                    .route(web::get().to(cloud_routing::retrieve_frm_rules))
+                    .route(web::delete().to(cloud_routing::delete_frm_rules)),
+            );
+        }
+
         #[cfg(feature = "payouts")]
         {
             route = route
@@ -811,6 +836,10 @@ impl PaymentMethods {
                 .service(
                     web::resource("/auth/exchange").route(web::post().to(pm_auth::exchange_token)),
                 )
+                .service(
+                    web::resource("/network_token/webhooks")
+                        .route(web::post().to(network_token_webhook_api)),
+                )
         }
         route
     }
@@ -836,6 +865,28 @@ impl Recon {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "frm"))]
+pub struct FrmReviewQueue;
+
+#[cfg(all(feature = "olap", feature = "frm"))]
+impl FrmReviewQueue {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/frm/review_queue")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(frm_routes::list_review_queue)))
+            .service(
+                web::resource("/policy")
+                    .route(web::get().to(frm_routes::retrieve_review_policy))
+                    .route(web::post().to(frm_routes::upsert_review_policy))
+                    .route(web::delete().to(frm_routes::delete_review_policy)),
+            )
+            .service(
+                web::resource("/{payment_id}/assign")
+                    .route(web::post().to(frm_routes::assign_reviewer)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Blocklist;
 
@@ -853,6 +904,11 @@ impl Blocklist {
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
+            .service(
+                web::resource("/velocity_rules")
+                    .route(web::get().to(blocklist::retrieve_velocity_rules))
+                    .route(web::post().to(blocklist::update_velocity_rules)),
+            )
     }
 }
 
@@ -871,6 +927,15 @@ impl MerchantAccount {
                     .route(web::get().to(merchant_account_kv_status)),
             )
             .service(web::resource("/kv").route(web::post().to(merchant_account_toggle_all_kv)))
+            .service(
+                web::resource("/{id}/key_rotation")
+                    .route(web::post().to(merchant_key_rotate))
+                    .route(web::get().to(merchant_key_rotation_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/key_rotation/resume")
+                    .route(web::post().to(merchant_key_rotation_resume)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(retrieve_merchant_account))
@@ -1039,6 +1104,10 @@ impl Disputes {
         web::scope("/disputes")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
+            .service(
+                web::resource("/expiring_soon")
+                    .route(web::get().to(retrieve_disputes_expiring_soon)),
+            )
             .service(web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)))
             .service(
                 web::resource("/evidence")
@@ -1046,6 +1115,10 @@ impl Disputes {
                     .route(web::put().to(attach_dispute_evidence))
                     .route(web::delete().to(delete_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/draft/{dispute_id}")
+                    .route(web::get().to(retrieve_dispute_evidence_draft)),
+            )
             .service(
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
@@ -1058,9 +1131,18 @@ pub struct Cards;
 
 impl Cards {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/cards")
-            .app_data(web::Data::new(state))
-            .service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
+        let mut route = web::scope("/cards").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(web::resource("/import").route(web::post().to(cards_info_import)))
+                .service(
+                    web::resource("/import/diff").route(web::post().to(cards_info_import_diff)),
+                );
+        }
+
+        route.service(web::resource("/{bin}").route(web::get().to(card_iin_info)))
     }
 }
 
@@ -1097,13 +1179,39 @@ impl PaymentLink {
         web::scope("/payment_link")
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::post().to(payments_link_list)))
+            .service(
+                web::resource("/reusable")
+                    .route(web::post().to(reusable_payment_link_create))
+                    .route(web::get().to(reusable_payment_link_list)),
+            )
             .service(
                 web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
             )
+            .service(
+                web::resource("/reusable/{reusable_payment_link_id}")
+                    .route(web::get().to(reusable_payment_link_retrieve))
+                    .route(web::post().to(reusable_payment_link_update)),
+            )
+            .service(
+                web::resource("/reusable/{reusable_payment_link_id}/payments")
+                    .route(web::get().to(reusable_payment_link_payments_list)),
+            )
+            .service(
+                web::resource("/reusable/{reusable_payment_link_id}/qr_code")
+                    .route(web::get().to(reusable_payment_link_qr_code)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/qr_code")
+                    .route(web::get().to(payment_link_qr_code)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(initiate_payment_link)),
             )
+            .service(
+                web::resource("pay/{merchant_id}/{reusable_payment_link_id}")
+                    .route(web::get().to(initiate_reusable_payment_link)),
+            )
             .service(
                 web::resource("status/{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link_status)),
@@ -1211,7 +1319,18 @@ impl User {
                     .route(web::post().to(set_dashboard_metadata)),
             )
             .service(web::resource("/totp/begin").route(web::get().to(totp_begin)))
-            .service(web::resource("/totp/verify").route(web::post().to(totp_verify)));
+            .service(web::resource("/totp/verify").route(web::post().to(totp_verify)))
+            .service(
+                web::scope("/sso/oidc")
+                    .service(web::resource("/authorize").route(web::get().to(oidc_sso_authorize)))
+                    .service(web::resource("/callback").route(web::post().to(oidc_sso_callback)))
+                    .service(
+                        web::resource("/config")
+                            .route(web::get().to(get_oidc_sso_config))
+                            .route(web::post().to(upsert_oidc_sso_config))
+                            .route(web::delete().to(delete_oidc_sso_config)),
+                    ),
+            );
 
         #[cfg(feature = "email")]
         {
@@ -1315,6 +1434,13 @@ impl WebhookEvents {
         web::scope("/events/{merchant_id_or_profile_id}")
             .app_data(web::Data::new(config))
             .service(web::resource("").route(web::get().to(list_initial_webhook_delivery_attempts)))
+            .service(
+                web::resource("/retry_failed")
+                    .route(web::post().to(bulk_retry_webhook_delivery_attempts)),
+            )
+            .service(
+                web::resource("/delivery_stats").route(web::get().to(get_webhook_delivery_stats)),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
@@ -1328,3 +1454,27 @@ impl WebhookEvents {
             )
     }
 }
+
+#[cfg(feature = "olap")]
+pub struct ConfigAuditLogs;
+
+#[cfg(feature = "olap")]
+impl ConfigAuditLogs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/global").route(web::get().to(list_global_config_audit_logs)))
+            .service(
+                web::resource("/global/export")
+                    .route(web::get().to(export_global_config_audit_logs)),
+            )
+            .service(
+                web::resource("/merchant/{merchant_id}")
+                    .route(web::get().to(list_merchant_config_audit_logs)),
+            )
+            .service(
+                web::resource("/merchant/{merchant_id}/export")
+                    .route(web::get().to(export_merchant_config_audit_logs)),
+            )
+    }
+}
@@ -31,11 +31,11 @@ pub async fn add_entry_to_blocklist(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, body, _| {
-            blocklist::add_entry_to_blocklist(state, auth.merchant_account, body)
+        |state, auth: auth::AuthenticationData, body, req_state| {
+            blocklist::add_entry_to_blocklist(state, auth.merchant_account, body, req_state)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
             &auth::JWTAuth(Permission::MerchantAccountWrite),
             req.headers(),
         ),
@@ -67,11 +67,11 @@ pub async fn remove_entry_from_blocklist(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, body, _| {
-            blocklist::remove_entry_from_blocklist(state, auth.merchant_account, body)
+        |state, auth: auth::AuthenticationData, body, req_state| {
+            blocklist::remove_entry_from_blocklist(state, auth.merchant_account, body, req_state)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
             &auth::JWTAuth(Permission::MerchantAccountWrite),
             req.headers(),
         ),
@@ -109,7 +109,7 @@ pub async fn list_blocked_payment_methods(
             blocklist::list_blocklist_entries(state, auth.merchant_account, query)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
             &auth::JWTAuth(Permission::MerchantAccountRead),
             req.headers(),
         ),
@@ -147,7 +147,73 @@ pub async fn toggle_blocklist_guard(
             blocklist::toggle_blocklist_guard(state, auth.merchant_account, query)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/velocity_rules",
+    responses(
+        (status = 200, description = "Velocity rules of the merchant", body = VelocityRulesConfig),
+    ),
+    tag = "Blocklist",
+    operation_id = "Retrieve velocity rules for a particular merchant",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_velocity_rules(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::RetrieveVelocityRules;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            blocklist::retrieve_velocity_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/velocity_rules",
+    request_body = VelocityRulesConfig,
+    responses(
+        (status = 200, description = "Velocity rules updated", body = VelocityRulesConfig),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Update velocity rules for a particular merchant",
+    security(("api_key" = []))
+)]
+pub async fn update_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::VelocityRulesConfig>,
+) -> HttpResponse {
+    let flow = Flow::UpdateVelocityRules;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, req_state| {
+            blocklist::update_velocity_rules(state, auth.merchant_account, body, req_state)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
             &auth::JWTAuth(Permission::MerchantAccountWrite),
             req.headers(),
         ),
@@ -1,10 +1,26 @@
+#[cfg(feature = "olap")]
+use actix_multipart::Multipart;
 use actix_web::{web, HttpRequest, Responder};
+#[cfg(feature = "olap")]
+use actix_web::{web::Bytes, HttpResponse};
+#[cfg(feature = "olap")]
+use common_utils::errors::CustomResult;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use futures::{StreamExt, TryStreamExt};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
     core::{api_locking, cards_info},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::{errors, files::helpers as files_helpers},
+    types::api as api_types,
+    utils::OptionExt,
 };
 
 /// Cards Info - Retrieve
@@ -36,7 +52,11 @@ pub async fn card_iin_info(
         card_iin,
     };
 
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -52,3 +72,127 @@ pub async fn card_iin_info(
     )
     .await
 }
+
+#[cfg(feature = "olap")]
+async fn get_card_info_import_request(
+    mut payload: Multipart,
+    dry_run: bool,
+) -> CustomResult<api_types::CardInfoImportRequest, errors::ApiErrorResponse> {
+    let mut provider: Option<String> = None;
+    let mut file_content: Option<Vec<Bytes>> = None;
+
+    while let Ok(Some(mut field)) = payload.try_next().await {
+        let content_disposition = field.content_disposition();
+        match content_disposition.get_name() {
+            Some("provider") => {
+                provider = files_helpers::read_string(&mut field).await;
+            }
+            Some("file") => {
+                let mut file_data = Vec::new();
+                let mut stream = field.into_stream();
+                while let Some(chunk) = stream.next().await {
+                    match chunk {
+                        Ok(bytes) => file_data.push(bytes),
+                        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable(format!("File parsing error: {err}"))?,
+                    }
+                }
+                file_content = Some(file_data);
+            }
+            // Can ignore other params
+            _ => (),
+        }
+    }
+
+    let provider = provider.get_required_value("provider")?;
+    let file = match file_content {
+        Some(file_content) if !file_content.is_empty() => file_content.concat(),
+        _ => Err(errors::ApiErrorResponse::MissingFile)
+            .attach_printable("Missing / Invalid file in the request")?,
+    };
+
+    Ok(api_types::CardInfoImportRequest {
+        provider,
+        dry_run,
+        file,
+    })
+}
+
+/// Cards Info - Import
+///
+/// Import a CSV file of card BIN records into the BIN database. BINs which are not stored yet are
+/// inserted and stored BINs are updated with the non-empty columns of the file.
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/cards/import",
+    request_body = MultipartRequestWithFile,
+    responses(
+        (status = 200, description = "Card information imported", body = CardInfoImportResponse),
+        (status = 400, description = "Invalid file")
+    ),
+    operation_id = "Import card information",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CardsInfoImport))]
+pub async fn cards_info_import(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let flow = Flow::CardsInfoImport;
+    let import_request = match get_card_info_import_request(payload, false).await {
+        Ok(import_request) => import_request,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        import_request,
+        |state, _, req, _| cards_info::import_cards_info_core(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Cards Info - Import Diff
+///
+/// Compare a CSV file of card BIN records with the BIN database without storing any of the records
+#[cfg(feature = "olap")]
+#[utoipa::path(
+    post,
+    path = "/cards/import/diff",
+    request_body = MultipartRequestWithFile,
+    responses(
+        (status = 200, description = "Changes the import would make", body = CardInfoImportResponse),
+        (status = 400, description = "Invalid file")
+    ),
+    operation_id = "Diff card information import",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CardsInfoImportDiff))]
+pub async fn cards_info_import_diff(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let flow = Flow::CardsInfoImportDiff;
+    let import_request = match get_card_info_import_request(payload, true).await {
+        Ok(import_request) => import_request,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        import_request,
+        |state, _, req, _| cards_info::import_cards_info_core(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
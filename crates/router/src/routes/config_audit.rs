@@ -0,0 +1,124 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::config_audit::ConfigAuditLogListConstraints;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, config_audit},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Config Audit Logs - List for a merchant
+///
+/// Lists the recorded changes to the configuration of a merchant account.
+#[instrument(skip_all, fields(flow = ?Flow::ConfigAuditLogList))]
+pub async fn list_merchant_config_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<ConfigAuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ConfigAuditLogList;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            config_audit::list_config_audit_logs(state, Some(merchant_id.clone()), constraints)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Config Audit Logs - Export for a merchant
+///
+/// Exports the recorded changes to the configuration of a merchant account as a CSV file.
+#[instrument(skip_all, fields(flow = ?Flow::ConfigAuditLogExport))]
+pub async fn export_merchant_config_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<ConfigAuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ConfigAuditLogExport;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            config_audit::export_config_audit_logs(state, Some(merchant_id.clone()), constraints)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Config Audit Logs - List for global configurations
+///
+/// Lists the recorded changes to configurations that are not specific to a merchant account.
+#[instrument(skip_all, fields(flow = ?Flow::ConfigAuditLogList))]
+pub async fn list_global_config_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ConfigAuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ConfigAuditLogList;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| config_audit::list_config_audit_logs(state, None, constraints),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Config Audit Logs - Export for global configurations
+///
+/// Exports the recorded changes to configurations that are not specific to a merchant account as
+/// a CSV file.
+#[instrument(skip_all, fields(flow = ?Flow::ConfigAuditLogExport))]
+pub async fn export_global_config_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ConfigAuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ConfigAuditLogExport;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| config_audit::export_config_audit_logs(state, None, constraints),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
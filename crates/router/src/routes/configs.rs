@@ -22,7 +22,7 @@ pub async fn config_key_create(
         state,
         &req,
         payload,
-        |state, _, data, _| configs::set_config(state, data),
+        |state, _, data, req_state| configs::set_config(state, data, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -65,7 +65,7 @@ pub async fn config_key_update(
         state,
         &req,
         &payload,
-        |state, _, payload, _| configs::update_config(state, payload),
+        |state, _, payload, req_state| configs::update_config(state, payload, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -86,7 +86,7 @@ pub async fn config_key_delete(
         state,
         &req,
         key,
-        |state, _, key, _| configs::config_delete(state, key),
+        |state, _, key, req_state| configs::config_delete(state, key, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -4,7 +4,7 @@ use router_env::Flow;
 use crate::{
     core::{api_locking, currency},
     routes::AppState,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
@@ -16,7 +16,7 @@ pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> Htt
         (),
         |state, _auth: auth::AuthenticationData, _, _| currency::retrieve_forex(state),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
         ),
@@ -39,16 +39,66 @@ pub async fn convert_forex(
         state.clone(),
         &req,
         (),
-        |state, _, _, _| {
+        |state, auth: auth::AuthenticationData, _, _| {
             currency::convert_forex(
                 state,
+                auth.merchant_account.merchant_id,
                 amount.get_amount_as_i64(),
                 to_currency.to_string(),
                 from_currency.to_string(),
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_fx_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::currency::FxQuoteRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateFxQuote;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            currency::create_fx_quote(state, auth.merchant_account.merchant_id, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
+            &auth::DashboardNoPermissionAuth,
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_fx_quote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveFxQuote;
+    let quote_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        quote_id,
+        |state, auth: auth::AuthenticationData, quote_id, _| {
+            currency::retrieve_fx_quote(state, auth.merchant_account.merchant_id, quote_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
         ),
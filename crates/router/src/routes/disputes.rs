@@ -45,7 +45,7 @@ pub async fn retrieve_dispute(
         dispute_id,
         |state, auth, req, _| disputes::retrieve_dispute(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeRead),
             &auth::JWTAuth(Permission::DisputeRead),
             req.headers(),
         ),
@@ -92,7 +92,7 @@ pub async fn retrieve_disputes_list(
         payload,
         |state, auth, req, _| disputes::retrieve_disputes_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeRead),
             &auth::JWTAuth(Permission::DisputeRead),
             req.headers(),
         ),
@@ -134,7 +134,7 @@ pub async fn accept_dispute(
             disputes::accept_dispute(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::JWTAuth(Permission::DisputeWrite),
             req.headers(),
         ),
@@ -171,7 +171,7 @@ pub async fn submit_dispute_evidence(
             disputes::submit_evidence(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::JWTAuth(Permission::DisputeWrite),
             req.headers(),
         ),
@@ -216,7 +216,7 @@ pub async fn attach_dispute_evidence(
             disputes::attach_evidence(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::JWTAuth(Permission::DisputeWrite),
             req.headers(),
         ),
@@ -259,7 +259,7 @@ pub async fn retrieve_dispute_evidence(
             disputes::retrieve_dispute_evidence(state, auth.merchant_account, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeRead),
             &auth::JWTAuth(Permission::DisputeRead),
             req.headers(),
         ),
@@ -297,7 +297,7 @@ pub async fn delete_dispute_evidence(
         json_payload.into_inner(),
         |state, auth, req, _| disputes::delete_evidence(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::JWTAuth(Permission::DisputeWrite),
             req.headers(),
         ),
@@ -305,3 +305,93 @@ pub async fn delete_dispute_evidence(
     ))
     .await
 }
+
+/// Disputes - List Disputes Expiring Soon
+///
+/// To list the open disputes whose evidence submission deadline falls within the given number of days, nearest deadline first
+#[utoipa::path(
+    get,
+    path = "/disputes/expiring_soon",
+    params(
+        ("within_days" = Option<u32>, Query, description = "Number of days ahead of now within which the evidence submission deadline falls, defaults to 7"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of Dispute Objects to include in the response"),
+    ),
+    responses(
+        (status = 200, description = "The disputes expiring soon were retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Disputes",
+    operation_id = "List Disputes Expiring Soon",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesExpiringSoonList))]
+pub async fn retrieve_disputes_expiring_soon(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_models::DisputeExpiringSoonConstraints>,
+) -> HttpResponse {
+    let flow = Flow::DisputesExpiringSoonList;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            disputes::retrieve_disputes_expiring_soon(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::DisputeRead),
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Disputes - Retrieve Evidence Draft
+///
+/// To retrieve an evidence submission pre-filled from the disputed payment and the evidence files attached to the dispute, which can be submitted as is
+#[utoipa::path(
+    get,
+    path = "/disputes/evidence/draft/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The evidence draft was retrieved successfully", body = SubmitEvidenceRequest),
+        (status = 400, description = "Bad Request"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Evidence Draft for a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceDraft))]
+pub async fn retrieve_dispute_evidence_draft(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceDraft;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth, req, _| {
+            disputes::retrieve_evidence_draft(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::DisputeRead),
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
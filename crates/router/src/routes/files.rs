@@ -8,7 +8,7 @@ pub mod transformers;
 use super::app::AppState;
 use crate::{
     core::files::*,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::files,
 };
 
@@ -46,7 +46,7 @@ pub async fn files_create(
         create_file_request,
         |state, auth, req, _| files_create_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
         ),
@@ -88,7 +88,7 @@ pub async fn files_delete(
         file_id,
         |state, auth, req, _| files_delete_core(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeWrite),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
         ),
@@ -132,7 +132,7 @@ pub async fn files_retrieve(
             files_retrieve_core(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::DisputeRead),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
         ),
@@ -1,10 +1,16 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+#[cfg(feature = "olap")]
+use api_models::frm_review;
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 use router_env::Flow;
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
 
+#[cfg(feature = "olap")]
+use crate::{core::fraud_check::review_queue, services::authentication as auth};
 use crate::{
     core::{api_locking, fraud_check as frm_core},
-    services::{self, api},
+    services::{self, api, authorization::permissions::Permission},
     types::fraud_check::FraudCheckResponseData,
     AppState,
 };
@@ -23,7 +29,131 @@ pub async fn frm_fulfillment(
         |state, auth, req, _| {
             frm_core::frm_fulfillment_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &services::authentication::ApiKeyAuth,
+        &services::authentication::ApiKeyAuth(Permission::PaymentWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewQueueList))]
+pub async fn list_review_queue(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<frm_review::FrmReviewQueueConstraints>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewQueueList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            review_queue::list_review_queue(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::PaymentRead),
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewAssign))]
+pub async fn assign_reviewer(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<frm_review::FrmReviewAssignRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewAssign;
+    let payment_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            review_queue::assign_reviewer(state, auth.merchant_account, payment_id.clone(), request)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::PaymentWrite),
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewPolicyUpsert))]
+pub async fn upsert_review_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<frm_review::FrmReviewPolicy>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewPolicyUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, policy, req_state| {
+            review_queue::upsert_frm_review_policy(state, auth.merchant_account, policy, req_state)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewPolicyRetrieve))]
+pub async fn retrieve_review_policy(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::FrmReviewPolicyRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            review_queue::retrieve_frm_review_policy(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountRead),
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewPolicyDelete))]
+pub async fn delete_review_policy(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::FrmReviewPolicyDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, req_state| {
+            review_queue::delete_frm_review_policy(state, auth.merchant_account, req_state)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::MerchantAccountWrite),
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
         api_locking::LockAction::NotApplicable,
     ))
     .await
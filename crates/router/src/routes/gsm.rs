@@ -39,7 +39,7 @@ pub async fn create_gsm_rule(
         state.clone(),
         &req,
         payload,
-        |state, _, payload, _| gsm::create_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::create_gsm_rule(state, payload, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -114,7 +114,7 @@ pub async fn update_gsm_rule(
         state.clone(),
         &req,
         payload,
-        |state, _, payload, _| gsm::update_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::update_gsm_rule(state, payload, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -153,7 +153,7 @@ pub async fn delete_gsm_rule(
         state,
         &req,
         payload,
-        |state, _, payload, _| gsm::delete_gsm_rule(state, payload),
+        |state, _, payload, req_state| gsm::delete_gsm_rule(state, payload, req_state),
         &auth::AdminApiAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -44,7 +44,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
-            | Flow::MerchantAccountList => Self::MerchantAccount,
+            | Flow::MerchantAccountList
+            | Flow::MerchantKeyRotate
+            | Flow::MerchantKeyRotationRetrieve
+            | Flow::MerchantKeyRotationResume => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
@@ -58,14 +61,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingDeleteConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
-            | Flow::DecisionManagerUpsertConfig => Self::Routing,
+            | Flow::DecisionManagerUpsertConfig
+            | Flow::FrmRulesUpsert
+            | Flow::FrmRulesDelete
+            | Flow::FrmRulesRetrieve => Self::Routing,
 
-            Flow::RetrieveForexFlow => Self::Forex,
+            Flow::RetrieveForexFlow | Flow::CreateFxQuote | Flow::RetrieveFxQuote => Self::Forex,
 
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
+            Flow::RetrieveVelocityRules => Self::Blocklist,
+            Flow::UpdateVelocityRules => Self::Blocklist,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
@@ -77,7 +85,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ConfigKeyFetch
             | Flow::ConfigKeyUpdate
             | Flow::ConfigKeyDelete
-            | Flow::CreateConfigKey => Self::Configs,
+            | Flow::CreateConfigKey
+            | Flow::ConfigAuditLogList
+            | Flow::ConfigAuditLogExport => Self::Configs,
 
             Flow::CustomersCreate
             | Flow::CustomersRetrieve
@@ -100,6 +110,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
+            | Flow::NetworkTokenWebhook
             | Flow::PaymentMethodSave => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
@@ -116,13 +127,19 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::PaymentsAuthorizationsExpiringSoonList
             | Flow::PaymentsFilters
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
-            | Flow::PaymentsCompleteAuthorize => Self::Payments,
+            | Flow::PaymentsCompleteAuthorize
+            | Flow::FrmReviewQueueList
+            | Flow::FrmReviewAssign
+            | Flow::FrmReviewPolicyUpsert
+            | Flow::FrmReviewPolicyRetrieve
+            | Flow::FrmReviewPolicyDelete => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -144,7 +161,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventBulkDeliveryRetry
+            | Flow::WebhookEventDeliveryStats => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -154,12 +173,14 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
+            | Flow::DisputesExpiringSoonList
+            | Flow::DisputesEvidenceDraft
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
-            Flow::CardsInfo => Self::CardsInfo,
+            Flow::CardsInfo | Flow::CardsInfoImport | Flow::CardsInfoImportDiff => Self::CardsInfo,
 
             Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
 
@@ -176,7 +197,14 @@ impl From<Flow> for ApiIdentifier {
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkQrCode
+            | Flow::ReusablePaymentLinkCreate
+            | Flow::ReusablePaymentLinkRetrieve
+            | Flow::ReusablePaymentLinkList
+            | Flow::ReusablePaymentLinkUpdate
+            | Flow::ReusablePaymentLinkPaymentsList
+            | Flow::ReusablePaymentLinkInitiate => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -214,7 +242,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::VerifyEmailRequest
             | Flow::UpdateUserAccountDetails
             | Flow::TotpBegin
-            | Flow::TotpVerify => Self::User,
+            | Flow::TotpVerify
+            | Flow::OidcSsoAuthorize
+            | Flow::OidcSsoCallback
+            | Flow::OidcSsoConfigRetrieve
+            | Flow::OidcSsoConfigUpsert
+            | Flow::OidcSsoConfigDelete => Self::User,
 
             Flow::ListRoles
             | Flow::GetRole
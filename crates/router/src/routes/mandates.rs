@@ -44,7 +44,7 @@ pub async fn get_mandate(
         |state, auth, req, _| {
             mandate::get_mandate(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateRead),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -85,7 +85,7 @@ pub async fn revoke_mandate(
         |state, auth, req, _| {
             mandate::revoke_mandate(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::MandateWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -130,7 +130,7 @@ pub async fn retrieve_mandates_list(
             mandate::retrieve_mandates_list(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MandateRead),
             &auth::JWTAuth(Permission::MandateRead),
             req.headers(),
         ),
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     core::{api_locking, payment_link::*},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     AppState,
 };
 
@@ -35,7 +35,11 @@ pub async fn payment_link_retrieve(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkRetrieve;
     let payload = json_payload.into_inner();
-    let (auth_type, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth_type, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok(auth) => auth,
         Err(err) => return api::log_and_return_error_response(error_stack::report!(err)),
     };
@@ -62,6 +66,7 @@ pub async fn initiate_payment_link(
         payment_id,
         merchant_id: merchant_id.clone(),
     };
+    let request_host = req.connection_info().host().to_owned();
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -73,6 +78,7 @@ pub async fn initiate_payment_link(
                 auth.merchant_account,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                Some(request_host.clone()),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
@@ -118,7 +124,7 @@ pub async fn payments_link_list(
         &req,
         payload,
         |state, auth, payload, _| list_payment_link(state, auth.merchant_account, payload),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -135,6 +141,7 @@ pub async fn payment_link_status(
         payment_id,
         merchant_id: merchant_id.clone(),
     };
+    let request_host = req.connection_info().host().to_owned();
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -146,6 +153,7 @@ pub async fn payment_link_status(
                 auth.merchant_account,
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
+                Some(request_host.clone()),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
@@ -153,3 +161,338 @@ pub async fn payment_link_status(
     ))
     .await
 }
+
+/// Reusable Payment Link - Create
+///
+/// To create a payment link that can be paid by any number of customers, for a fixed amount or an amount entered by the customer
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable",
+    request_body = ReusablePaymentLinkCreateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link created", body = ReusablePaymentLinkResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Link",
+    operation_id = "Create a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkCreate))]
+pub async fn reusable_payment_link_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkCreate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, payload, _| {
+            create_reusable_payment_link(state, auth.merchant_account, payload)
+        },
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Reusable Payment Link - Retrieve
+///
+/// To retrieve the properties of a reusable payment link
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    responses(
+        (status = 200, description = "Reusable payment link retrieved", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkRetrieve))]
+pub async fn reusable_payment_link_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkRetrieve;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth, reusable_payment_link_id, _| {
+            retrieve_reusable_payment_link(state, auth.merchant_account, reusable_payment_link_id)
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Reusable Payment Link - List
+///
+/// To list the reusable payment links of the merchant
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of reusable payment links to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of reusable payment links to skip"),
+    ),
+    responses(
+        (status = 200, description = "Reusable payment links retrieved", body = ReusablePaymentLinkListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Payment Link",
+    operation_id = "List all Reusable Payment Links",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkList))]
+pub async fn reusable_payment_link_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    query_payload: web::Query<api_models::payments::ReusablePaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, constraints, _| {
+            list_reusable_payment_links(state, auth.merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Reusable Payment Link - Update
+///
+/// To update the usage limit, expiry, description or active state of a reusable payment link
+#[utoipa::path(
+    post,
+    path = "/payment_link/reusable/{reusable_payment_link_id}",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link")
+    ),
+    request_body = ReusablePaymentLinkUpdateRequest,
+    responses(
+        (status = 200, description = "Reusable payment link updated", body = ReusablePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Update a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkUpdate))]
+pub async fn reusable_payment_link_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::ReusablePaymentLinkUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkUpdate;
+    let reusable_payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, payload, _| {
+            update_reusable_payment_link(
+                state,
+                auth.merchant_account,
+                reusable_payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Reusable Payment Link - List Payments
+///
+/// To list the payments made through a reusable payment link, along with a summary of all its payments
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}/payments",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of payments to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of payments to skip"),
+    ),
+    responses(
+        (status = 200, description = "Payments of the reusable payment link retrieved", body = ReusablePaymentLinkPaymentsResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "List Payments of a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReusablePaymentLinkPaymentsList))]
+pub async fn reusable_payment_link_payments_list(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payments::ReusablePaymentLinkListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkPaymentsList;
+    let reusable_payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, constraints, _| {
+            list_reusable_payment_link_payments(
+                state,
+                auth.merchant_account,
+                reusable_payment_link_id.clone(),
+                constraints,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+pub async fn initiate_reusable_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    query_payload: web::Query<api_models::payments::ReusablePaymentLinkInitiateQuery>,
+) -> impl Responder {
+    let flow = Flow::ReusablePaymentLinkInitiate;
+    let (merchant_id, reusable_payment_link_id) = path.into_inner();
+    let payload = api_models::payments::ReusablePaymentLinkInitiateRequest {
+        merchant_id: merchant_id.clone(),
+        reusable_payment_link_id,
+        amount: query_payload.into_inner().amount,
+    };
+    let request_host = req.connection_info().host().to_owned();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, req_state| {
+            initiate_reusable_payment_link_flow(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                Some(request_host.clone()),
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Link - QR Code
+///
+/// To retrieve the QR code of a payment link as a PNG or SVG image
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}/qr_code",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link"),
+        ("format" = Option<QrCodeImageFormat>, Query, description = "The image format of the QR code, defaults to png"),
+        ("size" = Option<u32>, Query, description = "The minimum width and height of the QR code, in pixels"),
+    ),
+    responses(
+        (status = 200, description = "QR code of the payment link"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve the QR Code of a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkQrCode))]
+pub async fn payment_link_qr_code(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payments::PaymentLinkQrCodeRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkQrCode;
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, payload, _| {
+            get_payment_link_qr_code_by_payment_link_id(
+                state,
+                auth.merchant_account,
+                payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Reusable Payment Link - QR Code
+///
+/// To retrieve the QR code of a reusable payment link as a PNG or SVG image
+#[utoipa::path(
+    get,
+    path = "/payment_link/reusable/{reusable_payment_link_id}/qr_code",
+    params(
+        ("reusable_payment_link_id" = String, Path, description = "The identifier for the reusable payment link"),
+        ("format" = Option<QrCodeImageFormat>, Query, description = "The image format of the QR code, defaults to png"),
+        ("size" = Option<u32>, Query, description = "The minimum width and height of the QR code, in pixels"),
+    ),
+    responses(
+        (status = 200, description = "QR code of the reusable payment link"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve the QR Code of a Reusable Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkQrCode))]
+pub async fn reusable_payment_link_qr_code(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payments::PaymentLinkQrCodeRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkQrCode;
+    let reusable_payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth, payload, _| {
+            get_reusable_payment_link_qr_code(
+                state,
+                auth.merchant_account,
+                reusable_payment_link_id.clone(),
+                payload,
+            )
+        },
+        &auth::ApiKeyAuth(Permission::PaymentRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -7,7 +7,10 @@ use time::PrimitiveDateTime;
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, errors, payment_methods::cards},
+    core::{
+        api_locking, errors,
+        payment_methods::{cards, network_tokenization},
+    },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::payment_methods::{self, PaymentMethodId},
@@ -38,7 +41,7 @@ pub async fn create_payment_method_api(
             ))
             .await
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -54,7 +57,11 @@ pub async fn save_payment_method_api(
     let flow = Flow::PaymentMethodSave;
     let payload = json_payload.into_inner();
     let pm_id = path.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::CustomerWrite,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -87,7 +94,11 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -139,11 +150,17 @@ pub async fn list_customer_payment_method_api(
     let payload = query_payload.into_inner();
     let customer_id = customer_id.into_inner().0;
 
-    let ephemeral_auth =
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let ephemeral_auth = match auth::is_ephemeral_auth(
+        req.headers(),
+        &*state.store,
+        &customer_id,
+        Permission::CustomerRead,
+    )
+    .await
+    {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -195,7 +212,11 @@ pub async fn list_customer_payment_method_api_client(
 ) -> HttpResponse {
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::CustomerRead,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -240,7 +261,7 @@ pub async fn payment_method_retrieve_api(
         |state, auth, pm, _| {
             cards::retrieve_payment_method(state, pm, auth.key_store, auth.merchant_account)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -257,7 +278,11 @@ pub async fn payment_method_update_api(
     let payment_method_id = path.into_inner();
     let payload = json_payload.into_inner();
 
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::CustomerWrite,
+    ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
@@ -300,7 +325,28 @@ pub async fn payment_method_delete_api(
         |state, auth, req, _| {
             cards::delete_payment_method(state, auth.merchant_account, req, auth.key_store)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::CustomerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenWebhook))]
+pub async fn network_token_webhook_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> HttpResponse {
+    let flow = Flow::NetworkTokenWebhook;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| {
+            network_tokenization::handle_network_token_webhook(state, req.headers(), &body)
+        },
+        &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -324,7 +370,7 @@ pub async fn list_countries_currencies_for_connector_payment_method(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::MerchantConnectorAccountWrite),
             &auth::JWTAuth(Permission::MerchantConnectorAccountWrite),
             req.headers(),
         ),
@@ -345,25 +391,29 @@ pub async fn default_payment_method_set_api(
     let payload = path.into_inner();
     let customer_id = payload.clone().customer_id;
 
-    let ephemeral_auth =
-        match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
-    let db = &*state.store.clone();
+    let ephemeral_auth = match auth::is_ephemeral_auth(
+        req.headers(),
+        &*state.store,
+        &customer_id,
+        Permission::CustomerWrite,
+    )
+    .await
+    {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
-        |_state, auth: auth::AuthenticationData, default_payment_method, _| {
+        |state, auth: auth::AuthenticationData, default_payment_method, _| {
             cards::set_default_payment_method(
-                db,
-                auth.merchant_account.merchant_id,
+                state,
+                auth.merchant_account,
                 auth.key_store,
                 &customer_id,
                 default_payment_method.payment_method_id,
-                auth.merchant_account.storage_scheme,
             )
         },
         &*ephemeral_auth,
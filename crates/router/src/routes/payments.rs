@@ -11,6 +11,8 @@ use masking::PeekInterface;
 use router_env::{env, instrument, tracing, types, Flow};
 
 use super::app::ReqState;
+#[cfg(feature = "frm")]
+use crate::core::fraud_check::review_queue as frm_review_queue;
 use crate::{
     self as app,
     core::{
@@ -137,9 +139,9 @@ pub async fn payments_create(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 req.headers(),
             ),
@@ -257,11 +259,14 @@ pub async fn payments_retrieve(
     tracing::Span::current().record("payment_id", &path.to_string());
     tracing::Span::current().record("flow", &flow.to_string());
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentRead,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -315,10 +320,11 @@ pub async fn payments_retrieve_with_gateway_creds(
     req: actix_web::HttpRequest,
     json_payload: web::Json<payment_types::PaymentRetrieveBodyWithCredentials>,
 ) -> impl Responder {
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentRead) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: payment_types::PaymentIdType::PaymentIntentId(
             json_payload.payment_id.to_string(),
@@ -401,10 +407,11 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Permission::PaymentWrite) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -478,11 +485,14 @@ pub async fn payments_confirm(
         }
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -564,7 +574,7 @@ pub async fn payments_capture(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -819,11 +829,14 @@ pub async fn payments_complete_authorize(
         ..Default::default()
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payment_confirm_req) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payment_confirm_req,
+        Permission::PaymentWrite,
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
@@ -910,7 +923,7 @@ pub async fn payments_cancel(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -956,7 +969,56 @@ pub async fn payments_list(
         payload,
         |state, auth, req, _| payments::list_payments(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PaymentRead),
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Payments - List Authorizations Expiring Soon
+///
+/// To list the authorized payments whose authorization is expected to expire within the given number of hours, earliest expiry first
+#[utoipa::path(
+    get,
+    path = "/payments/authorizations/expiring_soon",
+    params(
+        ("within_hours" = Option<u32>, Query, description = "Number of hours ahead of now within which the authorization is expected to expire, defaults to 24"),
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+    ),
+    responses(
+        (status = 200, description = "The authorizations expiring soon were retrieved successfully", body = Vec<PaymentAuthorizationExpiryResponse>),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Payments",
+    operation_id = "List Authorizations Expiring Soon",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsAuthorizationsExpiringSoonList))]
+#[cfg(feature = "olap")]
+pub async fn payments_authorizations_expiring_soon(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<payment_types::PaymentAuthorizationExpiringSoonConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentsAuthorizationsExpiringSoonList;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payments::authorization_expiry::list_authorizations_expiring_soon(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::PaymentRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -964,6 +1026,7 @@ pub async fn payments_list(
     )
     .await
 }
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(feature = "olap")]
 pub async fn payments_list_by_filter(
@@ -1054,15 +1117,21 @@ pub async fn payments_approve(
         state,
         &http_req,
         payload.clone(),
-        |state, auth, req, req_state| {
-            payments::payments_core::<api_types::Capture, payment_types::PaymentsResponse, _, _, _>(
-                state,
+        |state, auth: auth::AuthenticationData, req, req_state| async move {
+            let response = payments::payments_core::<
+                api_types::Capture,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
                 req_state,
-                auth.merchant_account,
-                auth.key_store,
+                auth.merchant_account.clone(),
+                auth.key_store.clone(),
                 payments::PaymentApprove,
                 payment_types::PaymentsCaptureRequest {
-                    payment_id: req.payment_id,
+                    payment_id: req.payment_id.clone(),
                     ..Default::default()
                 },
                 api::AuthFlow::Merchant,
@@ -1070,11 +1139,26 @@ pub async fn payments_approve(
                 None,
                 HeaderPayload::default(),
             )
+            .await;
+
+            #[cfg(feature = "frm")]
+            if response.is_ok() {
+                frm_review_queue::record_review_decision(
+                    &state,
+                    &auth.merchant_account,
+                    &auth.key_store,
+                    &req.payment_id,
+                    api_models::enums::FrmReviewDecision::Approved,
+                )
+                .await;
+            }
+
+            response
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1108,15 +1192,21 @@ pub async fn payments_reject(
         state,
         &http_req,
         payload.clone(),
-        |state, auth, req, req_state| {
-            payments::payments_core::<api_types::Void, payment_types::PaymentsResponse, _, _, _>(
-                state,
+        |state, auth: auth::AuthenticationData, req, req_state| async move {
+            let response = payments::payments_core::<
+                api_types::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
                 req_state,
-                auth.merchant_account,
-                auth.key_store,
+                auth.merchant_account.clone(),
+                auth.key_store.clone(),
                 payments::PaymentReject,
                 payment_types::PaymentsCancelRequest {
-                    payment_id: req.payment_id,
+                    payment_id: req.payment_id.clone(),
                     cancellation_reason: Some("Rejected by merchant".to_string()),
                     ..Default::default()
                 },
@@ -1125,11 +1215,26 @@ pub async fn payments_reject(
                 None,
                 HeaderPayload::default(),
             )
+            .await;
+
+            #[cfg(feature = "frm")]
+            if response.is_ok() {
+                frm_review_queue::record_review_decision(
+                    &state,
+                    &auth.merchant_account,
+                    &auth.key_store,
+                    &req.payment_id,
+                    api_models::enums::FrmReviewDecision::Rejected,
+                )
+                .await;
+            }
+
+            response
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ApiKeyAuth(Permission::PaymentWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::PaymentWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1271,7 +1376,7 @@ pub async fn payments_incremental_authorization(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentWrite),
         locking_action,
     ))
     .await
@@ -1407,7 +1512,7 @@ pub async fn retrieve_extended_card_info(
         |state, auth, payment_id, _| {
             payments::get_extended_card_info(state, auth.merchant_account.merchant_id, payment_id)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PaymentRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
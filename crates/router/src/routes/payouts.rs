@@ -41,7 +41,7 @@ pub async fn payouts_create(
         |state, auth, req, _| {
             payouts_create_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -83,7 +83,7 @@ pub async fn payouts_retrieve(
             payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PayoutRead),
             &auth::JWTAuth(Permission::PayoutRead),
             req.headers(),
         ),
@@ -126,7 +126,7 @@ pub async fn payouts_update(
         |state, auth, req, _| {
             payouts_update_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -166,7 +166,7 @@ pub async fn payouts_cancel(
         |state, auth, req, _| {
             payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -206,7 +206,7 @@ pub async fn payouts_fulfill(
         |state, auth, req, _| {
             payouts_fulfill_core(state, auth.merchant_account, auth.key_store, req)
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::PayoutWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -241,7 +241,7 @@ pub async fn payouts_list(
         payload,
         |state, auth, req, _| payouts_list_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PayoutRead),
             &auth::JWTAuth(Permission::PayoutRead),
             req.headers(),
         ),
@@ -281,7 +281,7 @@ pub async fn payouts_list_by_filter(
             payouts_filtered_list_core(state, auth.merchant_account, auth.key_store, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PayoutRead),
             &auth::JWTAuth(Permission::PayoutRead),
             req.headers(),
         ),
@@ -321,7 +321,7 @@ pub async fn payouts_list_available_filters(
             payouts_list_available_filters_core(state, auth.merchant_account, req)
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::PayoutRead),
             &auth::JWTAuth(Permission::PayoutRead),
             req.headers(),
         ),
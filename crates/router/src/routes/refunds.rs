@@ -38,7 +38,7 @@ pub async fn refunds_create(
         json_payload.into_inner(),
         |state, auth, req, _| refund_create_core(state, auth.merchant_account, auth.key_store, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundWrite),
             &auth::JWTAuth(Permission::RefundWrite),
             req.headers(),
         ),
@@ -98,7 +98,7 @@ pub async fn refunds_retrieve(
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -148,7 +148,7 @@ pub async fn refunds_retrieve_with_body(
                 refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -188,7 +188,7 @@ pub async fn refunds_update(
         &req,
         refund_update_req,
         |state, auth, req, _| refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        &auth::ApiKeyAuth(Permission::RefundWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -222,7 +222,7 @@ pub async fn refunds_list(
         payload.into_inner(),
         |state, auth, req, _| refund_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -260,7 +260,7 @@ pub async fn refunds_filter_list(
         payload.into_inner(),
         |state, auth, req, _| refund_filter_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -293,7 +293,7 @@ pub async fn get_refunds_filters(state: web::Data<AppState>, req: HttpRequest) -
         (),
         |state, auth, _, _| get_filters_for_refunds(state, auth.merchant_account),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RefundRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
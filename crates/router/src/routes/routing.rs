@@ -11,6 +11,8 @@ use router_env::{
     Flow,
 };
 
+#[cfg(all(feature = "olap", feature = "frm"))]
+use crate::core::fraud_check::rules as frm_rules;
 use crate::{
     core::{api_locking, conditional_config, routing, surcharge_decision_config},
     routes::AppState,
@@ -31,18 +33,19 @@ pub async fn routing_create_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload, _| {
+        |state, auth: auth::AuthenticationData, payload, req_state| {
             routing::create_routing_config(
                 state,
                 auth.merchant_account,
                 auth.key_store,
                 payload,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -67,7 +70,7 @@ pub async fn routing_link_config(
         state,
         &req,
         path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+        |state, auth: auth::AuthenticationData, algorithm_id, req_state| {
             routing::link_routing_config(
                 state,
                 auth.merchant_account,
@@ -75,11 +78,12 @@ pub async fn routing_link_config(
                 auth.key_store,
                 algorithm_id.0,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -109,7 +113,7 @@ pub async fn routing_retrieve_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -146,7 +150,7 @@ pub async fn list_routing_configs(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -170,7 +174,7 @@ pub async fn list_routing_configs(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -200,17 +204,18 @@ pub async fn routing_unlink_config(
             state,
             &req,
             payload.into_inner(),
-            |state, auth: auth::AuthenticationData, payload_req, _| {
+            |state, auth: auth::AuthenticationData, payload_req, req_state| {
                 routing::unlink_routing_config(
                     state,
                     auth.merchant_account,
                     payload_req,
                     transaction_type,
+                    req_state,
                 )
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingWrite),
                 &auth::JWTAuth(Permission::RoutingWrite),
                 req.headers(),
             ),
@@ -229,17 +234,18 @@ pub async fn routing_unlink_config(
             state,
             &req,
             (),
-            |state, auth: auth::AuthenticationData, _, _| {
+            |state, auth: auth::AuthenticationData, _, req_state| {
                 routing::unlink_routing_config(
                     state,
                     auth.merchant_account,
                     auth.key_store,
                     transaction_type,
+                    req_state,
                 )
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingWrite),
                 &auth::JWTAuth(Permission::RoutingWrite),
                 req.headers(),
             ),
@@ -264,17 +270,18 @@ pub async fn routing_update_default_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, updated_config, _| {
+        |state, auth: auth::AuthenticationData, updated_config, req_state| {
             routing::update_default_routing_config(
                 state,
                 auth.merchant_account,
                 updated_config,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
@@ -302,7 +309,7 @@ pub async fn routing_retrieve_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -326,17 +333,18 @@ pub async fn upsert_surcharge_decision_manager_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, update_decision, _| {
+        |state, auth: auth::AuthenticationData, update_decision, req_state| {
             surcharge_decision_config::upsert_surcharge_decision_config(
                 state,
                 auth.key_store,
                 auth.merchant_account,
                 update_decision,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -358,16 +366,17 @@ pub async fn delete_surcharge_decision_manager_config(
         state,
         &req,
         (),
-        |state, auth: auth::AuthenticationData, (), _| {
+        |state, auth: auth::AuthenticationData, (), req_state| {
             surcharge_decision_config::delete_surcharge_decision_config(
                 state,
                 auth.key_store,
                 auth.merchant_account,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -398,7 +407,7 @@ pub async fn retrieve_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -422,17 +431,18 @@ pub async fn upsert_decision_manager_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, update_decision, _| {
+        |state, auth: auth::AuthenticationData, update_decision, req_state| {
             conditional_config::upsert_conditional_config(
                 state,
                 auth.key_store,
                 auth.merchant_account,
                 update_decision,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -455,16 +465,17 @@ pub async fn delete_decision_manager_config(
         state,
         &req,
         (),
-        |state, auth: auth::AuthenticationData, (), _| {
+        |state, auth: auth::AuthenticationData, (), req_state| {
             conditional_config::delete_conditional_config(
                 state,
                 auth.key_store,
                 auth.merchant_account,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerWrite),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerWrite),
             req.headers(),
         ),
@@ -492,7 +503,7 @@ pub async fn retrieve_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::SurchargeDecisionManagerRead),
             &auth::JWTAuth(Permission::SurchargeDecisionManagerRead),
             req.headers(),
         ),
@@ -503,6 +514,91 @@ pub async fn retrieve_decision_manager_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "frm"))]
+#[instrument(skip_all)]
+pub async fn upsert_frm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::frm_rules::FrmRulesRequest>,
+) -> impl Responder {
+    let flow = Flow::FrmRulesUpsert;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            frm_rules::upsert_frm_rules(
+                state,
+                auth.key_store,
+                auth.merchant_account,
+                payload,
+                req_state,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
+            &auth::JWTAuth(Permission::RoutingWrite),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "frm"))]
+#[instrument(skip_all)]
+pub async fn retrieve_frm_rules(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::FrmRulesRetrieve;
+    oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            frm_rules::retrieve_frm_rules(state, auth.merchant_account)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::RoutingRead),
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "frm"))]
+#[instrument(skip_all)]
+pub async fn delete_frm_rules(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::FrmRulesDelete;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, (), req_state| {
+            frm_rules::delete_frm_rules(state, auth.merchant_account, req_state)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
+            &auth::JWTAuth(Permission::RoutingWrite),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn routing_retrieve_linked_config(
@@ -530,7 +626,7 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -554,7 +650,7 @@ pub async fn routing_retrieve_linked_config(
             },
             #[cfg(not(feature = "release"))]
             auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ApiKeyAuth(Permission::RoutingRead),
                 &auth::JWTAuth(Permission::RoutingRead),
                 req.headers(),
             ),
@@ -587,13 +683,13 @@ pub async fn routing_retrieve_default_config_for_profiles(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
         #[cfg(feature = "release")]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingRead),
             &auth::JWTAuth(Permission::RoutingRead),
             req.headers(),
         ),
@@ -620,18 +716,19 @@ pub async fn routing_update_default_config_for_profile(
         state,
         &req,
         routing_payload_wrapper,
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             routing::update_default_routing_config_for_profile(
                 state,
                 auth.merchant_account,
                 wrapper.updated_config,
                 wrapper.profile_id,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ApiKeyAuth(Permission::RoutingWrite),
             &auth::JWTAuth(Permission::RoutingWrite),
             req.headers(),
         ),
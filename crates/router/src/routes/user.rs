@@ -665,3 +665,90 @@ pub async fn totp_verify(
     ))
     .await
 }
+
+pub async fn oidc_sso_authorize(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<user_api::OidcSsoAuthorizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::OidcSsoAuthorize;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        query.into_inner(),
+        |state, _, req_body, _| user_core::sso::authorize_oidc_sso(state, req_body),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn oidc_sso_callback(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::OidcSsoCallbackRequest>,
+) -> HttpResponse {
+    let flow = Flow::OidcSsoCallback;
+    let browser_binding = auth::get_cookie_from_header(req.headers())
+        .ok()
+        .and_then(auth::cookies::get_oidc_sso_state_from_cookies);
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req_body, _| {
+            user_core::sso::oidc_sso_callback(state, req_body, browser_binding.clone())
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_oidc_sso_config(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::OidcSsoConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::sso::get_oidc_sso_config(state, user),
+        &auth::JWTAuth(Permission::UsersRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn upsert_oidc_sso_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::OidcSsoConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::OidcSsoConfigUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        user_core::sso::upsert_oidc_sso_config,
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_oidc_sso_config(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::OidcSsoConfigDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, req_state| user_core::sso::delete_oidc_sso_config(state, user, req_state),
+        &auth::JWTAuth(Permission::UsersWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
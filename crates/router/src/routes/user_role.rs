@@ -160,7 +160,7 @@ pub async fn update_role(
         state.clone(),
         &req,
         json_payload.into_inner(),
-        |state, user, req, _| role_core::update_role(state, user, req, &role_id),
+        |state, user, req, req_state| role_core::update_role(state, user, req, &role_id, req_state),
         &auth::JWTAuth(Permission::UsersWrite),
         api_locking::LockAction::NotApplicable,
     ))
@@ -7,7 +7,9 @@ use crate::{
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryBulkRetryRequest, WebhookDeliveryBulkRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookDeliveryStatsConstraints,
+        WebhookDeliveryStatsRequestInternal,
     },
 };
 
@@ -129,3 +131,83 @@ pub async fn retry_webhook_delivery_attempt(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventBulkDeliveryRetry))]
+pub async fn bulk_retry_webhook_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<WebhookDeliveryBulkRetryRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventBulkDeliveryRetry;
+    let merchant_id_or_profile_id = path.into_inner();
+
+    let request_internal = WebhookDeliveryBulkRetryRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::bulk_retry_delivery_attempts(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryStats))]
+pub async fn get_webhook_delivery_stats(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<WebhookDeliveryStatsConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryStats;
+    let merchant_id_or_profile_id = path.into_inner();
+
+    let request_internal = WebhookDeliveryStatsRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        constraints: query.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::get_delivery_stats(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
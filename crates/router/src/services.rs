@@ -53,6 +53,18 @@ pub async fn get_store(
         .map(StrongSecret::new)
         .expect("Failed to decode master key from hex");
 
+    #[allow(clippy::expect_used)]
+    let previous_master_enc_key = config
+        .secrets
+        .get_inner()
+        .previous_master_enc_key
+        .clone()
+        .map(|previous_master_enc_key| {
+            hex::decode(previous_master_enc_key.expose())
+                .map(StrongSecret::new)
+                .expect("Failed to decode previous master key from hex")
+        });
+
     #[cfg(not(feature = "olap"))]
     let conf = master_config.into();
     #[cfg(feature = "olap")]
@@ -72,6 +84,9 @@ pub async fn get_store(
         )
         .await?
     };
+    // Key stores wrapped with the previous master key remain readable until they are re-wrapped
+    // by a master key rotation
+    let store = store.with_previous_master_key(previous_master_enc_key);
 
     #[cfg(feature = "kv_store")]
     let store = KVRouterStore::from_store(
@@ -865,6 +865,7 @@ pub enum ApplicationResponse<R> {
 pub enum PaymentLinkAction {
     PaymentLinkFormData(PaymentLinkFormData),
     PaymentLinkStatus(PaymentLinkStatusData),
+    PaymentLinkAmountForm(PaymentLinkStatusData),
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
@@ -1016,6 +1017,7 @@ where
         .switch()?;
 
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -1247,6 +1249,18 @@ where
                         ),
                     }
                 }
+                PaymentLinkAction::PaymentLinkAmountForm(payment_link_data) => {
+                    match get_payment_link_amount_form(payment_link_data) {
+                        Ok(rendered_html) => http_response_html_data(rendered_html),
+                        Err(_) => http_response_err(
+                            r#"{
+                                "error": {
+                                    "message": "Error while rendering payment link amount page"
+                                }
+                            }"#,
+                        ),
+                    }
+                }
             }
         }
 
@@ -2100,6 +2114,58 @@ pub fn get_payment_link_status(
     }
 }
 
+pub fn get_payment_link_amount_form(
+    payment_link_data: PaymentLinkStatusData,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let mut tera = Tera::default();
+
+    // Add modification to css template with dynamic data
+    let css_template =
+        include_str!("../core/payment_link/payment_link_amount/amount.css").to_string();
+    let _ = tera.add_raw_template("payment_link_css", &css_template);
+    let mut context = Context::new();
+    context.insert("css_color_scheme", &payment_link_data.css_script);
+
+    let rendered_css = match tera.render("payment_link_css", &context) {
+        Ok(rendered_css) => rendered_css,
+        Err(tera_error) => {
+            crate::logger::warn!("{tera_error}");
+            Err(errors::ApiErrorResponse::InternalServerError)?
+        }
+    };
+
+    // Add modification to js template with dynamic data
+    let js_template =
+        include_str!("../core/payment_link/payment_link_amount/amount.js").to_string();
+    let _ = tera.add_raw_template("payment_link_js", &js_template);
+    context.insert("payment_details_js_script", &payment_link_data.js_script);
+
+    let rendered_js = match tera.render("payment_link_js", &context) {
+        Ok(rendered_js) => rendered_js,
+        Err(tera_error) => {
+            crate::logger::warn!("{tera_error}");
+            Err(errors::ApiErrorResponse::InternalServerError)?
+        }
+    };
+
+    // Modify Html template with rendered js and rendered css files
+    let html_template =
+        include_str!("../core/payment_link/payment_link_amount/amount.html").to_string();
+    let _ = tera.add_raw_template("payment_link_amount", &html_template);
+
+    context.insert("rendered_css", &rendered_css);
+
+    context.insert("rendered_js", &rendered_js);
+
+    match tera.render("payment_link_amount", &context) {
+        Ok(rendered_html) => Ok(rendered_html),
+        Err(tera_error) => {
+            crate::logger::warn!("{tera_error}");
+            Err(errors::ApiErrorResponse::InternalServerError)?
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
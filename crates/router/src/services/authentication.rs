@@ -34,7 +34,7 @@ use crate::{
     db::StorageInterface,
     routes::app::AppStateInfo,
     services::api,
-    types::domain,
+    types::{domain, storage},
     utils::OptionExt,
 };
 pub mod blacklist;
@@ -235,8 +235,11 @@ where
     ) -> RouterResult<(T, AuthenticationType)>;
 }
 
+/// Authenticates the request using an API key. API keys restricted to a set of permission groups
+/// can only access APIs requiring a permission from those groups, and API keys restricted to a set
+/// of IP addresses can only be used from those IP addresses.
 #[derive(Debug)]
-pub struct ApiKeyAuth;
+pub struct ApiKeyAuth(pub Permission);
 
 pub struct NoAuth;
 
@@ -297,6 +300,13 @@ where
                 .attach_printable("API key has expired");
         }
 
+        check_api_key_restrictions(
+            &stored_api_key,
+            &self.0,
+            request_headers,
+            state.conf().api_keys.get_inner().trusted_proxy_count,
+        )?;
+
         let key_store = state
             .store()
             .get_merchant_key_store_by_merchant_id(
@@ -327,6 +337,60 @@ where
     }
 }
 
+fn check_api_key_restrictions(
+    api_key: &storage::ApiKey,
+    required_permission: &Permission,
+    request_headers: &HeaderMap,
+    trusted_proxy_count: usize,
+) -> RouterResult<()> {
+    if let Some(permission_groups) = &api_key.permission_groups {
+        let permissions = authorization::get_permissions_from_groups(permission_groups);
+        if !permissions.contains(required_permission) {
+            return Err(report!(errors::ApiErrorResponse::ApiKeyAccessForbidden {
+                message: format!(
+                    "The API key does not have the {required_permission} permission required to perform this operation"
+                ),
+            }));
+        }
+    }
+
+    if let Some(allowed_ips) = &api_key.allowed_ips {
+        match get_ip_address_from_headers(request_headers, trusted_proxy_count) {
+            Some(ip_address) if api_keys::is_ip_address_allowed(allowed_ips, ip_address) => {}
+            Some(ip_address) => {
+                return Err(report!(errors::ApiErrorResponse::ApiKeyAccessForbidden {
+                    message: format!("The API key cannot be used from the IP address {ip_address}"),
+                }));
+            }
+            None => {
+                return Err(report!(errors::ApiErrorResponse::ApiKeyAccessForbidden {
+                    message: "The API key can only be used from allowed IP addresses, but the IP address of the request could not be determined".to_string(),
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches the IP address of the client from the `X-Forwarded-For` header. Each trusted proxy
+/// appends the address of its peer to the header, so the client IP address is the entry appended
+/// by the outermost trusted proxy, counting from the right. Entries to its left are set by the
+/// client and cannot be trusted.
+fn get_ip_address_from_headers(
+    headers: &HeaderMap,
+    trusted_proxy_count: usize,
+) -> Option<std::net::IpAddr> {
+    let forwarded_for = headers
+        .get(crate::headers::X_FORWARDED_FOR)
+        .and_then(|value| value.to_str().ok())?;
+
+    forwarded_for
+        .rsplit(',')
+        .nth(trusted_proxy_count.checked_sub(1)?)
+        .and_then(|ip_address| ip_address.trim().parse().ok())
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug)]
 pub(crate) struct SinglePurposeJWTAuth(pub TokenPurpose);
@@ -989,6 +1053,7 @@ impl ClientSecretFetch for api_models::payment_methods::PaymentMethodUpdate {
 
 pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
+    required_permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, A>>,
     api::AuthFlow,
@@ -998,12 +1063,16 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((
+        Box::new(ApiKeyAuth(required_permission)),
+        api::AuthFlow::Merchant,
+    ))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    required_permission: Permission,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, T>>,
     api::AuthFlow,
@@ -1031,18 +1100,22 @@ where
         }
         .into());
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((
+        Box::new(ApiKeyAuth(required_permission)),
+        api::AuthFlow::Merchant,
+    ))
 }
 
 pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
     db: &dyn StorageInterface,
     customer_id: &str,
+    required_permission: Permission,
 ) -> RouterResult<Box<dyn AuthenticateAndFetch<AuthenticationData, A>>> {
     let api_key = get_api_key(headers)?;
 
     if !api_key.starts_with("epk") {
-        return Ok(Box::new(ApiKeyAuth));
+        return Ok(Box::new(ApiKeyAuth(required_permission)));
     }
 
     let ephemeral_key = db
@@ -1192,3 +1265,46 @@ impl AuthenticateAndFetch<ReconUser, AppState> for ReconJWT {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn headers_with_forwarded_for(forwarded_for: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            crate::headers::X_FORWARDED_FOR,
+            forwarded_for.parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_ip_address_is_taken_from_trusted_proxy_entry() {
+        let headers = headers_with_forwarded_for("1.1.1.1, 10.0.0.2, 10.0.0.1");
+
+        assert_eq!(
+            get_ip_address_from_headers(&headers, 1),
+            Some("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(
+            get_ip_address_from_headers(&headers, 2),
+            Some("10.0.0.2".parse().unwrap())
+        );
+        assert_eq!(get_ip_address_from_headers(&headers, 4), None);
+        assert_eq!(get_ip_address_from_headers(&headers, 0), None);
+    }
+
+    #[test]
+    fn test_spoofed_ip_address_is_ignored() {
+        // The client prepends an allowed IP address, which the load balancer does not overwrite
+        let headers = headers_with_forwarded_for("192.168.1.1, 203.0.113.7");
+
+        assert_eq!(
+            get_ip_address_from_headers(&headers, 1),
+            Some("203.0.113.7".parse().unwrap())
+        );
+        assert_eq!(get_ip_address_from_headers(&HeaderMap::new(), 1), None);
+    }
+}
@@ -16,7 +16,10 @@ use crate::{
 };
 #[cfg(feature = "olap")]
 use crate::{
-    consts::JWT_TOKEN_TIME_IN_SECS,
+    consts::{
+        user::{OIDC_SSO_STATE_COOKIE_NAME, OIDC_SSO_STATE_EXPIRY_IN_SECS},
+        JWT_TOKEN_TIME_IN_SECS,
+    },
     core::errors::{UserErrors, UserResponse},
     services::ApplicationResponse,
 };
@@ -49,18 +52,52 @@ pub fn remove_cookie_response() -> UserResponse<()> {
     Ok(ApplicationResponse::JsonWithHeaders(((), header)))
 }
 
+/// Binds an OIDC authorization to the browser which started it, the callback is only accepted
+/// from a browser presenting the same cookie
+#[cfg(feature = "olap")]
+pub fn set_oidc_sso_state_cookie_response<R>(
+    response: R,
+    browser_binding: Secret<String>,
+) -> UserResponse<R> {
+    let (expiry, max_age) = get_expiry_and_max_age_from_seconds(OIDC_SSO_STATE_EXPIRY_IN_SECS);
+
+    // `Lax` as the user reaches the callback through a top level navigation from the identity
+    // provider
+    let header_value = Cookie::build((OIDC_SSO_STATE_COOKIE_NAME, browser_binding.expose()))
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Lax)
+        .path("/")
+        .expires(expiry)
+        .max_age(max_age)
+        .build()
+        .to_string()
+        .into_masked();
+    let header = vec![(get_set_cookie_header(), header_value)];
+
+    Ok(ApplicationResponse::JsonWithHeaders((response, header)))
+}
+
 pub fn parse_cookie(cookies: &str) -> RouterResult<String> {
-    Cookie::split_parse(cookies)
-        .find_map(|cookie| {
-            cookie
-                .ok()
-                .filter(|parsed_cookie| parsed_cookie.name() == JWT_TOKEN_COOKIE_NAME)
-                .map(|parsed_cookie| parsed_cookie.value().to_owned())
-        })
+    find_cookie_value(cookies, JWT_TOKEN_COOKIE_NAME)
         .ok_or(report!(ApiErrorResponse::InvalidCookie))
         .attach_printable("Cookie Parsing Failed")
 }
 
+#[cfg(feature = "olap")]
+pub fn get_oidc_sso_state_from_cookies(cookies: &str) -> Option<Secret<String>> {
+    find_cookie_value(cookies, OIDC_SSO_STATE_COOKIE_NAME).map(Secret::new)
+}
+
+fn find_cookie_value(cookies: &str, name: &str) -> Option<String> {
+    Cookie::split_parse(cookies).find_map(|cookie| {
+        cookie
+            .ok()
+            .filter(|parsed_cookie| parsed_cookie.name() == name)
+            .map(|parsed_cookie| parsed_cookie.value().to_owned())
+    })
+}
+
 #[cfg(feature = "olap")]
 fn create_cookie<'c>(
     token: Secret<String>,
@@ -92,3 +129,26 @@ fn get_set_cookie_header() -> String {
 pub fn get_cookie_header() -> String {
     actix_http::header::COOKIE.to_string()
 }
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::PeekInterface;
+
+    use super::*;
+
+    #[test]
+    fn test_get_oidc_sso_state_from_cookies() {
+        let cookies = format!("{JWT_TOKEN_COOKIE_NAME}=jwt; {OIDC_SSO_STATE_COOKIE_NAME}=binding");
+
+        assert_eq!(
+            get_oidc_sso_state_from_cookies(&cookies)
+                .unwrap()
+                .peek()
+                .as_str(),
+            "binding"
+        );
+        assert_eq!(parse_cookie(&cookies).unwrap(), "jwt");
+        assert!(get_oidc_sso_state_from_cookies(&format!("{JWT_TOKEN_COOKIE_NAME}=jwt")).is_none());
+    }
+}
@@ -56,6 +56,31 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    AnalyticsAlert {
+        rule_name: String,
+        metric: String,
+        connector: String,
+        observed_value: f64,
+        baseline_value: f64,
+        drop_threshold: f64,
+        window_start: String,
+        window_end: String,
+    },
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        connector: String,
+        days_left: u8,
+        challenge_required_by: String,
+    },
+    WebhookEndpointDisabled {
+        profile_id: String,
+        profile_name: String,
+        webhook_url: String,
+        failing_since: String,
+    },
 }
 
 pub mod html {
@@ -143,6 +168,56 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::AnalyticsAlert {
+                rule_name,
+                metric,
+                connector,
+                observed_value,
+                baseline_value,
+                drop_threshold,
+                window_start,
+                window_end,
+            } => format!(
+                include_str!("assets/analytics_alert.html"),
+                rule_name = rule_name,
+                metric = metric,
+                connector = connector,
+                observed_value = observed_value,
+                baseline_value = baseline_value,
+                drop_threshold = drop_threshold,
+                window_start = window_start,
+                window_end = window_end,
+            ),
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                connector,
+                days_left,
+                challenge_required_by,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                amount = amount,
+                currency = currency,
+                connector = connector,
+                days_left = days_left,
+                challenge_required_by = challenge_required_by,
+            ),
+            EmailBody::WebhookEndpointDisabled {
+                profile_id,
+                profile_name,
+                webhook_url,
+                failing_since,
+            } => format!(
+                include_str!("assets/webhook_endpoint_disabled.html"),
+                profile_id = profile_id,
+                profile_name = profile_name,
+                webhook_url = webhook_url,
+                failing_since = failing_since,
+            ),
         }
     }
 }
@@ -500,3 +575,101 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct AnalyticsAlertNotification {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub rule_name: String,
+    pub alert: diesel_models::analytics_alert::AnalyticsAlert,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsAlertNotification {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::AnalyticsAlert {
+            rule_name: self.rule_name.clone(),
+            metric: self.alert.metric.to_string(),
+            connector: self
+                .alert
+                .connector
+                .clone()
+                .unwrap_or_else(|| "all connectors".to_string()),
+            observed_value: (self.alert.observed_value * 100.0).round() / 100.0,
+            baseline_value: (self.alert.baseline_value * 100.0).round() / 100.0,
+            drop_threshold: self.alert.drop_threshold,
+            window_start: self.alert.window_start.to_string(),
+            window_end: self.alert.window_end.to_string(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub days_left: u8,
+    pub dispute: diesel_models::dispute::Dispute,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute.dispute_id.clone(),
+            payment_id: self.dispute.payment_id.clone(),
+            amount: self.dispute.amount.clone(),
+            currency: self.dispute.currency.clone(),
+            connector: self.dispute.connector.clone(),
+            days_left: self.days_left,
+            challenge_required_by: self
+                .dispute
+                .challenge_required_by
+                .map(|challenge_required_by| challenge_required_by.to_string())
+                .unwrap_or_default(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct WebhookEndpointDisabled {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub profile_id: String,
+    pub profile_name: String,
+    pub webhook_url: String,
+    pub failing_since: time::PrimitiveDateTime,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointDisabled {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::WebhookEndpointDisabled {
+            profile_id: self.profile_id.clone(),
+            profile_name: self.profile_name.clone(),
+            webhook_url: self.webhook_url.clone(),
+            failing_since: self.failing_since.to_string(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
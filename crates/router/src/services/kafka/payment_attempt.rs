@@ -52,6 +52,13 @@ pub struct KafkaPaymentAttempt<'a> {
     pub unified_code: Option<&'a String>,
     pub unified_message: Option<&'a String>,
     pub mandate_data: Option<&'a MandateDetails>,
+    pub card_network: Option<&'a str>,
+    pub card_issuing_country: Option<&'a str>,
+    pub fx_quote_id: Option<&'a String>,
+    pub presentment_amount: Option<MinorUnit>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub fx_rate: Option<&'a String>,
+    pub fx_markup_bps: Option<i32>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -96,10 +103,26 @@ impl<'a> KafkaPaymentAttempt<'a> {
             unified_code: attempt.unified_code.as_ref(),
             unified_message: attempt.unified_message.as_ref(),
             mandate_data: attempt.mandate_data.as_ref(),
+            card_network: get_additional_card_info(attempt, "card_network"),
+            card_issuing_country: get_additional_card_info(attempt, "card_issuing_country"),
+            fx_quote_id: attempt.fx_quote_id.as_ref(),
+            presentment_amount: attempt.presentment_amount,
+            presentment_currency: attempt.presentment_currency,
+            fx_rate: attempt.fx_rate.as_ref(),
+            fx_markup_bps: attempt.fx_markup_bps,
         }
     }
 }
 
+fn get_additional_card_info<'a>(attempt: &'a PaymentAttempt, key: &str) -> Option<&'a str> {
+    attempt
+        .payment_method_data
+        .as_ref()
+        .and_then(|data| data.get("card"))
+        .and_then(|card| card.get(key))
+        .and_then(serde_json::Value::as_str)
+}
+
 impl<'a> super::KafkaMessage for KafkaPaymentAttempt<'a> {
     fn key(&self) -> String {
         format!(
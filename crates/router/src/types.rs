@@ -355,6 +355,15 @@ pub struct PaymentsAuthorizeData {
     pub request_incremental_authorization: bool,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub authentication_data: Option<AuthenticationData>,
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
+    /// Present when the card in `payment_method_data` was replaced with its network token
+    pub network_token_data: Option<NetworkTokenPaymentData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkTokenPaymentData {
+    /// Cryptogram for the network token, only present for customer initiated transactions
+    pub cryptogram: Option<Secret<String>>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -368,6 +377,7 @@ pub struct PaymentsCaptureData {
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<pii::SecretSerdeValue>,
     // This metadata is used to store the metadata shared during the payment intent request.
+    pub l2_l3_data: Option<api_models::payments::L2L3Data>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1329,6 +1339,8 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             metadata: None,
             authentication_data: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
+            l2_l3_data: None,
+            network_token_data: None,
         }
     }
 }
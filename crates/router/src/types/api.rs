@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod api_keys;
 pub mod authentication;
+pub mod cards_info;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -35,8 +36,8 @@ pub use self::fraud_check::*;
 #[cfg(feature = "payouts")]
 pub use self::payouts::*;
 pub use self::{
-    admin::*, api_keys::*, authentication::*, configs::*, customers::*, disputes::*, files::*,
-    payment_link::*, payment_methods::*, payments::*, poll::*, refunds::*, webhooks::*,
+    admin::*, api_keys::*, authentication::*, cards_info::*, configs::*, customers::*, disputes::*,
+    files::*, payment_link::*, payment_methods::*, payments::*, poll::*, refunds::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
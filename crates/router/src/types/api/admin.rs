@@ -81,6 +81,17 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                 })
                 .transpose()?,
             use_billing_as_payment_method_billing: item.use_billing_as_payment_method_billing,
+            authorization_expiry_policy: item
+                .authorization_expiry_policy
+                .map(|authorization_expiry_policy| {
+                    authorization_expiry_policy.parse_value("AuthorizationExpiryPolicy")
+                })
+                .transpose()?,
+            webhook_retry_policy: item
+                .webhook_retry_policy
+                .map(|webhook_retry_policy| webhook_retry_policy.parse_value("WebhookRetryPolicy"))
+                .transpose()?,
+            webhook_endpoint_disabled_at: item.webhook_endpoint_disabled_at,
         })
     }
 }
@@ -185,6 +196,24 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(Some(true)),
             collect_shipping_details_from_wallet_connector: request
                 .collect_shipping_details_from_wallet_connector,
+            authorization_expiry_policy: request
+                .authorization_expiry_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "authorization_expiry_policy",
+                })?,
+            webhook_retry_policy: request
+                .webhook_retry_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "webhook_retry_policy",
+                })?,
+            webhook_endpoint_failing_since: None,
+            webhook_endpoint_disabled_at: None,
         })
     }
 }
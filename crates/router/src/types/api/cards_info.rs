@@ -0,0 +1,14 @@
+pub use api_models::cards_info::{
+    CardInfoChange, CardInfoChangeType, CardInfoImportError, CardInfoImportResponse,
+};
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CardInfoImportRequest {
+    /// Provider the BIN records were sourced from, eg. the name and release of the BIN database
+    pub provider: String,
+    /// Whether the records should only be diffed against the stored records, without writing them
+    pub dry_run: bool,
+    /// CSV file with a header row, holding one BIN record per line
+    #[serde(skip)]
+    pub file: Vec<u8>,
+}
@@ -51,6 +51,8 @@ impl VerifyConnectorData {
             statement_descriptor_suffix: None,
             request_incremental_authorization: false,
             authentication_data: None,
+            l2_l3_data: None,
+            network_token_data: None,
             customer_acceptance: None,
         }
     }
@@ -74,6 +74,7 @@ impl behaviour::Conversion for CustomerAddress {
     async fn convert_back(
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError> {
         let customer_id =
             other
@@ -83,7 +84,7 @@ impl behaviour::Conversion for CustomerAddress {
                     field_name: "customer_id".to_string(),
                 })?;
 
-        let address = Address::convert_back(other, key).await?;
+        let address = Address::convert_back(other, key, previous_key).await?;
 
         Ok(Self {
             address,
@@ -118,6 +119,7 @@ impl behaviour::Conversion for PaymentAddress {
     async fn convert_back(
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError> {
         let payment_id = other
             .payment_id
@@ -128,7 +130,7 @@ impl behaviour::Conversion for PaymentAddress {
 
         let customer_id = other.customer_id.clone();
 
-        let address = Address::convert_back(other, key).await?;
+        let address = Address::convert_back(other, key, previous_key).await?;
 
         Ok(Self {
             address,
@@ -181,10 +183,14 @@ impl behaviour::Conversion for Address {
     async fn convert_back(
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError> {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
-            let inner_decrypt_email = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt =
+                |inner| types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key);
+            let inner_decrypt_email =
+                |inner| types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key);
             Ok::<Self, error_stack::Report<common_utils::errors::CryptoError>>(Self {
                 id: other.id,
                 address_id: other.address_id,
@@ -1,5 +1,6 @@
 use common_utils::errors::{CustomResult, ValidationError};
 
+use super::MerchantKeyStore;
 use crate::pii::Secret;
 
 /// Trait for converting domain types to storage models
@@ -9,9 +10,13 @@ pub trait Conversion {
     type NewDstType;
     async fn convert(self) -> CustomResult<Self::DstType, ValidationError>;
 
+    /// Converts the storage model back to the domain type, decrypting data with `key`. Data that
+    /// has not been re-encrypted yet by a key rotation in progress is decrypted with
+    /// `previous_key`.
     async fn convert_back(
         item: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized;
@@ -22,11 +27,44 @@ pub trait Conversion {
 #[async_trait::async_trait]
 pub trait ReverseConversion<SrcType: Conversion> {
     async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<SrcType, ValidationError>;
+
+    async fn convert_with_previous_key(
+        self,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<SrcType, ValidationError>;
+
+    /// Converts data encrypted with the merchant's key, including data that has not been
+    /// re-encrypted yet by a merchant key rotation in progress
+    async fn convert_with_key_store(
+        self,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<SrcType, ValidationError>;
 }
 
 #[async_trait::async_trait]
 impl<T: Send, U: Conversion<DstType = T>> ReverseConversion<U> for T {
     async fn convert(self, key: &Secret<Vec<u8>>) -> CustomResult<U, ValidationError> {
-        U::convert_back(self, key).await
+        U::convert_back(self, key, None).await
+    }
+
+    async fn convert_with_previous_key(
+        self,
+        key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
+    ) -> CustomResult<U, ValidationError> {
+        U::convert_back(self, key, previous_key).await
+    }
+
+    async fn convert_with_key_store(
+        self,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<U, ValidationError> {
+        U::convert_back(
+            self,
+            key_store.key.get_inner(),
+            key_store.get_previous_key(),
+        )
+        .await
     }
 }
@@ -55,13 +55,17 @@ impl super::behaviour::Conversion for Customer {
     async fn convert_back(
         item: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
-            let inner_decrypt = |inner| types::decrypt(inner, key.peek());
-            let inner_decrypt_email = |inner| types::decrypt(inner, key.peek());
+            let inner_decrypt =
+                |inner| types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key);
+            let inner_decrypt_email =
+                |inner| types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key);
             Ok::<Self, error_stack::Report<common_utils::errors::CryptoError>>(Self {
                 id: Some(item.id),
                 customer_id: item.customer_id,
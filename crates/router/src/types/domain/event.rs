@@ -80,10 +80,12 @@ impl super::behaviour::Conversion for Event {
     async fn convert_back(
         item: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
+        let previous_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
         async {
             Ok::<Self, error_stack::Report<common_utils::errors::CryptoError>>(Self {
                 event_id: item.event_id,
@@ -100,11 +102,15 @@ impl super::behaviour::Conversion for Event {
                 initial_attempt_id: item.initial_attempt_id,
                 request: item
                     .request
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| {
+                        types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key)
+                    })
                     .await?,
                 response: item
                     .response
-                    .async_lift(|inner| types::decrypt(inner, key.peek()))
+                    .async_lift(|inner| {
+                        types::decrypt_optional_with_previous_key(inner, key.peek(), previous_key)
+                    })
                     .await?,
                 delivery_attempt: item.delivery_attempt,
             })
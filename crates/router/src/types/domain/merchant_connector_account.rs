@@ -1,5 +1,5 @@
 use common_utils::{
-    crypto::{Encryptable, GcmAes256},
+    crypto::Encryptable,
     date_time,
     errors::{CustomResult, ValidationError},
     pii,
@@ -11,7 +11,7 @@ use diesel_models::{
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 
-use super::{behaviour, types::TypeEncryption};
+use super::{behaviour, types};
 #[derive(Clone, Debug)]
 pub struct MerchantConnectorAccount {
     pub id: Option<i32>,
@@ -99,15 +99,16 @@ impl behaviour::Conversion for MerchantConnectorAccount {
     async fn convert_back(
         other: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError> {
         Ok(Self {
             id: Some(other.id),
             merchant_id: other.merchant_id,
             connector_name: other.connector_name,
-            connector_account_details: Encryptable::decrypt(
+            connector_account_details: types::decrypt_with_previous_key(
                 other.connector_account_details,
                 key.peek(),
-                GcmAes256,
+                previous_key.map(|previous_key| previous_key.peek().as_slice()),
             )
             .await
             .change_context(ValidationError::InvalidValue {
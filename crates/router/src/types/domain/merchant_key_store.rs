@@ -1,14 +1,11 @@
-use common_utils::{
-    crypto::{Encryptable, GcmAes256},
-    custom_serde, date_time,
-};
+use common_utils::{crypto::Encryptable, custom_serde, date_time};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::{
     errors::{CustomResult, ValidationError},
-    types::domain::types::TypeEncryption,
+    types::domain::types,
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -17,6 +14,21 @@ pub struct MerchantKeyStore {
     pub key: Encryptable<Secret<Vec<u8>>>,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub key_version: i32,
+    /// The key replaced by a key rotation, retained until all data encrypted with it has been
+    /// re-encrypted with the current key
+    pub previous_key: Option<Encryptable<Secret<Vec<u8>>>>,
+    pub previous_key_version: Option<i32>,
+}
+
+impl MerchantKeyStore {
+    /// The key to decrypt data with that has not been re-encrypted yet by a key rotation in
+    /// progress
+    pub fn get_previous_key(&self) -> Option<&Secret<Vec<u8>>> {
+        self.previous_key
+            .as_ref()
+            .map(|previous_key| previous_key.get_inner())
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,24 +40,47 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             key: self.key.into(),
             merchant_id: self.merchant_id,
             created_at: self.created_at,
+            key_version: self.key_version,
+            previous_key: self.previous_key.map(Into::into),
+            previous_key_version: self.previous_key_version,
         })
     }
 
     async fn convert_back(
         item: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
-        Ok(Self {
-            key: Encryptable::decrypt(item.key, key.peek(), GcmAes256)
+        // Key stores are encrypted with the master key, the previous key being the master key
+        // replaced by a master key rotation that is still in progress
+        let previous_master_key = previous_key.map(|previous_key| previous_key.peek().as_slice());
+        let merchant_key: Encryptable<Secret<Vec<u8>>> =
+            types::decrypt_with_previous_key(item.key, key.peek(), previous_master_key)
                 .await
                 .change_context(ValidationError::InvalidValue {
                     message: "Failed while decrypting customer data".to_string(),
-                })?,
+                })?;
+        let previous_merchant_key = match item.previous_key {
+            Some(previous_key) => Some(
+                types::decrypt_with_previous_key(previous_key, key.peek(), previous_master_key)
+                    .await
+                    .change_context(ValidationError::InvalidValue {
+                        message: "Failed while decrypting previous key".to_string(),
+                    })?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            key: merchant_key,
             merchant_id: item.merchant_id,
             created_at: item.created_at,
+            key_version: item.key_version,
+            previous_key: previous_merchant_key,
+            previous_key_version: item.previous_key_version,
         })
     }
 
@@ -54,6 +89,7 @@ impl super::behaviour::Conversion for MerchantKeyStore {
             merchant_id: self.merchant_id,
             key: self.key.into(),
             created_at: date_time::now(),
+            key_version: self.key_version,
         })
     }
 }
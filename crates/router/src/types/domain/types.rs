@@ -9,6 +9,7 @@ use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use router_env::{instrument, tracing};
 
+use super::MerchantKeyStore;
 use crate::routes::metrics::{request, DECRYPTION_TIME, ENCRYPTION_TIME};
 
 #[async_trait]
@@ -223,3 +224,62 @@ where
     .await
     .transpose()
 }
+
+/// Decrypts data with the given key. Data encrypted before a key rotation that is still in
+/// progress, and that has not been re-encrypted yet, is decrypted with the previous key.
+#[instrument(skip_all)]
+pub async fn decrypt_with_previous_key<T: Clone, S: masking::Strategy<T>>(
+    encrypted_data: Encryption,
+    key: &[u8],
+    previous_key: Option<&[u8]>,
+) -> CustomResult<crypto::Encryptable<Secret<T, S>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    let decrypted_data =
+        crypto::Encryptable::decrypt(encrypted_data.clone(), key, crypto::GcmAes256).await;
+    match (decrypted_data, previous_key) {
+        (Err(_), Some(previous_key)) => {
+            crypto::Encryptable::decrypt(encrypted_data, previous_key, crypto::GcmAes256).await
+        }
+        (decrypted_data, _) => decrypted_data,
+    }
+}
+
+#[inline]
+pub async fn decrypt_optional_with_previous_key<T: Clone, S: masking::Strategy<T>>(
+    inner: Option<Encryption>,
+    key: &[u8],
+    previous_key: Option<&[u8]>,
+) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    request::record_operation_time(
+        inner.async_map(|item| decrypt_with_previous_key(item, key, previous_key)),
+        &DECRYPTION_TIME,
+        &[],
+    )
+    .await
+    .transpose()
+}
+
+/// Decrypts data encrypted with the merchant's key, including data that has not been re-encrypted
+/// yet by a merchant key rotation that is in progress
+#[inline]
+pub async fn decrypt_with_key_store<T: Clone, S: masking::Strategy<T>>(
+    inner: Option<Encryption>,
+    key_store: &MerchantKeyStore,
+) -> CustomResult<Option<crypto::Encryptable<Secret<T, S>>>, errors::CryptoError>
+where
+    crypto::Encryptable<Secret<T, S>>: TypeEncryption<T, crypto::GcmAes256, S>,
+{
+    decrypt_optional_with_previous_key(
+        inner,
+        key_store.key.get_inner().peek(),
+        key_store
+            .get_previous_key()
+            .map(|previous_key| previous_key.peek().as_slice()),
+    )
+    .await
+}
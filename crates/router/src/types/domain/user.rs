@@ -310,6 +310,15 @@ impl From<InviteeUserRequestWithInvitedUserToken> for NewUserOrganization {
     }
 }
 
+impl From<SsoUserDetails> for NewUserOrganization {
+    fn from(value: SsoUserDetails) -> Self {
+        Self(diesel_org::OrganizationNew {
+            org_id: value.org_id,
+            org_name: None,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct MerchantId(String);
 
@@ -395,6 +404,7 @@ impl NewUserMerchant {
                 enable_payment_response_hash: None,
                 redirect_to_merchant_with_http_post: None,
             },
+            state.get_req_state(),
         ))
         .await
         .change_context(UserErrors::InternalServerError)
@@ -483,6 +493,19 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUserMerchant {
     }
 }
 
+impl TryFrom<SsoUserDetails> for NewUserMerchant {
+    type Error = error_stack::Report<UserErrors>;
+    fn try_from(value: SsoUserDetails) -> UserResult<Self> {
+        let merchant_id = MerchantId::new(value.merchant_id.clone())?;
+        let new_organization = NewUserOrganization::from(value);
+        Ok(Self {
+            company_name: None,
+            merchant_id,
+            new_organization,
+        })
+    }
+}
+
 type UserMerchantCreateRequestWithToken =
     (UserFromStorage, user_api::UserMerchantCreate, UserFromToken);
 
@@ -578,7 +601,8 @@ impl NewUser {
             .await?;
         let created_user = self.insert_user_in_db(db).await;
         if created_user.is_err() {
-            let _ = admin::merchant_account_delete(state, merchant_id).await;
+            let req_state = state.get_req_state();
+            let _ = admin::merchant_account_delete(state, merchant_id, req_state).await;
         };
         created_user
     }
@@ -760,6 +784,39 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUser {
     }
 }
 
+/// Details of a user being provisioned after signing in through SSO for the first time
+#[derive(Clone)]
+pub struct SsoUserDetails {
+    pub email: pii::Email,
+    pub name: Option<Secret<String>>,
+    pub merchant_id: String,
+    pub org_id: String,
+}
+
+impl TryFrom<SsoUserDetails> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+
+    fn try_from(value: SsoUserDetails) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.email.clone().try_into()?;
+        let name = match value.name.clone() {
+            Some(name) => UserName::new(name)?,
+            None => UserName::try_from(value.email.clone())?,
+        };
+        let password = UserPassword::new(password::get_temp_password())?;
+        let new_merchant = NewUserMerchant::try_from(value)?;
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password,
+            new_merchant,
+            is_temporary_password: true,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromStorage(pub storage_user::User);
 
@@ -105,6 +105,7 @@ pub enum Origin {
     VerifyEmail,
     AcceptInvitationFromEmail,
     ResetPassword,
+    SignInWithSso,
 }
 
 impl Origin {
@@ -116,6 +117,7 @@ impl Origin {
             Self::MagicLink => &MAGIC_LINK_FLOW,
             Self::AcceptInvitationFromEmail => &ACCEPT_INVITATION_FROM_EMAIL_FLOW,
             Self::ResetPassword => &RESET_PASSWORD_FLOW,
+            Self::SignInWithSso => &SIGNIN_WITH_SSO_FLOW,
         }
     }
 }
@@ -164,6 +166,11 @@ const RESET_PASSWORD_FLOW: [UserFlow; 3] = [
     UserFlow::SPTFlow(SPTFlow::ResetPassword),
 ];
 
+const SIGNIN_WITH_SSO_FLOW: [UserFlow; 2] = [
+    UserFlow::SPTFlow(SPTFlow::TOTP),
+    UserFlow::JWTFlow(JWTFlow::UserInfo),
+];
+
 pub struct CurrentFlow {
     origin: Origin,
     current_flow_index: usize,
@@ -311,3 +318,15 @@ impl From<JWTFlow> for UserFlow {
         Self::JWTFlow(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signin_with_sso_requires_totp() {
+        assert!(
+            Origin::SignInWithSso.get_flows().first() == Some(&UserFlow::SPTFlow(SPTFlow::TOTP))
+        );
+    }
+}
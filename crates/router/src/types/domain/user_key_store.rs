@@ -1,14 +1,11 @@
-use common_utils::{
-    crypto::{Encryptable, GcmAes256},
-    date_time,
-};
+use common_utils::{crypto::Encryptable, date_time};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::{
     errors::{CustomResult, ValidationError},
-    types::domain::types::TypeEncryption,
+    types::domain::types,
 };
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -34,16 +31,21 @@ impl super::behaviour::Conversion for UserKeyStore {
     async fn convert_back(
         item: Self::DstType,
         key: &Secret<Vec<u8>>,
+        previous_key: Option<&Secret<Vec<u8>>>,
     ) -> CustomResult<Self, ValidationError>
     where
         Self: Sized,
     {
         Ok(Self {
-            key: Encryptable::decrypt(item.key, key.peek(), GcmAes256)
-                .await
-                .change_context(ValidationError::InvalidValue {
-                    message: "Failed while decrypting customer data".to_string(),
-                })?,
+            key: types::decrypt_with_previous_key(
+                item.key,
+                key.peek(),
+                previous_key.map(|previous_key| previous_key.peek().as_slice()),
+            )
+            .await
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting customer data".to_string(),
+            })?,
             user_id: item.user_id,
             created_at: item.created_at,
         })
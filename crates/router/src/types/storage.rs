@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_alert;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -8,6 +9,7 @@ pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
+pub mod config_audit_log;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
@@ -24,13 +26,16 @@ pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_rotation;
 pub mod merchant_key_store;
+pub mod oidc_sso_config;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod reusable_payment_link;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
@@ -55,13 +60,14 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    address::*, analytics_alert::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    config_audit_log::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_rotation::*,
+    merchant_key_store::*, oidc_sso_config::*, payment_link::*, payment_method::*,
+    process_tracker::*, refund::*, reusable_payment_link::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
@@ -0,0 +1,4 @@
+pub use diesel_models::analytics_alert::{
+    AnalyticsAlert, AnalyticsAlertNew, AnalyticsAlertRule, AnalyticsAlertRuleNew,
+    AnalyticsAlertRuleUpdate, AnalyticsAlertTrackingData, AnalyticsAlertUpdate,
+};
@@ -0,0 +1 @@
+pub use diesel_models::config_audit_log::{ConfigAuditLog, ConfigAuditLogNew};
@@ -1,9 +1,12 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
-pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+pub use diesel_models::dispute::{
+    Dispute, DisputeDeadlineReminderTrackingData, DisputeNew, DisputeUpdate,
+};
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::ResultExt;
+use time::PrimitiveDateTime;
 
 use crate::{connection::PgPooledConn, logger};
 
@@ -14,6 +17,14 @@ pub trait DisputeDbExt: Sized {
         merchant_id: &str,
         dispute_list_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+
+    async fn filter_open_by_challenge_deadline(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        deadline_after: PrimitiveDateTime,
+        deadline_before: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -72,4 +83,35 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    async fn filter_open_by_challenge_deadline(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        deadline_after: PrimitiveDateTime,
+        deadline_before: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::dispute_stage.eq(diesel_models::enums::DisputeStage::Dispute))
+            .filter(dsl::dispute_status.eq(diesel_models::enums::DisputeStatus::DisputeOpened))
+            .filter(dsl::challenge_required_by.gt(deadline_after))
+            .filter(dsl::challenge_required_by.le(deadline_before))
+            .order(dsl::challenge_required_by.asc())
+            .into_boxed();
+
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering disputes by challenge deadline")
+    }
 }
@@ -1,3 +1,4 @@
 pub use diesel_models::fraud_check::{
     FraudCheck, FraudCheckNew, FraudCheckUpdate, FraudCheckUpdateInternal,
+    FrmReviewDeadlineTrackingData,
 };
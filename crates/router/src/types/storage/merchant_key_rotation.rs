@@ -0,0 +1,4 @@
+pub use diesel_models::merchant_key_rotation::{
+    EncryptedColumn, EncryptedRecord, MerchantKeyRotation, MerchantKeyRotationNew,
+    MerchantKeyRotationTrackingData, MerchantKeyRotationUpdate,
+};
@@ -0,0 +1 @@
+pub use diesel_models::oidc_sso_config::{OidcSsoConfig, OidcSsoConfigNew, OidcSsoConfigUpdate};
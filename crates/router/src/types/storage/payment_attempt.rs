@@ -1,4 +1,7 @@
 use common_utils::types::MinorUnit;
+pub use diesel_models::payment_attempt::{
+    AuthorizationExpiryTrackingData, ScheduledCaptureTrackingData,
+};
 use diesel_models::{capture::CaptureNew, enums};
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::payments::payment_attempt::{
@@ -691,6 +691,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
@@ -705,6 +707,8 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            allowed_ips: api_key.allowed_ips,
         }
     }
 }
@@ -718,6 +722,11 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            permission_groups: api_key.permission_groups.map(Some),
+            // An empty allowlist removes the restriction on the API key
+            allowed_ips: api_key
+                .allowed_ips
+                .map(|allowed_ips| (!allowed_ips.is_empty()).then_some(allowed_ips)),
         }
     }
 }
@@ -1107,6 +1116,46 @@ impl ForeignFrom<(storage::PaymentLink, payments::PaymentLinkStatus)>
     }
 }
 
+impl ForeignFrom<storage::ReusablePaymentLink> for payments::ReusablePaymentLinkResponse {
+    fn foreign_from(reusable_payment_link: storage::ReusablePaymentLink) -> Self {
+        Self {
+            reusable_payment_link_id: reusable_payment_link.reusable_payment_link_id,
+            merchant_id: reusable_payment_link.merchant_id,
+            profile_id: reusable_payment_link.profile_id,
+            link_to_pay: reusable_payment_link.link_to_pay,
+            currency: reusable_payment_link.currency,
+            amount: reusable_payment_link.amount,
+            min_amount: reusable_payment_link.min_amount,
+            max_amount: reusable_payment_link.max_amount,
+            usage_limit: reusable_payment_link
+                .usage_limit
+                .and_then(|usage_limit| u32::try_from(usage_limit).ok()),
+            expires_at: reusable_payment_link.expires_at,
+            is_active: reusable_payment_link.is_active,
+            description: reusable_payment_link.description,
+            return_url: reusable_payment_link.return_url,
+            created_at: reusable_payment_link.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::ConfigAuditLog> for api_models::config_audit::ConfigAuditLogResponse {
+    fn foreign_from(config_audit_log: storage::ConfigAuditLog) -> Self {
+        Self {
+            audit_id: config_audit_log.audit_id,
+            merchant_id: config_audit_log.merchant_id,
+            resource_type: config_audit_log.resource_type,
+            resource_id: config_audit_log.resource_id,
+            action: config_audit_log.action,
+            actor_type: config_audit_log.actor_type,
+            actor_id: config_audit_log.actor_id,
+            request_id: config_audit_log.request_id,
+            changes: config_audit_log.changes,
+            created_at: config_audit_log.created_at,
+        }
+    }
+}
+
 impl From<domain::Address> for payments::AddressDetails {
     fn from(addr: domain::Address) -> Self {
         Self {
@@ -1180,6 +1229,97 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
     }
 }
 
+impl ForeignFrom<storage::AnalyticsAlertRule>
+    for api_models::analytics::alerts::AnalyticsAlertRuleResponse
+{
+    fn foreign_from(rule: storage::AnalyticsAlertRule) -> Self {
+        Self {
+            rule_id: rule.rule_id,
+            merchant_id: rule.merchant_id,
+            profile_id: rule.profile_id,
+            name: rule.name,
+            metric: rule.metric,
+            connector: rule.connector,
+            drop_threshold: rule.drop_threshold,
+            evaluation_window_in_mins: u32::try_from(rule.evaluation_window_in_mins)
+                .unwrap_or_default(),
+            baseline_window_in_days: u32::try_from(rule.baseline_window_in_days)
+                .unwrap_or_default(),
+            is_active: rule.is_active,
+            created_at: rule.created_at,
+            modified_at: rule.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::AnalyticsAlert>
+    for api_models::analytics::alerts::AnalyticsAlertResponse
+{
+    fn foreign_from(alert: storage::AnalyticsAlert) -> Self {
+        Self {
+            alert_id: alert.alert_id,
+            rule_id: alert.rule_id,
+            merchant_id: alert.merchant_id,
+            metric: alert.metric,
+            connector: alert.connector,
+            observed_value: alert.observed_value,
+            baseline_value: alert.baseline_value,
+            drop_threshold: alert.drop_threshold,
+            window_start: alert.window_start,
+            window_end: alert.window_end,
+            email_sent: alert.email_sent,
+            webhook_sent: alert.webhook_sent,
+            created_at: alert.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::FraudCheck> for api_models::frm_review::FrmReviewQueueItem {
+    fn foreign_from(fraud_check: storage::FraudCheck) -> Self {
+        // `payment_details` is the snapshot of the payment sent to the fraud check provider
+        let payment_detail = |key: &str| {
+            fraud_check
+                .payment_details
+                .as_ref()
+                .and_then(|payment_details| payment_details.get(key))
+                .cloned()
+        };
+
+        Self {
+            amount: payment_detail("amount").and_then(|amount| amount.as_i64()),
+            currency: payment_detail("currency")
+                .and_then(|currency| serde_json::from_value(currency).ok()),
+            payment_id: fraud_check.payment_id,
+            attempt_id: fraud_check.attempt_id,
+            frm_name: fraud_check.frm_name,
+            frm_score: fraud_check.frm_score,
+            frm_reason: fraud_check.frm_reason,
+            review_assigned_to: fraud_check.review_assigned_to,
+            review_deadline: fraud_check.review_deadline,
+            review_decision: fraud_check.review_decision,
+            created_at: fraud_check.created_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::MerchantKeyRotation> for api_models::admin::MerchantKeyRotationResponse {
+    fn foreign_from(rotation: storage::MerchantKeyRotation) -> Self {
+        Self {
+            rotation_id: rotation.rotation_id,
+            merchant_id: rotation.merchant_id,
+            from_key_version: rotation.from_key_version,
+            to_key_version: rotation.to_key_version,
+            status: rotation.status,
+            current_entity: rotation.current_entity,
+            records_re_encrypted: rotation.records_re_encrypted,
+            error_message: rotation.error_message,
+            created_at: rotation.created_at,
+            modified_at: rotation.modified_at,
+            completed_at: rotation.completed_at,
+        }
+    }
+}
+
 impl ForeignFrom<&domain::Customer> for payments::CustomerDetails {
     fn foreign_from(customer: &domain::Customer) -> Self {
         Self {
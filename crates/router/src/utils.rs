@@ -191,6 +191,40 @@ impl QrImage {
             data: image_data_source,
         })
     }
+
+    /// Renders the QR code of the given data as a PNG image of at least `size` pixels wide
+    pub fn render_png(
+        data: &str,
+        size: u32,
+    ) -> Result<Vec<u8>, error_stack::Report<common_utils::errors::QrCodeError>> {
+        let qr_code = qrcode::QrCode::new(data.as_bytes())
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        let qrcode_image_buffer = qr_code
+            .render::<Luma<u8>>()
+            .min_dimensions(size, size)
+            .build();
+        let mut image_bytes = std::io::Cursor::new(Vec::new());
+        image::DynamicImage::ImageLuma8(qrcode_image_buffer)
+            .write_to(&mut image_bytes, image::ImageFormat::Png)
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        Ok(image_bytes.into_inner())
+    }
+
+    /// Renders the QR code of the given data as an SVG image of at least `size` pixels wide
+    pub fn render_svg(
+        data: &str,
+        size: u32,
+    ) -> Result<String, error_stack::Report<common_utils::errors::QrCodeError>> {
+        let qr_code = qrcode::QrCode::new(data.as_bytes())
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
+
+        Ok(qr_code
+            .render::<qrcode::render::svg::Color<'_>>()
+            .min_dimensions(size, size)
+            .build())
+    }
 }
 
 pub async fn find_payment_intent_from_payment_id_type(
@@ -931,4 +965,13 @@ mod tests {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_render_qr_code_images() {
+        let png = utils::QrImage::render_png("https://example.com/payment_link", 256);
+        assert!(png.is_ok_and(|png| png.starts_with(b"\x89PNG")));
+
+        let svg = utils::QrImage::render_svg("https://example.com/payment_link", 256);
+        assert!(svg.is_ok_and(|svg| svg.contains("<svg")));
+    }
 }
@@ -3,31 +3,33 @@ use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Durat
 use api_models::enums;
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
-use error_stack::ResultExt;
-use masking::PeekInterface;
+use error_stack::{report, ResultExt};
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
 use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
 use tokio::{sync::RwLock, time::sleep};
 
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, AppState,
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexProviderKind},
+    AppState,
 };
+
+pub mod providers;
+
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
     data: Arc<ExchangeRates>,
     timestamp: i64,
+    /// Provider the rates were fetched from
+    #[serde(default)]
+    source: Option<ForexProviderKind>,
+    /// Time the provider published the rates at, if it reports one
+    #[serde(default)]
+    rates_timestamp: Option<i64>,
 }
 
 static FX_EXCHANGE_RATES_CACHE: Lazy<RwLock<Option<FxExchangeRatesCacheEntry>>> =
@@ -73,16 +75,6 @@ pub enum ForexCacheError {
     WriteLockNotAcquired,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(transparent)]
 struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
@@ -95,10 +87,23 @@ impl Deref for FloatDecimal {
 }
 
 impl FxExchangeRatesCacheEntry {
-    fn new(exchange_rate: ExchangeRates) -> Self {
+    fn new(
+        exchange_rate: ExchangeRates,
+        source: ForexProviderKind,
+        rates_timestamp: Option<i64>,
+    ) -> Self {
         Self {
             data: Arc::new(exchange_rate),
             timestamp: date_time::now_unix_timestamp(),
+            source: Some(source),
+            rates_timestamp,
+        }
+    }
+    /// Copies the entry read from redis into the local cache, keeping the source of the rates
+    fn refreshed(&self) -> Self {
+        Self {
+            timestamp: date_time::now_unix_timestamp(),
+            ..self.clone()
         }
     }
     fn is_expired(&self, call_delay: i64) -> bool {
@@ -227,18 +232,10 @@ async fn successive_fetch_and_save_forex(
             let api_rates = fetch_forex_rates(state).await;
             match api_rates {
                 Ok(rates) => successive_save_data_to_redis_local(state, rates).await,
-                Err(err) => {
-                    // API not able to fetch data call secondary service
+                Err(err) => stale_redis_data.ok_or({
                     logger::error!(?err);
-                    let secondary_api_rates = fallback_fetch_forex_rates(state).await;
-                    match secondary_api_rates {
-                        Ok(rates) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-                        Err(err) => stale_redis_data.ok_or({
-                            logger::error!(?err);
-                            ForexCacheError::ApiUnresponsive.into()
-                        }),
-                    }
-                }
+                    ForexCacheError::ApiUnresponsive.into()
+                }),
             }
         }
         Err(e) => stale_redis_data.ok_or({
@@ -275,7 +272,7 @@ async fn fallback_forex_redis_check(
     match is_redis_expired(Some(redis_data.clone()).as_ref(), call_delay).await {
         Some(redis_forex) => {
             // Valid data present in redis
-            let exchange_rates = FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
+            let exchange_rates = redis_forex.refreshed();
             save_forex_to_local(exchange_rates.clone()).await?;
             Ok(exchange_rates)
         }
@@ -296,8 +293,7 @@ async fn handler_local_expired(
             match is_redis_expired(redis_data.as_ref(), call_delay).await {
                 Some(redis_forex) => {
                     // Valid data present in redis
-                    let exchange_rates =
-                        FxExchangeRatesCacheEntry::new(redis_forex.as_ref().clone());
+                    let exchange_rates = redis_forex.refreshed();
                     save_forex_to_local(exchange_rates.clone()).await?;
                     Ok(exchange_rates)
                 }
@@ -315,126 +311,29 @@ async fn handler_local_expired(
     }
 }
 
+/// Fetches the rates from the configured providers
 async fn fetch_forex_rates(
     state: &AppState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-
-    let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(?forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", forex_response);
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
-    }
-
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let forex_providers = providers::get_forex_providers(state.conf.forex_api.get_inner());
+    fetch_forex_rates_from_providers(state, &forex_providers).await
 }
 
-pub async fn fallback_fetch_forex_rates(
+/// Fetches the rates from the providers in order, falling back to the next provider when one
+/// fails
+async fn fetch_forex_rates_from_providers(
     state: &AppState,
+    forex_providers: &[Box<dyn providers::ForexProvider>],
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
-    let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
-
-    let fallback_forex_url: String =
-        format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(?fallback_forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", fallback_forex_response);
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
+    for provider in forex_providers {
+        match provider.fetch_rates(state).await {
+            Ok(rates) => return Ok(rates),
+            Err(err) => {
+                logger::error!(provider = %provider.kind(), ?err, "Failed to fetch forex rates");
             }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
-    }
-
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
         }
     }
+    Err(ForexCacheError::ApiUnresponsive.into())
 }
 
 async fn release_redis_lock(
@@ -499,25 +398,78 @@ async fn retrieve_forex_from_redis(
 async fn is_redis_expired(
     redis_cache: Option<&FxExchangeRatesCacheEntry>,
     call_delay: i64,
-) -> Option<Arc<ExchangeRates>> {
+) -> Option<&FxExchangeRatesCacheEntry> {
     redis_cache.and_then(|cache| {
         if cache.timestamp + call_delay > date_time::now_unix_timestamp() {
-            Some(cache.data.clone())
+            Some(cache)
         } else {
             None
         }
     })
 }
 
-pub async fn convert_currency(
-    state: AppState,
+/// Maximum markup, in basis points, that can be applied to the currency conversions of a merchant
+const MAX_FOREX_MARKUP_BPS: u16 = 1000;
+
+/// Config holding the markup, in basis points, applied to the currency conversions of a merchant.
+/// Markups above `MAX_FOREX_MARKUP_BPS` are rejected.
+pub fn get_forex_markup_config_key(merchant_id: &str) -> String {
+    format!("{merchant_id}_forex_markup_bps")
+}
+
+async fn get_forex_markup_bps(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<u16, ForexCacheError> {
+    match state
+        .store
+        .find_config_by_key(&get_forex_markup_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => parse_forex_markup_bps(&config.config),
+        Err(err) if err.current_context().is_db_not_found() => Ok(0),
+        Err(err) => Err(err.change_context(ForexCacheError::EntryNotFound)),
+    }
+}
+
+fn parse_forex_markup_bps(config: &str) -> CustomResult<u16, ForexCacheError> {
+    let markup_bps = config
+        .trim()
+        .parse::<u16>()
+        .change_context(ForexCacheError::ParsingError)
+        .attach_printable("Invalid forex markup configured for the merchant")?;
+
+    if markup_bps > MAX_FOREX_MARKUP_BPS {
+        return Err(report!(ForexCacheError::ParsingError)).attach_printable(format!(
+            "Forex markup of {markup_bps} bps configured for the merchant exceeds the maximum of \
+             {MAX_FOREX_MARKUP_BPS} bps"
+        ));
+    }
+
+    Ok(markup_bps)
+}
+
+/// An amount converted with the cached rates
+#[derive(Debug, Clone)]
+pub struct ConvertedAmount {
+    /// The converted amount, in the major unit of the currency it was converted to
+    pub amount: Decimal,
+    pub rate_source: Option<ForexProviderKind>,
+    pub rate_timestamp: i64,
+    pub markup_bps: u16,
+}
+
+/// Converts the amount with the cached rates, applying the markup configured for the merchant
+pub async fn convert_amount(
+    state: &AppState,
+    merchant_id: &str,
     amount: i64,
-    to_currency: String,
-    from_currency: String,
-) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> CustomResult<ConvertedAmount, ForexCacheError> {
     let forex_api = state.conf.forex_api.get_inner();
     let rates = get_forex_rates(
-        &state,
+        state,
         forex_api.call_delay,
         forex_api.local_fetch_retry_delay,
         forex_api.local_fetch_retry_count,
@@ -525,6 +477,32 @@ pub async fn convert_currency(
     .await
     .change_context(ForexCacheError::ApiError)?;
 
+    let markup_bps = get_forex_markup_bps(state, merchant_id).await?;
+
+    let converted_amount = currency_conversion::conversion::convert_with_markup(
+        &rates.data,
+        from_currency,
+        to_currency,
+        amount,
+        markup_bps,
+    )
+    .change_context(ForexCacheError::ConversionError)?;
+
+    Ok(ConvertedAmount {
+        amount: converted_amount,
+        rate_source: rates.source,
+        rate_timestamp: rates.rates_timestamp.unwrap_or(rates.timestamp),
+        markup_bps,
+    })
+}
+
+pub async fn convert_currency(
+    state: AppState,
+    merchant_id: &str,
+    amount: i64,
+    to_currency: String,
+    from_currency: String,
+) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
     let to_currency = enums::Currency::from_str(to_currency.as_str())
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
@@ -532,11 +510,133 @@ pub async fn convert_currency(
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
     let converted_amount =
-        currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
-            .change_context(ForexCacheError::ConversionError)?;
+        convert_amount(&state, merchant_id, amount, from_currency, to_currency).await?;
 
     Ok(api_models::currency::CurrencyConversionResponse {
-        converted_amount: converted_amount.to_string(),
+        converted_amount: converted_amount.amount.to_string(),
         currency: to_currency.to_string(),
+        rate_source: converted_amount
+            .rate_source
+            .map(|source| source.to_string()),
+        rate_timestamp: converted_amount.rate_timestamp,
+        markup_bps: converted_amount.markup_bps,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, services};
+
+    struct MockForexProvider {
+        kind: ForexProviderKind,
+        is_available: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl providers::ForexProvider for MockForexProvider {
+        fn kind(&self) -> ForexProviderKind {
+            self.kind
+        }
+
+        async fn fetch_rates(
+            &self,
+            _state: &AppState,
+        ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+            if self.is_available {
+                Ok(FxExchangeRatesCacheEntry::new(
+                    ExchangeRates::new(enums::Currency::USD, HashMap::new()),
+                    self.kind,
+                    None,
+                ))
+            } else {
+                Err(report!(ForexCacheError::ApiUnresponsive))
+            }
+        }
+    }
+
+    fn mock_providers(
+        available_providers: &[ForexProviderKind],
+    ) -> Vec<Box<dyn providers::ForexProvider>> {
+        [
+            ForexProviderKind::OpenExchangeRates,
+            ForexProviderKind::ApiLayer,
+            ForexProviderKind::StaticFile,
+        ]
+        .into_iter()
+        .map(|kind| -> Box<dyn providers::ForexProvider> {
+            Box::new(MockForexProvider {
+                kind,
+                is_available: available_providers.contains(&kind),
+            })
+        })
+        .collect()
+    }
+
+    #[actix_rt::test]
+    async fn test_fetch_forex_rates_from_providers_order() {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let api_client = Box::new(services::MockApiClient);
+        let state = Box::pin(AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            api_client,
+        ))
+        .await;
+
+        let fetched_source = |available_providers: &'static [ForexProviderKind]| {
+            let state = state.clone();
+            async move {
+                fetch_forex_rates_from_providers(&state, &mock_providers(available_providers))
+                    .await
+                    .map(|rates| rates.source)
+            }
+        };
+
+        // The primary provider is used whenever it is available
+        assert_eq!(
+            fetched_source(&[
+                ForexProviderKind::OpenExchangeRates,
+                ForexProviderKind::ApiLayer,
+                ForexProviderKind::StaticFile,
+            ])
+            .await
+            .unwrap(),
+            Some(ForexProviderKind::OpenExchangeRates)
+        );
+        // Then the fallback provider
+        assert_eq!(
+            fetched_source(&[ForexProviderKind::ApiLayer, ForexProviderKind::StaticFile])
+                .await
+                .unwrap(),
+            Some(ForexProviderKind::ApiLayer)
+        );
+        // And the static rates last
+        assert_eq!(
+            fetched_source(&[ForexProviderKind::StaticFile])
+                .await
+                .unwrap(),
+            Some(ForexProviderKind::StaticFile)
+        );
+        assert!(fetched_source(&[]).await.is_err());
+    }
+
+    #[test]
+    fn test_parse_forex_markup_bps() {
+        assert_eq!(parse_forex_markup_bps("150").unwrap(), 150);
+        assert_eq!(parse_forex_markup_bps(" 0\n").unwrap(), 0);
+        assert_eq!(
+            parse_forex_markup_bps(&MAX_FOREX_MARKUP_BPS.to_string()).unwrap(),
+            MAX_FOREX_MARKUP_BPS
+        );
+        assert!(parse_forex_markup_bps("1001").is_err());
+        assert!(parse_forex_markup_bps("65535").is_err());
+        assert!(parse_forex_markup_bps("-1").is_err());
+        assert!(parse_forex_markup_bps("1.5").is_err());
+    }
+}
@@ -0,0 +1,429 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use api_models::enums;
+use common_utils::errors::CustomResult;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::{FloatDecimal, ForexCacheError, FxExchangeRatesCacheEntry};
+use crate::{
+    logger,
+    routes::app::settings::{DefaultExchangeRates, ForexApi, ForexProviderKind},
+    services, AppState,
+};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+
+/// A source of exchange rates
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    /// The source recorded on the rates fetched from the provider
+    fn kind(&self) -> ForexProviderKind;
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError>;
+}
+
+/// Returns the configured providers in the order they should be tried
+pub fn get_forex_providers(forex_api: &ForexApi) -> Vec<Box<dyn ForexProvider>> {
+    let provider_kinds = if forex_api.providers.is_empty() {
+        vec![
+            ForexProviderKind::OpenExchangeRates,
+            ForexProviderKind::ApiLayer,
+        ]
+    } else {
+        forex_api.providers.clone()
+    };
+
+    provider_kinds
+        .into_iter()
+        .map(|provider_kind| -> Box<dyn ForexProvider> {
+            match provider_kind {
+                ForexProviderKind::OpenExchangeRates => Box::new(OpenExchangeRatesProvider {
+                    api_key: forex_api.api_key.clone(),
+                }),
+                ForexProviderKind::ApiLayer => Box::new(ApiLayerProvider {
+                    api_key: forex_api.fallback_api_key.clone(),
+                }),
+                ForexProviderKind::StaticFile => Box::new(StaticFileProvider {
+                    file_path: forex_api.static_rates_file.clone(),
+                }),
+                ForexProviderKind::Database => Box::new(DatabaseProvider {
+                    config_key: forex_api.database_rates_key.clone(),
+                }),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+    pub timestamp: Option<i64>,
+}
+
+impl ForexResponse {
+    fn into_cache_entry(self) -> FxExchangeRatesCacheEntry {
+        let conversions =
+            get_currency_factors(|currency| self.rates.get(&currency.to_string()).copied());
+
+        FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(enums::Currency::USD, conversions),
+            ForexProviderKind::OpenExchangeRates,
+            self.timestamp,
+        )
+    }
+}
+
+impl FallbackForexResponse {
+    fn into_cache_entry(self) -> FxExchangeRatesCacheEntry {
+        let conversions = get_currency_factors(|currency| {
+            self.quotes
+                .get(format!("{FALLBACK_FOREX_API_CURRENCY_PREFIX}{currency}").as_str())
+                .copied()
+        });
+
+        FxExchangeRatesCacheEntry::new(
+            ExchangeRates::new(enums::Currency::USD, conversions),
+            ForexProviderKind::ApiLayer,
+            self.timestamp,
+        )
+    }
+}
+
+pub struct OpenExchangeRatesProvider {
+    api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for OpenExchangeRatesProvider {
+    fn kind(&self) -> ForexProviderKind {
+        ForexProviderKind::OpenExchangeRates
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+        let forex_url: String = format!(
+            "{}{}{}",
+            FOREX_BASE_URL,
+            self.api_key.peek(),
+            FOREX_BASE_CURRENCY
+        );
+        let forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&forex_url)
+            .build();
+
+        logger::info!(?forex_request);
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexCacheError::ApiUnresponsive)?;
+        let forex_response = response
+            .json::<ForexResponse>()
+            .await
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", forex_response);
+
+        Ok(forex_response.into_cache_entry())
+    }
+}
+
+pub struct ApiLayerProvider {
+    api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for ApiLayerProvider {
+    fn kind(&self) -> ForexProviderKind {
+        ForexProviderKind::ApiLayer
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+        let fallback_forex_url: String =
+            format!("{}{}", FALLBACK_FOREX_BASE_URL, self.api_key.peek());
+        let fallback_forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&fallback_forex_url)
+            .build();
+
+        logger::info!(?fallback_forex_request);
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                fallback_forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexCacheError::ApiUnresponsive)?;
+        let fallback_forex_response = response
+            .json::<FallbackForexResponse>()
+            .await
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", fallback_forex_response);
+
+        Ok(fallback_forex_response.into_cache_entry())
+    }
+}
+
+/// Reads the rates from a JSON file in the format of the default exchange rates
+pub struct StaticFileProvider {
+    file_path: Option<PathBuf>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for StaticFileProvider {
+    fn kind(&self) -> ForexProviderKind {
+        ForexProviderKind::StaticFile
+    }
+
+    async fn fetch_rates(
+        &self,
+        _state: &AppState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+        let file_path = self
+            .file_path
+            .as_ref()
+            .ok_or(ForexCacheError::LocalReadError)
+            .attach_printable("Static forex rates file is not configured")?;
+        let file_content = tokio::fs::read(file_path)
+            .await
+            .change_context(ForexCacheError::LocalReadError)
+            .attach_printable_lazy(|| {
+                format!("Failed to read forex rates from {}", file_path.display())
+            })?;
+
+        parse_default_exchange_rates(&file_content, self.kind())
+    }
+}
+
+/// Reads the rates from the configs table in the format of the default exchange rates
+pub struct DatabaseProvider {
+    config_key: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for DatabaseProvider {
+    fn kind(&self) -> ForexProviderKind {
+        ForexProviderKind::Database
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+    ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+        let config_key = self
+            .config_key
+            .as_deref()
+            .ok_or(ForexCacheError::EntryNotFound)
+            .attach_printable("Forex rates config key is not configured")?;
+        let config = state
+            .store
+            .find_config_by_key(config_key)
+            .await
+            .change_context(ForexCacheError::EntryNotFound)
+            .attach_printable_lazy(|| format!("Failed to find forex rates config {config_key}"))?;
+
+        parse_default_exchange_rates(config.config.as_bytes(), self.kind())
+    }
+}
+
+/// Parses rates in the format of the default exchange rates
+fn parse_default_exchange_rates(
+    content: &[u8],
+    provider_kind: ForexProviderKind,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let default_exchange_rates: DefaultExchangeRates = serde_json::from_slice(content)
+        .change_context(ForexCacheError::DefaultCurrencyParsingError)?;
+    let rates_timestamp = default_exchange_rates.timestamp;
+    Ok(FxExchangeRatesCacheEntry::new(
+        ExchangeRates::try_from(default_exchange_rates)?,
+        provider_kind,
+        Some(rates_timestamp),
+    ))
+}
+
+/// Builds the conversion factors of the currencies from their rates against USD
+fn get_currency_factors(
+    get_rate: impl Fn(enums::Currency) -> Option<FloatDecimal>,
+) -> HashMap<enums::Currency, CurrencyFactors> {
+    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+    for enum_curr in enums::Currency::iter() {
+        match get_rate(enum_curr) {
+            Some(rate) => {
+                let from_factor = match Decimal::new(1, 0).checked_div(*rate) {
+                    Some(rate) => rate,
+                    None => {
+                        logger::error!("Rates for {} not received from API", &enum_curr);
+                        continue;
+                    }
+                };
+                let currency_factors = CurrencyFactors::new(*rate, from_factor);
+                conversions.insert(enum_curr, currency_factors);
+            }
+            None => {
+                logger::error!("Rates for {} not received from API", &enum_curr);
+            }
+        };
+    }
+    conversions
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_forex_providers_order() {
+        let provider_kinds = |forex_api: &ForexApi| {
+            get_forex_providers(forex_api)
+                .iter()
+                .map(|provider| provider.kind())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            provider_kinds(&ForexApi::default()),
+            vec![
+                ForexProviderKind::OpenExchangeRates,
+                ForexProviderKind::ApiLayer
+            ]
+        );
+
+        let forex_api = ForexApi {
+            providers: vec![
+                ForexProviderKind::OpenExchangeRates,
+                ForexProviderKind::ApiLayer,
+                ForexProviderKind::StaticFile,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            provider_kinds(&forex_api),
+            vec![
+                ForexProviderKind::OpenExchangeRates,
+                ForexProviderKind::ApiLayer,
+                ForexProviderKind::StaticFile
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_open_exchange_rates_response() {
+        let forex_response: ForexResponse = serde_json::from_str(
+            r#"{"timestamp": 1717000000, "base": "USD", "rates": {"USD": 1.0, "EUR": 0.5, "XYZ": 2.0}}"#,
+        )
+        .unwrap();
+        let cache_entry = forex_response.into_cache_entry();
+
+        assert_eq!(
+            cache_entry.source,
+            Some(ForexProviderKind::OpenExchangeRates)
+        );
+        assert_eq!(cache_entry.rates_timestamp, Some(1717000000));
+        assert_eq!(cache_entry.data.base_currency, enums::Currency::USD);
+        let eur = cache_entry
+            .data
+            .conversion
+            .get(&enums::Currency::EUR)
+            .unwrap();
+        assert_eq!(eur.to_factor, Decimal::new(5, 1));
+        assert_eq!(eur.from_factor, Decimal::new(2, 0));
+        // Currencies without a rate are left out
+        assert!(!cache_entry
+            .data
+            .conversion
+            .contains_key(&enums::Currency::JPY));
+    }
+
+    #[test]
+    fn test_parse_api_layer_response() {
+        let fallback_forex_response: FallbackForexResponse = serde_json::from_str(
+            r#"{"success": true, "timestamp": 1717000000, "source": "USD", "quotes": {"USDUSD": 1.0, "USDEUR": 0.5, "EUR": 2.0}}"#,
+        )
+        .unwrap();
+        let cache_entry = fallback_forex_response.into_cache_entry();
+
+        assert_eq!(cache_entry.source, Some(ForexProviderKind::ApiLayer));
+        assert_eq!(cache_entry.rates_timestamp, Some(1717000000));
+        let eur = cache_entry
+            .data
+            .conversion
+            .get(&enums::Currency::EUR)
+            .unwrap();
+        assert_eq!(eur.to_factor, Decimal::new(5, 1));
+        assert!(cache_entry
+            .data
+            .conversion
+            .contains_key(&enums::Currency::USD));
+        assert!(!cache_entry
+            .data
+            .conversion
+            .contains_key(&enums::Currency::JPY));
+    }
+
+    #[test]
+    fn test_parse_default_exchange_rates() {
+        let content = br#"{
+            "base_currency": "USD",
+            "timestamp": 1717000000,
+            "conversion": {
+                "EUR": {"to_factor": "0.8", "from_factor": "1.25"}
+            }
+        }"#;
+
+        for provider_kind in [ForexProviderKind::StaticFile, ForexProviderKind::Database] {
+            let cache_entry = parse_default_exchange_rates(content, provider_kind).unwrap();
+            assert_eq!(cache_entry.source, Some(provider_kind));
+            assert_eq!(cache_entry.rates_timestamp, Some(1717000000));
+            let eur = cache_entry
+                .data
+                .conversion
+                .get(&enums::Currency::EUR)
+                .unwrap();
+            assert_eq!(eur.to_factor, Decimal::new(8, 1));
+            assert_eq!(eur.from_factor, Decimal::new(125, 2));
+        }
+
+        assert!(parse_default_exchange_rates(b"{}", ForexProviderKind::StaticFile).is_err());
+        let unknown_currency = br#"{
+            "base_currency": "USD",
+            "timestamp": 1717000000,
+            "conversion": {"XYZ": {"to_factor": "0.8", "from_factor": "1.25"}}
+        }"#;
+        assert!(
+            parse_default_exchange_rates(unknown_currency, ForexProviderKind::Database).is_err()
+        );
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
 };
 
 pub mod dashboard_metadata;
+pub mod oidc;
 pub mod password;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
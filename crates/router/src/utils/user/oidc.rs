@@ -0,0 +1,465 @@
+use std::collections::HashMap;
+
+use base64::Engine;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    pii,
+    request::{Method, Request, RequestBuilder, RequestContent},
+};
+use error_stack::{report, ResultExt};
+use jsonwebtoken::{
+    jwk::{Jwk, JwkSet, KeyAlgorithm},
+    Algorithm, DecodingKey, Validation,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    routes::AppState,
+    services::send_request,
+    types::storage,
+};
+
+/// Algorithms accepted for signing ID tokens
+const ID_TOKEN_SIGNING_ALGORITHMS: [Algorithm; 2] = [Algorithm::RS256, Algorithm::ES256];
+
+/// The subset of the OpenID Provider metadata used for the authorization code flow
+#[derive(Debug, serde::Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// Details of an in-progress authorization stored against the `state` parameter
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AuthorizationState {
+    pub org_id: String,
+    pub code_verifier: Secret<String>,
+    pub nonce: Secret<String>,
+    /// Digest of the token set as a cookie in the browser which started the authorization
+    pub browser_binding_hash: String,
+}
+
+impl AuthorizationState {
+    pub fn is_bound_to_browser(
+        &self,
+        browser_binding: Option<&Secret<String>>,
+    ) -> UserResult<bool> {
+        browser_binding
+            .map(hash_browser_binding)
+            .transpose()
+            .map(|hash| hash.is_some_and(|hash| hash == self.browser_binding_hash))
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct TokenRequest {
+    grant_type: &'static str,
+    code: Secret<String>,
+    redirect_uri: String,
+    client_id: String,
+    client_secret: Secret<String>,
+    code_verifier: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    id_token: Secret<String>,
+}
+
+pub struct IdTokenClaims(HashMap<String, serde_json::Value>);
+
+impl IdTokenClaims {
+    pub fn get_email(&self) -> UserResult<pii::Email> {
+        let email = self
+            .0
+            .get("email")
+            .and_then(serde_json::Value::as_str)
+            .ok_or(UserErrors::SsoAuthenticationFailed)
+            .attach_printable("email claim not present in ID token")?;
+
+        pii::Email::try_from(email.to_string())
+            .change_context(UserErrors::SsoAuthenticationFailed)
+            .attach_printable("Invalid email claim in ID token")
+    }
+
+    /// Emails are treated as unverified unless the identity provider explicitly says otherwise
+    pub fn is_email_verified(&self) -> bool {
+        self.0
+            .get("email_verified")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    pub fn get_name(&self) -> Option<Secret<String>> {
+        self.0
+            .get("name")
+            .and_then(serde_json::Value::as_str)
+            .map(|name| Secret::new(name.to_string()))
+    }
+
+    /// Groups can be sent either as an array of strings or as a single string
+    pub fn get_groups(&self, groups_claim: &str) -> Vec<String> {
+        match self.0.get(groups_claim) {
+            Some(serde_json::Value::Array(groups)) => groups
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(ToString::to_string)
+                .collect(),
+            Some(serde_json::Value::String(group)) => vec![group.to_string()],
+            _ => Vec::new(),
+        }
+    }
+
+    fn get_nonce(&self) -> Option<&str> {
+        self.0.get("nonce").and_then(serde_json::Value::as_str)
+    }
+}
+
+pub fn generate_random_token() -> Secret<String> {
+    crypto::generate_cryptographically_secure_random_string(consts::user::OIDC_RANDOM_TOKEN_LENGTH)
+        .into()
+}
+
+/// Generates the `S256` code challenge for a PKCE code verifier
+pub fn generate_code_challenge(code_verifier: &Secret<String>) -> UserResult<String> {
+    let digest = crypto::Sha256
+        .generate_digest(code_verifier.peek().as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to generate PKCE code challenge")?;
+
+    Ok(consts::BASE64_ENGINE_URL_SAFE_NO_PAD.encode(digest))
+}
+
+/// Only the digest of the browser binding token is stored alongside the authorization state
+pub fn hash_browser_binding(browser_binding: &Secret<String>) -> UserResult<String> {
+    let digest = crypto::Sha256
+        .generate_digest(browser_binding.peek().as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to hash browser binding")?;
+
+    Ok(hex::encode(digest))
+}
+
+fn build_get_request(url: &str) -> Request {
+    RequestBuilder::new()
+        .method(Method::Get)
+        .url(url)
+        .attach_default_headers()
+        .build()
+}
+
+async fn send_and_parse<T: serde::de::DeserializeOwned>(
+    state: &AppState,
+    request: Request,
+    description: &'static str,
+) -> UserResult<T> {
+    let response = send_request(state, request, None)
+        .await
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable_lazy(|| format!("Failed to send {description} request"))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        logger::error!(?status, %body, "{description} request failed");
+        return Err(report!(UserErrors::SsoAuthenticationFailed))
+            .attach_printable(format!("{description} request failed with status {status}"));
+    }
+
+    response
+        .json()
+        .await
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable_lazy(|| format!("Failed to parse {description} response"))
+}
+
+pub async fn get_provider_metadata(
+    state: &AppState,
+    issuer_url: &str,
+) -> UserResult<ProviderMetadata> {
+    let issuer_url = issuer_url.trim_end_matches('/');
+    let metadata: ProviderMetadata = send_and_parse(
+        state,
+        build_get_request(&format!("{issuer_url}/.well-known/openid-configuration")),
+        "OpenID provider discovery",
+    )
+    .await?;
+
+    if metadata.issuer.trim_end_matches('/') != issuer_url {
+        return Err(report!(UserErrors::SsoAuthenticationFailed)).attach_printable(format!(
+            "Issuer in provider metadata {} does not match the configured issuer {issuer_url}",
+            metadata.issuer
+        ));
+    }
+
+    Ok(metadata)
+}
+
+pub fn build_authorization_url(
+    metadata: &ProviderMetadata,
+    config: &storage::OidcSsoConfig,
+    state_param: &str,
+    authorization_state: &AuthorizationState,
+) -> UserResult<String> {
+    let code_challenge = generate_code_challenge(&authorization_state.code_verifier)?;
+    let scope = config.scopes.join(" ");
+
+    url::Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", config.client_id.as_str()),
+            ("redirect_uri", config.redirect_uri.as_str()),
+            ("scope", scope.as_str()),
+            ("state", state_param),
+            ("nonce", authorization_state.nonce.peek().as_str()),
+            ("code_challenge", code_challenge.as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map(String::from)
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to build authorization URL")
+}
+
+pub async fn exchange_authorization_code(
+    state: &AppState,
+    metadata: &ProviderMetadata,
+    config: &storage::OidcSsoConfig,
+    client_secret: Secret<String>,
+    code: Secret<String>,
+    code_verifier: Secret<String>,
+) -> UserResult<Secret<String>> {
+    let request = RequestBuilder::new()
+        .method(Method::Post)
+        .url(&metadata.token_endpoint)
+        .attach_default_headers()
+        .set_body(RequestContent::FormUrlEncoded(Box::new(TokenRequest {
+            grant_type: "authorization_code",
+            code,
+            redirect_uri: config.redirect_uri.clone(),
+            client_id: config.client_id.clone(),
+            client_secret,
+            code_verifier,
+        })))
+        .build();
+
+    let token_response: TokenResponse = send_and_parse(state, request, "Token").await?;
+    Ok(token_response.id_token)
+}
+
+/// Validates the signature, issuer, audience, expiry and nonce of the ID token
+pub async fn validate_id_token(
+    state: &AppState,
+    metadata: &ProviderMetadata,
+    client_id: &str,
+    id_token: Secret<String>,
+    nonce: &Secret<String>,
+) -> UserResult<IdTokenClaims> {
+    let id_token = id_token.expose();
+    let header = jsonwebtoken::decode_header(&id_token)
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to decode ID token header")?;
+
+    let jwks: JwkSet = send_and_parse(state, build_get_request(&metadata.jwks_uri), "JWKS").await?;
+    let jwk = match header.kid.as_deref() {
+        Some(kid) => jwks.find(kid),
+        None => match jwks.keys.as_slice() {
+            [jwk] => Some(jwk),
+            _ => None,
+        },
+    }
+    .ok_or(UserErrors::SsoAuthenticationFailed)
+    .attach_printable("Signing key of the ID token not found in JWKS")?;
+
+    let decoding_key = DecodingKey::from_jwk(jwk)
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to create decoding key from JWK")?;
+
+    let algorithm = get_id_token_algorithm(header.alg, jwk)
+        .ok_or(UserErrors::SsoAuthenticationFailed)
+        .attach_printable_lazy(|| {
+            format!(
+                "Unsupported signing algorithm of the ID token: {:?}",
+                header.alg
+            )
+        })?;
+
+    let mut validation = Validation::new(algorithm);
+    validation.set_issuer(&[metadata.issuer.as_str()]);
+    validation.set_audience(&[client_id]);
+
+    let claims = IdTokenClaims(
+        jsonwebtoken::decode::<HashMap<String, serde_json::Value>>(
+            &id_token,
+            &decoding_key,
+            &validation,
+        )
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("ID token validation failed")?
+        .claims,
+    );
+
+    if claims.get_nonce() != Some(nonce.peek().as_str()) {
+        return Err(report!(UserErrors::SsoAuthenticationFailed))
+            .attach_printable("Nonce in ID token does not match");
+    }
+
+    Ok(claims)
+}
+
+/// Returns the algorithm to verify the ID token with, if the algorithm in the (not yet verified)
+/// token header is an allowed asymmetric algorithm matching the one declared by the signing key
+fn get_id_token_algorithm(header_algorithm: Algorithm, jwk: &Jwk) -> Option<Algorithm> {
+    let key_algorithm_matches = match jwk.common.key_algorithm {
+        Some(KeyAlgorithm::RS256) => header_algorithm == Algorithm::RS256,
+        Some(KeyAlgorithm::ES256) => header_algorithm == Algorithm::ES256,
+        Some(_) => false,
+        None => true,
+    };
+
+    (ID_TOKEN_SIGNING_ALGORITHMS.contains(&header_algorithm) && key_algorithm_matches)
+        .then_some(header_algorithm)
+}
+
+/// Returns the role of the first mapping matching one of the groups, falling back to the default
+/// role of the configuration
+pub fn get_role_id_from_groups(
+    role_mappings: &[api_models::user::OidcRoleMapping],
+    default_role_id: Option<&String>,
+    groups: &[String],
+) -> Option<String> {
+    role_mappings
+        .iter()
+        .find(|mapping| groups.contains(&mapping.group))
+        .map(|mapping| mapping.role_id.clone())
+        .or(default_role_id.cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::user::OidcRoleMapping;
+
+    use super::*;
+
+    #[test]
+    fn test_generate_code_challenge() {
+        // Example from RFC 7636 Appendix B
+        let code_verifier = Secret::new("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string());
+        assert_eq!(
+            generate_code_challenge(&code_verifier).unwrap(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_get_role_id_from_groups() {
+        let role_mappings = vec![
+            OidcRoleMapping {
+                group: "admins".to_string(),
+                role_id: "merchant_admin".to_string(),
+            },
+            OidcRoleMapping {
+                group: "developers".to_string(),
+                role_id: "merchant_developer".to_string(),
+            },
+        ];
+        let default_role_id = "merchant_view_only".to_string();
+
+        assert_eq!(
+            get_role_id_from_groups(
+                &role_mappings,
+                None,
+                &["developers".to_string(), "admins".to_string()]
+            ),
+            Some("merchant_admin".to_string())
+        );
+        assert_eq!(
+            get_role_id_from_groups(&role_mappings, None, &["finance".to_string()]),
+            None
+        );
+        assert_eq!(
+            get_role_id_from_groups(
+                &role_mappings,
+                Some(&default_role_id),
+                &["finance".to_string()]
+            ),
+            Some(default_role_id.clone())
+        );
+    }
+
+    #[test]
+    fn test_get_id_token_algorithm() {
+        let jwk_with = |value: serde_json::Value| -> Jwk { serde_json::from_value(value).unwrap() };
+        let rsa_jwk = jwk_with(serde_json::json!({
+            "kty": "RSA",
+            "kid": "key_1",
+            "alg": "RS256",
+            "n": "AQAB",
+            "e": "AQAB",
+        }));
+        let rsa_jwk_without_alg = jwk_with(serde_json::json!({
+            "kty": "RSA",
+            "kid": "key_1",
+            "n": "AQAB",
+            "e": "AQAB",
+        }));
+
+        assert_eq!(
+            get_id_token_algorithm(Algorithm::RS256, &rsa_jwk),
+            Some(Algorithm::RS256)
+        );
+        assert_eq!(
+            get_id_token_algorithm(Algorithm::RS256, &rsa_jwk_without_alg),
+            Some(Algorithm::RS256)
+        );
+        // The algorithm in the token header must match the one declared by the signing key
+        assert_eq!(get_id_token_algorithm(Algorithm::ES256, &rsa_jwk), None);
+        // Symmetric and other algorithms are never accepted
+        assert_eq!(get_id_token_algorithm(Algorithm::HS256, &rsa_jwk), None);
+        assert_eq!(
+            get_id_token_algorithm(Algorithm::HS256, &rsa_jwk_without_alg),
+            None
+        );
+        assert_eq!(
+            get_id_token_algorithm(Algorithm::PS256, &rsa_jwk_without_alg),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_email_verified() {
+        let claims_with =
+            |value: serde_json::Value| IdTokenClaims(serde_json::from_value(value).unwrap());
+
+        assert!(claims_with(serde_json::json!({ "email_verified": true })).is_email_verified());
+        assert!(!claims_with(serde_json::json!({ "email_verified": false })).is_email_verified());
+        assert!(!claims_with(serde_json::json!({ "email_verified": "true" })).is_email_verified());
+        assert!(!claims_with(serde_json::json!({})).is_email_verified());
+    }
+
+    #[test]
+    fn test_is_bound_to_browser() {
+        let browser_binding = generate_random_token();
+        let authorization_state = AuthorizationState {
+            org_id: "org_abc".to_string(),
+            code_verifier: generate_random_token(),
+            nonce: generate_random_token(),
+            browser_binding_hash: hash_browser_binding(&browser_binding).unwrap(),
+        };
+
+        assert!(authorization_state
+            .is_bound_to_browser(Some(&browser_binding))
+            .unwrap());
+        assert!(!authorization_state
+            .is_bound_to_browser(Some(&generate_random_token()))
+            .unwrap());
+        assert!(!authorization_state.is_bound_to_browser(None).unwrap());
+    }
+}
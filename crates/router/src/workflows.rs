@@ -1,8 +1,18 @@
+#[cfg(feature = "olap")]
+pub mod analytics_alert;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+pub mod card_account_updater;
+pub mod dispute_deadline_reminder;
+#[cfg(feature = "frm")]
+pub mod frm_review_deadline;
+pub mod merchant_key_rotation;
 pub mod outgoing_webhook_retry;
 pub mod payment_sync;
 pub mod refund_router;
+pub mod scheduled_capture;
 pub mod tokenized_data;
+pub mod webhook_bulk_retry;
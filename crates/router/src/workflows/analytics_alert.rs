@@ -0,0 +1,109 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::analytics_alerts,
+    errors,
+    logger::{self, error},
+    routes::AppState,
+    types::storage,
+};
+
+pub struct AnalyticsAlertWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AnalyticsAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AnalyticsAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsAlertTrackingData")?;
+
+        let rule = match db
+            .find_analytics_alert_rule_by_merchant_id_rule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.rule_id,
+            )
+            .await
+        {
+            Ok(rule) if rule.is_active => Some(rule),
+            Ok(_) => None,
+            // The rule was deleted after this task was picked up
+            Err(error) if error.current_context().is_db_not_found() => None,
+            Err(error) => return Err(error.into()),
+        };
+
+        let Some(rule) = rule else {
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "RULE_INACTIVE".to_string())
+                .await?;
+            return Ok(());
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        if let Some(alert) = analytics_alerts::evaluate_alert_rule(state, &rule).await? {
+            let alert =
+                analytics_alerts::raise_alert(state, &merchant_account, &key_store, &rule, alert)
+                    .await?;
+            logger::info!(
+                alert_id = %alert.alert_id,
+                rule_id = %rule.rule_id,
+                "Raised analytics alert"
+            );
+        }
+
+        analytics_alerts::reschedule_alert_evaluation_task(db, &rule).await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+
+        // Evaluation failures are transient (for example, the analytics store being unavailable),
+        // so the rule is evaluated again in the next window instead of being dropped
+        let tracking_data: storage::AnalyticsAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsAlertTrackingData")
+            .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+        let rule = state
+            .store
+            .find_analytics_alert_rule_by_merchant_id_rule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.rule_id,
+            )
+            .await
+            .change_context(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.rule_id.clone(),
+            })?;
+        analytics_alerts::reschedule_alert_evaluation_task(&*state.store, &rule)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
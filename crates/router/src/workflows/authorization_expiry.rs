@@ -0,0 +1,209 @@
+use api_models::{admin::AuthorizationExpiryAction, payments as payment_types};
+use common_utils::ext_traits::ValueExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::payments::{self, authorization_expiry, CallConnectorAction},
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    services,
+    types::{api, storage},
+};
+
+/// Number of times an action failing with an error is retried before the task is given up
+const MAX_AUTHORIZATION_EXPIRY_RETRIES: i32 = 3;
+/// Time (in seconds) to wait before retrying a failed action
+const AUTHORIZATION_EXPIRY_RETRY_INTERVAL_IN_SECS: i64 = 15 * 60;
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::payment_attempt::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        if !authorization_expiry::has_capturable_authorization(payment_intent.status)
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        {
+            // The payment was captured or voided before its authorization expired
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PAYMENT_NOT_AUTHORIZED".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let profile_id = payment_intent
+            .profile_id
+            .clone()
+            .or(merchant_account.default_profile.clone())
+            .ok_or(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: "business_profile".to_string(),
+            })?;
+        let business_profile = db.find_business_profile_by_profile_id(&profile_id).await?;
+
+        let Some(policy) =
+            authorization_expiry::get_authorization_expiry_policy(&business_profile)?
+        else {
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "POLICY_REMOVED".to_string())
+                .await?;
+            return Ok(());
+        };
+
+        let action_result = match policy.action {
+            AuthorizationExpiryAction::Void => Box::pin(payments::payments_core::<
+                api::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account,
+                key_store,
+                payments::PaymentCancel,
+                payment_types::PaymentsCancelRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    cancellation_reason: Some("authorization_expiring".to_string()),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                api::HeaderPayload::default(),
+            ))
+            .await
+            .map(|_| ()),
+            AuthorizationExpiryAction::Capture => Box::pin(payments::payments_core::<
+                api::Capture,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account,
+                key_store,
+                payments::PaymentCapture,
+                payment_types::PaymentsCaptureRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    merchant_id: Some(tracking_data.merchant_id.clone()),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                api::HeaderPayload::default(),
+            ))
+            .await
+            .map(|_| ()),
+            AuthorizationExpiryAction::Notify => {
+                authorization_expiry::notify_authorization_expiring(
+                    state,
+                    merchant_account,
+                    key_store,
+                    business_profile,
+                    &payment_intent,
+                )
+                .await
+            }
+        };
+
+        match action_result {
+            // The outgoing webhooks for a void or capture are sent by those flows themselves
+            Ok(()) => {
+                logger::info!(
+                    payment_id = %tracking_data.payment_id,
+                    action = ?policy.action,
+                    "Applied the authorization expiry policy to the payment"
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?;
+            }
+            Err(action_error) if process.retry_count < MAX_AUTHORIZATION_EXPIRY_RETRIES => {
+                logger::warn!(
+                    payment_id = %tracking_data.payment_id,
+                    ?action_error,
+                    "Authorization expiry action failed, retrying"
+                );
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    time::Duration::seconds(AUTHORIZATION_EXPIRY_RETRY_INTERVAL_IN_SECS),
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "flow",
+                        "AuthorizationExpiry",
+                    )],
+                );
+            }
+            Err(action_error) => {
+                logger::error!(
+                    payment_id = %tracking_data.payment_id,
+                    ?action_error,
+                    "Authorization expiry action failed, retries exhausted"
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
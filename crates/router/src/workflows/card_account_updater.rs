@@ -0,0 +1,113 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::payment_methods::account_updater,
+    errors,
+    logger::{self, error},
+    routes::AppState,
+    types::storage,
+};
+
+pub struct CardAccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.card_account_updater;
+        let tracking_data: storage::CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")?;
+
+        if !config.enabled {
+            // Saving a card after the updater is enabled again restarts the task
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "UPDATER_DISABLED".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        let batch_length = account_updater::run_account_updater_batch(
+            state,
+            &merchant_account,
+            &key_store,
+            tracking_data.offset,
+        )
+        .await?;
+        logger::info!(
+            merchant_id = %tracking_data.merchant_id,
+            offset = tracking_data.offset,
+            batch_length,
+            "Checked saved cards for account updates"
+        );
+
+        // A full batch means there may be more saved cards, which are picked up right away. The
+        // next pass over all the saved cards starts after the configured interval.
+        let (next_offset, schedule_time) =
+            if i64::try_from(batch_length).unwrap_or(i64::MAX) >= config.batch_size {
+                (
+                    tracking_data.offset.saturating_add(config.batch_size),
+                    date_time::now(),
+                )
+            } else {
+                (0, account_updater::get_next_run_time(config))
+            };
+        account_updater::reschedule_account_updater_task(
+            state,
+            &tracking_data.merchant_id,
+            next_offset,
+            schedule_time,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed to check saved cards for account updates");
+
+        // The task must stay scheduled, otherwise the merchant's saved cards are never checked
+        // again. The failed batch is not retried right away; the next pass starts over from the
+        // first saved card after the configured interval.
+        let tracking_data: storage::CardAccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardAccountUpdaterTrackingData")
+            .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+        account_updater::reschedule_account_updater_task(
+            state,
+            &tracking_data.merchant_id,
+            0,
+            account_updater::get_next_run_time(&state.conf.card_account_updater),
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
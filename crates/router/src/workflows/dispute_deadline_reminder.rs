@@ -0,0 +1,204 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::disputes,
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    types::storage::{self, enums},
+};
+
+pub struct DisputeDeadlineReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeDeadlineReminderWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::DisputeDeadlineReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineReminderTrackingData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        if !(dispute.dispute_stage == enums::DisputeStage::Dispute
+            && dispute.dispute_status == enums::DisputeStatus::DisputeOpened)
+        {
+            // Evidence was submitted or the dispute was closed before the deadline
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "DISPUTE_CLOSED".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let retry_count = process.retry_count;
+        let days_left = tracking_data
+            .reminder_days
+            .get(
+                usize::try_from(retry_count)
+                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+            )
+            .copied()
+            .ok_or(errors::ProcessTrackerError::EApiErrorResponse)?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        #[cfg(feature = "email")]
+        send_reminder_email(state, &merchant_account, &dispute, days_left).await;
+
+        disputes::trigger_deadline_reminder_webhook(
+            state,
+            &merchant_account,
+            &key_store,
+            &dispute,
+            days_left,
+        )
+        .await?;
+        logger::info!(
+            dispute_id = %dispute.dispute_id,
+            %days_left,
+            "Sent dispute deadline reminder"
+        );
+
+        match tracking_data
+            .reminder_days
+            .get(
+                usize::try_from(retry_count + 1)
+                    .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?,
+            )
+            .copied()
+        {
+            // If tasks are remaining that has to be scheduled
+            Some(next_reminder_day) => {
+                let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(retry_count + 1),
+                    schedule_time: Some(disputes::get_reminder_time(
+                        tracking_data.challenge_required_by,
+                        next_reminder_day,
+                    )),
+                    tracking_data: None,
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                db.process_tracker_update_process_status_by_ids(
+                    vec![process.id.clone()],
+                    updated_process_tracker_data,
+                )
+                .await?;
+                // Remaining tasks are re-scheduled, so will be resetting the added count
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes(
+                        "flow",
+                        "DisputeDeadlineReminder",
+                    )],
+                );
+            }
+            // If all the reminders have been sent
+            None => {
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
+
+/// Emails the merchant about the approaching deadline. Failures are logged, so that the webhook
+/// reminder is still sent.
+#[cfg(feature = "email")]
+async fn send_reminder_email(
+    state: &AppState,
+    merchant_account: &crate::types::domain::MerchantAccount,
+    dispute: &storage::Dispute,
+    days_left: u8,
+) {
+    use crate::{
+        services::email::types::DisputeDeadlineReminder,
+        types::{api, domain},
+    };
+
+    let result: Result<(), errors::ProcessTrackerError> = async {
+        let Some(email_id) = merchant_account
+            .merchant_details
+            .clone()
+            .parse_value::<api::MerchantDetails>("MerchantDetails")?
+            .primary_email
+        else {
+            logger::debug!(
+                merchant_id = %merchant_account.merchant_id,
+                "Merchant does not have a primary email configured; skipping reminder email"
+            );
+            return Ok(());
+        };
+
+        let email_contents = DisputeDeadlineReminder {
+            recipient_email: domain::UserEmail::from_pii_email(email_id).map_err(|err| {
+                logger::error!(%err, "Failed to convert recipient's email to UserEmail from pii::Email");
+                errors::ProcessTrackerError::EApiErrorResponse
+            })?,
+            subject: "Dispute Evidence Deadline Approaching",
+            days_left,
+            dispute: dispute.clone(),
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .map_err(errors::ProcessTrackerError::EEmailError)?;
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            dispute_id = %dispute.dispute_id,
+            ?error,
+            "Failed to send dispute deadline reminder email"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use api_models::payments as payment_types;
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::{
+        fraud_check::review_queue,
+        payments::{self, CallConnectorAction},
+    },
+    errors, logger,
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Number of times a failing task is retried before it is given up
+const MAX_FRM_REVIEW_DEADLINE_RETRIES: i32 = 5;
+/// Time (in seconds) to wait before the first retry of a failing task
+const FRM_REVIEW_DEADLINE_RETRY_INTERVAL_IN_SECS: i64 = 60;
+
+pub struct FrmReviewDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for FrmReviewDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::FrmReviewDeadlineTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("FrmReviewDeadlineTrackingData")?;
+
+        let fraud_check = db
+            .find_fraud_check_by_payment_id(
+                tracking_data.payment_id.clone(),
+                tracking_data.merchant_id.clone(),
+            )
+            .await?;
+
+        if fraud_check.frm_status != enums::FraudCheckStatus::ManualReview
+            || fraud_check.review_decision.is_some()
+        {
+            // The payment was decided by a reviewer before the deadline
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "ALREADY_REVIEWED".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        if tracking_data.expiry_decision.is_approved() {
+            Box::pin(payments::payments_core::<
+                api::Capture,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account.clone(),
+                key_store.clone(),
+                payments::PaymentApprove,
+                payment_types::PaymentsCaptureRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                api::HeaderPayload::default(),
+            ))
+            .await?;
+        } else {
+            Box::pin(payments::payments_core::<
+                api::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account.clone(),
+                key_store.clone(),
+                payments::PaymentReject,
+                payment_types::PaymentsCancelRequest {
+                    payment_id: tracking_data.payment_id.clone(),
+                    cancellation_reason: Some("Manual review deadline expired".to_string()),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                CallConnectorAction::Trigger,
+                None,
+                api::HeaderPayload::default(),
+            ))
+            .await?;
+        }
+
+        review_queue::record_review_decision(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.payment_id,
+            tracking_data.expiry_decision,
+        )
+        .await;
+        logger::info!(
+            payment_id = %tracking_data.payment_id,
+            decision = %tracking_data.expiry_decision,
+            "Decided payment on expiry of its manual review deadline"
+        );
+
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        // The payment stays held for review until the deadline is acted upon, so failures are
+        // retried before the task is given up
+        if process.retry_count >= MAX_FRM_REVIEW_DEADLINE_RETRIES {
+            return consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                .await;
+        }
+
+        logger::warn!(%process.id, ?error, "Manual review deadline task failed, retrying");
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            get_retry_delay_in_secs(process.retry_count),
+        ));
+        state
+            .store
+            .as_scheduler()
+            .retry_process(process, schedule_time)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+        metrics::TASKS_RESET_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::request::add_attributes(
+                "flow",
+                "FrmReviewDeadline",
+            )],
+        );
+
+        Ok(())
+    }
+}
+
+/// Retries back off exponentially, starting at [`FRM_REVIEW_DEADLINE_RETRY_INTERVAL_IN_SECS`]
+fn get_retry_delay_in_secs(retry_count: i32) -> i64 {
+    let exponent = u32::try_from(retry_count).unwrap_or_default();
+    FRM_REVIEW_DEADLINE_RETRY_INTERVAL_IN_SECS.saturating_mul(2_i64.saturating_pow(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_retry_delay_in_secs() {
+        assert_eq!(get_retry_delay_in_secs(0), 60);
+        assert_eq!(get_retry_delay_in_secs(1), 120);
+        assert_eq!(get_retry_delay_in_secs(4), 960);
+        assert_eq!(get_retry_delay_in_secs(-1), 60);
+        assert_eq!(get_retry_delay_in_secs(i32::MAX), i64::MAX);
+    }
+}
@@ -0,0 +1,126 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::merchant_key_rotation,
+    errors,
+    logger::{self, error},
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct MerchantKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for MerchantKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::MerchantKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyRotationTrackingData")?;
+
+        let rotation = db
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(
+                &tracking_data.merchant_id,
+                &tracking_data.rotation_id,
+            )
+            .await?;
+
+        if rotation.status != enums::KeyRotationStatus::InProgress {
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    "ROTATION_NOT_IN_PROGRESS".to_string(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let rotation = merchant_key_rotation::re_encrypt_next_batches(
+            db,
+            &key_store,
+            merchant_account.storage_scheme,
+            rotation,
+        )
+        .await?;
+        logger::info!(
+            rotation_id = %rotation.rotation_id,
+            records_re_encrypted = rotation.records_re_encrypted,
+            "Re-encrypted merchant data with rotated key"
+        );
+
+        if rotation.status == enums::KeyRotationStatus::Completed {
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await?;
+        } else {
+            merchant_key_rotation::reschedule_key_rotation_task(db, &process, false).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+
+        if process.retry_count < merchant_key_rotation::KEY_ROTATION_MAX_RETRIES {
+            merchant_key_rotation::reschedule_key_rotation_task(&*state.store, &process, true)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+            return Ok(());
+        }
+
+        // The rotation is left in a failed state with the progress made so far, so that it can
+        // be resumed once the cause of the failure has been addressed
+        let tracking_data: storage::MerchantKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantKeyRotationTrackingData")
+            .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+        let rotation = state
+            .store
+            .find_merchant_key_rotation_by_merchant_id_rotation_id(
+                &tracking_data.merchant_id,
+                &tracking_data.rotation_id,
+            )
+            .await
+            .change_context(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.rotation_id.clone(),
+            })?;
+        merchant_key_rotation::fail_key_rotation(&*state.store, &rotation, error.to_string())
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        state
+            .get_db()
+            .as_scheduler()
+            .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+            .await
+    }
+}
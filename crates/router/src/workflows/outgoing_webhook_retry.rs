@@ -1,4 +1,5 @@
 use api_models::{
+    admin::WebhookRetryPolicy,
     enums::EventType,
     webhook_events::OutgoingWebhookRequestContent,
     webhooks::{OutgoingWebhook, OutgoingWebhookContent},
@@ -217,7 +218,8 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
 
 /// Get the schedule time for the specified retry count.
 ///
-/// The schedule time can be configured in configs with this key: `pt_mapping_outgoing_webhooks`.
+/// The retry policy of the business profile is used if one is configured. Otherwise, the schedule
+/// time can be configured in configs with this key: `pt_mapping_outgoing_webhooks`.
 ///
 /// ```json
 /// {
@@ -246,9 +248,14 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
 #[instrument(skip_all)]
 pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     db: &dyn StorageInterface,
-    merchant_id: &str,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
     retry_count: i32,
 ) -> Option<time::PrimitiveDateTime> {
+    if let Some(retry_policy) = webhooks_core::utils::get_webhook_retry_policy(business_profile) {
+        let time_delta = get_retry_policy_delay(&retry_policy, retry_count);
+        return scheduler_utils::get_time_from_delta(time_delta);
+    }
+
     let key = "pt_mapping_outgoing_webhooks";
 
     let result = db
@@ -280,24 +287,59 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
 
     let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
         mapping,
-        merchant_id,
+        &business_profile.merchant_id,
         retry_count,
     );
 
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
-/// Schedule the webhook delivery task for retry
+/// Get the delay in seconds before the specified retry as per the retry policy, or `None` if all
+/// retries allowed by the policy have been exhausted.
+fn get_retry_policy_delay(retry_policy: &WebhookRetryPolicy, retry_count: i32) -> Option<i32> {
+    let retry_count = u32::try_from(retry_count).ok()?;
+    if retry_count >= u32::from(retry_policy.max_retries) {
+        return None;
+    }
+
+    let delay = u32::from(retry_policy.backoff_multiplier)
+        .checked_pow(retry_count)
+        .and_then(|factor| retry_policy.initial_backoff_in_secs.checked_mul(factor))
+        .unwrap_or(u32::MAX)
+        .min(retry_policy.max_backoff_in_secs);
+
+    Some(i32::try_from(delay).unwrap_or(i32::MAX))
+}
+
+/// Schedule the webhook delivery task for retry. No further retries are scheduled for events older
+/// than the maximum event age of the retry policy of the business profile.
 #[instrument(skip_all)]
 pub(crate) async fn retry_webhook_delivery_task(
     db: &dyn StorageInterface,
-    merchant_id: &str,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
     process: storage::ProcessTracker,
 ) -> errors::CustomResult<(), errors::StorageError> {
     let schedule_time =
-        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+        get_webhook_delivery_retry_schedule_time(db, business_profile, process.retry_count + 1)
+            .await;
+
+    let max_event_age_exceeded = webhooks_core::utils::get_webhook_retry_policy(business_profile)
+        .zip(schedule_time)
+        .is_some_and(|(retry_policy, schedule_time)| {
+            process
+                .created_at
+                .saturating_add(time::Duration::seconds(i64::from(
+                    retry_policy.max_event_age_in_secs,
+                )))
+                < schedule_time
+        });
 
     match schedule_time {
+        Some(_) if max_event_age_exceeded => {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "MAX_EVENT_AGE_EXCEEDED".to_string())
+                .await
+        }
         Some(schedule_time) => {
             db.as_scheduler()
                 .retry_process(process, schedule_time)
@@ -329,6 +371,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::webhooks::get_payment_method_webhook_content,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core,
         },
@@ -379,7 +422,16 @@ async fn get_outgoing_webhook_content_and_event_type(
                         })
                     }
                 }?;
-            let event_type = Option::<EventType>::foreign_from(payments_response.status);
+            let event_type = match tracking_data.event_type {
+                // Authorization expiry notifications remain valid while the payment is authorized
+                EventType::PaymentAuthorizationExpiring
+                    if payments_response.status
+                        == api_models::enums::IntentStatus::RequiresCapture =>
+                {
+                    Some(EventType::PaymentAuthorizationExpiring)
+                }
+                _ => Option::<EventType>::foreign_from(payments_response.status),
+            };
             logger::debug!(current_resource_status=%payments_response.status);
 
             Ok((
@@ -474,5 +526,143 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::AnalyticsAlerts => {
+            let alert = state
+                .store
+                .find_analytics_alert_by_merchant_id_alert_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+            let alert_response =
+                api_models::analytics::alerts::AnalyticsAlertResponse::foreign_from(alert);
+
+            Ok((
+                OutgoingWebhookContent::AnalyticsAlertDetails(Box::new(alert_response)),
+                Some(EventType::AnalyticsAlertTriggered),
+            ))
+        }
+
+        diesel_models::enums::EventClass::FraudChecks => {
+            let fraud_check = state
+                .store
+                .find_fraud_check_by_payment_id(
+                    tracking_data.primary_object_id.clone(),
+                    tracking_data.merchant_id.clone(),
+                )
+                .await?;
+            let event_type = fraud_check.review_decision.map(|decision| {
+                if decision.is_approved() {
+                    EventType::FrmApproved
+                } else {
+                    EventType::FrmRejected
+                }
+            });
+            logger::debug!(current_resource_status=?fraud_check.review_decision);
+            let fraud_check_response =
+                api_models::frm_review::FrmReviewQueueItem::foreign_from(fraud_check);
+
+            Ok((
+                OutgoingWebhookContent::FraudCheckDetails(Box::new(fraud_check_response)),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &tracking_data.primary_object_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+            let payment_method_response =
+                get_payment_method_webhook_content(&state, &key_store, &payment_method).await?;
+
+            // Payment method events describe a change rather than a state of the payment method,
+            // so the event being retried is always considered current
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+                Some(tracking_data.event_type),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_retry_policy_delay() {
+        let retry_policy = WebhookRetryPolicy {
+            max_retries: 5,
+            initial_backoff_in_secs: 60,
+            backoff_multiplier: 3,
+            max_backoff_in_secs: 1000,
+            max_event_age_in_secs: 86400,
+            disable_endpoint_after_failing_for_secs: None,
+        };
+
+        let delays = (0..6)
+            .map(|retry_count| get_retry_policy_delay(&retry_policy, retry_count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            vec![Some(60), Some(180), Some(540), Some(1000), Some(1000), None]
+        );
+        assert_eq!(get_retry_policy_delay(&retry_policy, -1), None);
+    }
+
+    #[test]
+    fn test_get_retry_policy_delay_edge_cases() {
+        let retry_policy = WebhookRetryPolicy {
+            max_retries: 3,
+            initial_backoff_in_secs: 120,
+            backoff_multiplier: 1,
+            max_backoff_in_secs: 3600,
+            max_event_age_in_secs: 86400,
+            disable_endpoint_after_failing_for_secs: None,
+        };
+
+        // A backoff multiplier of 1 retries at fixed intervals
+        let delays = (0..4)
+            .map(|retry_count| get_retry_policy_delay(&retry_policy, retry_count))
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![Some(120), Some(120), Some(120), None]);
+
+        // The maximum backoff applies to the first retry as well
+        let low_max_backoff_policy = WebhookRetryPolicy {
+            max_backoff_in_secs: 30,
+            ..retry_policy.clone()
+        };
+        assert_eq!(get_retry_policy_delay(&low_max_backoff_policy, 0), Some(30));
+
+        // Overflowing backoffs are capped at the maximum backoff
+        let overflowing_policy = WebhookRetryPolicy {
+            max_retries: u16::MAX,
+            initial_backoff_in_secs: u32::MAX,
+            backoff_multiplier: u16::MAX,
+            max_backoff_in_secs: u32::MAX,
+            ..retry_policy.clone()
+        };
+        assert_eq!(
+            get_retry_policy_delay(&overflowing_policy, 0),
+            Some(i32::MAX)
+        );
+        assert_eq!(
+            get_retry_policy_delay(&overflowing_policy, 1000),
+            Some(i32::MAX)
+        );
+
+        // No retries once the maximum number of retries is reached
+        let single_retry_policy = WebhookRetryPolicy {
+            max_retries: 1,
+            ..retry_policy
+        };
+        assert_eq!(get_retry_policy_delay(&single_retry_policy, 0), Some(120));
+        assert_eq!(get_retry_policy_delay(&single_retry_policy, 1), None);
     }
 }
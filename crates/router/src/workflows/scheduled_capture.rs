@@ -0,0 +1,151 @@
+use api_models::payments as payment_types;
+use common_utils::ext_traits::ValueExt;
+use scheduler::{workflows::ProcessTrackerWorkflow, SchedulerAppState};
+
+use crate::{
+    core::payments::{self, CallConnectorAction},
+    errors,
+    logger::{self, error},
+    routes::{metrics, AppState},
+    services,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+};
+
+/// Number of times a capture failing with an error is retried before the task is given up
+const MAX_SCHEDULED_CAPTURE_RETRIES: i32 = 3;
+/// Time (in seconds) to wait before retrying a failed capture
+const SCHEDULED_CAPTURE_RETRY_INTERVAL_IN_SECS: i64 = 15 * 60;
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ScheduledCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::payment_attempt::ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                tracking_data.merchant_id.as_str(),
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(tracking_data.merchant_id.as_str(), &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        if payment_intent.status != enums::IntentStatus::RequiresCapture
+            || payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        {
+            // The payment was captured or voided before the scheduled time
+            state
+                .get_db()
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PAYMENT_NOT_CAPTURABLE".to_string())
+                .await?;
+            return Ok(());
+        }
+
+        let capture_response = Box::pin(payments::payments_core::<
+            api::Capture,
+            payment_types::PaymentsResponse,
+            _,
+            _,
+            _,
+        >(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            key_store,
+            payments::PaymentCapture,
+            payment_types::PaymentsCaptureRequest {
+                payment_id: tracking_data.payment_id.clone(),
+                merchant_id: Some(tracking_data.merchant_id.clone()),
+                ..Default::default()
+            },
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        match capture_response {
+            // The outgoing webhook for the capture is sent by the capture flow itself
+            Ok(_) => {
+                logger::info!(
+                    payment_id = %tracking_data.payment_id,
+                    "Captured payment at its scheduled capture time"
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?;
+            }
+            Err(capture_error) if process.retry_count < MAX_SCHEDULED_CAPTURE_RETRIES => {
+                logger::warn!(
+                    payment_id = %tracking_data.payment_id,
+                    ?capture_error,
+                    "Scheduled capture failed, retrying"
+                );
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    time::Duration::seconds(SCHEDULED_CAPTURE_RETRY_INTERVAL_IN_SECS),
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+                metrics::TASKS_RESET_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes("flow", "ScheduledCapture")],
+                );
+            }
+            Err(capture_error) => {
+                logger::error!(
+                    payment_id = %tracking_data.payment_id,
+                    ?capture_error,
+                    "Scheduled capture failed, retries exhausted"
+                );
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::tracing::{self, instrument};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    SchedulerAppState,
+};
+
+use crate::{
+    core::webhooks::{types::WebhookBulkRetryTrackingData, webhook_events},
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct WebhookBulkRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for WebhookBulkRetryWorkflow {
+    #[instrument(skip_all)]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: WebhookBulkRetryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookBulkRetryTrackingData")?;
+
+        match webhook_events::run_bulk_retry_batch(state, &tracking_data).await? {
+            // The next batch is picked up right away, so that the job does not hold on to a
+            // consumer for longer than a single batch
+            Some(next_offset) => {
+                let tracking_data = WebhookBulkRetryTrackingData {
+                    offset: next_offset,
+                    ..tracking_data
+                };
+                let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(common_utils::date_time::now()),
+                    tracking_data: Some(
+                        serde_json::to_value(tracking_data)
+                            .change_context(errors::ProcessTrackerError::SerializationFailed)?,
+                    ),
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                };
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .process_tracker_update_process_status_by_ids(
+                        vec![process.id.clone()],
+                        updated_process_tracker_data,
+                    )
+                    .await?;
+            }
+            None => {
+                state
+                    .get_db()
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
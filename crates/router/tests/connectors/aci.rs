@@ -74,6 +74,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            l2_l3_data: None,
+            network_token_data: None,
             customer_acceptance: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -100,6 +100,8 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         request_incremental_authorization: false,
         metadata: None,
         authentication_data: None,
+        l2_l3_data: None,
+        network_token_data: None,
         customer_acceptance: None,
     })
 }
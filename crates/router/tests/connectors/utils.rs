@@ -941,6 +941,8 @@ impl Default for PaymentAuthorizeType {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            l2_l3_data: None,
+            network_token_data: None,
             customer_acceptance: None,
         };
         Self(data)
@@ -110,6 +110,8 @@ impl WorldlineTest {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            l2_l3_data: None,
+            network_token_data: None,
             customer_acceptance: None,
         })
     }
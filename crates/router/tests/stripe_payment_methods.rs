@@ -0,0 +1,50 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+use actix_web::test::{call_and_read_body_json, call_service, TestRequest};
+use utils::{mk_service, ApiKey, AppClient, MerchantId};
+
+#[actix_web::test]
+async fn detach_unknown_payment_method() {
+    let server = Box::pin(mk_service()).await;
+    let client = AppClient::guest();
+    let admin_client = client.admin("test_admin");
+
+    let hlist_pat![_merchant_id, api_key]: HList![MerchantId, ApiKey] =
+        admin_client.create_merchant_account(&server, None).await;
+
+    // IDs are never matched on their prefix, an ID that looks like a mandate ID but has no
+    // mandate behind it is still looked up as a payment method.
+    for payment_method_id in ["pm_missing", "man_missing"] {
+        let request = TestRequest::post()
+            .uri(&format!(
+                "/vs/v1/payment_methods/{payment_method_id}/detach"
+            ))
+            .append_header(("api-key", api_key.as_str()))
+            .to_request();
+        let response: serde_json::Value = call_and_read_body_json(&server, request).await;
+
+        let error = response.get("error").unwrap();
+        assert_eq!(error.get("code").unwrap(), "resource_missing");
+        assert_eq!(error.get("message").unwrap(), "No such payment method");
+    }
+}
+
+#[actix_web::test]
+async fn attach_payment_method_is_not_routed() {
+    let server = Box::pin(mk_service()).await;
+    let client = AppClient::guest();
+    let admin_client = client.admin("test_admin");
+
+    let hlist_pat![_merchant_id, api_key]: HList![MerchantId, ApiKey] =
+        admin_client.create_merchant_account(&server, None).await;
+
+    let request = TestRequest::post()
+        .uri("/vs/v1/payment_methods/pm_missing/attach")
+        .append_header(("api-key", api_key.as_str()))
+        .to_request();
+    let response = call_service(&server, request).await;
+
+    assert_eq!(response.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
@@ -129,6 +129,8 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Network token lifecycle webhook flow.
+    NetworkTokenWebhook,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -153,6 +155,8 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments authorizations expiring soon list flow.
+    PaymentsAuthorizationsExpiringSoonList,
     // Payments filters flow
     PaymentsFilters,
     #[cfg(feature = "payouts")]
@@ -196,6 +200,10 @@ pub enum Flow {
     RefundsFilters,
     // Retrieve forex flow.
     RetrieveForexFlow,
+    /// Create FX quote flow.
+    CreateFxQuote,
+    /// Retrieve FX quote flow.
+    RetrieveFxQuote,
     /// Toggles recon service for a merchant.
     ReconMerchantUpdate,
     /// Recon token request flow.
@@ -232,6 +240,10 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Retrieve velocity rules for merchant
+    RetrieveVelocityRules,
+    /// Update velocity rules for merchant
+    UpdateVelocityRules,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -250,8 +262,16 @@ pub enum Flow {
     DisputesRetrieve,
     /// Dispute List flow
     DisputesList,
+    /// Disputes expiring soon list flow
+    DisputesExpiringSoonList,
+    /// Dispute evidence draft retrieve flow
+    DisputesEvidenceDraft,
     /// Cards Info flow
     CardsInfo,
+    /// Cards Info bulk import flow
+    CardsInfoImport,
+    /// Cards Info bulk import diff flow
+    CardsInfoImportDiff,
     /// Create File flow
     CreateFile,
     /// Delete File flow
@@ -278,6 +298,20 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment link QR code flow
+    PaymentLinkQrCode,
+    /// Reusable payment link create flow
+    ReusablePaymentLinkCreate,
+    /// Reusable payment link retrieve flow
+    ReusablePaymentLinkRetrieve,
+    /// Reusable payment link list flow
+    ReusablePaymentLinkList,
+    /// Reusable payment link update flow
+    ReusablePaymentLinkUpdate,
+    /// Reusable payment link payments list flow
+    ReusablePaymentLinkPaymentsList,
+    /// Reusable payment link initiate flow
+    ReusablePaymentLinkInitiate,
     /// Create a business profile
     BusinessProfileCreate,
     /// Update a business profile
@@ -314,8 +348,24 @@ pub enum Flow {
     DecisionManagerDeleteConfig,
     /// Retrieve Decision Manager Config
     DecisionManagerRetrieveConfig,
+    /// Upsert native fraud rules
+    FrmRulesUpsert,
+    /// Delete native fraud rules
+    FrmRulesDelete,
+    /// Retrieve native fraud rules
+    FrmRulesRetrieve,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// List payments pending manual fraud review
+    FrmReviewQueueList,
+    /// Assign a reviewer to a payment pending manual fraud review
+    FrmReviewAssign,
+    /// Upsert manual fraud review policy
+    FrmReviewPolicyUpsert,
+    /// Retrieve manual fraud review policy
+    FrmReviewPolicyRetrieve,
+    /// Delete manual fraud review policy
+    FrmReviewPolicyDelete,
     /// Change password flow
     ChangePassword,
     /// Signout flow
@@ -406,12 +456,26 @@ pub enum Flow {
     TotpBegin,
     /// Verify TOTP
     TotpVerify,
+    /// Begin OIDC single sign on
+    OidcSsoAuthorize,
+    /// Complete OIDC single sign on
+    OidcSsoCallback,
+    /// Retrieve OIDC single sign on configuration
+    OidcSsoConfigRetrieve,
+    /// Create or update OIDC single sign on configuration
+    OidcSsoConfigUpsert,
+    /// Delete OIDC single sign on configuration
+    OidcSsoConfigDelete,
     /// List initial webhook delivery attempts
     WebhookEventInitialDeliveryAttemptList,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Redeliver all webhook events which could not be delivered within a time range
+    WebhookEventBulkDeliveryRetry,
+    /// Retrieve webhook delivery statistics
+    WebhookEventDeliveryStats,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -420,6 +484,16 @@ pub enum Flow {
     ToggleConnectorAgnosticMit,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
+    /// Rotate the data encryption key of a merchant
+    MerchantKeyRotate,
+    /// Retrieve the status of the latest key rotation of a merchant
+    MerchantKeyRotationRetrieve,
+    /// Resume a failed key rotation of a merchant
+    MerchantKeyRotationResume,
+    /// List the recorded changes to configurations
+    ConfigAuditLogList,
+    /// Export the recorded changes to configurations
+    ConfigAuditLogExport,
 }
 
 ///
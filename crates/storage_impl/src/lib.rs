@@ -40,6 +40,7 @@ pub struct RouterStore<T: DatabaseStore> {
     db_store: T,
     cache_store: RedisStore,
     master_encryption_key: StrongSecret<Vec<u8>>,
+    previous_master_encryption_key: Option<StrongSecret<Vec<u8>>>,
     pub request_id: Option<String>,
 }
 
@@ -113,6 +114,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
         })
     }
@@ -121,6 +123,20 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    /// Sets the master key replaced by a master key rotation, which is used to read key stores
+    /// that have not been re-wrapped with the current master key yet
+    pub fn with_previous_master_key(
+        mut self,
+        previous_master_encryption_key: Option<StrongSecret<Vec<u8>>>,
+    ) -> Self {
+        self.previous_master_encryption_key = previous_master_encryption_key;
+        self
+    }
+
+    pub fn previous_master_key(&self) -> Option<&StrongSecret<Vec<u8>>> {
+        self.previous_master_encryption_key.as_ref()
+    }
+
     /// # Panics
     ///
     /// Will panic if `CONNECTOR_AUTH_FILE_PATH` is not set
@@ -139,6 +155,7 @@ impl<T: DatabaseStore> RouterStore<T> {
             db_store,
             cache_store,
             master_encryption_key: encryption_key,
+            previous_master_encryption_key: None,
             request_id: None,
         })
     }
@@ -212,6 +229,10 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         self.router_store.master_key()
     }
 
+    pub fn previous_master_key(&self) -> Option<&StrongSecret<Vec<u8>>> {
+        self.router_store.previous_master_key()
+    }
+
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
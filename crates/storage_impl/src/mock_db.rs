@@ -43,6 +43,7 @@ pub struct MockDb {
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
     pub merchant_key_store: Arc<Mutex<Vec<store::merchant_key_store::MerchantKeyStore>>>,
+    pub merchant_key_rotations: Arc<Mutex<Vec<store::merchant_key_rotation::MerchantKeyRotation>>>,
     pub business_profiles: Arc<Mutex<Vec<store::business_profile::BusinessProfile>>>,
     pub reverse_lookups: Arc<Mutex<Vec<store::ReverseLookup>>>,
     pub payment_link: Arc<Mutex<Vec<store::payment_link::PaymentLink>>>,
@@ -58,6 +59,7 @@ pub struct MockDb {
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
+    pub oidc_sso_configs: Arc<Mutex<Vec<store::oidc_sso_config::OidcSsoConfig>>>,
 }
 
 impl MockDb {
@@ -87,6 +89,7 @@ impl MockDb {
             mandates: Default::default(),
             captures: Default::default(),
             merchant_key_store: Default::default(),
+            merchant_key_rotations: Default::default(),
             business_profiles: Default::default(),
             reverse_lookups: Default::default(),
             payment_link: Default::default(),
@@ -102,6 +105,7 @@ impl MockDb {
             authentications: Default::default(),
             roles: Default::default(),
             user_key_store: Default::default(),
+            oidc_sso_configs: Default::default(),
         })
     }
 }
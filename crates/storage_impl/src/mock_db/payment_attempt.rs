@@ -159,6 +159,12 @@ impl PaymentAttemptInterface for MockDb {
             fingerprint_id: payment_attempt.fingerprint_id,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            authorization_expires_at: payment_attempt.authorization_expires_at,
+            fx_quote_id: payment_attempt.fx_quote_id,
+            presentment_amount: payment_attempt.presentment_amount,
+            presentment_currency: payment_attempt.presentment_currency,
+            fx_rate: payment_attempt.fx_rate,
+            fx_markup_bps: payment_attempt.fx_markup_bps,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -238,4 +244,39 @@ impl PaymentAttemptInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+        &self,
+        merchant_id: &str,
+        expires_after: time::PrimitiveDateTime,
+        expires_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        let mut expiring_attempts: Vec<PaymentAttempt> = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_attempt.status == storage_enums::AttemptStatus::Authorized
+                    && payment_attempt
+                        .authorization_expires_at
+                        .is_some_and(|expires_at| {
+                            expires_at > expires_after && expires_at <= expires_before
+                        })
+            })
+            .cloned()
+            .collect();
+        expiring_attempts.sort_by_key(|payment_attempt| payment_attempt.authorization_expires_at);
+
+        Ok(expiring_attempts
+            .into_iter()
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
 }
@@ -109,6 +109,7 @@ impl PaymentIntentInterface for MockDb {
             session_expiry: new.session_expiry,
             request_external_three_ds_authentication: new.request_external_three_ds_authentication,
             frm_metadata: new.frm_metadata,
+            l2_l3_data: new.l2_l3_data,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -323,6 +323,36 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             er.change_context(new_err)
         })
     }
+
+    #[instrument(skip_all)]
+    async fn find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+        &self,
+        merchant_id: &str,
+        expires_after: time::PrimitiveDateTime,
+        expires_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_authorized_by_merchant_id_authorization_expiry(
+            &conn,
+            merchant_id,
+            expires_after,
+            expires_before,
+            limit,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|attempts| {
+            attempts
+                .into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -415,6 +445,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
                     client_source: payment_attempt.client_source.clone(),
                     client_version: payment_attempt.client_version.clone(),
+                    authorization_expires_at: payment_attempt.authorization_expires_at,
+                    fx_quote_id: payment_attempt.fx_quote_id.clone(),
+                    presentment_amount: payment_attempt.presentment_amount,
+                    presentment_currency: payment_attempt.presentment_currency,
+                    fx_rate: payment_attempt.fx_rate.clone(),
+                    fx_markup_bps: payment_attempt.fx_markup_bps,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1066,6 +1102,26 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             )
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+        &self,
+        merchant_id: &str,
+        expires_after: time::PrimitiveDateTime,
+        expires_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        self.router_store
+            .find_authorized_payment_attempts_by_merchant_id_authorization_expiry(
+                merchant_id,
+                expires_after,
+                expires_before,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 impl DataModelExt for MandateAmountData {
@@ -1199,6 +1255,14 @@ impl DataModelExt for PaymentAttempt {
             fingerprint_id: self.fingerprint_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            authorization_expires_at: self.authorization_expires_at,
+            fx_quote_id: self.fx_quote_id,
+            presentment_amount: self
+                .presentment_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            presentment_currency: self.presentment_currency,
+            fx_rate: self.fx_rate,
+            fx_markup_bps: self.fx_markup_bps,
         }
     }
 
@@ -1265,6 +1329,12 @@ impl DataModelExt for PaymentAttempt {
             fingerprint_id: storage_model.fingerprint_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            authorization_expires_at: storage_model.authorization_expires_at,
+            fx_quote_id: storage_model.fx_quote_id,
+            presentment_amount: storage_model.presentment_amount.map(MinorUnit::new),
+            presentment_currency: storage_model.presentment_currency,
+            fx_rate: storage_model.fx_rate,
+            fx_markup_bps: storage_model.fx_markup_bps,
         }
     }
 }
@@ -1335,6 +1405,14 @@ impl DataModelExt for PaymentAttemptNew {
             fingerprint_id: self.fingerprint_id,
             client_source: self.client_source,
             client_version: self.client_version,
+            authorization_expires_at: self.authorization_expires_at,
+            fx_quote_id: self.fx_quote_id,
+            presentment_amount: self
+                .presentment_amount
+                .map(|amount| amount.get_amount_as_i64()),
+            presentment_currency: self.presentment_currency,
+            fx_rate: self.fx_rate,
+            fx_markup_bps: self.fx_markup_bps,
         }
     }
 
@@ -1399,6 +1477,12 @@ impl DataModelExt for PaymentAttemptNew {
             fingerprint_id: storage_model.fingerprint_id,
             client_source: storage_model.client_source,
             client_version: storage_model.client_version,
+            authorization_expires_at: storage_model.authorization_expires_at,
+            fx_quote_id: storage_model.fx_quote_id,
+            presentment_amount: storage_model.presentment_amount.map(MinorUnit::new),
+            presentment_currency: storage_model.presentment_currency,
+            fx_rate: storage_model.fx_rate,
+            fx_markup_bps: storage_model.fx_markup_bps,
         }
     }
 }
@@ -1523,6 +1607,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount,
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: amount.get_amount_as_i64(),
                 currency,
@@ -1555,6 +1644,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount: presentment_amount.map(|amount| amount.get_amount_as_i64()),
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
             },
             Self::VoidUpdate {
                 status,
@@ -1585,6 +1679,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1606,6 +1701,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1835,6 +1931,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount,
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
             } => Self::ConfirmUpdate {
                 amount: MinorUnit::new(amount),
                 currency,
@@ -1865,6 +1966,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 payment_method_billing_address_id,
                 client_source,
                 client_version,
+                fx_quote_id,
+                presentment_amount: presentment_amount.map(MinorUnit::new),
+                presentment_currency,
+                fx_rate,
+                fx_markup_bps,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1913,6 +2019,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1933,6 +2040,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                authorization_expires_at,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
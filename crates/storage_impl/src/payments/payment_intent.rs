@@ -86,6 +86,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     return_url: new.return_url.clone(),
                     metadata: new.metadata.clone(),
                     frm_metadata: new.frm_metadata.clone(),
+                    l2_l3_data: new.l2_l3_data.clone(),
                     connector_id: new.connector_id.clone(),
                     shipping_address_id: new.shipping_address_id.clone(),
                     billing_address_id: new.billing_address_id.clone(),
@@ -818,6 +819,7 @@ impl DataModelExt for PaymentIntentNew {
             return_url: self.return_url,
             metadata: self.metadata,
             frm_metadata: self.frm_metadata,
+            l2_l3_data: self.l2_l3_data,
             connector_id: self.connector_id,
             shipping_address_id: self.shipping_address_id,
             billing_address_id: self.billing_address_id,
@@ -865,6 +867,7 @@ impl DataModelExt for PaymentIntentNew {
             return_url: storage_model.return_url,
             metadata: storage_model.metadata,
             frm_metadata: storage_model.frm_metadata,
+            l2_l3_data: storage_model.l2_l3_data,
             connector_id: storage_model.connector_id,
             shipping_address_id: storage_model.shipping_address_id,
             billing_address_id: storage_model.billing_address_id,
@@ -949,6 +952,7 @@ impl DataModelExt for PaymentIntent {
             session_expiry: self.session_expiry,
             request_external_three_ds_authentication: self.request_external_three_ds_authentication,
             frm_metadata: self.frm_metadata,
+            l2_l3_data: self.l2_l3_data,
         }
     }
 
@@ -998,6 +1002,7 @@ impl DataModelExt for PaymentIntent {
             request_external_three_ds_authentication: storage_model
                 .request_external_three_ds_authentication,
             frm_metadata: storage_model.frm_metadata,
+            l2_l3_data: storage_model.l2_l3_data,
         }
     }
 }
@@ -1086,6 +1091,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
                 currency,
@@ -1108,6 +1114,7 @@ impl DataModelExt for PaymentIntentUpdate {
                 session_expiry,
                 request_external_three_ds_authentication,
                 frm_metadata,
+                l2_l3_data,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
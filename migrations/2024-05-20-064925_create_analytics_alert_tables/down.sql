@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS analytics_alert_merchant_id_created_at_index;
+DROP TABLE IF EXISTS analytics_alert;
+
+DROP INDEX IF EXISTS analytics_alert_rule_merchant_id_index;
+DROP TABLE IF EXISTS analytics_alert_rule;
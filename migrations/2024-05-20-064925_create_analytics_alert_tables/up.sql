@@ -0,0 +1,41 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_alert_rule (
+    rule_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    name VARCHAR(64) NOT NULL,
+    metric VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    drop_threshold DOUBLE PRECISION NOT NULL,
+    evaluation_window_in_mins INTEGER NOT NULL,
+    baseline_window_in_days INTEGER NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_rule_merchant_id_index ON analytics_alert_rule (merchant_id);
+
+CREATE TABLE IF NOT EXISTS analytics_alert (
+    alert_id VARCHAR(64) PRIMARY KEY,
+    rule_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    metric VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    observed_value DOUBLE PRECISION NOT NULL,
+    baseline_value DOUBLE PRECISION NOT NULL,
+    drop_threshold DOUBLE PRECISION NOT NULL,
+    window_start TIMESTAMP NOT NULL,
+    window_end TIMESTAMP NOT NULL,
+    email_sent BOOLEAN NOT NULL DEFAULT FALSE,
+    webhook_sent BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_merchant_id_created_at_index ON analytics_alert (merchant_id, created_at DESC);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'analytics_alerts';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'analytics_alert_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'analytics_alert_triggered';
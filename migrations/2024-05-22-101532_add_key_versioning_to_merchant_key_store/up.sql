@@ -0,0 +1,22 @@
+-- Your SQL goes here
+ALTER TABLE merchant_key_store
+ADD COLUMN IF NOT EXISTS key_version INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN IF NOT EXISTS previous_key BYTEA,
+ADD COLUMN IF NOT EXISTS previous_key_version INTEGER;
+
+CREATE TABLE IF NOT EXISTS merchant_key_rotation (
+    rotation_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    from_key_version INTEGER NOT NULL,
+    to_key_version INTEGER NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    current_entity VARCHAR(64),
+    last_processed_id VARCHAR(255),
+    records_re_encrypted BIGINT NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS merchant_key_rotation_merchant_id_created_at_index ON merchant_key_rotation (merchant_id, created_at DESC);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_link_reusable_payment_link_id_index;
+
+ALTER TABLE payment_link DROP COLUMN IF EXISTS reusable_payment_link_id;
+
+DROP TABLE IF EXISTS reusable_payment_link;
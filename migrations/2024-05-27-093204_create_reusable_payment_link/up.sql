@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS reusable_payment_link (
+    reusable_payment_link_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    link_to_pay VARCHAR(255) NOT NULL,
+    currency "Currency" NOT NULL,
+    amount INT8,
+    min_amount INT8,
+    max_amount INT8,
+    usage_limit INTEGER,
+    expires_at TIMESTAMP,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    description VARCHAR(255),
+    return_url VARCHAR(255),
+    payment_link_config JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS reusable_payment_link_merchant_id_index ON reusable_payment_link (merchant_id, created_at DESC);
+
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS reusable_payment_link_id VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_link_reusable_payment_link_id_index ON payment_link (reusable_payment_link_id);
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS config_audit_log (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(255),
+    request_id VARCHAR(64),
+    changes JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS config_audit_log_merchant_id_index ON config_audit_log (merchant_id, created_at DESC);
+
+CREATE INDEX IF NOT EXISTS config_audit_log_created_at_index ON config_audit_log (created_at DESC);
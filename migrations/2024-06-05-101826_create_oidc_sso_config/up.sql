@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS oidc_sso_config (
+    org_id VARCHAR(32) PRIMARY KEY,
+    issuer_url VARCHAR(255) NOT NULL,
+    client_id VARCHAR(255) NOT NULL,
+    client_secret BYTEA NOT NULL,
+    redirect_uri VARCHAR(255) NOT NULL,
+    scopes TEXT[] NOT NULL,
+    groups_claim VARCHAR(64) NOT NULL DEFAULT 'groups',
+    role_mappings JSONB NOT NULL,
+    default_role_id VARCHAR(64),
+    provisioning_merchant_id VARCHAR(64) NOT NULL,
+    is_sso_enforced BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_email';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'customer_id';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'billing_country';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'device_fingerprint';
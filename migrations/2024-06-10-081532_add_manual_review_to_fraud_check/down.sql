@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS fraud_check_merchant_id_frm_status_index;
+
+ALTER TABLE fraud_check
+DROP COLUMN IF EXISTS review_assigned_to,
+DROP COLUMN IF EXISTS review_deadline,
+DROP COLUMN IF EXISTS review_decision;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TABLE fraud_check
+ADD COLUMN IF NOT EXISTS review_assigned_to VARCHAR(64),
+ADD COLUMN IF NOT EXISTS review_deadline TIMESTAMP,
+ADD COLUMN IF NOT EXISTS review_decision VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS fraud_check_merchant_id_frm_status_index ON fraud_check (merchant_id, frm_status);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'fraud_checks';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'fraud_check_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'frm_approved';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'frm_rejected';
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS dispute_merchant_id_challenge_required_by_index ON dispute (merchant_id, challenge_required_by);
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_deadline_approaching';
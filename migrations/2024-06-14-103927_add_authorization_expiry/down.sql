@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS authorization_expiry_policy;
+
+DROP INDEX IF EXISTS payment_attempt_merchant_id_authorization_expires_at_index;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS authorization_expires_at;
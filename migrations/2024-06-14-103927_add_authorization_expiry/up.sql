@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS authorization_expires_at TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_authorization_expires_at_index ON payment_attempt (merchant_id, authorization_expires_at);
+
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS authorization_expiry_policy JSONB;
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_authorization_expiring';
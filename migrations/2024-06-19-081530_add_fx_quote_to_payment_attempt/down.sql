@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS fx_quote_id,
+DROP COLUMN IF EXISTS presentment_amount,
+DROP COLUMN IF EXISTS presentment_currency,
+DROP COLUMN IF EXISTS fx_rate,
+DROP COLUMN IF EXISTS fx_markup_bps;
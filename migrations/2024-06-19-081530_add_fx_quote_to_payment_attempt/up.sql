@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS fx_quote_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS presentment_amount BIGINT,
+ADD COLUMN IF NOT EXISTS presentment_currency "Currency",
+ADD COLUMN IF NOT EXISTS fx_rate VARCHAR(64),
+ADD COLUMN IF NOT EXISTS fx_markup_bps INTEGER;
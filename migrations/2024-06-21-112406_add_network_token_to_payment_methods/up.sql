@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS network_token_reference_id VARCHAR(128),
+ADD COLUMN IF NOT EXISTS network_token_locker_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS network_token_status VARCHAR(32),
+ADD COLUMN IF NOT EXISTS network_token_details JSONB;
+
+CREATE INDEX IF NOT EXISTS payment_methods_network_token_reference_id_index ON payment_methods (network_token_reference_id);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_payment_method_index ON payment_methods (merchant_id, payment_method);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_created';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_deleted';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'default_payment_method_changed';
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_business_profile_id_created_at_index;
+
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS webhook_retry_policy,
+DROP COLUMN IF EXISTS webhook_endpoint_failing_since,
+DROP COLUMN IF EXISTS webhook_endpoint_disabled_at;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS webhook_retry_policy JSONB DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS webhook_endpoint_failing_since TIMESTAMP DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS webhook_endpoint_disabled_at TIMESTAMP DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS events_business_profile_id_created_at_index ON events (business_profile_id, created_at);